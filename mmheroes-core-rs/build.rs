@@ -0,0 +1,11 @@
+fn main() {
+    // cbindgen раскрывает макросы крейта с помощью `cargo rustc -Zunpretty=expanded`
+    // и выставляет при этом эту переменную окружения. По `cfg(cbindgen)` из разбора
+    // исключаются модули, которые не нужны FFI и которые cbindgen не умеет разбирать
+    // (см. `[parse.expand]` в cbindgen.toml).
+    println!("cargo::rustc-check-cfg=cfg(cbindgen)");
+    println!("cargo::rerun-if-env-changed=_CBINDGEN_IS_RUNNING");
+    if std::env::var_os("_CBINDGEN_IS_RUNNING").is_some() {
+        println!("cargo::rustc-cfg=cbindgen");
+    }
+}
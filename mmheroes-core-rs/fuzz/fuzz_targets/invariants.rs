@@ -0,0 +1,203 @@
+//! Играет в игру с произвольными зерном, режимом и нажатиями клавиш и после каждого
+//! нажатия проверяет инварианты состояния игры и отрисовки.
+//!
+//! Запуск: `cargo fuzz run invariants`.
+
+#![no_main]
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+use mmheroes_core::logic::{
+    create_game, CauseOfDeath, GameMode, GameState, SemesterLength, StateHolder, Time,
+};
+use mmheroes_core::ui::recording::NoInputRecording;
+use mmheroes_core::ui::renderer::{
+    Column, Line, RendererRequest, RendererRequestConsumer,
+};
+use mmheroes_core::ui::{GameUI, Input, Locale, TERMINAL_HEIGHT, TERMINAL_WIDTH};
+
+#[derive(Arbitrary, Debug)]
+enum FuzzedMode {
+    Normal,
+    SelectInitialParameters,
+    God,
+}
+
+impl From<FuzzedMode> for GameMode {
+    fn from(mode: FuzzedMode) -> Self {
+        match mode {
+            FuzzedMode::Normal => GameMode::Normal,
+            FuzzedMode::SelectInitialParameters => GameMode::SelectInitialParameters,
+            FuzzedMode::God => GameMode::God,
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+enum FuzzedLocale {
+    Russian,
+    English,
+}
+
+impl From<FuzzedLocale> for Locale {
+    fn from(locale: FuzzedLocale) -> Self {
+        match locale {
+            FuzzedLocale::Russian => Locale::Russian,
+            FuzzedLocale::English => Locale::English,
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug, Copy, Clone)]
+enum FuzzedInput {
+    KeyUp,
+    KeyDown,
+    Enter,
+    Other,
+    Character(char),
+    Backspace,
+    Undo,
+    Hotkey(char),
+    Escape,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    PointerAt { line: Line, column: Column },
+    Click { line: Line, column: Column },
+}
+
+impl From<FuzzedInput> for Input {
+    fn from(input: FuzzedInput) -> Self {
+        match input {
+            FuzzedInput::KeyUp => Input::KeyUp,
+            FuzzedInput::KeyDown => Input::KeyDown,
+            FuzzedInput::Enter => Input::Enter,
+            FuzzedInput::Other => Input::Other,
+            FuzzedInput::Character(c) => Input::Character(c),
+            FuzzedInput::Backspace => Input::Backspace,
+            FuzzedInput::Undo => Input::Undo,
+            FuzzedInput::Hotkey(c) => Input::Hotkey(c),
+            FuzzedInput::Escape => Input::Escape,
+            FuzzedInput::Home => Input::Home,
+            FuzzedInput::End => Input::End,
+            FuzzedInput::PageUp => Input::PageUp,
+            FuzzedInput::PageDown => Input::PageDown,
+            FuzzedInput::PointerAt { line, column } => Input::PointerAt { line, column },
+            FuzzedInput::Click { line, column } => Input::Click { line, column },
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzedGame {
+    seed: u64,
+    mode: FuzzedMode,
+    locale: FuzzedLocale,
+    semester_length: u8,
+    inputs: Vec<FuzzedInput>,
+}
+
+impl FuzzedGame {
+    fn semester_length(&self) -> SemesterLength {
+        let min = SemesterLength::MIN.days();
+        let max = SemesterLength::MAX.days();
+        SemesterLength::new(min + self.semester_length % (max - min + 1)).unwrap()
+    }
+}
+
+/// Следит за тем, чтобы курсор не выходил за пределы экрана.
+struct CursorChecker {
+    line: usize,
+    column: usize,
+}
+
+impl CursorChecker {
+    fn check(&self) {
+        assert!(
+            self.line < TERMINAL_HEIGHT && self.column <= TERMINAL_WIDTH,
+            "The cursor is out of the screen: line {}, column {}",
+            self.line,
+            self.column
+        );
+    }
+}
+
+impl RendererRequestConsumer for CursorChecker {
+    fn consume_request(&mut self, request: RendererRequest) {
+        match request {
+            RendererRequest::ClearScreen => {
+                self.line = 0;
+                self.column = 0;
+            }
+            RendererRequest::WriteStr(s) => {
+                for c in s.chars() {
+                    if c == '\n' {
+                        self.line += 1;
+                        self.column = 0;
+                    } else {
+                        if self.column == TERMINAL_WIDTH {
+                            self.line += 1;
+                            self.column = 0;
+                        }
+                        self.column += 1;
+                    }
+                    self.check();
+                }
+            }
+            RendererRequest::MoveCursor { line, column } => {
+                self.line = line as usize;
+                self.column = column as usize;
+                self.check();
+            }
+            RendererRequest::Flush
+            | RendererRequest::SetColor { .. }
+            | RendererRequest::Sleep(_) => (),
+        }
+    }
+}
+
+fn check_game_state(state: &GameState) {
+    let player = state.player();
+    let cause_of_death = player.cause_of_death();
+    if cause_of_death.is_none() {
+        assert!(player.health() > 0, "Alive with health {}", player.health());
+        assert!(player.brain() > 0, "Alive with brain {}", player.brain());
+    }
+    assert!(state.current_time() < Time(24));
+    // Если семестр закончился, день равен его продолжительности, но тогда игрок
+    // уже мёртв.
+    assert!(
+        state.current_day_index() < state.timetable().length().days()
+            || cause_of_death == Some(CauseOfDeath::TimeOut),
+        "Day index {} is out of the timetable",
+        state.current_day_index()
+    );
+}
+
+fuzz_target!(|game: FuzzedGame| {
+    let semester_length = game.semester_length();
+    let state_holder =
+        StateHolder::new(game.mode.into()).with_semester_length(semester_length);
+    let pinned_game = core::pin::pin!(create_game(game.seed, &state_holder));
+    let mut game_ui = GameUI::new(
+        &state_holder,
+        pinned_game,
+        game.seed,
+        None,
+        CursorChecker { line: 0, column: 0 },
+        None::<NoInputRecording>,
+        game.locale.into(),
+    );
+
+    for input in game.inputs {
+        let game_continues = game_ui.continue_game(input.into());
+        assert!(!game_ui.has_bug(), "The game has crashed");
+        if let Some(state) = state_holder.observable_state().screen().state() {
+            check_game_state(state);
+        }
+        if !game_continues {
+            break;
+        }
+    }
+});
@@ -14,37 +14,366 @@
  */
 #define MMHEROES_MAX_OPTIONS_IN_SCENE_ROUTER 12
 
-#define MMHEROES_NUM_DAYS 6
+/**
+ * Наибольшая продолжительность сессии в днях.
+ */
+#define MMHEROES_MAX_NUM_DAYS 14
+
+/**
+ * Версия формата сохранения. Увеличивается при любом несовместимом изменении
+ * формата.
+ */
+#define MMHEROES_SAVED_GAME_FORMAT_VERSION 3
+
+/**
+ * Размер сохранения в байтах.
+ */
+#define MMHEROES_SAVED_GAME_SIZE 296
 
 #define MMHEROES_TERMINAL_WIDTH 80
 
 #define MMHEROES_TERMINAL_HEIGHT 24
 
+/**
+ * Версия формата повтора. Увеличивается при любом несовместимом изменении
+ * формата.
+ */
+#define MMHEROES_REPLAY_FORMAT_VERSION 1
+
 #define MMHEROES_SCORE_COUNT 5
 
 #define MMHEROES_RECORD_SIZE 35
 
 #define MMHEROES_BUFFER_SIZE (MMHEROES_SCORE_COUNT * MMHEROES_RECORD_SIZE)
 
+/**
+ * Максимальная длина имени в таблице рекордов в символах.
+ */
 #define MMHEROES_MAX_NAME_LENGTH 32
 
-typedef enum MMHEROES_Color {
-  MMHEROES_Color_Black = 0,
-  MMHEROES_Color_Red = 1,
-  MMHEROES_Color_Yellow = 3,
-  MMHEROES_Color_Blue = 4,
-  MMHEROES_Color_Magenta = 5,
-  MMHEROES_Color_Cyan = 6,
-  MMHEROES_Color_White = 7,
-  MMHEROES_Color_Gray = 8,
-  MMHEROES_Color_RedBright = 9,
-  MMHEROES_Color_Green = 10,
-  MMHEROES_Color_YellowBright = 11,
-  MMHEROES_Color_BlueBright = 12,
-  MMHEROES_Color_MagentaBright = 13,
-  MMHEROES_Color_CyanBright = 14,
-  MMHEROES_Color_WhiteBright = 15,
-} MMHEROES_Color;
+/**
+ * Сколько последних ходов можно отменить.
+ */
+#define MMHEROES_UNDO_DEPTH 16
+
+enum MMHEROES_HealthAssessment
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * "живой труп"
+   */
+  MMHEROES_HealthAssessment_LivingDead,
+  /**
+   * "пора помирать ..."
+   */
+  MMHEROES_HealthAssessment_TimeToDie,
+  /**
+   * "плохое"
+   */
+  MMHEROES_HealthAssessment_Bad,
+  /**
+   * "так себе"
+   */
+  MMHEROES_HealthAssessment_SoSo,
+  /**
+   * "среднее"
+   */
+  MMHEROES_HealthAssessment_Average,
+  /**
+   * "хорошее"
+   */
+  MMHEROES_HealthAssessment_Good,
+  /**
+   * "отличное"
+   */
+  MMHEROES_HealthAssessment_Great,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum MMHEROES_HealthAssessment MMHEROES_HealthAssessment;
+#else
+typedef uint8_t MMHEROES_HealthAssessment;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+enum MMHEROES_BrainAssessment
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * "Клиническая смерть мозга"
+   */
+  MMHEROES_BrainAssessment_ClinicalBrainDeath,
+  /**
+   * "Голова просто никакая"
+   */
+  MMHEROES_BrainAssessment_BrainIsAlmostNonFunctioning,
+  /**
+   * "Думать практически невозможно"
+   */
+  MMHEROES_BrainAssessment_ThinkingIsAlmostImpossible,
+  /**
+   * "Думать трудно"
+   */
+  MMHEROES_BrainAssessment_ThinkingIsDifficult,
+  /**
+   * "Голова почти в норме"
+   */
+  MMHEROES_BrainAssessment_BrainIsAlmostOK,
+  /**
+   * "Голова в норме"
+   */
+  MMHEROES_BrainAssessment_BrainIsOK,
+  /**
+   * "Голова свежая"
+   */
+  MMHEROES_BrainAssessment_BrainIsFresh,
+  /**
+   * "Легкость в мыслях необыкновенная"
+   */
+  MMHEROES_BrainAssessment_ExtraordinaryEaseOfThought,
+  /**
+   * "Обратитесь к разработчику ;)"
+   */
+  MMHEROES_BrainAssessment_ContactTheDeveloper,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum MMHEROES_BrainAssessment MMHEROES_BrainAssessment;
+#else
+typedef uint8_t MMHEROES_BrainAssessment;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+enum MMHEROES_StaminaAssessment
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * "Мама, роди меня обратно!"
+   */
+  MMHEROES_StaminaAssessment_MamaTakeMeBack,
+  /**
+   * "Окончательно заучился"
+   */
+  MMHEROES_StaminaAssessment_CompletelyOverstudied,
+  /**
+   * "Я так больше немогууу!"
+   */
+  MMHEROES_StaminaAssessment_ICantTakeIt,
+  /**
+   * "Скорее бы все это кончилось..."
+   */
+  MMHEROES_StaminaAssessment_IWishItAllEndedSoon,
+  /**
+   * "Еще немного и пора отдыхать"
+   */
+  MMHEROES_StaminaAssessment_ALittleMoreAndThenRest,
+  /**
+   * "Немного устал"
+   */
+  MMHEROES_StaminaAssessment_ABitTired,
+  /**
+   * "Готов к труду и обороне"
+   */
+  MMHEROES_StaminaAssessment_ReadyForEverything,
+  /**
+   * "Нас ждут великие дела"
+   */
+  MMHEROES_StaminaAssessment_GreatThingsAwaitUs,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum MMHEROES_StaminaAssessment MMHEROES_StaminaAssessment;
+#else
+typedef uint8_t MMHEROES_StaminaAssessment;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+enum MMHEROES_CharismaAssessment
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * "Очень замкнутый товарищ"
+   */
+  MMHEROES_CharismaAssessment_VeryIntroverted,
+  /**
+   * "Предпочитаешь одиночество"
+   */
+  MMHEROES_CharismaAssessment_PreferSolitariness,
+  /**
+   * "Тебе трудно общаться с людьми"
+   */
+  MMHEROES_CharismaAssessment_VeryHardToTalkToPeople,
+  /**
+   * "Тебе непросто общаться с людьми"
+   */
+  MMHEROES_CharismaAssessment_NotEasyToTalkToPeople,
+  /**
+   * "Ты нормально относишься к окружающим"
+   */
+  MMHEROES_CharismaAssessment_Normal,
+  /**
+   * "У тебя много друзей"
+   */
+  MMHEROES_CharismaAssessment_ManyFriends,
+  /**
+   * "У тебя очень много друзей"
+   */
+  MMHEROES_CharismaAssessment_TonsOfFriends,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum MMHEROES_CharismaAssessment MMHEROES_CharismaAssessment;
+#else
+typedef uint8_t MMHEROES_CharismaAssessment;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+enum MMHEROES_Subject
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  MMHEROES_Subject_AlgebraAndNumberTheory = 0,
+  MMHEROES_Subject_Calculus,
+  MMHEROES_Subject_GeometryAndTopology,
+  MMHEROES_Subject_ComputerScience,
+  MMHEROES_Subject_English,
+  MMHEROES_Subject_PhysicalEducation,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum MMHEROES_Subject MMHEROES_Subject;
+#else
+typedef uint8_t MMHEROES_Subject;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+enum MMHEROES_KnowledgeAssessment
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  MMHEROES_KnowledgeAssessment_Bad,
+  MMHEROES_KnowledgeAssessment_Satisfactory,
+  MMHEROES_KnowledgeAssessment_Good,
+  MMHEROES_KnowledgeAssessment_VeryGood,
+  MMHEROES_KnowledgeAssessment_Excellent,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum MMHEROES_KnowledgeAssessment MMHEROES_KnowledgeAssessment;
+#else
+typedef uint8_t MMHEROES_KnowledgeAssessment;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+enum MMHEROES_Location
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  MMHEROES_Location_PUNK = 1,
+  MMHEROES_Location_PDMI = 2,
+  MMHEROES_Location_ComputerClass = 3,
+  MMHEROES_Location_Dorm = 4,
+  MMHEROES_Location_Mausoleum = 5,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum MMHEROES_Location MMHEROES_Location;
+#else
+typedef uint8_t MMHEROES_Location;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+enum MMHEROES_Classmate
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  MMHEROES_Classmate_Kolya = 0,
+  MMHEROES_Classmate_Pasha,
+  MMHEROES_Classmate_Diamond,
+  MMHEROES_Classmate_RAI,
+  MMHEROES_Classmate_Misha,
+  MMHEROES_Classmate_Serj,
+  MMHEROES_Classmate_Sasha,
+  MMHEROES_Classmate_NiL,
+  MMHEROES_Classmate_Kuzmenko,
+  MMHEROES_Classmate_DJuG,
+  MMHEROES_Classmate_Andrew,
+  MMHEROES_Classmate_Grisha,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum MMHEROES_Classmate MMHEROES_Classmate;
+#else
+typedef uint8_t MMHEROES_Classmate;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
+/**
+ * Вид текущего экрана. Варианты соответствуют вариантам `GameScreen` в движке.
+ */
+typedef enum MMHEROES_GameScreen {
+  MMHEROES_GameScreen_Intro,
+  MMHEROES_GameScreen_InitialParameters,
+  MMHEROES_GameScreen_Ding,
+  MMHEROES_GameScreen_Timetable,
+  MMHEROES_GameScreen_SceneRouter,
+  MMHEROES_GameScreen_Study,
+  MMHEROES_GameScreen_PromptUseLectureNotes,
+  MMHEROES_GameScreen_HighScores,
+  MMHEROES_GameScreen_RestInMausoleum,
+  MMHEROES_GameScreen_Cafe,
+  MMHEROES_GameScreen_Midnight,
+  MMHEROES_GameScreen_TrainToPDMI,
+  MMHEROES_GameScreen_TrainFromPDMI,
+  MMHEROES_GameScreen_KolyaInteraction,
+  MMHEROES_GameScreen_PashaInteraction,
+  MMHEROES_GameScreen_GrishaInteraction,
+  MMHEROES_GameScreen_SashaInteraction,
+  MMHEROES_GameScreen_KuzmenkoInteraction,
+  MMHEROES_GameScreen_DiamondInteraction,
+  MMHEROES_GameScreen_SerjInteraction,
+  MMHEROES_GameScreen_MishaInteraction,
+  MMHEROES_GameScreen_AndrewInteraction,
+  MMHEROES_GameScreen_DjugInteraction,
+  MMHEROES_GameScreen_RaiInteraction,
+  MMHEROES_GameScreen_NilInteraction,
+  MMHEROES_GameScreen_Terkom,
+  MMHEROES_GameScreen_GoToProfessor,
+  MMHEROES_GameScreen_ExamIntro,
+  MMHEROES_GameScreen_Exam,
+  MMHEROES_GameScreen_BaltiyskiyRailwayStation,
+  MMHEROES_GameScreen_DontWantToSleep,
+  MMHEROES_GameScreen_CantStayAwake,
+  MMHEROES_GameScreen_NeighborInvites,
+  MMHEROES_GameScreen_Dreaming,
+  MMHEROES_GameScreen_SurfInternet,
+  MMHEROES_GameScreen_PlayMmheroes,
+  MMHEROES_GameScreen_ComputerClassClosing,
+  MMHEROES_GameScreen_IAmDone,
+  MMHEROES_GameScreen_GameEnd,
+  MMHEROES_GameScreen_HighScoreEntry,
+  MMHEROES_GameScreen_WannaTryAgain,
+  MMHEROES_GameScreen_Disclaimer,
+  MMHEROES_GameScreen_WhatToDo,
+  MMHEROES_GameScreen_AboutScreen,
+  MMHEROES_GameScreen_WhereToGoAndWhy,
+  MMHEROES_GameScreen_AboutProfessors,
+  MMHEROES_GameScreen_AboutCharacters,
+  MMHEROES_GameScreen_AboutThisProgram,
+  MMHEROES_GameScreen_Terminal,
+} MMHEROES_GameScreen;
 
 /**
  * The game mode selector.
@@ -72,11 +401,43 @@ typedef enum MMHEROES_GameMode {
   MMHEROES_GameMode_God,
 } MMHEROES_GameMode;
 
+/**
+ * Язык интерфейса. Выбирается при создании [`GameUI`](crate::ui::GameUI).
+ */
+typedef enum MMHEROES_Locale {
+  MMHEROES_Locale_Russian = 0,
+  MMHEROES_Locale_English = 1,
+} MMHEROES_Locale;
+
+typedef enum MMHEROES_Color {
+  MMHEROES_Color_Black = 0,
+  MMHEROES_Color_Red = 1,
+  MMHEROES_Color_Yellow = 3,
+  MMHEROES_Color_Blue = 4,
+  MMHEROES_Color_Magenta = 5,
+  MMHEROES_Color_Cyan = 6,
+  MMHEROES_Color_White = 7,
+  MMHEROES_Color_Gray = 8,
+  MMHEROES_Color_RedBright = 9,
+  MMHEROES_Color_Green = 10,
+  MMHEROES_Color_YellowBright = 11,
+  MMHEROES_Color_BlueBright = 12,
+  MMHEROES_Color_MagentaBright = 13,
+  MMHEROES_Color_CyanBright = 14,
+  MMHEROES_Color_WhiteBright = 15,
+} MMHEROES_Color;
+
 typedef enum MMHEROES_Input {
   MMHEROES_Input_KeyUp,
   MMHEROES_Input_KeyDown,
   MMHEROES_Input_Enter,
   MMHEROES_Input_Other,
+  MMHEROES_Input_Backspace,
+  MMHEROES_Input_Escape,
+  MMHEROES_Input_Home,
+  MMHEROES_Input_End,
+  MMHEROES_Input_PageUp,
+  MMHEROES_Input_PageDown,
 } MMHEROES_Input;
 
 /**
@@ -88,8 +449,77 @@ typedef enum MMHEROES_Input {
  */
 typedef uint8_t MMHEROES_Time;
 
+typedef int16_t MMHEROES_HealthLevel;
+
 typedef int16_t MMHEROES_Money;
 
+typedef int16_t MMHEROES_BrainLevel;
+
+typedef int16_t MMHEROES_StaminaLevel;
+
+typedef int16_t MMHEROES_CharismaLevel;
+
+typedef struct MMHEROES_Player {
+  MMHEROES_HealthLevel health;
+  MMHEROES_Money money;
+  MMHEROES_BrainLevel brain;
+  MMHEROES_StaminaLevel stamina;
+  MMHEROES_CharismaLevel charisma;
+  MMHEROES_HealthAssessment health_assessment;
+  MMHEROES_BrainAssessment brain_assessment;
+  MMHEROES_StaminaAssessment stamina_assessment;
+  MMHEROES_CharismaAssessment charisma_assessment;
+  uint8_t exams_left;
+  bool is_god_mode;
+  bool has_mmheroes_floppy;
+  bool has_internet;
+  bool is_employed_at_terkom;
+  bool got_stipend;
+  bool has_train_ticket;
+  bool knows_djug;
+  bool is_dead;
+} MMHEROES_Player;
+
+typedef struct MMHEROES_SubjectStatus {
+  MMHEROES_BrainLevel knowledge;
+  /**
+   * Оценка знаний по шкале преподавателя этого предмета.
+   */
+  MMHEROES_KnowledgeAssessment knowledge_assessment;
+  uint8_t problems_done;
+  uint8_t required_problems;
+  bool passed;
+  /**
+   * Индекс дня, в который был получен зачёт. Имеет смысл, только если `passed`.
+   */
+  uint8_t passed_exam_day;
+  bool has_lecture_notes;
+} MMHEROES_SubjectStatus;
+
+typedef struct MMHEROES_Exam {
+  MMHEROES_Time from;
+  MMHEROES_Time to;
+  MMHEROES_Location location;
+} MMHEROES_Exam;
+
+typedef enum MMHEROES_ClassmateLocation_Tag {
+  MMHEROES_ClassmateLocation_Nowhere,
+  MMHEROES_ClassmateLocation_Exam,
+  MMHEROES_ClassmateLocation_Location,
+} MMHEROES_ClassmateLocation_Tag;
+
+typedef struct MMHEROES_ClassmateLocation {
+  MMHEROES_ClassmateLocation_Tag tag;
+  union {
+    struct {
+      MMHEROES_Subject exam;
+    };
+    struct {
+      MMHEROES_Location location;
+    };
+  };
+} MMHEROES_ClassmateLocation;
+
 typedef struct MMHEROES_HighScore {
   const uint8_t *name;
   uintptr_t name_len;
@@ -105,6 +535,32 @@ typedef void *MMHEROES_AllocatorContext;
  */
 typedef void *(*MMHEROES_Allocator)(MMHEROES_AllocatorContext, uintptr_t, uintptr_t);
 
+/**
+ * Функция, принимающая в качестве первого аргумента некоторый контекст,
+ * в качестве второго — указатель на освобождаемый блок памяти,
+ * а в качестве третьего — размер освобождаемого блока.
+ */
+typedef void (*MMHEROES_Deallocator)(MMHEROES_AllocatorContext, void*, uintptr_t);
+
+typedef struct MMHEROES_InputRecorderSink {
+  void *context;
+  bool (*sink)(void*, const uint8_t*, uintptr_t);
+  bool (*display)(void*, void*);
+} MMHEROES_InputRecorderSink;
+
+/**
+ * Запросы на отрисовку, накопленные при последнем вызове, который продолжает игру:
+ * `mmheroes_continue`, `mmheroes_continue_with_character`, `mmheroes_undo`,
+ * `mmheroes_perform_action` или `mmheroes_replay`.
+ *
+ * Итератор и строки из запросов `MMHEROES_RendererRequest_WriteStr` действительны
+ * до следующего такого вызова или до удаления игры.
+ */
+typedef struct MMHEROES_RendererRequestIterator {
+  const void *game;
+  uintptr_t next;
+} MMHEROES_RendererRequestIterator;
+
 typedef int32_t MMHEROES_Milliseconds;
 
 typedef enum MMHEROES_RendererRequest_Tag {
@@ -145,21 +601,6 @@ typedef struct MMHEROES_RendererRequest {
   };
 } MMHEROES_RendererRequest;
 
-typedef void (*MMHEROES_RendererRequestCallback)(void*, struct MMHEROES_RendererRequest);
-
-typedef struct MMHEROES_InputRecorderSink {
-  void *context;
-  bool (*sink)(void*, const uint8_t*, uintptr_t);
-  bool (*display)(void*, void*);
-} MMHEROES_InputRecorderSink;
-
-/**
- * Функция, принимающая в качестве первого аргумента некоторый контекст,
- * в качестве второго — указатель на освобождаемый блок памяти,
- * а в качестве третьего — размер освобождаемого блока.
- */
-typedef void (*MMHEROES_Deallocator)(MMHEROES_AllocatorContext, void*, uintptr_t);
-
 #define MMHEROES_WORKDAY_BEGINS 9
 
 #define MMHEROES_WORKDAY_ENDS 18
@@ -179,6 +620,55 @@ bool mmheroes_game_get_current_time(const void *game,
                                     uint8_t *out_day,
                                     MMHEROES_Time *out_time);
 
+/**
+ * Возвращает продолжительность сессии в днях. Если игра продолжена
+ * из сохранения, продолжительность берётся из сохранения.
+ */
+uint8_t mmheroes_game_get_semester_length(const void *game);
+
+/**
+ * Записывает характеристики игрока в `out` и возвращает `true`, если они доступны,
+ * иначе не трогает `out` и возвращает `false`.
+ */
+bool mmheroes_game_get_player(const void *game,
+                              struct MMHEROES_Player *out);
+
+/**
+ * Записывает состояние игрока по предмету `subject` в `out` и возвращает `true`,
+ * если оно доступно, иначе не трогает `out` и возвращает `false`.
+ */
+bool mmheroes_game_get_subject_status(const void *game,
+                                      MMHEROES_Subject subject,
+                                      struct MMHEROES_SubjectStatus *out);
+
+/**
+ * Записывает в `out` время и место зачёта по предмету `subject` в день с индексом
+ * `day` и возвращает `true`. Дни нумеруются от 0 до продолжительности сессии
+ * минус один, а сессия длится не больше `MMHEROES_MAX_NUM_DAYS` дней.
+ *
+ * Если расписание недоступно, такого дня нет или зачёта в этот день нет,
+ * не трогает `out` и возвращает `false`.
+ */
+bool mmheroes_game_get_exam(const void *game,
+                            uint8_t day,
+                            MMHEROES_Subject subject,
+                            struct MMHEROES_Exam *out);
+
+/**
+ * Записывает в `out`, где сейчас находится одногруппник `classmate`, и возвращает
+ * `true`, если это известно, иначе не трогает `out` и возвращает `false`.
+ */
+bool mmheroes_game_get_classmate_location(const void *game,
+                                          MMHEROES_Classmate classmate,
+                                          struct MMHEROES_ClassmateLocation *out);
+
+/**
+ * Записывает вид текущего экрана в `out` и возвращает `true`.
+ * Если `game` — нулевой указатель, не трогает `out` и возвращает `false`.
+ */
+bool mmheroes_game_get_screen(const void *game,
+                              enum MMHEROES_GameScreen *out);
+
 /**
  * Выделяет память для объекта, используя переданный аллокатор,
  * а затем инициализирует объект и возвращает на него указатель.
@@ -187,22 +677,60 @@ bool mmheroes_game_get_current_time(const void *game,
  * достаточного размера. Нарушение любого из этих условий — неопределённое поведение.
  *
  * Размер и выравнивание передаются в качестве аргументов аллокатору.
+ * Этим же аллокатором выделяется память для буфера запросов на отрисовку, а
+ * `deallocator` освобождает её, когда буфер растёт, и при удалении игры.
+ *
+ * Параметр `semester_length` — продолжительность сессии в днях, от 3
+ * до `MMHEROES_MAX_NUM_DAYS`. В оригинальной игре сессия длится 6 дней.
+ * Если продолжительность недопустимая, возвращает нулевой указатель.
+ *
+ * Параметр `locale` — язык, на котором выводится игра.
  *
  * Параметр `high_scores` — указатель (возможно нулевой) на массив из
  * `MMHEROES_SCORE_COUNT` элементов.
  */
 void *mmheroes_game_create(enum MMHEROES_GameMode mode,
                            uint64_t seed,
+                           uint8_t semester_length,
+                           enum MMHEROES_Locale locale,
                            const struct MMHEROES_HighScore *high_scores,
                            MMHEROES_AllocatorContext allocator_context,
                            MMHEROES_Allocator allocator,
-                           void *renderer_request_callback_context,
-                           MMHEROES_RendererRequestCallback renderer_request_callback,
+                           MMHEROES_Deallocator deallocator,
                            struct MMHEROES_InputRecorderSink input_recorder_sink);
 
-void mmheroes_game_destroy(void *game,
-                           MMHEROES_AllocatorContext allocator_context,
-                           MMHEROES_Deallocator deallocator);
+/**
+ * То же, что и `mmheroes_game_create`, но вместо новой игры продолжает игру,
+ * сохранённую ранее с помощью `mmheroes_game_save`.
+ *
+ * `saved_game` — ненулевой указатель на сохранение длиной `saved_game_len` байт.
+ * Если сохранение повреждено или сделано в неподдерживаемой версии формата,
+ * возвращает нулевой указатель.
+ */
+void *mmheroes_game_restore(const uint8_t *saved_game,
+                            uintptr_t saved_game_len,
+                            enum MMHEROES_Locale locale,
+                            const struct MMHEROES_HighScore *high_scores,
+                            MMHEROES_AllocatorContext allocator_context,
+                            MMHEROES_Allocator allocator,
+                            MMHEROES_Deallocator deallocator,
+                            struct MMHEROES_InputRecorderSink input_recorder_sink);
+
+/**
+ * Сохраняет игру, записывая `MMHEROES_SAVED_GAME_SIZE` байт в буфер `out`,
+ * и возвращает `true`. `out` не должен быть нулевым указателем.
+ *
+ * Сохранить игру можно только когда на экране главное меню локации. В противном
+ * случае функция не трогает `out` и возвращает `false`.
+ */
+bool mmheroes_game_save(const void *game,
+                        uint8_t *out);
+
+/**
+ * Удаляет игру и освобождает память с помощью `deallocator`, переданного
+ * в `mmheroes_game_create` или `mmheroes_game_restore`.
+ */
+void mmheroes_game_destroy(void *game);
 
 /**
  * Записывает в аргумент `out` `MMHEROES_SCORE_COUNT` элементов.
@@ -219,9 +747,32 @@ void mmheroes_game_get_high_scores(const void *game,
 void mmheroes_game_set_high_scores(void *game,
                                    const struct MMHEROES_HighScore *new_high_scores);
 
+/**
+ * Возвращает итератор по запросам на отрисовку, накопленным при последнем вызове,
+ * который продолжает игру.
+ */
+struct MMHEROES_RendererRequestIterator mmheroes_renderer_request_iterator_begin(const void *game);
+
+/**
+ * Записывает следующий запрос на отрисовку в `out` и возвращает `true`.
+ * Если запросы закончились, не трогает `out` и возвращает `false`.
+ */
+bool mmheroes_renderer_request_iterator_next(struct MMHEROES_RendererRequestIterator *iterator,
+                                             struct MMHEROES_RendererRequest *out);
+
 /**
  * Воспроизводит игру с помощью входных данных, записанных ранее с помощью
- * `InputRecorder`.
+ * `InputRecorder`, или повтора, записанного с помощью `ReplayWriter`.
+ *
+ * Повтор можно воспроизвести только в игре с теми же зерном, режимом игры
+ * и [`RngMode`](crate::logic::RngMode) и с той же продолжительностью сессии,
+ * что указаны в его заголовке. Если в заголовке есть таблица рекордов,
+ * она заменяет текущую.
+ * Если состояние игры разошлось с записанным в повторе хэшем, воспроизведение
+ * останавливается.
+ *
+ * Из запросов на отрисовку остаются только те, что выведены после последней
+ * очистки экрана.
  *
  * В случае ошибки возвращает `false`, иначе — `true`.
  */
@@ -237,6 +788,81 @@ bool mmheroes_replay(void *game,
 bool mmheroes_continue(void *game,
                        enum MMHEROES_Input input);
 
+/**
+ * То же, что и `mmheroes_continue`, но вместо нажатия клавиши передаётся введённый
+ * символ — кодовая точка Unicode. Например, так вводится имя для таблицы рекордов.
+ *
+ * Некорректная кодовая точка обрабатывается как `MMHEROES_Input_Other`.
+ */
+bool mmheroes_continue_with_character(void *game,
+                                      uint32_t character);
+
+/**
+ * То же, что и `mmheroes_continue`, но передаётся клавиша быстрого выбора — цифра
+ * или латинская буква в виде кодовой точки Unicode. В диалоге она сразу выбирает
+ * вариант с этим номером: `1`–`9`, дальше `a`, `b`, `c` и т. д.
+ *
+ * Некорректная кодовая точка обрабатывается как `MMHEROES_Input_Other`.
+ */
+bool mmheroes_continue_with_hotkey(void *game,
+                                   uint32_t character);
+
+/**
+ * То же, что и `mmheroes_continue`, но передаётся событие мыши в клетке экрана
+ * `(line, column)`: нажатие, если `click` равен `true`, и перемещение указателя,
+ * если `false`. В диалоге нажатие выбирает вариант под указателем, а перемещение
+ * переводит на него курсор.
+ */
+bool mmheroes_continue_with_pointer(void *game,
+                                    uint8_t line,
+                                    uint8_t column,
+                                    bool click);
+
+/**
+ * Можно ли сейчас отменить ход с помощью `mmheroes_undo`.
+ */
+bool mmheroes_can_undo(const void *game);
+
+/**
+ * Отменяет последний ход: возвращает игру в главное меню локации, в котором
+ * игрок был ход назад. Экран перерисовывается.
+ *
+ * Возвращает `false`, если отменять нечего.
+ */
+bool mmheroes_undo(void *game);
+
+/**
+ * Количество вариантов в диалоге на текущем экране или 0, если диалога нет.
+ *
+ * Варианты нумеруются сверху вниз, начиная с нуля. Номер варианта — его
+ * идентификатор в `mmheroes_get_action_label` и `mmheroes_perform_action`,
+ * пока на экране тот же диалог.
+ */
+uintptr_t mmheroes_available_action_count(const void *game);
+
+/**
+ * Записывает в буфер `out` длиной `out_len` байт текст варианта `action_id`
+ * в кодировке UTF-8 так, как он выводится на экране, и возвращает полную длину
+ * текста в байтах. Если буфер короче, текст обрезается по границе символа.
+ * `out` может быть нулевым указателем, если `out_len` равен 0.
+ *
+ * Если такого варианта нет, возвращает 0.
+ */
+uintptr_t mmheroes_get_action_label(const void *game,
+                                    uintptr_t action_id,
+                                    uint8_t *out,
+                                    uintptr_t out_len);
+
+/**
+ * Выбирает вариант `action_id` в диалоге на текущем экране. То же, что нажать
+ * нужное количество раз стрелку и Enter, только за один вызов. В запись нажатых
+ * клавиш попадают эти нажатия.
+ *
+ * Возвращает `false`, если такого варианта нет или игра закончилась.
+ */
+bool mmheroes_perform_action(void *game,
+                             uintptr_t action_id);
+
 bool mmheroes_flush_input_recorder(void *game);
 
 bool mmheroes_rust_display(const uint8_t *string, uintptr_t len, void *formatter);
@@ -4,13 +4,20 @@ use crate::ui::high_scores;
 use crate::ui::Milliseconds;
 use crate::ui::*;
 
-use crate::logic::{create_game, Game, GameMode, Money, StateHolder, Time};
+use crate::logic::{
+    create_or_restore_game, save::SAVED_GAME_SIZE, BrainAssessment, BrainLevel,
+    CharismaAssessment, CharismaLevel, Classmate, ClassmateLocation, Game, GameMode,
+    GameScreen, GameState, HealthAssessment, HealthLevel, KnowledgeAssessment, Location,
+    Money, SavedGame, SemesterLength, StaminaAssessment, StaminaLevel, StateHolder,
+    Subject, Time,
+};
 
 use crate::ui::high_scores::{HighScore, SCORE_COUNT};
 use crate::ui::renderer::RendererRequestConsumer;
 use crate::util::TinyString;
+use core::cell::Ref;
 use core::ffi::c_void;
-use core::mem::{align_of_val, size_of_val, MaybeUninit};
+use core::mem::{align_of, align_of_val, size_of, size_of_val, MaybeUninit};
 
 pub type AllocatorContext = *mut c_void;
 
@@ -24,20 +31,220 @@ pub type Allocator = unsafe extern "C" fn(AllocatorContext, usize, usize) -> *mu
 /// а в качестве третьего — размер освобождаемого блока.
 pub type Deallocator = unsafe extern "C" fn(AllocatorContext, *mut c_void, usize);
 
-pub type RendererRequestCallback = extern "C" fn(*mut c_void, FfiRendererRequest);
+#[derive(Copy, Clone)]
+struct FfiAllocator {
+    context: AllocatorContext,
+    allocate: Allocator,
+    deallocate: Deallocator,
+}
+
+/// Растущий массив в памяти, которую выделяет вызывающая сторона.
+struct FfiVec<T> {
+    allocator: FfiAllocator,
+    ptr: *mut T,
+    len: usize,
+    capacity: usize,
+}
+
+impl<T: Copy> FfiVec<T> {
+    const MIN_CAPACITY: usize = 64;
+
+    fn new(allocator: FfiAllocator) -> Self {
+        FfiVec {
+            allocator,
+            ptr: core::ptr::NonNull::dangling().as_ptr(),
+            len: 0,
+            capacity: 0,
+        }
+    }
 
+    /// Возвращает `false`, если не удалось выделить память.
+    fn reserve(&mut self, additional: usize) -> bool {
+        let required = self.len + additional;
+        if required <= self.capacity {
+            return true;
+        }
+        let capacity = required.max(self.capacity * 2).max(Self::MIN_CAPACITY);
+        let ptr = unsafe {
+            (self.allocator.allocate)(
+                self.allocator.context,
+                capacity * size_of::<T>(),
+                align_of::<T>(),
+            )
+        } as *mut T;
+        if ptr.is_null() {
+            return false;
+        }
+        unsafe {
+            ptr.copy_from_nonoverlapping(self.ptr, self.len);
+        }
+        self.free();
+        self.ptr = ptr;
+        self.capacity = capacity;
+        true
+    }
+
+    fn extend_from_slice(&mut self, values: &[T]) -> bool {
+        if !self.reserve(values.len()) {
+            return false;
+        }
+        unsafe {
+            self.ptr
+                .add(self.len)
+                .copy_from_nonoverlapping(values.as_ptr(), values.len());
+        }
+        self.len += values.len();
+        true
+    }
+
+    fn push(&mut self, value: T) -> bool {
+        self.extend_from_slice(core::slice::from_ref(&value))
+    }
+
+    fn clear(&mut self) {
+        self.len = 0
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<T> FfiVec<T> {
+    fn free(&mut self) {
+        if self.capacity > 0 {
+            unsafe {
+                (self.allocator.deallocate)(
+                    self.allocator.context,
+                    self.ptr as *mut c_void,
+                    self.capacity * size_of::<T>(),
+                )
+            }
+        }
+    }
+}
+
+impl<T> Drop for FfiVec<T> {
+    fn drop(&mut self) {
+        self.free()
+    }
+}
+
+/// То же, что и `RendererRequest`, но вместо строки хранит её положение в буфере.
+#[derive(Debug, Copy, Clone)]
+enum BufferedRendererRequest {
+    ClearScreen,
+    Flush,
+    WriteStr {
+        offset: usize,
+        length: usize,
+    },
+    MoveCursor {
+        line: u8,
+        column: u8,
+    },
+    SetColor {
+        foreground: Color,
+        background: Color,
+    },
+    Sleep(Milliseconds),
+}
+
+/// Накапливает запросы на отрисовку между вызовами, которые продолжают игру.
+/// Строки из запросов копируются в буфер, которым владеет игра.
 pub struct FfiRendererRequestConsumer {
-    context: *mut c_void,
-    callback: RendererRequestCallback,
+    requests: FfiVec<BufferedRendererRequest>,
+    text: FfiVec<u8>,
+    /// Отбрасывать ли то, что выведено до очистки экрана. Так при воспроизведении
+    /// записи не копятся экраны, которые всё равно не будут видны.
+    skip_hidden: bool,
+}
+
+impl FfiRendererRequestConsumer {
+    fn new(allocator: FfiAllocator) -> Self {
+        FfiRendererRequestConsumer {
+            requests: FfiVec::new(allocator),
+            text: FfiVec::new(allocator),
+            skip_hidden: false,
+        }
+    }
+
+    fn clear(&mut self, skip_hidden: bool) {
+        self.requests.clear();
+        self.text.clear();
+        self.skip_hidden = skip_hidden;
+    }
+
+    fn get(&self, index: usize) -> Option<FfiRendererRequest> {
+        let request = match *self.requests.as_slice().get(index)? {
+            BufferedRendererRequest::ClearScreen => FfiRendererRequest::ClearScreen,
+            BufferedRendererRequest::Flush => FfiRendererRequest::Flush,
+            BufferedRendererRequest::WriteStr { offset, length } => {
+                FfiRendererRequest::WriteStr {
+                    buf: self.text.as_slice()[offset..].as_ptr(),
+                    length,
+                }
+            }
+            BufferedRendererRequest::MoveCursor { line, column } => {
+                FfiRendererRequest::MoveCursor { line, column }
+            }
+            BufferedRendererRequest::SetColor {
+                foreground,
+                background,
+            } => FfiRendererRequest::SetColor {
+                foreground,
+                background,
+            },
+            BufferedRendererRequest::Sleep(milliseconds) => {
+                FfiRendererRequest::Sleep { milliseconds }
+            }
+        };
+        Some(request)
+    }
 }
 
 impl RendererRequestConsumer for FfiRendererRequestConsumer {
+    /// Если не удалось выделить память, запрос теряется.
     fn consume_request(&mut self, request: RendererRequest) {
-        (self.callback)(self.context, request.into())
+        let request = match request {
+            RendererRequest::ClearScreen => {
+                if self.skip_hidden {
+                    self.requests.clear();
+                    self.text.clear();
+                }
+                BufferedRendererRequest::ClearScreen
+            }
+            RendererRequest::Flush => BufferedRendererRequest::Flush,
+            RendererRequest::WriteStr(s) => {
+                let offset = self.text.len;
+                if !self.text.extend_from_slice(s.as_bytes()) {
+                    return;
+                }
+                BufferedRendererRequest::WriteStr {
+                    offset,
+                    length: s.len(),
+                }
+            }
+            RendererRequest::MoveCursor { line, column } => {
+                BufferedRendererRequest::MoveCursor { line, column }
+            }
+            RendererRequest::SetColor {
+                foreground,
+                background,
+            } => BufferedRendererRequest::SetColor {
+                foreground,
+                background,
+            },
+            RendererRequest::Sleep(milliseconds) => {
+                BufferedRendererRequest::Sleep(milliseconds)
+            }
+        };
+        self.requests.push(request);
     }
 }
 
 struct FfiGame<G: 'static> {
+    allocator: FfiAllocator,
     state_holder: StateHolder,
     game: MaybeUninit<G>,
     game_ui:
@@ -45,6 +252,18 @@ struct FfiGame<G: 'static> {
 }
 
 impl<G: Game> FfiGame<G> {
+    /// Возвращает `GameUI`, предварительно удалив накопленные запросы на отрисовку.
+    /// Запросы, полученные ранее через `mmheroes_renderer_request_iterator_next`,
+    /// после этого недействительны.
+    unsafe fn game_ui_for_next_frame(
+        &mut self,
+        skip_hidden: bool,
+    ) -> &mut GameUI<'static, G, FfiRendererRequestConsumer, InputRecorderSink> {
+        let game_ui = self.game_ui.assume_init_mut();
+        game_ui.request_consumer_mut().clear(skip_hidden);
+        game_ui
+    }
+
     unsafe fn cast_mut<'a>(
         raw_ptr: *mut c_void,
         _constructor: impl FnOnce() -> G,
@@ -67,7 +286,7 @@ macro_rules! game_or_return {
         } else {
             FfiGame::cast_ref($memory, || {
                 #[allow(unreachable_code)]
-                create_game(unreachable!(), unreachable!())
+                create_or_restore_game(unreachable!(), unreachable!(), unreachable!())
             })
         }
     };
@@ -77,7 +296,7 @@ macro_rules! game_or_return {
         } else {
             FfiGame::cast_mut($memory, || {
                 #[allow(unreachable_code)]
-                create_game(unreachable!(), unreachable!())
+                create_or_restore_game(unreachable!(), unreachable!(), unreachable!())
             })
         }
     };
@@ -105,6 +324,344 @@ pub unsafe extern "C" fn mmheroes_game_get_current_time(
     }
 }
 
+/// Возвращает продолжительность сессии в днях. Если игра продолжена
+/// из сохранения, продолжительность берётся из сохранения.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_game_get_semester_length(game: *const c_void) -> u8 {
+    let game = game_or_return!(const game, return 0);
+    game.state_holder.semester_length().days()
+}
+
+/// Возвращает состояние игры, если оно доступно на текущем экране.
+fn game_state<G>(game: &FfiGame<G>) -> Option<Ref<'_, GameState>> {
+    Ref::filter_map(game.state_holder.observable_state(), |state| {
+        state.screen().state()
+    })
+    .ok()
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FfiPlayer {
+    health: HealthLevel,
+    money: Money,
+    brain: BrainLevel,
+    stamina: StaminaLevel,
+    charisma: CharismaLevel,
+    health_assessment: HealthAssessment,
+    brain_assessment: BrainAssessment,
+    stamina_assessment: StaminaAssessment,
+    charisma_assessment: CharismaAssessment,
+    exams_left: u8,
+    is_god_mode: bool,
+    has_mmheroes_floppy: bool,
+    has_internet: bool,
+    is_employed_at_terkom: bool,
+    got_stipend: bool,
+    has_train_ticket: bool,
+    knows_djug: bool,
+    is_dead: bool,
+}
+
+impl From<&GameState> for FfiPlayer {
+    fn from(state: &GameState) -> Self {
+        let player = state.player();
+        FfiPlayer {
+            health: player.health(),
+            money: player.money(),
+            brain: player.brain(),
+            stamina: player.stamina(),
+            charisma: player.charisma(),
+            health_assessment: HealthAssessment::from_health_level(
+                player.health(),
+                state.rules(),
+            ),
+            brain_assessment: BrainAssessment::from_brain_level(
+                player.brain(),
+                state.rules(),
+            ),
+            stamina_assessment: StaminaAssessment::from_stamina_level(player.stamina()),
+            charisma_assessment: CharismaAssessment::from_charisma_level(
+                player.charisma(),
+            ),
+            exams_left: player.exams_left() as u8,
+            is_god_mode: player.is_god_mode(),
+            has_mmheroes_floppy: player.has_mmheroes_floppy(),
+            has_internet: player.has_internet(),
+            is_employed_at_terkom: player.is_employed_at_terkom(),
+            got_stipend: player.got_stipend(),
+            has_train_ticket: player.has_train_ticket(),
+            knows_djug: player.knows_djug(),
+            is_dead: player.cause_of_death().is_some(),
+        }
+    }
+}
+
+/// Записывает характеристики игрока в `out` и возвращает `true`, если они доступны,
+/// иначе не трогает `out` и возвращает `false`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_game_get_player(
+    game: *const c_void,
+    out: &mut FfiPlayer,
+) -> bool {
+    let game = game_or_return!(const game, return false);
+    match game_state(game) {
+        Some(state) => {
+            *out = (&*state).into();
+            true
+        }
+        None => false,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FfiSubjectStatus {
+    knowledge: BrainLevel,
+    /// Оценка знаний по шкале преподавателя этого предмета.
+    knowledge_assessment: KnowledgeAssessment,
+    problems_done: u8,
+    required_problems: u8,
+    passed: bool,
+    /// Индекс дня, в который был получен зачёт. Имеет смысл, только если `passed`.
+    passed_exam_day: u8,
+    has_lecture_notes: bool,
+}
+
+/// Записывает состояние игрока по предмету `subject` в `out` и возвращает `true`,
+/// если оно доступно, иначе не трогает `out` и возвращает `false`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_game_get_subject_status(
+    game: *const c_void,
+    subject: Subject,
+    out: &mut FfiSubjectStatus,
+) -> bool {
+    let game = game_or_return!(const game, return false);
+    let Some(state) = game_state(game) else {
+        return false;
+    };
+    let status = state.player().status_for_subject(subject);
+    *out = FfiSubjectStatus {
+        knowledge: status.knowledge(),
+        knowledge_assessment: KnowledgeAssessment::relative(
+            status.knowledge(),
+            subject,
+            state.rules(),
+        ),
+        problems_done: status.problems_done(),
+        required_problems: state.rules().subject(subject).required_problems,
+        passed: status.passed(),
+        passed_exam_day: status
+            .passed_exam_day(state.timetable())
+            .map_or(0, |day| day.index() as u8),
+        has_lecture_notes: status.has_lecture_notes(),
+    };
+    true
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FfiExam {
+    from: Time,
+    to: Time,
+    location: Location,
+}
+
+/// Записывает в `out` время и место зачёта по предмету `subject` в день с индексом
+/// `day` и возвращает `true`. Дни нумеруются от 0 до продолжительности сессии
+/// минус один, а сессия длится не больше `MMHEROES_MAX_NUM_DAYS` дней.
+///
+/// Если расписание недоступно, такого дня нет или зачёта в этот день нет,
+/// не трогает `out` и возвращает `false`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_game_get_exam(
+    game: *const c_void,
+    day: u8,
+    subject: Subject,
+    out: &mut FfiExam,
+) -> bool {
+    let game = game_or_return!(const game, return false);
+    let Some(state) = game_state(game) else {
+        return false;
+    };
+    let Some(day) = state.timetable().days().get(day as usize) else {
+        return false;
+    };
+    match day.exam(subject) {
+        Some(exam) => {
+            *out = FfiExam {
+                from: exam.from(),
+                to: exam.to(),
+                location: exam.location(),
+            };
+            true
+        }
+        None => false,
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FfiClassmateLocation {
+    Nowhere,
+    Exam(Subject),
+    Location(Location),
+}
+
+impl From<ClassmateLocation> for FfiClassmateLocation {
+    fn from(location: ClassmateLocation) -> Self {
+        match location {
+            ClassmateLocation::Nowhere => FfiClassmateLocation::Nowhere,
+            ClassmateLocation::Exam(subject) => FfiClassmateLocation::Exam(subject),
+            ClassmateLocation::Location(location) => {
+                FfiClassmateLocation::Location(location)
+            }
+        }
+    }
+}
+
+/// Записывает в `out`, где сейчас находится одногруппник `classmate`, и возвращает
+/// `true`, если это известно, иначе не трогает `out` и возвращает `false`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_game_get_classmate_location(
+    game: *const c_void,
+    classmate: Classmate,
+    out: &mut FfiClassmateLocation,
+) -> bool {
+    let game = game_or_return!(const game, return false);
+    match game_state(game) {
+        Some(state) => {
+            *out = state.classmates()[classmate].current_location().into();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Вид текущего экрана. Варианты соответствуют вариантам `GameScreen` в движке.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FfiGameScreen {
+    Intro,
+    InitialParameters,
+    Ding,
+    Timetable,
+    SceneRouter,
+    Study,
+    PromptUseLectureNotes,
+    HighScores,
+    RestInMausoleum,
+    Cafe,
+    Midnight,
+    TrainToPDMI,
+    TrainFromPDMI,
+    KolyaInteraction,
+    PashaInteraction,
+    GrishaInteraction,
+    SashaInteraction,
+    KuzmenkoInteraction,
+    DiamondInteraction,
+    SerjInteraction,
+    MishaInteraction,
+    AndrewInteraction,
+    DjugInteraction,
+    RaiInteraction,
+    NilInteraction,
+    Terkom,
+    GoToProfessor,
+    ExamIntro,
+    Exam,
+    BaltiyskiyRailwayStation,
+    DontWantToSleep,
+    CantStayAwake,
+    NeighborInvites,
+    Dreaming,
+    SurfInternet,
+    PlayMmheroes,
+    ComputerClassClosing,
+    IAmDone,
+    GameEnd,
+    HighScoreEntry,
+    WannaTryAgain,
+    Disclaimer,
+    WhatToDo,
+    AboutScreen,
+    WhereToGoAndWhy,
+    AboutProfessors,
+    AboutCharacters,
+    AboutThisProgram,
+    Terminal,
+}
+
+impl From<&GameScreen> for FfiGameScreen {
+    fn from(screen: &GameScreen) -> Self {
+        use FfiGameScreen::*;
+        match screen {
+            GameScreen::Intro => Intro,
+            GameScreen::InitialParameters => InitialParameters,
+            GameScreen::Ding => Ding,
+            GameScreen::Timetable(_) => Timetable,
+            GameScreen::SceneRouter(_) => SceneRouter,
+            GameScreen::Study(_) => Study,
+            GameScreen::PromptUseLectureNotes(_) => PromptUseLectureNotes,
+            GameScreen::HighScores(_) => HighScores,
+            GameScreen::RestInMausoleum(_) => RestInMausoleum,
+            GameScreen::Cafe(_) => Cafe,
+            GameScreen::Midnight(_) => Midnight,
+            GameScreen::TrainToPDMI(_, _) => TrainToPDMI,
+            GameScreen::TrainFromPDMI(_, _) => TrainFromPDMI,
+            GameScreen::KolyaInteraction(_, _) => KolyaInteraction,
+            GameScreen::PashaInteraction(_, _) => PashaInteraction,
+            GameScreen::GrishaInteraction(_, _) => GrishaInteraction,
+            GameScreen::SashaInteraction(_, _) => SashaInteraction,
+            GameScreen::KuzmenkoInteraction(_, _) => KuzmenkoInteraction,
+            GameScreen::DiamondInteraction(_, _, _) => DiamondInteraction,
+            GameScreen::SerjInteraction(_, _, _) => SerjInteraction,
+            GameScreen::MishaInteraction(_) => MishaInteraction,
+            GameScreen::AndrewInteraction(_) => AndrewInteraction,
+            GameScreen::DjugInteraction(_) => DjugInteraction,
+            GameScreen::RaiInteraction(_) => RaiInteraction,
+            GameScreen::NilInteraction(_) => NilInteraction,
+            GameScreen::Terkom(_, _) => Terkom,
+            GameScreen::GoToProfessor(_) => GoToProfessor,
+            GameScreen::ExamIntro(_) => ExamIntro,
+            GameScreen::Exam(_) => Exam,
+            GameScreen::BaltiyskiyRailwayStation(_) => BaltiyskiyRailwayStation,
+            GameScreen::DontWantToSleep => DontWantToSleep,
+            GameScreen::CantStayAwake(_) => CantStayAwake,
+            GameScreen::NeighborInvites(_) => NeighborInvites,
+            GameScreen::Dreaming(_) => Dreaming,
+            GameScreen::SurfInternet { .. } => SurfInternet,
+            GameScreen::PlayMmheroes(_) => PlayMmheroes,
+            GameScreen::ComputerClassClosing(_) => ComputerClassClosing,
+            GameScreen::IAmDone(_) => IAmDone,
+            GameScreen::GameEnd(_) => GameEnd,
+            GameScreen::HighScoreEntry(_) => HighScoreEntry,
+            GameScreen::WannaTryAgain => WannaTryAgain,
+            GameScreen::Disclaimer => Disclaimer,
+            GameScreen::WhatToDo(_) => WhatToDo,
+            GameScreen::AboutScreen(_) => AboutScreen,
+            GameScreen::WhereToGoAndWhy(_) => WhereToGoAndWhy,
+            GameScreen::AboutProfessors(_) => AboutProfessors,
+            GameScreen::AboutCharacters(_) => AboutCharacters,
+            GameScreen::AboutThisProgram(_) => AboutThisProgram,
+            GameScreen::Terminal => Terminal,
+        }
+    }
+}
+
+/// Записывает вид текущего экрана в `out` и возвращает `true`.
+/// Если `game` — нулевой указатель, не трогает `out` и возвращает `false`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_game_get_screen(
+    game: *const c_void,
+    out: &mut FfiGameScreen,
+) -> bool {
+    let game = game_or_return!(const game, return false);
+    *out = game.state_holder.observable_state().screen().into();
+    true
+}
+
 #[repr(C)]
 pub struct FfiHighScore {
     name: *const u8,
@@ -123,7 +680,7 @@ unsafe fn get_high_scores(ptr: *const FfiHighScore) -> Option<[HighScore; SCORE_
     if ptr.is_null() {
         None
     } else {
-        let mut scores = high_scores::default_high_scores();
+        let mut scores = high_scores::default_high_scores(Locale::Russian);
         let slice = core::slice::from_raw_parts(ptr, SCORE_COUNT);
         for (i, score) in slice.iter().enumerate() {
             let name = score.name();
@@ -141,6 +698,14 @@ unsafe fn get_high_scores(ptr: *const FfiHighScore) -> Option<[HighScore; SCORE_
 /// достаточного размера. Нарушение любого из этих условий — неопределённое поведение.
 ///
 /// Размер и выравнивание передаются в качестве аргументов аллокатору.
+/// Этим же аллокатором выделяется память для буфера запросов на отрисовку, а
+/// `deallocator` освобождает её, когда буфер растёт, и при удалении игры.
+///
+/// Параметр `semester_length` — продолжительность сессии в днях, от 3
+/// до `MMHEROES_MAX_NUM_DAYS`. В оригинальной игре сессия длится 6 дней.
+/// Если продолжительность недопустимая, возвращает нулевой указатель.
+///
+/// Параметр `locale` — язык, на котором выводится игра.
 ///
 /// Параметр `high_scores` — указатель (возможно нулевой) на массив из
 /// `MMHEROES_SCORE_COUNT` элементов.
@@ -148,30 +713,102 @@ unsafe fn get_high_scores(ptr: *const FfiHighScore) -> Option<[HighScore; SCORE_
 pub unsafe extern "C" fn mmheroes_game_create(
     mode: GameMode,
     seed: u64,
+    semester_length: u8,
+    locale: Locale,
+    high_scores: *const FfiHighScore,
+    allocator_context: AllocatorContext,
+    allocator: Allocator,
+    deallocator: Deallocator,
+    input_recorder_sink: InputRecorderSink,
+) -> *mut c_void {
+    let Some(semester_length) = SemesterLength::new(semester_length) else {
+        return core::ptr::null_mut();
+    };
+    create_ffi_game(
+        mode,
+        seed,
+        semester_length,
+        locale,
+        None,
+        high_scores,
+        allocator_context,
+        allocator,
+        deallocator,
+        input_recorder_sink,
+    )
+}
+
+/// То же, что и `mmheroes_game_create`, но вместо новой игры продолжает игру,
+/// сохранённую ранее с помощью `mmheroes_game_save`.
+///
+/// `saved_game` — ненулевой указатель на сохранение длиной `saved_game_len` байт.
+/// Если сохранение повреждено или сделано в неподдерживаемой версии формата,
+/// возвращает нулевой указатель.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_game_restore(
+    saved_game: *const u8,
+    saved_game_len: usize,
+    locale: Locale,
+    high_scores: *const FfiHighScore,
+    allocator_context: AllocatorContext,
+    allocator: Allocator,
+    deallocator: Deallocator,
+    input_recorder_sink: InputRecorderSink,
+) -> *mut c_void {
+    assert!(!saved_game.is_null());
+    let bytes = core::slice::from_raw_parts(saved_game, saved_game_len);
+    let saved_game = match SavedGame::decode(bytes) {
+        Ok(saved_game) => saved_game,
+        Err(_) => return core::ptr::null_mut(),
+    };
+    // Состояние генераторов случайных чисел берётся из сохранения, поэтому
+    // зерно не нужно.
+    create_ffi_game(
+        saved_game.mode(),
+        0,
+        saved_game.state().timetable().length(),
+        locale,
+        Some(saved_game),
+        high_scores,
+        allocator_context,
+        allocator,
+        deallocator,
+        input_recorder_sink,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn create_ffi_game(
+    mode: GameMode,
+    seed: u64,
+    semester_length: SemesterLength,
+    locale: Locale,
+    saved_game: Option<SavedGame>,
     high_scores: *const FfiHighScore,
     allocator_context: AllocatorContext,
     allocator: Allocator,
-    renderer_request_callback_context: *mut c_void,
-    renderer_request_callback: RendererRequestCallback,
+    deallocator: Deallocator,
     input_recorder_sink: InputRecorderSink,
 ) -> *mut c_void {
     use core::ptr::{null_mut, NonNull};
 
     let scores = get_high_scores(high_scores);
 
-    let renderer_request_consumer = FfiRendererRequestConsumer {
-        context: renderer_request_callback_context,
-        callback: renderer_request_callback,
+    let allocator = FfiAllocator {
+        context: allocator_context,
+        allocate: allocator,
+        deallocate: deallocator,
     };
 
     let ffi_game = FfiGame {
-        state_holder: StateHolder::new(mode),
+        allocator,
+        state_holder: StateHolder::new(mode).with_semester_length(semester_length),
         game: MaybeUninit::uninit(),
         game_ui: MaybeUninit::uninit(),
     };
 
-    let memory: *mut FfiGame<_> = allocator(
-        allocator_context,
+    let memory: *mut FfiGame<_> = (allocator.allocate)(
+        allocator.context,
         size_of_val(&ffi_game),
         align_of_val(&ffi_game),
     ) as *mut _;
@@ -182,32 +819,54 @@ pub unsafe extern "C" fn mmheroes_game_create(
     memory.write(ffi_game);
 
     let state_holder = &memory.as_ref().state_holder;
-    let game = memory.as_mut().game.write(create_game(seed, state_holder));
+    let game = memory.as_mut().game.write(create_or_restore_game(
+        seed,
+        saved_game,
+        state_holder,
+    ));
     memory.as_mut().game_ui.write(GameUI::new(
         state_holder,
         core::pin::Pin::new_unchecked(game),
         seed,
         scores,
-        renderer_request_consumer,
+        FfiRendererRequestConsumer::new(allocator),
         Some(input_recorder_sink),
+        locale,
     ));
 
     memory.as_ptr() as *mut c_void
 }
 
+/// Сохраняет игру, записывая `MMHEROES_SAVED_GAME_SIZE` байт в буфер `out`,
+/// и возвращает `true`. `out` не должен быть нулевым указателем.
+///
+/// Сохранить игру можно только когда на экране главное меню локации. В противном
+/// случае функция не трогает `out` и возвращает `false`.
 #[no_mangle]
-pub unsafe extern "C" fn mmheroes_game_destroy(
-    game: *mut c_void,
-    allocator_context: AllocatorContext,
-    deallocator: Deallocator,
-) {
+pub unsafe extern "C" fn mmheroes_game_save(game: *const c_void, out: *mut u8) -> bool {
+    assert!(!out.is_null());
+    let game = game_or_return!(const game, return false);
+    match game.state_holder.save_game() {
+        Some(saved_game) => {
+            out.copy_from_nonoverlapping(saved_game.encode().as_ptr(), SAVED_GAME_SIZE);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Удаляет игру и освобождает память с помощью `deallocator`, переданного
+/// в `mmheroes_game_create` или `mmheroes_game_restore`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_game_destroy(game: *mut c_void) {
     let game = game_or_return!(mut game, return);
     let size = size_of_val(game);
+    let allocator = game.allocator;
     game.game_ui.assume_init_drop();
     game.game.assume_init_drop();
     let game_ptr: *mut FfiGame<_> = game as *mut _;
     game_ptr.drop_in_place();
-    deallocator(allocator_context, game_ptr as *mut c_void, size);
+    (allocator.deallocate)(allocator.context, game_ptr as *mut c_void, size);
 }
 
 /// Записывает в аргумент `out` `MMHEROES_SCORE_COUNT` элементов.
@@ -282,34 +941,63 @@ pub enum FfiRendererRequest {
     },
 }
 
-impl<'a> From<RendererRequest<'a>> for FfiRendererRequest {
-    fn from(request: RendererRequest<'a>) -> Self {
-        match request {
-            RendererRequest::ClearScreen => FfiRendererRequest::ClearScreen,
-            RendererRequest::Flush => FfiRendererRequest::Flush,
-            RendererRequest::WriteStr(s) => FfiRendererRequest::WriteStr {
-                buf: s.as_ptr(), // FIXME: Lifetime!!!
-                length: s.len(),
-            },
-            RendererRequest::MoveCursor { line, column } => {
-                FfiRendererRequest::MoveCursor { line, column }
-            }
-            RendererRequest::SetColor {
-                foreground,
-                background,
-            } => FfiRendererRequest::SetColor {
-                foreground,
-                background,
-            },
-            RendererRequest::Sleep(milliseconds) => {
-                FfiRendererRequest::Sleep { milliseconds }
-            }
+/// Запросы на отрисовку, накопленные при последнем вызове, который продолжает игру:
+/// `mmheroes_continue`, `mmheroes_continue_with_character`, `mmheroes_undo`,
+/// `mmheroes_perform_action` или `mmheroes_replay`.
+///
+/// Итератор и строки из запросов `MMHEROES_RendererRequest_WriteStr` действительны
+/// до следующего такого вызова или до удаления игры.
+#[repr(C)]
+pub struct FfiRendererRequestIterator {
+    game: *const c_void,
+    next: usize,
+}
+
+/// Возвращает итератор по запросам на отрисовку, накопленным при последнем вызове,
+/// который продолжает игру.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_renderer_request_iterator_begin(
+    game: *const c_void,
+) -> FfiRendererRequestIterator {
+    FfiRendererRequestIterator { game, next: 0 }
+}
+
+/// Записывает следующий запрос на отрисовку в `out` и возвращает `true`.
+/// Если запросы закончились, не трогает `out` и возвращает `false`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_renderer_request_iterator_next(
+    iterator: &mut FfiRendererRequestIterator,
+    out: &mut FfiRendererRequest,
+) -> bool {
+    let game = iterator.game;
+    let game = game_or_return!(const game, return false);
+    match game
+        .game_ui
+        .assume_init_ref()
+        .request_consumer()
+        .get(iterator.next)
+    {
+        Some(request) => {
+            *out = request;
+            iterator.next += 1;
+            true
         }
+        None => false,
     }
 }
 
 /// Воспроизводит игру с помощью входных данных, записанных ранее с помощью
-/// `InputRecorder`.
+/// `InputRecorder`, или повтора, записанного с помощью `ReplayWriter`.
+///
+/// Повтор можно воспроизвести только в игре с теми же зерном, режимом игры
+/// и [`RngMode`](crate::logic::RngMode) и с той же продолжительностью сессии,
+/// что указаны в его заголовке. Если в заголовке есть таблица рекордов,
+/// она заменяет текущую.
+/// Если состояние игры разошлось с записанным в повторе хэшем, воспроизведение
+/// останавливается.
+///
+/// Из запросов на отрисовку остаются только те, что выведены после последней
+/// очистки экрана.
 ///
 /// В случае ошибки возвращает `false`, иначе — `true`.
 #[no_mangle]
@@ -325,19 +1013,233 @@ pub unsafe extern "C" fn mmheroes_replay(
         Ok(s) => s,
         Err(_) => return false,
     };
-    let mut parser = recording::InputRecordingParser::new(s);
-    parser
-        .parse_all(|input| game.game_ui.assume_init_mut().continue_game(input))
+    let mode = game.state_holder.observable_state().mode();
+    let rng_mode = game.state_holder.rng_mode();
+    let semester_length = game.state_holder.semester_length();
+    let game_ui = game.game_ui_for_next_frame(true);
+    if !recording::is_replay(s) {
+        let mut parser = recording::InputRecordingParser::new(s);
+        return parser
+            .parse_all(|input| game_ui.continue_game(input))
+            .is_ok();
+    }
+    let mut reader = match recording::ReplayReader::new(s) {
+        Ok(reader) => reader,
+        Err(_) => return false,
+    };
+    let header = reader.header();
+    if header.seed != game_ui.seed()
+        || header.mode != mode
+        || header.rng_mode != rng_mode
+        || header.semester_length != semester_length
+    {
+        return false;
+    }
+    if let Some(high_scores) = &header.high_scores {
+        game_ui.high_scores = high_scores.clone();
+    }
+    reader
+        .parse_all(|event| match event {
+            recording::ReplayEvent::Input(input) => game_ui.continue_game(input),
+            recording::ReplayEvent::StateHash(hash) => game_ui.state_hash() == Some(hash),
+        })
         .is_ok()
 }
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FfiInput {
+    KeyUp,
+    KeyDown,
+    Enter,
+    Other,
+    Backspace,
+    Escape,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+}
+
+impl From<FfiInput> for Input {
+    fn from(input: FfiInput) -> Self {
+        match input {
+            FfiInput::KeyUp => Input::KeyUp,
+            FfiInput::KeyDown => Input::KeyDown,
+            FfiInput::Enter => Input::Enter,
+            FfiInput::Other => Input::Other,
+            FfiInput::Backspace => Input::Backspace,
+            FfiInput::Escape => Input::Escape,
+            FfiInput::Home => Input::Home,
+            FfiInput::End => Input::End,
+            FfiInput::PageUp => Input::PageUp,
+            FfiInput::PageDown => Input::PageDown,
+        }
+    }
+}
+
 /// Продолжает игру до следующего запроса на нажатие клавиши.
 ///
 /// При первом вызове этой функции неважно, что передаётся в параметре `input`.
 #[no_mangle]
-pub unsafe extern "C" fn mmheroes_continue(game: *mut c_void, input: Input) -> bool {
+pub unsafe extern "C" fn mmheroes_continue(game: *mut c_void, input: FfiInput) -> bool {
+    let game = game_or_return!(mut game, return false);
+    game.game_ui_for_next_frame(false)
+        .continue_game(input.into())
+}
+
+/// То же, что и `mmheroes_continue`, но вместо нажатия клавиши передаётся введённый
+/// символ — кодовая точка Unicode. Например, так вводится имя для таблицы рекордов.
+///
+/// Некорректная кодовая точка обрабатывается как `MMHEROES_Input_Other`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_continue_with_character(
+    game: *mut c_void,
+    character: u32,
+) -> bool {
+    let game = game_or_return!(mut game, return false);
+    let input = char::from_u32(character).map_or(Input::Other, Input::Character);
+    game.game_ui_for_next_frame(false).continue_game(input)
+}
+
+/// То же, что и `mmheroes_continue`, но передаётся клавиша быстрого выбора — цифра
+/// или латинская буква в виде кодовой точки Unicode. В диалоге она сразу выбирает
+/// вариант с этим номером: `1`–`9`, дальше `a`, `b`, `c` и т. д.
+///
+/// Некорректная кодовая точка обрабатывается как `MMHEROES_Input_Other`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_continue_with_hotkey(
+    game: *mut c_void,
+    character: u32,
+) -> bool {
+    let game = game_or_return!(mut game, return false);
+    let input = char::from_u32(character).map_or(Input::Other, Input::Hotkey);
+    game.game_ui_for_next_frame(false).continue_game(input)
+}
+
+/// То же, что и `mmheroes_continue`, но передаётся событие мыши в клетке экрана
+/// `(line, column)`: нажатие, если `click` равен `true`, и перемещение указателя,
+/// если `false`. В диалоге нажатие выбирает вариант под указателем, а перемещение
+/// переводит на него курсор.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_continue_with_pointer(
+    game: *mut c_void,
+    line: u8,
+    column: u8,
+    click: bool,
+) -> bool {
+    let game = game_or_return!(mut game, return false);
+    let input = if click {
+        Input::Click { line, column }
+    } else {
+        Input::PointerAt { line, column }
+    };
+    game.game_ui_for_next_frame(false).continue_game(input)
+}
+
+/// Можно ли сейчас отменить ход с помощью `mmheroes_undo`.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_can_undo(game: *const c_void) -> bool {
+    let game = game_or_return!(const game, return false);
+    game.game_ui.assume_init_ref().can_undo()
+}
+
+/// Отменяет последний ход: возвращает игру в главное меню локации, в котором
+/// игрок был ход назад. Экран перерисовывается.
+///
+/// Возвращает `false`, если отменять нечего.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_undo(game: *mut c_void) -> bool {
+    let game = game_or_return!(mut game, return false);
+    game.game_ui_for_next_frame(false).undo()
+}
+
+/// Количество вариантов в диалоге на текущем экране или 0, если диалога нет.
+///
+/// Варианты нумеруются сверху вниз, начиная с нуля. Номер варианта — его
+/// идентификатор в `mmheroes_get_action_label` и `mmheroes_perform_action`,
+/// пока на экране тот же диалог.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_available_action_count(game: *const c_void) -> usize {
+    let game = game_or_return!(const game, return 0);
+    game.game_ui.assume_init_ref().available_actions().len()
+}
+
+/// Записывает в буфер `out` длиной `out_len` байт текст варианта `action_id`
+/// в кодировке UTF-8 так, как он выводится на экране, и возвращает полную длину
+/// текста в байтах. Если буфер короче, текст обрезается по границе символа.
+/// `out` может быть нулевым указателем, если `out_len` равен 0.
+///
+/// Если такого варианта нет, возвращает 0.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_get_action_label(
+    game: *const c_void,
+    action_id: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> usize {
+    struct Label<'a> {
+        buf: &'a mut [u8],
+        written: usize,
+        len: usize,
+    }
+
+    impl core::fmt::Write for Label<'_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            if self.written == self.len {
+                let mut end = s.len().min(self.buf.len() - self.written);
+                while !s.is_char_boundary(end) {
+                    end -= 1;
+                }
+                self.buf[self.written..self.written + end]
+                    .copy_from_slice(&s.as_bytes()[..end]);
+                self.written += end;
+            }
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    let game = game_or_return!(const game, return 0);
+    let game_ui = game.game_ui.assume_init_ref();
+    let Some(&action) = game_ui.available_actions().get(action_id) else {
+        return 0;
+    };
+    let buf = if out_len == 0 {
+        &mut [][..]
+    } else {
+        assert!(!out.is_null());
+        core::slice::from_raw_parts_mut(out, out_len)
+    };
+    let mut label = Label {
+        buf,
+        written: 0,
+        len: 0,
+    };
+    let _ = game_ui.write_action_label(action, &mut label);
+    label.len
+}
+
+/// Выбирает вариант `action_id` в диалоге на текущем экране. То же, что нажать
+/// нужное количество раз стрелку и Enter, только за один вызов. В запись нажатых
+/// клавиш попадают эти нажатия.
+///
+/// Возвращает `false`, если такого варианта нет или игра закончилась.
+#[no_mangle]
+pub unsafe extern "C" fn mmheroes_perform_action(
+    game: *mut c_void,
+    action_id: usize,
+) -> bool {
     let game = game_or_return!(mut game, return false);
-    game.game_ui.assume_init_mut().continue_game(input)
+    let Some(&action) = game
+        .game_ui
+        .assume_init_ref()
+        .available_actions()
+        .get(action_id)
+    else {
+        return false;
+    };
+    game.game_ui_for_next_frame(false).perform_action(action)
 }
 
 #[no_mangle]
@@ -412,7 +1314,7 @@ mod tests {
         size: usize,
         alignment: usize,
     ) -> *mut c_void {
-        std::alloc::alloc_zeroed(Layout::from_size_align(size, alignment).unwrap())
+        std::alloc::alloc_zeroed(Layout::from_size_align(size, alignment.max(8)).unwrap())
             as *mut c_void
     }
 
@@ -424,6 +1326,16 @@ mod tests {
         std::alloc::dealloc(memory as *mut u8, Layout::from_size_align(size, 8).unwrap())
     }
 
+    unsafe fn renderer_requests(game: *const c_void) -> Vec<FfiRendererRequest> {
+        let mut iterator = mmheroes_renderer_request_iterator_begin(game);
+        let mut request = FfiRendererRequest::Flush;
+        let mut requests = Vec::new();
+        while mmheroes_renderer_request_iterator_next(&mut iterator, &mut request) {
+            requests.push(request);
+        }
+        requests
+    }
+
     fn high_scores() -> [FfiHighScore; high_scores::SCORE_COUNT] {
         macro_rules! ffi_high_score {
             ($name:literal, $score:literal) => {
@@ -464,15 +1376,6 @@ mod tests {
         unsafe {
             let scores = high_scores();
 
-            let mut requests = <Vec<FfiRendererRequest>>::new();
-
-            extern "C" fn renderer_request_callback(
-                context: *mut c_void,
-                renderer_request: FfiRendererRequest,
-            ) {
-                unsafe { (*(context as *mut Vec<_>)).push(renderer_request) }
-            }
-
             let mut log = String::new();
 
             let sink = InputRecorderSink {
@@ -484,20 +1387,18 @@ mod tests {
             let game = mmheroes_game_create(
                 GameMode::Normal,
                 0,
+                6,
+                Locale::Russian,
                 scores.as_ptr(),
                 null_mut(),
                 allocator,
-                &mut requests as *mut _ as *mut c_void,
-                renderer_request_callback,
+                deallocator,
                 sink,
             );
 
             let mut scores =
                 [const { MaybeUninit::<FfiHighScore>::uninit() }; SCORE_COUNT];
-            mmheroes_game_get_high_scores(
-                game,
-                core::mem::transmute(scores.as_mut_ptr()),
-            );
+            mmheroes_game_get_high_scores(game, scores.as_mut_ptr() as *mut FfiHighScore);
 
             assert_eq!(scores[0].assume_init_ref().name(), "Оля");
             assert_eq!(scores[1].assume_init_ref().name(), "Вероника");
@@ -505,20 +1406,20 @@ mod tests {
             assert_eq!(scores[3].assume_init_ref().name(), "Катя");
             assert_eq!(scores[4].assume_init_ref().name(), "Рита");
 
-            mmheroes_continue(game, Input::Enter);
+            mmheroes_continue(game, FfiInput::Enter);
 
-            assert_eq!(requests.len(), 29);
+            assert_eq!(renderer_requests(game).len(), 29);
 
             let mut day = 255u8;
             let mut time = Time(255);
             assert!(!mmheroes_game_get_current_time(game, &mut day, &mut time));
 
-            mmheroes_continue(game, Input::Enter);
-            mmheroes_continue(game, Input::Enter);
-            mmheroes_continue(game, Input::Enter);
-            mmheroes_continue(game, Input::KeyDown);
-            mmheroes_continue(game, Input::KeyDown);
-            mmheroes_continue(game, Input::Enter);
+            mmheroes_continue(game, FfiInput::Enter);
+            mmheroes_continue(game, FfiInput::Enter);
+            mmheroes_continue(game, FfiInput::Enter);
+            mmheroes_continue(game, FfiInput::KeyDown);
+            mmheroes_continue(game, FfiInput::KeyDown);
+            mmheroes_continue(game, FfiInput::Enter);
 
             assert!(mmheroes_game_get_current_time(game, &mut day, &mut time));
             assert_eq!(day, 0);
@@ -528,7 +1429,532 @@ mod tests {
             mmheroes_flush_input_recorder(game);
             assert_eq!(log, "4r2↓r");
 
-            mmheroes_game_destroy(game, null_mut(), deallocator);
+            let mut saved_game = [0u8; SAVED_GAME_SIZE];
+            assert!(mmheroes_game_save(game, saved_game.as_mut_ptr()));
+
+            mmheroes_game_destroy(game);
+
+            let restored_game = mmheroes_game_restore(
+                saved_game.as_ptr(),
+                saved_game.len(),
+                Locale::Russian,
+                null_mut(),
+                null_mut(),
+                allocator,
+                deallocator,
+                InputRecorderSink {
+                    context: null_mut(),
+                    sink: None,
+                    display: None,
+                },
+            );
+            mmheroes_continue(restored_game, FfiInput::Enter);
+            mmheroes_continue(restored_game, FfiInput::Enter);
+
+            let mut day = 255u8;
+            let mut time = Time(255);
+            assert!(mmheroes_game_get_current_time(
+                restored_game,
+                &mut day,
+                &mut time
+            ));
+            assert_eq!(day, 0);
+            assert_eq!(time, Time(9));
+
+            mmheroes_game_destroy(restored_game);
+
+            assert!(mmheroes_game_restore(
+                saved_game.as_ptr(),
+                saved_game.len() - 1,
+                Locale::Russian,
+                null_mut(),
+                null_mut(),
+                allocator,
+                deallocator,
+                InputRecorderSink {
+                    context: null_mut(),
+                    sink: None,
+                    display: None,
+                },
+            )
+            .is_null());
+        }
+    }
+
+    #[test]
+    fn test_ffi_replay() {
+        fn create_game(seed: u64) -> *mut c_void {
+            unsafe {
+                mmheroes_game_create(
+                    GameMode::Normal,
+                    seed,
+                    6,
+                    Locale::Russian,
+                    high_scores().as_ptr(),
+                    null_mut(),
+                    allocator,
+                    deallocator,
+                    InputRecorderSink {
+                        context: null_mut(),
+                        sink: None,
+                        display: None,
+                    },
+                )
+            }
+        }
+
+        let replay_high_scores = crate::high_scores![
+            "Коля" => 400,
+            "Саша" => 280,
+            "Эндрю" => 180,
+            "Паша" => 100,
+            "Гриша" => 20,
+        ];
+        let header = recording::ReplayHeader::new(
+            0,
+            GameMode::Normal,
+            crate::logic::RngMode::SingleStream,
+            crate::logic::SemesterLength::NORMAL,
+            None,
+            Some(replay_high_scores.clone()),
+        );
+        let mut writer = recording::ReplayWriter::new(String::new(), &header).unwrap();
+        for input in [Input::Enter; 4] {
+            writer.record_input(input).unwrap();
+        }
+        for input in [Input::KeyDown, Input::KeyDown, Input::Enter] {
+            writer.record_input(input).unwrap();
+        }
+        writer.flush().unwrap();
+        let replay = writer.output();
+
+        unsafe {
+            let game = create_game(0);
+            assert!(mmheroes_replay(game, replay.as_ptr(), replay.len()));
+
+            let mut day = 255u8;
+            let mut time = Time(255);
+            assert!(mmheroes_game_get_current_time(game, &mut day, &mut time));
+            assert_eq!(day, 0);
+            assert_eq!(time, Time(9));
+
+            let mut scores =
+                [const { MaybeUninit::<FfiHighScore>::uninit() }; SCORE_COUNT];
+            mmheroes_game_get_high_scores(game, scores.as_mut_ptr() as *mut FfiHighScore);
+            assert_eq!(scores[0].assume_init_ref().name(), "Коля");
+            assert_eq!(scores[4].assume_init_ref().name(), "Гриша");
+
+            mmheroes_game_destroy(game);
+
+            // Повтор записан для другого зерна.
+            let game = create_game(1);
+            assert!(!mmheroes_replay(game, replay.as_ptr(), replay.len()));
+            mmheroes_game_destroy(game);
+
+            // Состояние игры разошлось с записанным.
+            let game = create_game(0);
+            let diverged = format!("{}#00000000deadbeef\n", replay);
+            assert!(!mmheroes_replay(game, diverged.as_ptr(), diverged.len()));
+            mmheroes_game_destroy(game);
+
+            // Повреждённый заголовок.
+            let game = create_game(0);
+            let corrupted = replay.replace("seed 0", "seed x");
+            assert!(!mmheroes_replay(game, corrupted.as_ptr(), corrupted.len()));
+            mmheroes_game_destroy(game);
+        }
+    }
+
+    #[test]
+    fn test_ffi_undo() {
+        unsafe {
+            let game = mmheroes_game_create(
+                GameMode::Normal,
+                0,
+                6,
+                Locale::Russian,
+                null_mut(),
+                null_mut(),
+                allocator,
+                deallocator,
+                InputRecorderSink {
+                    context: null_mut(),
+                    sink: None,
+                    display: None,
+                },
+            );
+            assert!(!mmheroes_can_undo(game));
+
+            // Доходим до общаги и готовимся к алгебре
+            for _ in 0..6 {
+                mmheroes_continue(game, FfiInput::Enter);
+            }
+            let mut day = 255u8;
+            let mut time = Time(255);
+            assert!(mmheroes_game_get_current_time(game, &mut day, &mut time));
+            assert_eq!(time, Time(9));
+
+            assert!(mmheroes_can_undo(game));
+            assert!(mmheroes_undo(game));
+            assert!(mmheroes_game_get_current_time(game, &mut day, &mut time));
+            assert_eq!(time, Time(8));
+
+            assert!(!mmheroes_can_undo(game));
+            assert!(!mmheroes_undo(game));
+
+            mmheroes_game_destroy(game);
+        }
+    }
+
+    #[test]
+    fn test_ffi_semester_length() {
+        unsafe {
+            let create_game = |semester_length| {
+                mmheroes_game_create(
+                    GameMode::Normal,
+                    0,
+                    semester_length,
+                    Locale::Russian,
+                    null_mut(),
+                    null_mut(),
+                    allocator,
+                    deallocator,
+                    InputRecorderSink {
+                        context: null_mut(),
+                        sink: None,
+                        display: None,
+                    },
+                )
+            };
+            assert!(create_game(2).is_null());
+            assert!(create_game(SemesterLength::MAX.days() + 1).is_null());
+
+            let game = create_game(3);
+            assert_eq!(mmheroes_game_get_semester_length(game), 3);
+            for _ in 0..4 {
+                mmheroes_continue(game, FfiInput::Enter);
+            }
+            let mut exam = FfiExam {
+                from: Time(0),
+                to: Time(0),
+                location: Location::Dorm,
+            };
+            assert!(!mmheroes_game_get_exam(
+                game,
+                3,
+                Subject::Calculus,
+                &mut exam
+            ));
+
+            let mut saved_game = [0u8; SAVED_GAME_SIZE];
+            assert!(mmheroes_game_save(game, saved_game.as_mut_ptr()));
+            mmheroes_game_destroy(game);
+
+            let restored_game = mmheroes_game_restore(
+                saved_game.as_ptr(),
+                saved_game.len(),
+                Locale::Russian,
+                null_mut(),
+                null_mut(),
+                allocator,
+                deallocator,
+                InputRecorderSink {
+                    context: null_mut(),
+                    sink: None,
+                    display: None,
+                },
+            );
+            assert_eq!(mmheroes_game_get_semester_length(restored_game), 3);
+            mmheroes_game_destroy(restored_game);
+
+            assert_eq!(mmheroes_game_get_semester_length(core::ptr::null()), 0);
+        }
+    }
+
+    #[test]
+    fn test_ffi_state_queries() {
+        unsafe {
+            let game = mmheroes_game_create(
+                GameMode::Normal,
+                0,
+                6,
+                Locale::Russian,
+                null_mut(),
+                null_mut(),
+                allocator,
+                deallocator,
+                InputRecorderSink {
+                    context: null_mut(),
+                    sink: None,
+                    display: None,
+                },
+            );
+
+            let mut screen = FfiGameScreen::Terminal;
+            assert!(mmheroes_game_get_screen(game, &mut screen));
+            assert_eq!(screen, FfiGameScreen::Intro);
+            assert!(!mmheroes_game_get_screen(core::ptr::null(), &mut screen));
+
+            let mut player = core::mem::zeroed::<FfiPlayer>();
+            assert!(!mmheroes_game_get_player(game, &mut player));
+
+            for _ in 0..3 {
+                mmheroes_continue(game, FfiInput::Enter);
+            }
+            assert!(mmheroes_game_get_screen(game, &mut screen));
+            assert_eq!(screen, FfiGameScreen::Timetable);
+
+            assert!(mmheroes_game_get_player(game, &mut player));
+            assert_eq!(player.health, 44);
+            assert_eq!(player.money, Money(0));
+            assert_eq!(player.brain, 5);
+            assert_eq!(player.stamina, 4);
+            assert_eq!(player.charisma, 5);
+            assert_eq!(player.health_assessment, HealthAssessment::Great);
+            assert_eq!(player.brain_assessment, BrainAssessment::BrainIsFresh);
+            assert_eq!(player.stamina_assessment, StaminaAssessment::ABitTired);
+            assert_eq!(player.charisma_assessment, CharismaAssessment::ManyFriends);
+            assert_eq!(player.exams_left, 6);
+            assert!(!player.is_god_mode);
+            assert!(!player.is_dead);
+
+            let mut status = core::mem::zeroed::<FfiSubjectStatus>();
+            assert!(mmheroes_game_get_subject_status(
+                game,
+                Subject::AlgebraAndNumberTheory,
+                &mut status
+            ));
+            assert_eq!(
+                status,
+                FfiSubjectStatus {
+                    knowledge: 2,
+                    knowledge_assessment: KnowledgeAssessment::Bad,
+                    problems_done: 0,
+                    required_problems: 12,
+                    passed: false,
+                    passed_exam_day: 0,
+                    has_lecture_notes: false,
+                }
+            );
+
+            let mut exam = FfiExam {
+                from: Time(0),
+                to: Time(0),
+                location: Location::Dorm,
+            };
+            assert!(mmheroes_game_get_exam(
+                game,
+                4,
+                Subject::AlgebraAndNumberTheory,
+                &mut exam
+            ));
+            assert_eq!(
+                exam,
+                FfiExam {
+                    from: Time(12),
+                    to: Time(16),
+                    location: Location::PDMI,
+                }
+            );
+            assert!(!mmheroes_game_get_exam(
+                game,
+                2,
+                Subject::AlgebraAndNumberTheory,
+                &mut exam
+            ));
+            assert!(!mmheroes_game_get_exam(
+                game,
+                crate::logic::SemesterLength::NORMAL.days(),
+                Subject::Calculus,
+                &mut exam
+            ));
+            assert_eq!(exam.location, Location::PDMI);
+
+            let mut location = FfiClassmateLocation::Exam(Subject::English);
+            assert!(mmheroes_game_get_classmate_location(
+                game,
+                Classmate::Kolya,
+                &mut location
+            ));
+            assert_eq!(location, FfiClassmateLocation::Nowhere);
+
+            mmheroes_continue(game, FfiInput::Enter);
+            assert!(mmheroes_game_get_screen(game, &mut screen));
+            assert_eq!(screen, FfiGameScreen::SceneRouter);
+
+            // Одногруппники расходятся по локациям, когда проходит время.
+            for _ in 0..4 {
+                mmheroes_continue(game, FfiInput::Enter);
+            }
+            assert!(mmheroes_game_get_screen(game, &mut screen));
+            assert_eq!(screen, FfiGameScreen::SceneRouter);
+            assert!(mmheroes_game_get_classmate_location(
+                game,
+                Classmate::Kolya,
+                &mut location
+            ));
+            assert_eq!(
+                location,
+                FfiClassmateLocation::Location(Location::Mausoleum)
+            );
+            assert!(mmheroes_game_get_classmate_location(
+                game,
+                Classmate::Diamond,
+                &mut location
+            ));
+            assert_eq!(
+                location,
+                FfiClassmateLocation::Location(Location::ComputerClass)
+            );
+
+            mmheroes_game_destroy(game);
+        }
+    }
+
+    #[test]
+    fn test_ffi_actions() {
+        unsafe {
+            let mut log = String::new();
+            let game = mmheroes_game_create(
+                GameMode::Normal,
+                0,
+                6,
+                Locale::Russian,
+                null_mut(),
+                null_mut(),
+                allocator,
+                deallocator,
+                InputRecorderSink {
+                    context: &mut log as *mut String as *mut c_void,
+                    sink: Some(sink),
+                    display: Some(display),
+                },
+            );
+            mmheroes_continue(game, FfiInput::Enter);
+            assert_eq!(mmheroes_available_action_count(game), 0);
+            assert!(!mmheroes_perform_action(game, 0));
+
+            // Доходим до общаги
+            for _ in 0..3 {
+                mmheroes_continue(game, FfiInput::Enter);
+            }
+            assert_eq!(mmheroes_available_action_count(game), 9);
+
+            let mut label = [0u8; 64];
+            let len = mmheroes_get_action_label(game, 4, label.as_mut_ptr(), label.len());
+            assert_eq!(
+                core::str::from_utf8(&label[..len]).unwrap(),
+                "Пойти на факультет"
+            );
+            assert_eq!(mmheroes_get_action_label(game, 0, null_mut(), 0), 20);
+            let mut short_label = [0u8; 5];
+            assert_eq!(
+                mmheroes_get_action_label(
+                    game,
+                    0,
+                    short_label.as_mut_ptr(),
+                    short_label.len()
+                ),
+                20
+            );
+            assert_eq!(&short_label[..4], "Го".as_bytes());
+            assert_eq!(short_label[4], 0);
+            assert_eq!(mmheroes_get_action_label(game, 9, null_mut(), 0), 0);
+
+            assert!(!mmheroes_perform_action(game, 9));
+            assert!(mmheroes_perform_action(game, 4));
+            let mut screen = FfiGameScreen::Terminal;
+            assert!(mmheroes_game_get_screen(game, &mut screen));
+            assert_eq!(screen, FfiGameScreen::SceneRouter);
+            assert_eq!(mmheroes_available_action_count(game), 7);
+            assert!(mmheroes_continue_with_hotkey(game, 'z' as u32));
+            assert!(mmheroes_continue(game, FfiInput::End));
+            mmheroes_flush_input_recorder(game);
+            assert_eq!(log, "4r4↓r@z⇲");
+
+            mmheroes_game_destroy(game);
+        }
+    }
+
+    #[test]
+    fn test_ffi_renderer_requests() {
+        unsafe fn text(requests: &[FfiRendererRequest]) -> String {
+            requests
+                .iter()
+                .filter_map(|request| match *request {
+                    FfiRendererRequest::WriteStr { buf, length } => Some(
+                        core::str::from_utf8(core::slice::from_raw_parts(buf, length))
+                            .unwrap(),
+                    ),
+                    _ => None,
+                })
+                .collect()
+        }
+
+        fn create_game() -> *mut c_void {
+            unsafe {
+                mmheroes_game_create(
+                    GameMode::Normal,
+                    0,
+                    6,
+                    Locale::Russian,
+                    null_mut(),
+                    null_mut(),
+                    allocator,
+                    deallocator,
+                    InputRecorderSink {
+                        context: null_mut(),
+                        sink: None,
+                        display: None,
+                    },
+                )
+            }
+        }
+
+        unsafe {
+            assert!(renderer_requests(core::ptr::null()).is_empty());
+
+            let game = create_game();
+            assert!(renderer_requests(game).is_empty());
+
+            // Доходим до общаги
+            for _ in 0..4 {
+                mmheroes_continue(game, FfiInput::Enter);
+            }
+            let requests = renderer_requests(game);
+            assert_eq!(requests[0], FfiRendererRequest::ClearScreen);
+            let screen = text(&requests);
+            assert!(screen.contains("Ты в общаге. Что делать?"), "{screen}");
+
+            // Запросы остаются доступными, пока игра не продолжилась
+            assert_eq!(renderer_requests(game), requests);
+
+            mmheroes_continue(game, FfiInput::KeyDown);
+            let requests = renderer_requests(game);
+            assert!(!requests.contains(&FfiRendererRequest::ClearScreen));
+            assert!(text(&requests).contains("Посмотреть расписание"));
+
+            // При воспроизведении остаётся только последний экран
+            let replayed_game = create_game();
+            let recording = "4r↓";
+            assert!(mmheroes_replay(
+                replayed_game,
+                recording.as_ptr(),
+                recording.len()
+            ));
+            let replayed_requests = renderer_requests(replayed_game);
+            assert_eq!(replayed_requests[0], FfiRendererRequest::ClearScreen);
+            assert_eq!(
+                replayed_requests
+                    .iter()
+                    .filter(|&&request| request == FfiRendererRequest::ClearScreen)
+                    .count(),
+                1
+            );
+            assert!(text(&replayed_requests).contains("Ты в общаге. Что делать?"));
+
+            mmheroes_game_destroy(replayed_game);
+            mmheroes_game_destroy(game);
         }
     }
 }
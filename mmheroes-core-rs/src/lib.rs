@@ -5,6 +5,8 @@ pub mod util;
 
 mod random;
 
+pub use random::{Rng, SplitMix64};
+
 pub mod ffi;
 pub mod logic;
 pub mod ui;
@@ -1,6 +1,6 @@
 use crate::logic::{Classmate, Subject};
 use crate::util::TinyVec;
-use strum::EnumIter;
+use strum::{EnumIter, IntoStaticStr};
 
 macro_rules! action_conversion {
     ($sub_action:ty, $action:ident) => {
@@ -168,7 +168,7 @@ pub enum InvitationFromNeighborAction {
 
 action_conversion!(InvitationFromNeighborAction, InvitationFromNeighbor);
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, IntoStaticStr)]
 pub enum Action {
     AnyKey,
     YesOrNo(YesOrNoAction),
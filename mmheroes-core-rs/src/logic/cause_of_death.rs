@@ -1,5 +1,6 @@
 use super::subjects::Subject;
-use crate::logic::Classmate;
+use crate::logic::{save, Classmate};
+use strum::VariantArray;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum CauseOfDeath {
@@ -93,3 +94,94 @@ pub enum CauseOfDeath {
     /// Раздавлен безжалостной ошибкой в программе.
     SoftwareBug,
 }
+
+use CauseOfDeath::*;
+
+/// Причины смерти без параметров. В сохранении причина смерти кодируется индексом
+/// в этом массиве, поэтому новые причины нужно добавлять только в конец.
+const SIMPLE_CAUSES_OF_DEATH: [CauseOfDeath; 28] = [
+    OnTheWayToPUNK,
+    OnTheWayToMausoleum,
+    OnTheWayToDorm,
+    FellFromStairs,
+    Burnout,
+    Overstudied,
+    StudiedTooWell,
+    CouldntLeaveTheComputer,
+    CorpseFoundInTheTrain,
+    KilledByInspectors,
+    FellAsleepInTheTrain,
+    SplitPersonality,
+    BeerAlcoholism,
+    DrankTooMuch,
+    DrankTooMuchBeer,
+    Altruism,
+    TurnedToVegetable,
+    DestroyedByVsemirnov,
+    FairyWasNotInTheMood,
+    KilledByRai,
+    ExhaustedByMisha,
+    AndrewCanDefendHimself,
+    DontTalkToDjug,
+    DjugIsDeadly,
+    Paranoia,
+    TimeOut,
+    Suicide,
+    SoftwareBug,
+];
+
+const ALIVE_CODE: u8 = 0;
+const TORTURED_BY_PROFESSOR_CODE: u8 = 0x80;
+const BETTER_NOT_IGNORE_CLASSMATE_CODE: u8 = 0x81;
+
+impl CauseOfDeath {
+    /// Записывает причину смерти (или её отсутствие) в виде двух байт: кода причины
+    /// и её параметра.
+    pub(in crate::logic) fn encode(
+        cause: Option<CauseOfDeath>,
+        w: &mut save::SaveWriter,
+    ) {
+        let (code, argument) = match cause {
+            None => (ALIVE_CODE, 0),
+            Some(TorturedByProfessor(subject)) => {
+                (TORTURED_BY_PROFESSOR_CODE, subject as u8)
+            }
+            Some(BetterNotIgnoreClassmate(classmate)) => {
+                (BETTER_NOT_IGNORE_CLASSMATE_CODE, classmate as u8)
+            }
+            Some(cause) => {
+                let index = SIMPLE_CAUSES_OF_DEATH
+                    .iter()
+                    .position(|&simple_cause| simple_cause == cause)
+                    .expect("Every cause of death must be encodable");
+                (index as u8 + 1, 0)
+            }
+        };
+        w.write_u8(code);
+        w.write_u8(argument);
+    }
+
+    pub(in crate::logic) fn decode(
+        r: &mut save::SaveReader,
+    ) -> Result<Option<CauseOfDeath>, save::SavedGameError> {
+        let code = r.read_u8()?;
+        let argument = r.read_u8()?;
+        let cause = match code {
+            ALIVE_CODE => None,
+            TORTURED_BY_PROFESSOR_CODE => Some(TorturedByProfessor(
+                Subject::from_repr(argument).ok_or(save::SavedGameError::InvalidData)?,
+            )),
+            BETTER_NOT_IGNORE_CLASSMATE_CODE => Some(BetterNotIgnoreClassmate(
+                *Classmate::VARIANTS
+                    .get(argument as usize)
+                    .ok_or(save::SavedGameError::InvalidData)?,
+            )),
+            _ => Some(
+                *SIMPLE_CAUSES_OF_DEATH
+                    .get(code as usize - 1)
+                    .ok_or(save::SavedGameError::InvalidData)?,
+            ),
+        };
+        Ok(cause)
+    }
+}
@@ -4,6 +4,8 @@ macro_rules! define_characteristic {
     ($name:ident) => {
         #[repr(transparent)]
         #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $name(pub i16);
 
         impl core::ops::Add for $name {
@@ -107,6 +109,7 @@ pub type StaminaLevel = i16;
 pub type CharismaLevel = i16;
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[repr(u8)]
 pub enum HealthAssessment {
     /// "живой труп"
     LivingDead,
@@ -135,15 +138,16 @@ pub(in crate::logic) const LOCATION_CHANGE_LARGE_HEALTH_PENALTY: HealthLevel = 3
 pub(in crate::logic) const LOCATION_CHANGE_SMALL_HEALTH_PENALTY: HealthLevel = 2;
 
 impl HealthAssessment {
-    pub fn from_health_level(level: HealthLevel) -> HealthAssessment {
+    pub fn from_health_level(level: HealthLevel, rules: &Rules) -> HealthAssessment {
         use HealthAssessment::*;
+        let bounds = rules.health_assessment_bounds;
         let scale = [
-            (1, LivingDead),
-            (9, TimeToDie),
-            (17, Bad),
-            (25, SoSo),
-            (33, Average),
-            (41, Good),
+            (bounds[0], LivingDead),
+            (bounds[1], TimeToDie),
+            (bounds[2], Bad),
+            (bounds[3], SoSo),
+            (bounds[4], Average),
+            (bounds[5], Good),
         ];
         *crate::util::assess(&scale, &level, &Great)
     }
@@ -154,58 +158,14 @@ impl Money {
         Money(0)
     }
 
-    /// Стоимость настойки овса для Коли
-    pub const fn oat_tincture_cost() -> Money {
-        Money(15)
-    }
-
     /// Размер стипендии
     pub const fn stipend() -> Money {
         Money(50)
     }
-
-    /// Стоимость стакана колы в мавзолее
-    pub const fn cola_cost() -> Money {
-        Money(4)
-    }
-
-    /// Стоимость супа в мавзолее
-    pub const fn soup_cost() -> Money {
-        Money(6)
-    }
-
-    /// Стоимость пива в мавзолее
-    pub const fn beer_cost() -> Money {
-        Money(8)
-    }
-
-    /// Стоимость чая/кофе в буфете ПУНКа/ПОМИ
-    pub const fn drink_cost() -> Money {
-        Money(2)
-    }
-
-    /// Стоимость выпечки в буфете ПУНКа/ПОМИ
-    pub const fn pastry_cost() -> Money {
-        Money(4)
-    }
-
-    /// Стоимость чая/кофе с выпечкой в буфете ПУНКа/ПОМИ
-    pub const fn drink_with_pastry_cost() -> Money {
-        Money(6)
-    }
-
-    /// Стоимость билета на электричку в ПОМИ и обратно
-    pub const fn roundtrip_train_ticket_cost() -> Money {
-        Money(10)
-    }
-
-    /// Стоимость билета на электричку из ПОМИ в ПУНК
-    pub const fn one_way_train_ticket_cost() -> Money {
-        Money(5)
-    }
 }
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[repr(u8)]
 pub enum KnowledgeAssessment {
     Bad,
     Satisfactory,
@@ -215,6 +175,7 @@ pub enum KnowledgeAssessment {
 }
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[repr(u8)]
 pub enum BrainAssessment {
     /// "Клиническая смерть мозга"
     ClinicalBrainDeath,
@@ -245,17 +206,18 @@ pub enum BrainAssessment {
 }
 
 impl BrainAssessment {
-    pub fn from_brain_level(brain_level: BrainLevel) -> BrainAssessment {
+    pub fn from_brain_level(brain_level: BrainLevel, rules: &Rules) -> BrainAssessment {
         use BrainAssessment::*;
+        let bounds = rules.brain_assessment_bounds;
         let scale = [
-            (0, ClinicalBrainDeath),
-            (1, BrainIsAlmostNonFunctioning),
-            (2, ThinkingIsAlmostImpossible),
-            (3, ThinkingIsDifficult),
-            (4, BrainIsAlmostOK),
-            (5, BrainIsOK),
-            (6, BrainIsFresh),
-            (101, ExtraordinaryEaseOfThought),
+            (bounds[0], ClinicalBrainDeath),
+            (bounds[1], BrainIsAlmostNonFunctioning),
+            (bounds[2], ThinkingIsAlmostImpossible),
+            (bounds[3], ThinkingIsDifficult),
+            (bounds[4], BrainIsAlmostOK),
+            (bounds[5], BrainIsOK),
+            (bounds[6], BrainIsFresh),
+            (bounds[7], ExtraordinaryEaseOfThought),
         ];
         let assessment = *crate::util::assess(&scale, &brain_level, &ContactTheDeveloper);
         assert_ne!(assessment, ContactTheDeveloper, "Чересчур умный");
@@ -263,22 +225,37 @@ impl BrainAssessment {
     }
 }
 impl KnowledgeAssessment {
-    pub fn absolute(knowledge: BrainLevel) -> KnowledgeAssessment {
+    pub fn absolute(knowledge: BrainLevel, rules: &Rules) -> KnowledgeAssessment {
         use KnowledgeAssessment::*;
-        let scale = [(6, Bad), (13, Satisfactory), (21, Good), (31, VeryGood)];
+        let bounds = rules.knowledge_assessment_bounds;
+        let scale = [
+            (bounds[0], Bad),
+            (bounds[1], Satisfactory),
+            (bounds[2], Good),
+            (bounds[3], VeryGood),
+        ];
         *crate::util::assess(&scale, &knowledge, &Excellent)
     }
 
-    pub fn relative(knowledge: BrainLevel, subject: Subject) -> KnowledgeAssessment {
-        *crate::util::assess(
-            subject.assessment_bounds(),
-            &knowledge,
-            &KnowledgeAssessment::Excellent,
-        )
+    /// Оценка знаний по шкале препода, который принимает зачёт по предмету `subject`.
+    pub fn relative(
+        knowledge: BrainLevel,
+        subject: Subject,
+        rules: &Rules,
+    ) -> KnowledgeAssessment {
+        use KnowledgeAssessment::*;
+        let bounds = rules.subject(subject).assessment_bounds;
+        let scale = [
+            (bounds[0], Bad),
+            (bounds[1], Satisfactory),
+            (bounds[2], Good),
+        ];
+        *crate::util::assess(&scale, &knowledge, &Excellent)
     }
 }
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[repr(u8)]
 pub enum StaminaAssessment {
     /// "Мама, роди меня обратно!"
     MamaTakeMeBack,
@@ -322,6 +299,7 @@ impl StaminaAssessment {
 }
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[repr(u8)]
 pub enum CharismaAssessment {
     /// "Очень замкнутый товарищ"
     VeryIntroverted,
@@ -6,19 +6,36 @@ pub(in crate::logic) enum GameEnd {
     Restart,
 }
 
-/// Точка входа
-pub(super) async fn run(g: &mut InternalGameState<'_>) {
+/// Точка входа. Если передано сохранённое состояние игры, игра продолжается с него.
+pub(super) async fn run(
+    g: &mut InternalGameState<'_>,
+    mut saved_state: Option<GameState>,
+) {
     loop {
-        let play_style = select_play_style(g).await;
-        let player = g.initialize_player(play_style);
-        g.set_screen_and_wait_for_any_key(GameScreen::Ding).await;
-        let state = GameState::new(
-            player.clone(),
-            Timetable::random(&mut g.rng),
-            Location::Dorm,
-        );
-        timetable::show(g, &state).await;
-        if let GameEnd::Exit = scene_router::run(g, state.clone()).await {
+        let (state, resumed) = match saved_state.take() {
+            Some(mut state) => {
+                state.rules = g.rules;
+                (state, true)
+            }
+            None => {
+                let play_style = select_play_style(g).await;
+                let player = g.initialize_player(play_style);
+                g.set_screen_and_wait_for_any_key(GameScreen::Ding).await;
+                let timetable = match &g.state_holder.timetable {
+                    Some(timetable) => timetable.clone(),
+                    None => Timetable::random(
+                        g.rng.timetable(),
+                        g.rules,
+                        g.state_holder.semester_length(),
+                    ),
+                };
+                let state =
+                    GameState::new(player.clone(), timetable, Location::Dorm, g.rules);
+                timetable::show(g, &state).await;
+                (state, false)
+            }
+        };
+        if let GameEnd::Exit = scene_router::run(g, state, resumed).await {
             return;
         }
     }
@@ -1,7 +1,10 @@
 use super::*;
 use crate::logic::scene_router::{dorm, terkom};
+use strum::IntoStaticStr;
 
-#[derive(Debug)]
+/// Имя варианта (например, `"SceneRouter"`) можно получить через
+/// `<&'static str>::from(&screen)`.
+#[derive(Debug, IntoStaticStr)]
 pub enum GameScreen {
     /// Самый первый экран, который видит пользователь.
     Intro,
@@ -125,6 +128,11 @@ pub enum GameScreen {
     /// Финальный экран с описанием причины смерти/отчисления, либо поздравлением.
     GameEnd(GameState),
 
+    /// Экран, который следует за поздравлением, если игрок сдал все зачёты.
+    /// Если результат игрока попадает в таблицу рекордов, игрок вводит своё имя.
+    /// Затем показывается таблица рекордов.
+    HighScoreEntry(Money),
+
     /// Пользователю предлагается либо повторить игру, либо выйти.
     WannaTryAgain,
 
@@ -244,6 +252,7 @@ impl GameScreen {
             | AndrewInteraction(andrew::AndrewInteraction::AndrewSolvedProblems {
                 ..
             })
+            | HighScoreEntry(_)
             | WannaTryAgain
             | Disclaimer
             | Terminal => None,
@@ -5,8 +5,9 @@ use strum::FromRepr;
 
 #[bitfield(u32, debug = false, default = false)]
 struct GameStateBits {
+    /// Младшие три бита индекса текущего дня.
     #[bits(3)]
-    current_day_index: u8,
+    current_day_index_low: u8,
 
     #[bits(5, default = Time(8))]
     current_time: Time,
@@ -32,16 +33,36 @@ struct GameStateBits {
     #[bits(5)]
     recursion: u8,
 
-    #[bits(10)]
+    /// Старший бит индекса текущего дня. Хранится отдельно от младших, чтобы
+    /// сохранения шестидневной сессии не отличались от сохранений, сделанных
+    /// до появления сессий другой длины.
+    #[bits(1)]
+    current_day_index_high: u8,
+
+    #[bits(9)]
     _padding: u32,
 }
 
+impl GameStateBits {
+    const fn current_day_index(self) -> u8 {
+        self.current_day_index_low() | self.current_day_index_high() << 3
+    }
+
+    fn set_current_day_index(&mut self, index: u8) {
+        self.set_current_day_index_low(index & 0b111);
+        self.set_current_day_index_high(index >> 3);
+    }
+}
+
 #[derive(Clone)]
 pub struct GameState {
     pub(in crate::logic) player: Player,
     pub(in crate::logic) timetable: Timetable,
     pub(in crate::logic) classmates: Classmates,
 
+    /// Правила, по которым идёт игра. В сохранение не попадают.
+    pub(in crate::logic) rules: &'static Rules,
+
     bits: GameStateBits,
 }
 
@@ -50,16 +71,21 @@ impl GameState {
         player: Player,
         timetable: Timetable,
         location: Location,
+        rules: &'static Rules,
     ) -> GameState {
         GameState {
             player,
             timetable,
             classmates: Classmates::new(),
+            rules,
             bits: GameStateBits::new().with_location(location),
         }
     }
 
-    pub(in crate::logic) fn current_day_index(&self) -> u8 {
+    /// Индекс текущего дня. Если игрок не дожил до конца семестра
+    /// (`CauseOfDeath::TimeOut`), он равен продолжительности сессии, и вызывать
+    /// [`GameState::current_day`] нельзя.
+    pub fn current_day_index(&self) -> u8 {
         self.bits.current_day_index()
     }
 
@@ -112,6 +138,10 @@ impl GameState {
         &self.classmates
     }
 
+    pub fn rules(&self) -> &'static Rules {
+        self.rules
+    }
+
     pub(in crate::logic) fn additional_computer_science_exams(&self) -> u8 {
         self.bits.additional_computer_science_exams()
     }
@@ -165,6 +195,55 @@ impl GameState {
     pub(in crate::logic) fn add_recursion_level(&mut self) {
         self.bits.set_recursion(self.bits.recursion() + 1);
     }
+
+    pub(in crate::logic) fn encode(&self, w: &mut save::SaveWriter) {
+        w.write_u32(self.bits.into_bits());
+        self.player.encode(w);
+        self.timetable.encode(w);
+        self.classmates.encode(w);
+    }
+
+    /// Правил в сохранении нет, поэтому у восстановленного состояния правила
+    /// по умолчанию. Игра, которая продолжается с этого состояния, заменяет их
+    /// своими.
+    pub(in crate::logic) fn decode(
+        r: &mut save::SaveReader,
+    ) -> Result<GameState, save::SavedGameError> {
+        let raw_bits = r.read_u32()?;
+        let bits = GameStateBits::from_bits(raw_bits);
+        save::ensure_valid(bits.current_time() < Time(24))?;
+        save::ensure_valid(
+            Location::from_repr(save::raw_field(
+                raw_bits,
+                GameStateBits::LOCATION_OFFSET,
+                GameStateBits::LOCATION_BITS,
+            ) as u8)
+            .is_some(),
+        )?;
+        Ok(GameState {
+            player: Player::decode(r)?,
+            timetable: Timetable::decode(r)?,
+            classmates: Classmates::decode(r)?,
+            rules: &Rules::DEFAULT,
+            bits,
+        })
+    }
+
+    /// Проверяет индексы дней в восстановленном состоянии. Вызывается, когда
+    /// известна продолжительность сессии, то есть после
+    /// [`Timetable::decode_extension`].
+    pub(in crate::logic) fn validate_day_indices(
+        &self,
+    ) -> Result<(), save::SavedGameError> {
+        let num_days = self.timetable.length().days();
+        save::ensure_valid(self.current_day_index() < num_days)?;
+        save::ensure_valid(Subject::all_subjects().all(|subject| {
+            self.player
+                .status_for_subject(subject)
+                .passed_exam_day_index()
+                .is_none_or(|day_index| day_index < num_days)
+        }))
+    }
 }
 
 impl Debug for GameState {
@@ -199,6 +278,8 @@ impl Debug for GameState {
 }
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug, FromRepr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
 pub enum Location {
     PUNK = 1,
     PDMI = 2,
@@ -209,7 +290,7 @@ pub enum Location {
 
 impl Location {
     pub(super) const fn from_bits(bits: u8) -> Location {
-        match Location::from_repr(bits as usize) {
+        match Location::from_repr(bits) {
             Some(location) => location,
             None => panic!("Invalid location"),
         }
@@ -222,11 +303,11 @@ impl Location {
     pub fn is_exam_here_on_day(self, subject: Subject, today: &Day) -> bool {
         today
             .exam(subject)
-            .map_or(false, |exam| exam.location() == self)
+            .is_some_and(|exam| exam.location() == self)
     }
 
     pub fn is_exam_here_now(self, subject: Subject, today: &Day, time: Time) -> bool {
-        today.exam(subject).map_or(false, |exam| {
+        today.exam(subject).is_some_and(|exam| {
             exam.location() == self && time >= exam.from() && time < exam.to()
         })
     }
@@ -238,10 +319,14 @@ mod tests {
 
     #[test]
     fn test_game_state_bits() {
-        let mut rng = random::Rng::new(0);
+        let mut rng = random::SplitMix64::new(0);
         let player = Player::new(false, 10, 11, 12, 13, |subject| subject as i16);
-        let mut state =
-            GameState::new(player, Timetable::random(&mut rng), Location::Dorm);
+        let mut state = GameState::new(
+            player,
+            Timetable::random(&mut rng, &Rules::DEFAULT, SemesterLength::NORMAL),
+            Location::Dorm,
+            &Rules::DEFAULT,
+        );
         assert_eq!(state.bits.0, 0b1_100_111_00_01000_000);
         assert!(state.sasha_has_lecture_notes(Subject::AlgebraAndNumberTheory));
         assert!(state.sasha_has_lecture_notes(Subject::Calculus));
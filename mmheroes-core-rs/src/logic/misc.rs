@@ -29,6 +29,12 @@ pub(in crate::logic) async fn hour_pass(
     state: &mut GameState,
     exam_in_progress: Option<Subject>,
 ) {
+    if state.current_day_index() == state.timetable().length().days() {
+        // Семестр закончился посреди какого-то долгого занятия (например,
+        // вечеринки у соседа), и игрок уже умер от `CauseOfDeath::TimeOut`.
+        // Время дальше не идёт: расписания на следующий день нет.
+        return;
+    }
     state.set_terkom_has_places(true);
     g.run_classmate_routines(state);
     state.adjust_time(Duration(1));
@@ -72,6 +78,12 @@ pub(in crate::logic) async fn game_end(
 ) -> GameEnd {
     g.set_screen_and_wait_for_any_key(GameScreen::GameEnd(state.clone()))
         .await;
+    if state.player.cause_of_death.is_none() && state.player.exams_left() == 0 {
+        g.set_screen_and_wait_for_any_key(GameScreen::HighScoreEntry(
+            state.player.score(),
+        ))
+        .await;
+    }
     // Хочешь попробовать снова? Да или нет.
     match g
         .set_screen_and_wait_for_action(GameScreen::WannaTryAgain)
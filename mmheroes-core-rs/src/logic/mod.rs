@@ -1,5 +1,7 @@
 pub mod timetable;
-pub use timetable::{Day, Duration, Time, Timetable};
+pub use timetable::{
+    Day, Duration, SemesterLength, Time, Timetable, TimetableBuilder, TimetableError,
+};
 
 pub mod characteristics;
 pub use characteristics::*;
@@ -31,14 +33,24 @@ pub use game_screen::*;
 
 pub mod scene_router;
 
+pub mod save;
+pub use save::{SavedGame, SavedGameError};
+
+pub mod rules;
+pub use rules::{Rules, RulesError};
+
+pub mod simulation;
+pub use simulation::{Simulation, SimulationError};
+
 mod entry_point;
 mod misc;
 pub mod sleep;
 
-use crate::random;
+pub use crate::random::RngMode;
+use crate::random::{self, Rng, RngStreams};
 
 use crate::util::async_support::*;
-use core::cell::{Ref, RefCell};
+use core::cell::{Cell, Ref, RefCell};
 use core::future::Future;
 use core::pin::Pin;
 use strum::IntoEnumIterator;
@@ -103,14 +115,19 @@ impl ObservableGameState {
 
 struct InternalGameState<'a> {
     state_holder: &'a StateHolder,
-    rng: random::Rng,
+    rng: RngStreams,
+    rules: &'static Rules,
 }
 
-impl<'a: 'b, 'b> InternalGameState<'a> {
-    fn new(seed: u64, state_holder: &'a StateHolder) -> InternalGameState<'a> {
-        let rng = random::Rng::new(seed);
+impl<'a> InternalGameState<'a> {
+    fn new(rng: RngStreams, state_holder: &'a StateHolder) -> InternalGameState<'a> {
+        state_holder.rng_mode.set(rng.state().mode);
         state_holder.observable_state.borrow_mut().reset();
-        InternalGameState { state_holder, rng }
+        InternalGameState {
+            state_holder,
+            rng,
+            rules: state_holder.rules,
+        }
     }
 
     fn set_screen(&self, new_screen: GameScreen) {
@@ -161,6 +178,12 @@ impl<'a: 'b, 'b> InternalGameState<'a> {
         state.available_actions = actions;
     }
 
+    /// Запоминает состояние генераторов случайных чисел, чтобы игру можно было
+    /// сохранить на текущем экране.
+    fn set_save_point(&self) {
+        self.state_holder.save_point_rng_state.set(self.rng.state());
+    }
+
     fn set_available_actions_from_vec(&self, actions: ActionVec) {
         self.state_holder
             .observable_state
@@ -172,35 +195,35 @@ impl<'a: 'b, 'b> InternalGameState<'a> {
         let (god_mode, brain, stamina, charisma) = match style {
             actions::PlayStyle::RandomStudent => (
                 false,
-                self.rng.random_in_range(4..7),
-                self.rng.random_in_range(4..7),
-                self.rng.random_in_range(4..7),
+                self.rng.initial_parameters().random_in_range(4..7),
+                self.rng.initial_parameters().random_in_range(4..7),
+                self.rng.initial_parameters().random_in_range(4..7),
             ),
             actions::PlayStyle::CleverStudent => (
                 false,
-                self.rng.random_in_range(5..10),
-                self.rng.random_in_range(2..5),
-                self.rng.random_in_range(2..5),
+                self.rng.initial_parameters().random_in_range(5..10),
+                self.rng.initial_parameters().random_in_range(2..5),
+                self.rng.initial_parameters().random_in_range(2..5),
             ),
             actions::PlayStyle::ImpudentStudent => (
                 false,
-                self.rng.random_in_range(2..5),
-                self.rng.random_in_range(5..10),
-                self.rng.random_in_range(2..5),
+                self.rng.initial_parameters().random_in_range(2..5),
+                self.rng.initial_parameters().random_in_range(5..10),
+                self.rng.initial_parameters().random_in_range(2..5),
             ),
             actions::PlayStyle::SociableStudent => (
                 false,
-                self.rng.random_in_range(2..5),
-                self.rng.random_in_range(2..5),
-                self.rng.random_in_range(5..10),
+                self.rng.initial_parameters().random_in_range(2..5),
+                self.rng.initial_parameters().random_in_range(2..5),
+                self.rng.initial_parameters().random_in_range(5..10),
             ),
             actions::PlayStyle::GodMode => (true, 30, 30, 30),
         };
 
-        let health = self.rng.random(stamina * 2) + 40;
+        let health = self.rng.initial_parameters().random(stamina * 2) + 40;
 
         Player::new(god_mode, health, brain, stamina, charisma, |_| {
-            self.rng.random(brain)
+            self.rng.initial_parameters().random(brain)
         })
     }
 
@@ -211,7 +234,7 @@ impl<'a: 'b, 'b> InternalGameState<'a> {
         let location = state.location();
         let classmates = &mut state.classmates;
         for classmate in classmates.iter_mut() {
-            classmate.update(&mut self.rng, location, day, time);
+            classmate.update(self.rng.classmates(), location, day, time);
         }
     }
 
@@ -245,25 +268,140 @@ impl<F: Future> Game for GameExecutor<'_, F> {
 pub struct StateHolder {
     observable_state: RefCell<ObservableGameState>,
     shared_future_data: RefCell<Option<FutureData<Action, ()>>>,
+    save_point_rng_state: Cell<random::RngState>,
+    rng_mode: Cell<RngMode>,
+
+    /// Сохранение, к которому нужно вернуть игру (см. [`StateHolder::restore_game`]).
+    restore_request: RefCell<Option<SavedGame>>,
+
+    rules: &'static Rules,
+
+    /// Расписание, с которым начинается новая игра. `None` — случайное.
+    timetable: Option<Timetable>,
+
+    semester_length: SemesterLength,
 }
 
 impl StateHolder {
     pub fn new(mode: GameMode) -> Self {
+        Self::with_valid_rules(mode, &Rules::DEFAULT)
+    }
+
+    /// Как [`StateHolder::new`], но игра будет идти по правилам `rules` вместо
+    /// правил оригинальной игры. Если правила не проходят [`Rules::validate`],
+    /// возвращается ошибка.
+    pub fn with_rules(mode: GameMode, rules: &'static Rules) -> Result<Self, RulesError> {
+        rules.validate()?;
+        Ok(Self::with_valid_rules(mode, rules))
+    }
+
+    fn with_valid_rules(mode: GameMode, rules: &'static Rules) -> Self {
         Self {
             observable_state: RefCell::new(ObservableGameState::new(mode)),
             shared_future_data: RefCell::new(None),
+            save_point_rng_state: Cell::new(random::RngState {
+                mode: RngMode::default(),
+                streams: [0; random::STREAM_COUNT],
+            }),
+            rng_mode: Cell::new(RngMode::default()),
+            restore_request: RefCell::new(None),
+            rules,
+            timetable: None,
+            semester_length: SemesterLength::NORMAL,
         }
     }
 
-    pub fn observable_state(&self) -> Ref<ObservableGameState> {
+    /// Игра, созданная с помощью [`create_game`], будет получать случайные числа
+    /// так, как указано в `rng_mode`. По умолчанию — [`RngMode::SingleStream`].
+    pub fn with_rng_mode(self, rng_mode: RngMode) -> Self {
+        self.rng_mode.set(rng_mode);
+        self
+    }
+
+    /// Игра, созданная с помощью [`create_game`], начнётся с расписания
+    /// `timetable` вместо случайного (см. [`TimetableBuilder`]). Если игра
+    /// продолжена из сохранения, расписание берётся из сохранения.
+    /// Продолжительность сессии в этом случае тоже берётся из расписания.
+    pub fn with_timetable(mut self, timetable: Timetable) -> Self {
+        self.timetable = Some(timetable);
+        self
+    }
+
+    /// Сессия в игре, созданной с помощью [`create_game`], будет длиться
+    /// `semester_length` дней. По умолчанию — [`SemesterLength::NORMAL`].
+    pub fn with_semester_length(mut self, semester_length: SemesterLength) -> Self {
+        self.semester_length = semester_length;
+        self
+    }
+
+    /// Продолжительность сессии в новой игре со случайным расписанием.
+    pub fn semester_length(&self) -> SemesterLength {
+        self.semester_length
+    }
+
+    pub fn rules(&self) -> &'static Rules {
+        self.rules
+    }
+
+    /// Как игра получает случайные числа. Если игра продолжена из сохранения,
+    /// режим берётся из сохранения.
+    pub fn rng_mode(&self) -> RngMode {
+        self.rng_mode.get()
+    }
+
+    pub fn observable_state(&self) -> Ref<'_, ObservableGameState> {
         self.observable_state.borrow()
     }
 }
 
+/// Создаёт новую игру с зерном `seed`. Режим игры, правила, продолжительность
+/// сессии и генератор случайных чисел берутся из `state_holder`: например,
+/// `StateHolder::new(mode).with_rng_mode(RngMode::BorlandPascal)` создаёт игру,
+/// в которой случайные числа получаются так же, как в оригинале
+/// (см. [`StateHolder::with_rng_mode`]).
 pub fn create_game(seed: u64, state_holder: &StateHolder) -> impl Game + '_ {
-    let mut game = InternalGameState::new(seed, state_holder);
+    create_or_restore_game(seed, None, state_holder)
+}
+
+/// Создаёт новую игру, если `saved_game` — `None`, иначе продолжает сохранённую игру
+/// с того экрана, на котором она была сохранена. Режим игры и
+/// [`RngMode`] в этом случае берутся из сохранения, а `seed` не используется.
+pub fn create_or_restore_game(
+    seed: u64,
+    saved_game: Option<SavedGame>,
+    state_holder: &StateHolder,
+) -> impl Game + '_ {
+    let (mut game, mut saved_state) = match saved_game {
+        Some(saved_game) => {
+            let game = InternalGameState::new(
+                RngStreams::restore(saved_game.rng_state),
+                state_holder,
+            );
+            state_holder.observable_state.borrow_mut().mode = saved_game.mode;
+            (game, Some(saved_game.state))
+        }
+        None => {
+            let rng = RngStreams::new(seed, state_holder.rng_mode());
+            (InternalGameState::new(rng, state_holder), None)
+        }
+    };
     GameExecutor::new(
-        async move { entry_point::run(&mut game).await },
+        async move {
+            loop {
+                let run = entry_point::run(&mut game, saved_state.take());
+                match interruptible(run, &state_holder.restore_request).await {
+                    Ok(()) => return,
+                    Err(saved_game) => {
+                        game = InternalGameState::new(
+                            RngStreams::restore(saved_game.rng_state),
+                            state_holder,
+                        );
+                        state_holder.observable_state.borrow_mut().mode = saved_game.mode;
+                        saved_state = Some(saved_game.state);
+                    }
+                }
+            }
+        },
         &state_holder.shared_future_data,
     )
 }
@@ -275,7 +413,7 @@ mod memory_tests {
     #[test]
     fn observable_game_state_memory() {
         let observable_game_state = ObservableGameState::new(GameMode::Normal);
-        assert_eq!(size_of_val(&observable_game_state), 216);
+        assert_eq!(size_of_val(&observable_game_state), 352);
     }
 
     #[test]
@@ -300,23 +438,23 @@ mod memory_tests {
 
     #[test]
     fn timetable_memory() {
-        assert_eq!(size_of::<Timetable>(), 84);
+        assert_eq!(size_of::<Timetable>(), 198);
     }
 
     #[test]
     fn game_state_memory() {
-        assert_eq!(size_of::<GameState>(), 164);
+        assert_eq!(size_of::<GameState>(), 288);
     }
 
     #[test]
     fn game_screen_memory() {
-        assert_eq!(size_of::<GameScreen>(), 172);
+        assert_eq!(size_of::<GameScreen>(), 304);
     }
 
     #[test]
     fn whole_game_memory() {
         let state_holder = StateHolder::new(GameMode::Normal);
         let game = create_game(0, &state_holder);
-        assert_eq!(size_of_val(&game), 1416);
+        assert_eq!(size_of_val(&game), 2696);
     }
 }
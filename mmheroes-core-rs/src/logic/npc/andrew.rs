@@ -109,13 +109,15 @@ pub(super) async fn interact(
         .await
     {
         actions::HelpFromAndrewAction::YesAmIWorseThanEveryoneElse => {
-            if state.player.charisma > g.rng.random(14) {
+            if state.player.charisma > g.rng.conversations().random(14) {
                 let problems_remaining = state
                     .player
                     .status_for_subject(subject)
-                    .problems_remaining();
+                    .problems_remaining(g.rules);
                 let mut solved_by_andrew =
-                    (g.rng.random(problems_remaining) as f32).sqrt().floor() as u8;
+                    (g.rng.conversations().random(problems_remaining) as f32)
+                        .sqrt()
+                        .floor() as u8;
                 if solved_by_andrew > 2 {
                     solved_by_andrew = 0;
                 }
@@ -126,7 +128,7 @@ pub(super) async fn interact(
                 let no_problems_remaining = state
                     .player
                     .status_for_subject(subject)
-                    .solved_all_problems();
+                    .solved_all_problems(g.rules);
                 g.set_screen_and_wait_for_any_key(GameScreen::AndrewInteraction(
                     AndrewSolvedProblems {
                         solved_by_andrew,
@@ -134,7 +136,7 @@ pub(super) async fn interact(
                     },
                 ))
                 .await;
-                state.player.stamina -= g.rng.random(2);
+                state.player.stamina -= g.rng.conversations().random(2);
                 misc::hour_pass(g, state, exam_in_progress).await;
             } else {
                 g.set_screen_and_wait_for_any_key(GameScreen::AndrewInteraction(
@@ -143,18 +145,21 @@ pub(super) async fn interact(
                 .await;
                 misc::decrease_health(
                     state,
-                    g.rng.random_in_range(2..7),
+                    g.rng.conversations().random_in_range(2..7),
                     CauseOfDeath::AndrewCanDefendHimself,
                 )
             }
         }
         actions::HelpFromAndrewAction::IWillDoItMyself => {
-            if g.rng.roll_dice(3) {
+            if g.rng.conversations().roll_dice(3) {
                 // Баг в оригинальной реализации: прогноз берётся для текущего предмета,
                 // но отображается имя случайного преподавателя.
-                let random_subject = g.rng.random_variant();
+                let random_subject = g.rng.conversations().random_variant();
                 let prediction = scene_router::exams::number_of_problems_accepted(
-                    &mut g.rng, state, subject, false,
+                    g.rng.conversations(),
+                    state,
+                    subject,
+                    false,
                 );
                 g.set_screen_and_wait_for_any_key(GameScreen::AndrewInteraction(
                     ScorePrediction {
@@ -164,7 +169,7 @@ pub(super) async fn interact(
                 ))
                 .await;
             } else {
-                let reply = g.rng.random_variant();
+                let reply = g.rng.conversations().random_variant();
                 g.set_screen_and_wait_for_any_key(GameScreen::AndrewInteraction(
                     RandomReply(reply),
                 ))
@@ -114,7 +114,7 @@ pub(super) async fn interact(
 ) {
     if !state.player().has_mmheroes_floppy()
         && state.location() == Location::ComputerClass
-        && g.rng.roll_dice(8)
+        && g.rng.conversations().roll_dice(8)
     {
         match g
             .set_screen_and_wait_for_action::<MmheroesFloppyAction>(
@@ -147,9 +147,9 @@ pub(super) async fn interact(
         return;
     }
 
-    let reply = g.rng.random_variant();
+    let reply = g.rng.conversations().random_variant();
 
-    let diamond_leaves = exam_in_progress.is_none() && g.rng.roll_dice(2);
+    let diamond_leaves = exam_in_progress.is_none() && g.rng.conversations().roll_dice(2);
     g.set_screen_and_wait_for_any_key(GameScreen::DiamondInteraction(
         state.clone(),
         Reply(reply),
@@ -8,7 +8,7 @@ pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameStat
         .status_for_subject_mut(Subject::GeometryAndTopology)
         .knowledge;
     if *geometry_knowledge > 5 {
-        *geometry_knowledge -= g.rng.random(5);
+        *geometry_knowledge -= g.rng.conversations().random(5);
     }
     misc::decrease_health(state, 15, CauseOfDeath::DontTalkToDjug);
 }
@@ -2,6 +2,7 @@ use crate::logic::actions::TerkomEmploymentAction;
 use crate::logic::{
     misc, CauseOfDeath, GameScreen, GameState, InternalGameState, Location,
 };
+use crate::random::Rng;
 use strum::VariantArray;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -78,7 +79,8 @@ use GrishaInteraction::*;
 
 pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameState) {
     assert_eq!(state.location(), Location::Mausoleum);
-    let mut has_enough_charisma = || state.player.charisma > g.rng.random(20);
+    let mut has_enough_charisma =
+        || state.player.charisma > g.rng.conversations().random(20);
     if !state.player.is_employed_at_terkom() && has_enough_charisma() {
         match g
             .set_screen_and_wait_for_action::<TerkomEmploymentAction>(
@@ -110,9 +112,9 @@ pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameStat
         .await;
         state.player.set_has_internet();
     } else {
-        let drink_beer = g.rng.random(3) > 0;
-        let hour_pass = g.rng.roll_dice(3);
-        let reply = g.rng.random_variant();
+        let drink_beer = g.rng.conversations().random(3) > 0;
+        let hour_pass = g.rng.conversations().roll_dice(3);
+        let reply = g.rng.conversations().random_variant();
         g.set_screen_and_wait_for_any_key(GameScreen::GrishaInteraction(
             state.clone(),
             RandomReply {
@@ -123,8 +125,12 @@ pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameStat
         ))
         .await;
         if drink_beer {
-            misc::decrease_brain(state, g.rng.random(2), CauseOfDeath::DrankTooMuchBeer);
-            state.player.charisma += g.rng.random(2);
+            misc::decrease_brain(
+                state,
+                g.rng.conversations().random(2),
+                CauseOfDeath::DrankTooMuchBeer,
+            );
+            state.player.charisma += g.rng.conversations().random(2);
         }
         if hour_pass {
             misc::hour_pass(g, state, None).await;
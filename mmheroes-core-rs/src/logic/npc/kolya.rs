@@ -1,8 +1,9 @@
 use crate::logic::actions::YesOrNoAction;
 use crate::logic::Subject::AlgebraAndNumberTheory;
 use crate::logic::{
-    misc, CauseOfDeath, GameScreen, GameState, InternalGameState, Location, Money, Player,
+    misc, CauseOfDeath, GameScreen, GameState, InternalGameState, Location,
 };
+use crate::random::Rng;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum KolyaInteraction {
@@ -33,13 +34,14 @@ pub enum KolyaInteraction {
 
 use KolyaInteraction::*;
 
-fn can_solve_algebra_problems(rng: &mut crate::random::Rng, player: &mut Player) -> bool {
-    if player.charisma <= rng.random(10) {
+fn can_solve_algebra_problems(rng: &mut impl Rng, state: &GameState) -> bool {
+    if state.player.charisma <= rng.random(10) {
         return false;
     }
-    player
+    state
+        .player
         .status_for_subject(AlgebraAndNumberTheory)
-        .problems_remaining()
+        .problems_remaining(state.rules)
         >= 2
 }
 
@@ -62,11 +64,11 @@ async fn solve_algebra_problems(
 
 pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameState) {
     assert_eq!(state.location(), Location::Mausoleum);
-    if can_solve_algebra_problems(&mut g.rng, &mut state.player) {
+    if can_solve_algebra_problems(g.rng.conversations(), state) {
         return solve_algebra_problems(g, state, SolvedAlgebraProblemsForFree).await;
     }
 
-    if state.player.money < Money::oat_tincture_cost() {
+    if state.player.money < g.rules.costs.oat_tincture {
         // "Коля достает тормозную жидкость, и вы распиваете еще по стакану."
         g.set_screen_and_wait_for_any_key(GameScreen::KolyaInteraction(
             state.clone(),
@@ -84,7 +86,7 @@ pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameStat
             .await
         {
             YesOrNoAction::Yes => {
-                if can_solve_algebra_problems(&mut g.rng, &mut state.player) {
+                if can_solve_algebra_problems(g.rng.conversations(), state) {
                     solve_algebra_problems(g, state, SolvedAlgebraProblemsForOatTincture)
                         .await;
                 } else {
@@ -94,7 +96,7 @@ pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameStat
                     ))
                     .await;
                 }
-                state.player.money -= Money::oat_tincture_cost();
+                state.player.money -= g.rules.costs.oat_tincture;
             }
             YesOrNoAction::No => {
                 g.set_screen_and_wait_for_any_key(GameScreen::KolyaInteraction(
@@ -2,7 +2,7 @@ use crate::logic::{
     timetable, Duration, GameScreen, GameState, InternalGameState, Location, Subject,
     Time,
 };
-use crate::random;
+use crate::random::Rng;
 use strum::VariantArray;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -56,14 +56,14 @@ pub enum KuzmenkoReply {
 
 use KuzmenkoInteraction::*;
 
-fn additional_exam_day_index(rng: &mut random::Rng, state: &mut GameState) -> Option<u8> {
+fn additional_exam_day_index(rng: &mut impl Rng, state: &mut GameState) -> Option<u8> {
     let tomorrow = state.current_day_index() + 1;
-    let saturday = 5;
-    if tomorrow > saturday {
+    let last_day = state.timetable.last_day_index();
+    if tomorrow > last_day {
         return None;
     }
     let mut additional_exam_day_idx: Option<u8> = None;
-    for i in (tomorrow..=saturday).rev() {
+    for i in (tomorrow..=last_day).rev() {
         let day = state.timetable.day_mut(i);
         let has_enough_charisma = state.player.charisma > rng.random(18);
         let can_add_exam = day.exam(Subject::ComputerScience).is_none();
@@ -85,7 +85,7 @@ fn additional_exam_day_index(rng: &mut random::Rng, state: &mut GameState) -> Op
 }
 
 pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameState) {
-    let new_screen = match additional_exam_day_index(&mut g.rng, state) {
+    let new_screen = match additional_exam_day_index(g.rng.conversations(), state) {
         Some(additional_exam_day_idx)
             if state.additional_computer_science_exams() < 2 =>
         {
@@ -103,7 +103,7 @@ pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameStat
         }
         _ => GameScreen::KuzmenkoInteraction(
             state.clone(),
-            RandomReply(g.rng.random_variant()),
+            RandomReply(g.rng.conversations().random_variant()),
         ),
     };
     g.set_screen_and_wait_for_any_key(new_screen).await;
@@ -2,6 +2,7 @@ use crate::logic::{
     actions, misc, CauseOfDeath, GameScreen, GameState, InternalGameState, Location,
     Subject,
 };
+use crate::random::Rng;
 use strum::VariantArray;
 
 #[derive(Debug, Clone)]
@@ -104,13 +105,13 @@ pub(super) async fn interact(
     if state.location() == Location::PUNK
         && exam_in_progress.is_none()
         && state.player().is_employed_at_terkom()
-        && state.player().charisma() > g.rng.random(8)
+        && state.player().charisma() > g.rng.conversations().random(8)
     {
         maybe_play_tennis_with_misha(g, state, exam_in_progress).await;
         return;
     }
 
-    let reply = g.rng.random_variant();
+    let reply = g.rng.conversations().random_variant();
     g.set_screen_and_wait_for_any_key(GameScreen::MishaInteraction(RandomReply(
         state.clone(),
         reply,
@@ -140,7 +141,7 @@ async fn maybe_play_bug_squasher_with_misha(
         actions::BugSquasherAction::NoIWontPlay => {
             g.set_screen_and_wait_for_any_key(GameScreen::MishaInteraction(TooBad))
                 .await;
-            state.player.charisma -= g.rng.random(2);
+            state.player.charisma -= g.rng.conversations().random(2);
         }
     }
 }
@@ -162,10 +163,10 @@ async fn maybe_play_tennis_with_misha(
             ))
             .await;
             state.player.charisma += 1;
-            if state.player.charisma < g.rng.random(10) {
+            if state.player.charisma < g.rng.conversations().random(10) {
                 misc::decrease_health(
                     state,
-                    g.rng.random_in_range(3..6),
+                    g.rng.conversations().random_in_range(3..6),
                     CauseOfDeath::ExhaustedByMisha,
                 )
             }
@@ -15,6 +15,7 @@ use super::*;
 use strum::VariantArray;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, VariantArray)]
+#[repr(u8)]
 pub enum Classmate {
     Kolya = 0,
     Pasha,
@@ -36,27 +37,6 @@ impl From<Classmate> for u16 {
     }
 }
 
-impl Classmate {
-    pub(in crate::logic) fn health_penalty(self) -> HealthLevel {
-        match self {
-            Kolya | Pasha | Diamond | Kuzmenko | DJuG | Andrew | Grisha | Misha
-            | Serj | Sasha => 0,
-            RAI | NiL => 8,
-        }
-    }
-
-    /// «Вероятность» того, что человек будет приставать во время сдачи зачёта.
-    pub(in crate::logic) fn annoyance(self) -> i16 {
-        match self {
-            Kolya | Pasha | Diamond | Serj | Sasha | Kuzmenko | DJuG | Andrew
-            | Grisha => 0,
-            RAI => 4,
-            Misha => 2,
-            NiL => 6,
-        }
-    }
-}
-
 use Classmate::*;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -69,7 +49,7 @@ pub enum ClassmateLocation {
 #[derive(Debug, Clone)]
 pub struct ClassmateInfo {
     classmate: Classmate,
-    current_location: ClassmateLocation,
+    pub(in crate::logic) current_location: ClassmateLocation,
 }
 
 impl ClassmateInfo {
@@ -83,7 +63,7 @@ impl ClassmateInfo {
 
     pub(in crate::logic) fn update(
         &mut self,
-        rng: &mut random::Rng,
+        rng: &mut impl Rng,
         current_location: Location,
         today: &Day,
         time: Time,
@@ -215,7 +195,7 @@ impl ClassmateInfo {
 }
 
 fn maybe_on_exam_inner<I: IntoIterator<Item = Subject>>(
-    rng: &mut random::Rng,
+    rng: &mut impl Rng,
     current_location: Location,
     today: &Day,
     classmate: &mut ClassmateInfo,
@@ -236,7 +216,7 @@ fn maybe_on_exam_inner<I: IntoIterator<Item = Subject>>(
 }
 
 fn maybe_on_exam<I: IntoIterator<Item = Subject>>(
-    rng: &mut random::Rng,
+    rng: &mut impl Rng,
     current_location: Location,
     today: &Day,
     classmate: &mut ClassmateInfo,
@@ -330,6 +310,36 @@ impl Classmates {
         self.iter()
             .filter(move |&classmate| classmate.is_at_exam(subject))
     }
+
+    /// Местонахождение каждого NPC кодируется одним байтом: старшие 4 бита —
+    /// вид местонахождения, младшие — предмет или локация.
+    pub(in crate::logic) fn encode(&self, w: &mut save::SaveWriter) {
+        for classmate in self.iter() {
+            w.write_u8(match classmate.current_location {
+                ClassmateLocation::Nowhere => 0,
+                ClassmateLocation::Exam(subject) => 0x10 | subject as u8,
+                ClassmateLocation::Location(location) => 0x20 | location as u8,
+            });
+        }
+    }
+
+    pub(in crate::logic) fn decode(
+        r: &mut save::SaveReader,
+    ) -> Result<Classmates, save::SavedGameError> {
+        let mut classmates = Classmates::new();
+        for classmate in classmates.iter_mut() {
+            let code = r.read_u8()?;
+            let argument = code & 0xF;
+            classmate.current_location = match code >> 4 {
+                0 if argument == 0 => Some(ClassmateLocation::Nowhere),
+                1 => Subject::from_repr(argument).map(ClassmateLocation::Exam),
+                2 => Location::from_repr(argument).map(ClassmateLocation::Location),
+                _ => None,
+            }
+            .ok_or(save::SavedGameError::InvalidData)?;
+        }
+        Ok(classmates)
+    }
 }
 
 impl core::ops::Index<Classmate> for Classmates {
@@ -21,6 +21,7 @@ pub enum NilInteraction {
 use crate::logic::nil::NilInteraction::{
     DidntWorkOut, RefusedToHelp, ThanksHereIsYourMoney,
 };
+use crate::random::Rng;
 use NilInteraction::WillYouHelpMe;
 
 pub(super) async fn interact(
@@ -41,7 +42,7 @@ pub(super) async fn interact(
         actions::NilAction::YesOfCourse => {
             let player = &mut state.player;
             let subject_knowledge = player.status_for_subject(subject).knowledge();
-            if subject_knowledge > subject.mental_load() {
+            if subject_knowledge > g.rules.subject(subject).mental_load {
                 let reward = Money(subject_knowledge);
                 g.set_screen_and_wait_for_any_key(GameScreen::NilInteraction(
                     ThanksHereIsYourMoney(reward),
@@ -52,11 +53,13 @@ pub(super) async fn interact(
                 // Тут что-то странное, но так сделано в оригинале.
                 // При этом может возникнуть отрицательный уровень знания.
                 player.status_for_subject_mut(subject).knowledge -=
-                    subject.single_problem_mental_factor() as i16
-                        + g.rng.random(subject.health_penalty());
+                    g.rules.subject(subject).single_problem_mental_factor as i16
+                        + g.rng
+                            .conversations()
+                            .random(g.rules.subject(subject).health_penalty);
                 misc::decrease_health(
                     state,
-                    subject.health_penalty(),
+                    g.rules.subject(subject).health_penalty,
                     CauseOfDeath::Altruism,
                 );
                 misc::hour_pass(g, state, exam_in_progress).await;
@@ -68,7 +71,7 @@ pub(super) async fn interact(
                 misc::hour_pass(g, state, exam_in_progress).await;
                 misc::decrease_health(
                     state,
-                    subject.health_penalty(),
+                    g.rules.subject(subject).health_penalty,
                     CauseOfDeath::Altruism,
                 );
             }
@@ -79,9 +82,9 @@ pub(super) async fn interact(
             // Баг в оригинальной реализации: если какая-то из этих характеристик
             // опускается до нуля, смерть не наступает.
             let player = &mut state.player;
-            player.brain -= g.rng.random(2);
-            player.charisma -= g.rng.random(2);
-            player.stamina -= g.rng.random(2);
+            player.brain -= g.rng.conversations().random(2);
+            player.charisma -= g.rng.conversations().random(2);
+            player.stamina -= g.rng.conversations().random(2);
         }
     }
 }
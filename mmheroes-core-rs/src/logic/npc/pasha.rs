@@ -33,7 +33,7 @@ pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameStat
             for subject in Subject::all_subjects() {
                 let knowledge = &mut player.status_for_subject_mut(subject).knowledge;
                 if *knowledge > 3 {
-                    *knowledge -= g.rng.random(3);
+                    *knowledge -= g.rng.conversations().random(3);
                 }
             }
         }
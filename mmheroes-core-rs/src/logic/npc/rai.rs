@@ -42,8 +42,11 @@ pub(super) async fn interact(
             {
                 RaiAction::YesOfCourse => {
                     if g.rng
+                        .conversations()
                         .random(state.player.status_for_subject(subject).knowledge)
-                        > g.rng.random(subject.mental_load())
+                        > g.rng
+                            .conversations()
+                            .random(g.rules.subject(subject).mental_load)
                     {
                         g.set_screen_and_wait_for_any_key(GameScreen::RaiInteraction(
                             YouHelped,
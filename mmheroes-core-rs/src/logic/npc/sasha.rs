@@ -15,6 +15,7 @@ pub enum SashaInteraction {
 
 use crate::logic::actions::{illegal_action, ActionVec};
 use crate::logic::{Action, GameScreen, GameState, InternalGameState, Location, Subject};
+use crate::random::Rng;
 use SashaInteraction::*;
 
 pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameState) {
@@ -30,7 +31,7 @@ pub(super) async fn interact(g: &mut InternalGameState<'_>, state: &mut GameStat
     );
     let new_interaction = match g.wait_for_action().await {
         Action::RequestLectureNotesFromSasha(subject) => {
-            if state.player.charisma > g.rng.random(18)
+            if state.player.charisma > g.rng.conversations().random(18)
                 && state.sasha_has_lecture_notes(subject)
             {
                 state
@@ -89,9 +89,10 @@ pub(super) async fn interact(
     state: &mut GameState,
     exam_in_progress: Option<Subject>,
 ) {
-    let serj_leaves = state.player.charisma < g.rng.random(9);
+    let serj_leaves = state.player.charisma < g.rng.conversations().random(9);
 
-    if g.rng.random(state.player.charisma) > g.rng.random_in_range(2..5)
+    if g.rng.conversations().random(state.player.charisma)
+        > g.rng.conversations().random_in_range(2..5)
         && state.player.charisma * 2 + 20 > state.player.health
     {
         g.set_screen_and_wait_for_any_key(GameScreen::SerjInteraction(
@@ -102,7 +103,7 @@ pub(super) async fn interact(
         .await;
 
         state.player.health += state.player.charisma;
-        state.player.health += g.rng.random(state.player.charisma);
+        state.player.health += g.rng.conversations().random(state.player.charisma);
 
         if let Some(current_subject) = exam_in_progress {
             let knowledge = &mut state
@@ -110,10 +111,12 @@ pub(super) async fn interact(
                 .status_for_subject_mut(current_subject)
                 .knowledge;
             if *knowledge > 3 {
-                *knowledge -= g.rng.random(3);
+                *knowledge -= g.rng.conversations().random(3);
             }
         }
-    } else if g.rng.random(state.player.charisma) > g.rng.random_in_range(2..8) {
+    } else if g.rng.conversations().random(state.player.charisma)
+        > g.rng.conversations().random_in_range(2..8)
+    {
         if state.player.status_for_subject(PhysicalEducation).knowledge < 10 {
             g.set_screen_and_wait_for_any_key(GameScreen::SerjInteraction(
                 state.clone(),
@@ -127,7 +130,7 @@ pub(super) async fn interact(
                 .knowledge += 30;
         }
     } else {
-        let reply = g.rng.random_variant();
+        let reply = g.rng.conversations().random_variant();
         g.set_screen_and_wait_for_any_key(GameScreen::SerjInteraction(
             state.clone(),
             RandomReply(reply),
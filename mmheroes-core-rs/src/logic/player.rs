@@ -214,4 +214,64 @@ impl Player {
     pub fn cause_of_death(&self) -> Option<CauseOfDeath> {
         self.cause_of_death
     }
+
+    /// Результат, с которым игрок, сдавший все зачёты, попадает в таблицу рекордов —
+    /// сколько денег он заработал к концу игры.
+    pub fn score(&self) -> Money {
+        self.money
+    }
+
+    pub(in crate::logic) fn encode(&self, w: &mut save::SaveWriter) {
+        w.write_u16(self.bits.into_bits());
+        w.write_i16(self.garlic);
+        w.write_i16(self.health);
+        w.write_i16(self.money.0);
+        w.write_i16(self.brain);
+        w.write_i16(self.stamina);
+        w.write_i16(self.charisma);
+        CauseOfDeath::encode(self.cause_of_death, w);
+        for subject in self.subjects.iter() {
+            subject.encode(w);
+        }
+    }
+
+    pub(in crate::logic) fn decode(
+        r: &mut save::SaveReader,
+    ) -> Result<Player, save::SavedGameError> {
+        let raw_bits = r.read_u16()?;
+        save::ensure_valid(
+            Subject::from_repr(save::raw_field(
+                raw_bits.into(),
+                PlayerBits::LAST_EXAM_OFFSET,
+                PlayerBits::LAST_EXAM_BITS,
+            ) as u8)
+            .is_some(),
+        )?;
+        let garlic = r.read_i16()?;
+        let health = r.read_i16()?;
+        let money = Money(r.read_i16()?);
+        let brain = r.read_i16()?;
+        let stamina = r.read_i16()?;
+        let charisma = r.read_i16()?;
+        let cause_of_death = CauseOfDeath::decode(r)?;
+        let subjects = [
+            SubjectStatus::decode(r, Subject::AlgebraAndNumberTheory)?,
+            SubjectStatus::decode(r, Subject::Calculus)?,
+            SubjectStatus::decode(r, Subject::GeometryAndTopology)?,
+            SubjectStatus::decode(r, Subject::ComputerScience)?,
+            SubjectStatus::decode(r, Subject::English)?,
+            SubjectStatus::decode(r, Subject::PhysicalEducation)?,
+        ];
+        Ok(Player {
+            subjects,
+            bits: PlayerBits::from_bits(raw_bits),
+            garlic,
+            health,
+            money,
+            brain,
+            stamina,
+            charisma,
+            cause_of_death,
+        })
+    }
 }
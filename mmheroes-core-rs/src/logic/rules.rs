@@ -0,0 +1,456 @@
+//! Числа, от которых зависит баланс игры: сложность предметов, цены, пороги оценок
+//! характеристик и т. д.
+//!
+//! По умолчанию используются [`Rules::DEFAULT`] — числа из оригинальной игры.
+//! С фичей `serde` правила можно читать из файлов и записывать в них.
+
+use super::*;
+use core::ops::Index;
+use strum::VariantArray;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Значение для каждого предмета.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct SubjectTable<T> {
+    pub algebra_and_number_theory: T,
+    pub calculus: T,
+    pub geometry_and_topology: T,
+    pub computer_science: T,
+    pub english: T,
+    pub physical_education: T,
+}
+
+impl<T> Index<Subject> for SubjectTable<T> {
+    type Output = T;
+
+    fn index(&self, subject: Subject) -> &T {
+        match subject {
+            Subject::AlgebraAndNumberTheory => &self.algebra_and_number_theory,
+            Subject::Calculus => &self.calculus,
+            Subject::GeometryAndTopology => &self.geometry_and_topology,
+            Subject::ComputerScience => &self.computer_science,
+            Subject::English => &self.english,
+            Subject::PhysicalEducation => &self.physical_education,
+        }
+    }
+}
+
+/// Значение для каждого однокурсника.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ClassmateTable<T> {
+    pub kolya: T,
+    pub pasha: T,
+    pub diamond: T,
+    pub rai: T,
+    pub misha: T,
+    pub serj: T,
+    pub sasha: T,
+    pub nil: T,
+    pub kuzmenko: T,
+    pub djug: T,
+    pub andrew: T,
+    pub grisha: T,
+}
+
+impl<T> Index<Classmate> for ClassmateTable<T> {
+    type Output = T;
+
+    fn index(&self, classmate: Classmate) -> &T {
+        match classmate {
+            Classmate::Kolya => &self.kolya,
+            Classmate::Pasha => &self.pasha,
+            Classmate::Diamond => &self.diamond,
+            Classmate::RAI => &self.rai,
+            Classmate::Misha => &self.misha,
+            Classmate::Serj => &self.serj,
+            Classmate::Sasha => &self.sasha,
+            Classmate::NiL => &self.nil,
+            Classmate::Kuzmenko => &self.kuzmenko,
+            Classmate::DJuG => &self.djug,
+            Classmate::Andrew => &self.andrew,
+            Classmate::Grisha => &self.grisha,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct SubjectRules {
+    /// Количество задач, которые необходимо решить для получения зачёта по предмету.
+    pub required_problems: u8,
+
+    /// Чем больше это число, тем больше знаний, интеллекта и здоровья нужно для того,
+    /// чтобы преподаватель зачёл задачу.
+    pub mental_load: BrainLevel,
+
+    /// Чем больше это число, тем больше выносливости нужно чтобы не терять здоровье
+    /// при попытке сдать зачёт.
+    pub health_penalty: HealthLevel,
+
+    /// Чем больше это число, тем больше mental capacity нужно для того,
+    /// чтобы зачли _одну_ задачу.
+    pub single_problem_mental_factor: f32,
+
+    /// В каких местах может проходить зачёт по предмету.
+    ///
+    /// При составлении расписания из этого массива выбирается случайный элемент.
+    pub exam_places: [Location; 3],
+
+    /// Верхние границы (не включительно) уровня знаний, который по шкале этого
+    /// препода оценивается как «плохо», «удовлетворительно» и «хорошо». Всё, что
+    /// выше, — «отлично».
+    pub assessment_bounds: [BrainLevel; 3],
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ClassmateRules {
+    /// «Вероятность» того, что человек будет приставать во время сдачи зачёта.
+    pub annoyance: i16,
+
+    /// Сколько здоровья отнимает разговор с человеком во время сдачи зачёта.
+    pub health_penalty: HealthLevel,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Costs {
+    /// Стоимость настойки овса для Коли
+    pub oat_tincture: Money,
+
+    /// Стоимость стакана колы в мавзолее
+    pub cola: Money,
+
+    /// Стоимость супа в мавзолее
+    pub soup: Money,
+
+    /// Стоимость пива в мавзолее
+    pub beer: Money,
+
+    /// Стоимость чая/кофе в буфете ПУНКа/ПОМИ
+    pub drink: Money,
+
+    /// Стоимость выпечки в буфете ПУНКа/ПОМИ
+    pub pastry: Money,
+
+    /// Стоимость чая/кофе с выпечкой в буфете ПУНКа/ПОМИ
+    pub drink_with_pastry: Money,
+
+    /// Стоимость билета на электричку в ПОМИ и обратно
+    pub roundtrip_train_ticket: Money,
+
+    /// Стоимость билета на электричку из ПОМИ в ПУНК
+    pub one_way_train_ticket: Money,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Rules {
+    pub subjects: SubjectTable<SubjectRules>,
+
+    pub classmates: ClassmateTable<ClassmateRules>,
+
+    pub costs: Costs,
+
+    /// Верхние границы (не включительно) оценок здоровья от «живой труп»
+    /// до «хорошее» (см. [`HealthAssessment`]). Всё, что выше, — «отличное».
+    pub health_assessment_bounds: [HealthLevel; 6],
+
+    /// Верхние границы (не включительно) оценок интеллекта от «клиническая смерть
+    /// мозга» до «легкость в мыслях необыкновенная» (см. [`BrainAssessment`]).
+    /// Интеллект выше последней границы считается ошибкой.
+    pub brain_assessment_bounds: [BrainLevel; 8],
+
+    /// Верхние границы (не включительно) оценок знаний без учёта предмета от «плохо»
+    /// до «очень хорошо» (см. [`KnowledgeAssessment::absolute`]). Всё, что выше, —
+    /// «отлично».
+    pub knowledge_assessment_bounds: [BrainLevel; 4],
+}
+
+/// Почему правила не подходят для игры.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RulesError {
+    /// Для предмета нужно решить 0 задач или больше, чем помещается в сохранение.
+    InvalidRequiredProblems(Subject),
+
+    /// Отрицательное число, которое используется как верхняя граница случайного.
+    NegativeSubjectValue(Subject),
+
+    /// Неположительный `single_problem_mental_factor`.
+    InvalidMentalFactor(Subject),
+
+    /// Зачёт не может проходить в общаге или в мавзолее.
+    InvalidExamPlace(Subject),
+
+    NegativeClassmateValue(Classmate),
+
+    NegativeCost,
+
+    /// Пороги одной из шкал оценок не возрастают.
+    UnorderedBounds,
+}
+
+impl core::fmt::Display for RulesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RulesError::InvalidRequiredProblems(subject) => write!(
+                f,
+                "required_problems for {:?} must be between 1 and {}",
+                subject, MAX_REQUIRED_PROBLEMS
+            ),
+            RulesError::NegativeSubjectValue(subject) => {
+                write!(
+                    f,
+                    "negative mental_load or health_penalty for {:?}",
+                    subject
+                )
+            }
+            RulesError::InvalidMentalFactor(subject) => write!(
+                f,
+                "single_problem_mental_factor for {:?} must be positive",
+                subject
+            ),
+            RulesError::InvalidExamPlace(subject) => {
+                write!(f, "an exam in {:?} can't take place there", subject)
+            }
+            RulesError::NegativeClassmateValue(classmate) => {
+                write!(
+                    f,
+                    "negative annoyance or health_penalty for {:?}",
+                    classmate
+                )
+            }
+            RulesError::NegativeCost => f.write_str("costs can't be negative"),
+            RulesError::UnorderedBounds => {
+                f.write_str("assessment bounds must be strictly increasing")
+            }
+        }
+    }
+}
+
+/// Сколько задач по предмету может быть решено. Ограничено размером поля
+/// в сохранении.
+const MAX_REQUIRED_PROBLEMS: u8 = 127;
+
+const fn subject_rules(
+    required_problems: u8,
+    mental_load: BrainLevel,
+    health_penalty: HealthLevel,
+    single_problem_mental_factor: f32,
+    exam_places: [Location; 3],
+    assessment_bounds: [BrainLevel; 3],
+) -> SubjectRules {
+    SubjectRules {
+        required_problems,
+        mental_load,
+        health_penalty,
+        single_problem_mental_factor,
+        exam_places,
+        assessment_bounds,
+    }
+}
+
+const fn classmate_rules(annoyance: i16, health_penalty: HealthLevel) -> ClassmateRules {
+    ClassmateRules {
+        annoyance,
+        health_penalty,
+    }
+}
+
+fn is_strictly_increasing(bounds: &[i16]) -> bool {
+    bounds.windows(2).all(|pair| pair[0] < pair[1])
+}
+
+impl Rules {
+    /// Правила оригинальной игры.
+    pub const DEFAULT: Rules = {
+        use Location::*;
+        Rules {
+            subjects: SubjectTable {
+                algebra_and_number_theory: subject_rules(
+                    12,
+                    10,
+                    17,
+                    3.0,
+                    [PUNK, PUNK, PDMI],
+                    [11, 21, 51],
+                ),
+                calculus: subject_rules(10, 8, 14, 2.0, [PUNK, PUNK, PUNK], [9, 19, 41]),
+                geometry_and_topology: subject_rules(
+                    3,
+                    4,
+                    8,
+                    3.0,
+                    [PUNK, PDMI, PDMI],
+                    [6, 11, 31],
+                ),
+                computer_science: subject_rules(
+                    2,
+                    5,
+                    6,
+                    3.0,
+                    [ComputerClass, ComputerClass, ComputerClass],
+                    [10, 16, 31],
+                ),
+                english: subject_rules(3, 7, 10, 1.0, [PUNK, PUNK, PUNK], [5, 9, 16]),
+                physical_education: subject_rules(
+                    1,
+                    7,
+                    20,
+                    1.0,
+                    [PUNK, PUNK, PUNK],
+                    [5, 9, 16],
+                ),
+            },
+            classmates: ClassmateTable {
+                kolya: classmate_rules(0, 0),
+                pasha: classmate_rules(0, 0),
+                diamond: classmate_rules(0, 0),
+                rai: classmate_rules(4, 8),
+                misha: classmate_rules(2, 0),
+                serj: classmate_rules(0, 0),
+                sasha: classmate_rules(0, 0),
+                nil: classmate_rules(6, 8),
+                kuzmenko: classmate_rules(0, 0),
+                djug: classmate_rules(0, 0),
+                andrew: classmate_rules(0, 0),
+                grisha: classmate_rules(0, 0),
+            },
+            costs: Costs {
+                oat_tincture: Money(15),
+                cola: Money(4),
+                soup: Money(6),
+                beer: Money(8),
+                drink: Money(2),
+                pastry: Money(4),
+                drink_with_pastry: Money(6),
+                roundtrip_train_ticket: Money(10),
+                one_way_train_ticket: Money(5),
+            },
+            health_assessment_bounds: [1, 9, 17, 25, 33, 41],
+            brain_assessment_bounds: [0, 1, 2, 3, 4, 5, 6, 101],
+            knowledge_assessment_bounds: [6, 13, 21, 31],
+        }
+    };
+
+    pub fn subject(&self, subject: Subject) -> &SubjectRules {
+        &self.subjects[subject]
+    }
+
+    pub fn classmate(&self, classmate: Classmate) -> &ClassmateRules {
+        &self.classmates[classmate]
+    }
+
+    /// Проверяет, что с этими правилами игра не сломается: например, что зачёт
+    /// не назначен в общаге, а пороги оценок возрастают.
+    pub fn validate(&self) -> Result<(), RulesError> {
+        for subject in Subject::all_subjects() {
+            let rules = self.subject(subject);
+            if !(1..=MAX_REQUIRED_PROBLEMS).contains(&rules.required_problems) {
+                return Err(RulesError::InvalidRequiredProblems(subject));
+            }
+            if rules.mental_load < 0 || rules.health_penalty < 0 {
+                return Err(RulesError::NegativeSubjectValue(subject));
+            }
+            let factor = rules.single_problem_mental_factor;
+            if factor.is_nan() || factor <= 0.0 {
+                return Err(RulesError::InvalidMentalFactor(subject));
+            }
+            if rules.exam_places.iter().any(|place| {
+                !matches!(
+                    place,
+                    Location::PUNK | Location::PDMI | Location::ComputerClass
+                )
+            }) {
+                return Err(RulesError::InvalidExamPlace(subject));
+            }
+            if !is_strictly_increasing(&rules.assessment_bounds) {
+                return Err(RulesError::UnorderedBounds);
+            }
+        }
+        for &classmate in Classmate::VARIANTS {
+            let rules = self.classmate(classmate);
+            if rules.annoyance < 0 || rules.health_penalty < 0 {
+                return Err(RulesError::NegativeClassmateValue(classmate));
+            }
+        }
+        let costs = &self.costs;
+        if [
+            costs.oat_tincture,
+            costs.cola,
+            costs.soup,
+            costs.beer,
+            costs.drink,
+            costs.pastry,
+            costs.drink_with_pastry,
+            costs.roundtrip_train_ticket,
+            costs.one_way_train_ticket,
+        ]
+        .iter()
+        .any(|&cost| cost < 0)
+        {
+            return Err(RulesError::NegativeCost);
+        }
+        if !is_strictly_increasing(&self.health_assessment_bounds)
+            || !is_strictly_increasing(&self.brain_assessment_bounds)
+            || !is_strictly_increasing(&self.knowledge_assessment_bounds)
+        {
+            return Err(RulesError::UnorderedBounds);
+        }
+        Ok(())
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_rules_are_valid() {
+        assert_eq!(Rules::DEFAULT.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let mut rules = Rules::DEFAULT;
+        rules.subjects.calculus.required_problems = 0;
+        assert_eq!(
+            rules.validate(),
+            Err(RulesError::InvalidRequiredProblems(Subject::Calculus))
+        );
+
+        let mut rules = Rules::DEFAULT;
+        rules.subjects.english.exam_places[1] = Location::Dorm;
+        assert_eq!(
+            rules.validate(),
+            Err(RulesError::InvalidExamPlace(Subject::English))
+        );
+
+        let mut rules = Rules::DEFAULT;
+        rules.subjects.english.single_problem_mental_factor = f32::NAN;
+        assert_eq!(
+            rules.validate(),
+            Err(RulesError::InvalidMentalFactor(Subject::English))
+        );
+
+        let mut rules = Rules::DEFAULT;
+        rules.health_assessment_bounds[2] = 9;
+        assert_eq!(rules.validate(), Err(RulesError::UnorderedBounds));
+    }
+}
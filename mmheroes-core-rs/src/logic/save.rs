@@ -0,0 +1,654 @@
+//! Сохранение незаконченной игры и её последующая загрузка.
+//!
+//! Игру можно сохранить только когда на экране главное меню локации
+//! (`GameScreen::SceneRouter`): в этот момент всё состояние игры — это `GameState`
+//! и состояние генераторов случайных чисел.
+//!
+//! Формат сохранения (все числа — little-endian):
+//!
+//! | Смещение | Размер | Содержимое                                           |
+//! |----------|--------|------------------------------------------------------|
+//! | 0        | 4      | Сигнатура `MMHS`                                     |
+//! | 4        | 1      | Версия формата (`SAVED_GAME_FORMAT_VERSION`)         |
+//! | 5        | 1      | `GameMode`                                           |
+//! | 6        | 8      | Состояние первого генератора случайных чисел         |
+//! | 14       | 4      | Биты `GameState` (день, время, локация, …)           |
+//! | 18       | 40     | `Player`: биты, характеристики, причина смерти       |
+//! |          |        | и 6 × (знания по предмету, биты `SubjectStatus`)     |
+//! | 58       | 72     | Расписание: 6 дней × 6 зачётов, биты `Exam`          |
+//! | 130      | 12     | Местонахождение каждого из NPC                       |
+//! | 142      | 1      | [`RngMode`]                                          |
+//! | 143      | 56     | Состояния остальных 7 генераторов случайных чисел    |
+//! | 199      | 1      | Продолжительность сессии в днях                      |
+//! | 200      | 96     | Расписание: остальные 8 дней × 6 зачётов             |
+//!
+//! В первой версии формата нет полей начиная со смещения 142, а генератор
+//! случайных чисел один ([`RngMode::SingleStream`]). Во второй версии нет полей
+//! начиная со смещения 199, а сессия длится шесть дней. Такие сохранения тоже
+//! можно загрузить.
+use super::*;
+
+/// Версия формата сохранения. Увеличивается при любом несовместимом изменении
+/// формата.
+pub const SAVED_GAME_FORMAT_VERSION: u8 = 3;
+
+/// Размер сохранения в байтах.
+pub const SAVED_GAME_SIZE: usize = 296;
+
+/// Размер сохранения в первой версии формата.
+const SAVED_GAME_SIZE_V1: usize = 142;
+
+/// Размер сохранения во второй версии формата.
+const SAVED_GAME_SIZE_V2: usize = 199;
+
+// Размеры записаны числами, потому что cbindgen не вычисляет выражения.
+const _: () = assert!(
+    SAVED_GAME_SIZE_V2 == SAVED_GAME_SIZE_V1 + 1 + 8 * (random::STREAM_COUNT - 1)
+);
+const _: () =
+    assert!(SAVED_GAME_SIZE == SAVED_GAME_SIZE_V2 + timetable::SAVED_EXTENSION_SIZE);
+
+const SIGNATURE: [u8; 4] = *b"MMHS";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SavedGameError {
+    /// Данные не являются сохранением игры.
+    InvalidSignature,
+
+    /// Сохранение сделано в версии формата, которую мы не поддерживаем.
+    UnsupportedVersion(u8),
+
+    /// Данные закончились раньше, чем ожидалось.
+    UnexpectedEOF,
+
+    /// После сохранения есть лишние данные.
+    TrailingData,
+
+    /// Данные повреждены: какое-то из полей имеет недопустимое значение.
+    InvalidData,
+}
+
+/// Сохранённая игра. Получить её можно с помощью [`StateHolder::save_game`],
+/// а продолжить — с помощью [`create_or_restore_game`].
+#[derive(Debug, Clone)]
+pub struct SavedGame {
+    pub(in crate::logic) mode: GameMode,
+    pub(in crate::logic) rng_state: random::RngState,
+    pub(in crate::logic) state: GameState,
+}
+
+impl SavedGame {
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    pub fn rng_mode(&self) -> RngMode {
+        self.rng_state.mode
+    }
+
+    pub fn encode(&self) -> [u8; SAVED_GAME_SIZE] {
+        let mut result = [0u8; SAVED_GAME_SIZE];
+        let mut w = SaveWriter {
+            buffer: &mut result,
+            position: 0,
+        };
+        w.write_bytes(&SIGNATURE);
+        w.write_u8(SAVED_GAME_FORMAT_VERSION);
+        w.write_u8(match self.mode {
+            GameMode::Normal => 0,
+            GameMode::SelectInitialParameters => 1,
+            GameMode::God => 2,
+        });
+        let [first_stream, other_streams @ ..] = self.rng_state.streams;
+        w.write_u64(first_stream);
+        self.state.encode(&mut w);
+        w.write_u8(match self.rng_state.mode {
+            RngMode::SingleStream => 0,
+            RngMode::SeparateStreams => 1,
+            RngMode::BorlandPascal => 2,
+        });
+        for stream in other_streams {
+            w.write_u64(stream);
+        }
+        self.state.timetable.encode_extension(&mut w);
+        assert_eq!(w.position, SAVED_GAME_SIZE);
+        result
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<SavedGame, SavedGameError> {
+        let mut r = SaveReader { bytes, position: 0 };
+        if r.read_bytes(SIGNATURE.len())? != SIGNATURE {
+            return Err(SavedGameError::InvalidSignature);
+        }
+        let version = r.read_u8()?;
+        if !(1..=SAVED_GAME_FORMAT_VERSION).contains(&version) {
+            return Err(SavedGameError::UnsupportedVersion(version));
+        }
+        let mode = match r.read_u8()? {
+            0 => GameMode::Normal,
+            1 => GameMode::SelectInitialParameters,
+            2 => GameMode::God,
+            _ => return Err(SavedGameError::InvalidData),
+        };
+        let mut rng_state = random::RngState {
+            mode: RngMode::SingleStream,
+            streams: [0; random::STREAM_COUNT],
+        };
+        rng_state.streams[0] = r.read_u64()?;
+        let mut state = GameState::decode(&mut r)?;
+        if version > 1 {
+            rng_state.mode = match r.read_u8()? {
+                0 => RngMode::SingleStream,
+                1 => RngMode::SeparateStreams,
+                2 => RngMode::BorlandPascal,
+                _ => return Err(SavedGameError::InvalidData),
+            };
+            for stream in &mut rng_state.streams[1..] {
+                *stream = r.read_u64()?;
+            }
+            // Неиспользуемые генераторы всегда нулевые, иначе у одинаковых
+            // состояний игры были бы разные хэши.
+            ensure_valid(
+                rng_state.mode == RngMode::SeparateStreams
+                    || rng_state.streams[1..].iter().all(|&stream| stream == 0),
+            )?;
+            // `RandSeed` в Borland Pascal 32-битный.
+            ensure_valid(
+                rng_state.mode != RngMode::BorlandPascal
+                    || u32::try_from(rng_state.streams[0]).is_ok(),
+            )?;
+        }
+        if version > 2 {
+            state.timetable.decode_extension(&mut r)?;
+        }
+        state.validate_day_indices()?;
+        if r.position != bytes.len() {
+            return Err(SavedGameError::TrailingData);
+        }
+        Ok(SavedGame {
+            mode,
+            rng_state,
+            state,
+        })
+    }
+}
+
+impl StateHolder {
+    /// Сохраняет игру, если это возможно в текущий момент, то есть если на экране
+    /// главное меню локации. Иначе возвращает `None`.
+    pub fn save_game(&self) -> Option<SavedGame> {
+        match self.observable_state().screen() {
+            GameScreen::SceneRouter(state) => Some(SavedGame {
+                mode: self.observable_state().mode(),
+                rng_state: self.save_point_rng_state.get(),
+                state: state.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Возвращает игру `game` к сохранению `saved_game`: незаконченное действие
+    /// прерывается, и на экране снова главное меню локации из сохранения.
+    ///
+    /// `game` должна быть создана с помощью [`create_or_restore_game`] с этим же
+    /// `StateHolder` и ещё не закончиться (экран — не `GameScreen::Terminal`).
+    pub fn restore_game<G: Game + ?Sized>(
+        &self,
+        game: Pin<&mut G>,
+        saved_game: SavedGame,
+    ) {
+        self.restore_request.replace(Some(saved_game));
+        // Действие будет проигнорировано: игра прервётся раньше, чем его получит.
+        game.perform_action(Action::AnyKey);
+    }
+
+    /// Хэш состояния игры (см. [`SavedGame::state_hash`]), если на экране главное
+    /// меню локации. Иначе возвращает `None`.
+    pub fn state_hash(&self) -> Option<u64> {
+        self.save_game().map(|saved_game| saved_game.state_hash())
+    }
+}
+
+/// Смещение, с которого в сохранении начинается состояние генератора случайных
+/// чисел, а за ним — `GameState`.
+const STATE_HASH_OFFSET: usize = 6;
+
+impl SavedGame {
+    /// Стабильный хэш логического состояния игры: `GameState` со всеми его
+    /// частями (`Player`, расписание, местонахождение NPC) и состояния генераторов
+    /// случайных чисел. Режим игры в хэш не входит.
+    ///
+    /// Хэш считается по закодированному сохранению (FNV-1a), поэтому не зависит
+    /// от платформы и меняется, только если меняется само состояние или формат
+    /// сохранения. Если поля, которые появились в более новых версиях формата,
+    /// такие же, как у сохранений старых версий ([`RngMode::SingleStream`],
+    /// шестидневная сессия), хэш по ним не считается, чтобы хэши в старых
+    /// повторах оставались верными.
+    pub fn state_hash(&self) -> u64 {
+        let end = if self.state.timetable.length() != SemesterLength::NORMAL {
+            SAVED_GAME_SIZE
+        } else if self.rng_state.mode != RngMode::SingleStream {
+            SAVED_GAME_SIZE_V2
+        } else {
+            SAVED_GAME_SIZE_V1
+        };
+        fnv1a(FNV_OFFSET_BASIS, &self.encode()[STATE_HASH_OFFSET..end])
+    }
+
+    /// Хэш одного только `GameState`, без состояния генераторов случайных чисел
+    /// и без режима генератора.
+    ///
+    /// В отличие от [`SavedGame::state_hash`], совпадает у состояний, которые
+    /// различаются только тем, какие случайные числа выпадут дальше. Пригодится,
+    /// например, чтобы при переборе ходов не считать новым состояние, в котором
+    /// поменялся только генератор.
+    pub fn game_state_hash(&self) -> u64 {
+        let encoded = self.encode();
+        let hash = fnv1a(
+            FNV_OFFSET_BASIS,
+            &encoded[GAME_STATE_OFFSET..SAVED_GAME_SIZE_V1],
+        );
+        fnv1a(hash, &encoded[SAVED_GAME_SIZE_V2..])
+    }
+}
+
+/// Смещение, с которого в сохранении начинается `GameState`.
+const GAME_STATE_OFFSET: usize = STATE_HASH_OFFSET + 8;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    const PRIME: u64 = 0x100000001b3;
+    bytes
+        .iter()
+        .fold(hash, |hash, &byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
+pub(in crate::logic) struct SaveWriter<'a> {
+    buffer: &'a mut [u8],
+    position: usize,
+}
+
+impl SaveWriter<'_> {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.buffer[self.position..(self.position + bytes.len())].copy_from_slice(bytes);
+        self.position += bytes.len();
+    }
+
+    pub(in crate::logic) fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value])
+    }
+
+    pub(in crate::logic) fn write_u16(&mut self, value: u16) {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    pub(in crate::logic) fn write_i16(&mut self, value: i16) {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    pub(in crate::logic) fn write_u32(&mut self, value: u32) {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes())
+    }
+}
+
+pub(in crate::logic) struct SaveReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl SaveReader<'_> {
+    fn read_bytes(&mut self, count: usize) -> Result<&[u8], SavedGameError> {
+        let bytes = self
+            .bytes
+            .get(self.position..(self.position + count))
+            .ok_or(SavedGameError::UnexpectedEOF)?;
+        self.position += count;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], SavedGameError> {
+        Ok(self.read_bytes(N)?.try_into().unwrap())
+    }
+
+    pub(in crate::logic) fn read_u8(&mut self) -> Result<u8, SavedGameError> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub(in crate::logic) fn read_u16(&mut self) -> Result<u16, SavedGameError> {
+        self.read_array().map(u16::from_le_bytes)
+    }
+
+    pub(in crate::logic) fn read_i16(&mut self) -> Result<i16, SavedGameError> {
+        self.read_array().map(i16::from_le_bytes)
+    }
+
+    pub(in crate::logic) fn read_u32(&mut self) -> Result<u32, SavedGameError> {
+        self.read_array().map(u32::from_le_bytes)
+    }
+
+    fn read_u64(&mut self) -> Result<u64, SavedGameError> {
+        self.read_array().map(u64::from_le_bytes)
+    }
+}
+
+/// Возвращает `SavedGameError::InvalidData`, если условие не выполнено.
+pub(in crate::logic) fn ensure_valid(condition: bool) -> Result<(), SavedGameError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(SavedGameError::InvalidData)
+    }
+}
+
+/// Извлекает из битового поля `bits` значение шириной `width` бит, начинающееся
+/// с бита `offset`.
+///
+/// Нужно для проверки значений, которые геттеры битовых полей не умеют обрабатывать
+/// без паники.
+pub(in crate::logic) const fn raw_field(bits: u32, offset: usize, width: usize) -> u32 {
+    (bits >> offset) & ((1 << width) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved_game() -> SavedGame {
+        let mut rng = random::SplitMix64::new(42);
+        let player = Player::new(false, 40, 7, 4, 6, |subject| subject as i16);
+        let mut state = GameState::new(
+            player,
+            Timetable::random(&mut rng, &Rules::DEFAULT, SemesterLength::NORMAL),
+            Location::PUNK,
+            &Rules::DEFAULT,
+        );
+        state.next_day();
+        state.set_current_time(Time(15));
+        state.set_sasha_has_lecture_notes(Subject::Calculus, false);
+        state.player.money = Money(42);
+        state.player.garlic = 3;
+        state.player.set_has_internet();
+        state.player.set_last_exam(Subject::English);
+        state
+            .player
+            .status_for_subject_mut(Subject::English)
+            .more_problems_solved(3);
+        state
+            .player
+            .status_for_subject_mut(Subject::English)
+            .set_passed_exam_day_index(1);
+        state
+            .player
+            .status_for_subject_mut(Subject::Calculus)
+            .set_has_lecture_notes();
+        state.classmates[Classmate::Kolya].current_location =
+            ClassmateLocation::Location(Location::Mausoleum);
+        state.classmates[Classmate::Andrew].current_location =
+            ClassmateLocation::Exam(Subject::Calculus);
+        SavedGame {
+            mode: GameMode::SelectInitialParameters,
+            rng_state: random::RngState {
+                mode: RngMode::SingleStream,
+                streams: core::array::from_fn(|i| if i == 0 { 0xDEADBEEF } else { 0 }),
+            },
+            state,
+        }
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let saved_game = saved_game();
+        let encoded = saved_game.encode();
+        assert_eq!(&encoded[..6], b"MMHS\x03\x01");
+
+        let decoded = SavedGame::decode(&encoded).unwrap();
+        assert_eq!(decoded.mode(), GameMode::SelectInitialParameters);
+        assert_eq!(decoded.rng_state, saved_game.rng_state);
+        assert_eq!(
+            format!("{:?}", decoded.state()),
+            format!("{:?}", saved_game.state())
+        );
+        assert_eq!(decoded.encode(), encoded);
+    }
+
+    #[test]
+    fn test_state_hash() {
+        let saved_game = saved_game();
+        let hash = saved_game.state_hash();
+        assert_eq!(hash, 0xa13bd34065a4341c);
+
+        let mut other = saved_game.clone();
+        other.mode = GameMode::God;
+        assert_eq!(other.state_hash(), hash);
+
+        let mut other = saved_game.clone();
+        other.rng_state.streams[0] += 1;
+        assert_ne!(other.state_hash(), hash);
+
+        let mut other = saved_game.clone();
+        other.rng_state.mode = RngMode::SeparateStreams;
+        assert_ne!(other.state_hash(), hash);
+        let hash = other.state_hash();
+        other.rng_state.streams[random::STREAM_COUNT - 1] += 1;
+        assert_ne!(other.state_hash(), hash);
+
+        let mut other = saved_game.clone();
+        other.state.classmates[Classmate::Kolya].current_location =
+            ClassmateLocation::Location(Location::PUNK);
+        assert_ne!(other.state_hash(), hash);
+    }
+
+    #[test]
+    fn test_game_state_hash() {
+        let saved_game = saved_game();
+        let hash = saved_game.game_state_hash();
+
+        let mut other = saved_game.clone();
+        other.mode = GameMode::God;
+        assert_eq!(other.game_state_hash(), hash);
+
+        let mut other = saved_game.clone();
+        other.rng_state = RngStreams::new(42, RngMode::SeparateStreams).state();
+        assert_eq!(other.game_state_hash(), hash);
+
+        let mut other = saved_game.clone();
+        other.state.classmates[Classmate::Kolya].current_location =
+            ClassmateLocation::Location(Location::PUNK);
+        assert_ne!(other.game_state_hash(), hash);
+    }
+
+    #[test]
+    fn test_separate_streams() {
+        let mut saved_game = saved_game();
+        saved_game.rng_state = RngStreams::new(42, RngMode::SeparateStreams).state();
+        let decoded = SavedGame::decode(&saved_game.encode()).unwrap();
+        assert_eq!(decoded.rng_mode(), RngMode::SeparateStreams);
+        assert_eq!(decoded.rng_state, saved_game.rng_state);
+    }
+
+    #[test]
+    fn test_borland_pascal() {
+        let mut saved_game = saved_game();
+        saved_game.rng_state = RngStreams::new(42, RngMode::BorlandPascal).state();
+        let mut encoded = saved_game.encode();
+        let decoded = SavedGame::decode(&encoded).unwrap();
+        assert_eq!(decoded.rng_mode(), RngMode::BorlandPascal);
+        assert_eq!(decoded.rng_state, saved_game.rng_state);
+
+        let mut single_stream = saved_game.clone();
+        single_stream.rng_state.mode = RngMode::SingleStream;
+        assert_ne!(single_stream.state_hash(), saved_game.state_hash());
+
+        // `RandSeed` не помещается в 32 бита.
+        encoded[STATE_HASH_OFFSET + 4] = 1;
+        assert_eq!(
+            SavedGame::decode(&encoded).unwrap_err(),
+            SavedGameError::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_decode_version_1() {
+        let saved_game = saved_game();
+        let mut encoded = saved_game.encode();
+        encoded[4] = 1;
+        let decoded = SavedGame::decode(&encoded[..SAVED_GAME_SIZE_V1]).unwrap();
+        assert_eq!(decoded.rng_state, saved_game.rng_state);
+        assert_eq!(decoded.state_hash(), saved_game.state_hash());
+        assert_eq!(
+            SavedGame::decode(&encoded).unwrap_err(),
+            SavedGameError::TrailingData
+        );
+    }
+
+    #[test]
+    fn test_decode_version_2() {
+        let saved_game = saved_game();
+        let mut encoded = saved_game.encode();
+        encoded[4] = 2;
+        let decoded = SavedGame::decode(&encoded[..SAVED_GAME_SIZE_V2]).unwrap();
+        assert_eq!(decoded.state().timetable(), saved_game.state().timetable());
+        assert_eq!(decoded.state_hash(), saved_game.state_hash());
+    }
+
+    #[test]
+    fn test_semester_length() {
+        let mut saved_game = saved_game();
+        let mut rng = random::SplitMix64::new(42);
+        saved_game.state.timetable =
+            Timetable::random(&mut rng, &Rules::DEFAULT, SemesterLength::MARATHON);
+        for _ in 0..10 {
+            saved_game.state.next_day();
+        }
+        saved_game
+            .state
+            .player
+            .status_for_subject_mut(Subject::Calculus)
+            .set_passed_exam_day_index(9);
+        let mut encoded = saved_game.encode();
+        let decoded = SavedGame::decode(&encoded).unwrap();
+        assert_eq!(decoded.state().current_day_index(), 11);
+        assert_eq!(
+            decoded.state().timetable().length(),
+            SemesterLength::MARATHON
+        );
+        assert_eq!(decoded.encode(), encoded);
+
+        let mut normal = saved_game.clone();
+        normal.state.timetable =
+            Timetable::random(&mut rng, &Rules::DEFAULT, SemesterLength::NORMAL);
+        assert_ne!(normal.state_hash(), saved_game.state_hash());
+
+        // Сессия не может длиться 15 дней.
+        encoded[SAVED_GAME_SIZE_V2] = 15;
+        assert_eq!(
+            SavedGame::decode(&encoded).unwrap_err(),
+            SavedGameError::InvalidData
+        );
+
+        // В шестидневной сессии не может быть двенадцатого дня.
+        encoded[SAVED_GAME_SIZE_V2] = 6;
+        assert_eq!(
+            SavedGame::decode(&encoded).unwrap_err(),
+            SavedGameError::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_cause_of_death_roundtrip() {
+        let mut saved_game = saved_game();
+        for cause in [
+            CauseOfDeath::OnTheWayToPUNK,
+            CauseOfDeath::TorturedByProfessor(Subject::ComputerScience),
+            CauseOfDeath::BetterNotIgnoreClassmate(Classmate::Grisha),
+            CauseOfDeath::SoftwareBug,
+        ] {
+            saved_game.state.player.cause_of_death = Some(cause);
+            let decoded = SavedGame::decode(&saved_game.encode()).unwrap();
+            assert_eq!(decoded.state().player().cause_of_death(), Some(cause));
+        }
+    }
+
+    #[test]
+    fn test_decoding_errors() {
+        let encoded = saved_game().encode();
+
+        assert_eq!(
+            SavedGame::decode(&encoded[..SAVED_GAME_SIZE - 1]).unwrap_err(),
+            SavedGameError::UnexpectedEOF
+        );
+        assert_eq!(
+            SavedGame::decode(&[]).unwrap_err(),
+            SavedGameError::UnexpectedEOF
+        );
+
+        let mut with_trailing_data = [0u8; SAVED_GAME_SIZE + 1];
+        with_trailing_data[..SAVED_GAME_SIZE].copy_from_slice(&encoded);
+        assert_eq!(
+            SavedGame::decode(&with_trailing_data).unwrap_err(),
+            SavedGameError::TrailingData
+        );
+
+        let mut corrupted = encoded;
+        corrupted[0] = b'X';
+        assert_eq!(
+            SavedGame::decode(&corrupted).unwrap_err(),
+            SavedGameError::InvalidSignature
+        );
+
+        let mut corrupted = encoded;
+        corrupted[4] = 42;
+        assert_eq!(
+            SavedGame::decode(&corrupted).unwrap_err(),
+            SavedGameError::UnsupportedVersion(42)
+        );
+
+        let mut corrupted = encoded;
+        corrupted[5] = 3;
+        assert_eq!(
+            SavedGame::decode(&corrupted).unwrap_err(),
+            SavedGameError::InvalidData
+        );
+
+        // Локация 7 не существует.
+        let mut corrupted = encoded;
+        corrupted[15] |= 0b111 << 5;
+        assert_eq!(
+            SavedGame::decode(&corrupted).unwrap_err(),
+            SavedGameError::InvalidData
+        );
+
+        // Неизвестное местонахождение NPC.
+        let mut corrupted = encoded;
+        corrupted[SAVED_GAME_SIZE_V1 - 1] = 0xFF;
+        assert_eq!(
+            SavedGame::decode(&corrupted).unwrap_err(),
+            SavedGameError::InvalidData
+        );
+
+        // Неизвестный `RngMode`.
+        let mut corrupted = encoded;
+        corrupted[SAVED_GAME_SIZE_V1] = 3;
+        assert_eq!(
+            SavedGame::decode(&corrupted).unwrap_err(),
+            SavedGameError::InvalidData
+        );
+
+        // Лишний генератор в режиме `RngMode::SingleStream`.
+        let mut corrupted = encoded;
+        corrupted[SAVED_GAME_SIZE_V2 - 1] = 1;
+        assert_eq!(
+            SavedGame::decode(&corrupted).unwrap_err(),
+            SavedGameError::InvalidData
+        );
+    }
+}
@@ -2,6 +2,7 @@ use crate::logic::actions::{illegal_action, ActionVec};
 use crate::logic::{
     misc, Action, GameScreen, GameState, HealthLevel, InternalGameState, Money,
 };
+use crate::random::Rng;
 
 pub(super) async fn go(
     g: &mut InternalGameState<'_>,
@@ -21,7 +22,7 @@ pub(super) async fn go(
     available_actions.push(exit_action);
     g.set_screen_and_action_vec(GameScreen::Cafe(state.clone()), available_actions);
     let selected_action = g.wait_for_action().await;
-    let charisma_dependent_health_gain = g.rng.random(state.player.charisma);
+    let charisma_dependent_health_gain = g.rng.activities().random(state.player.charisma);
     if let Some(&(_, cost, menu_health_gain)) = menu
         .iter()
         .find(|(action, _, _)| *action == selected_action)
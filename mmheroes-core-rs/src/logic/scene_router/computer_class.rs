@@ -45,11 +45,11 @@ async fn surf_internet(g: &mut InternalGameState<'_>, state: &mut GameState) {
     let player = &state.player;
     let solved_all_problems = player
         .status_for_subject(Subject::ComputerScience)
-        .solved_all_problems();
+        .solved_all_problems(g.rules);
     // В GOD-режиме можно нафармить сколь угодно много решённых задач.
     // Наверное, баг в оригинальной реализации. А может и нет.
-    let found_program =
-        player.is_god_mode() || (g.rng.random(player.brain) > 6 && !solved_all_problems);
+    let found_program = player.is_god_mode()
+        || (g.rng.activities().random(player.brain) > 6 && !solved_all_problems);
     g.set_screen_and_wait_for_any_key(GameScreen::SurfInternet { found_program })
         .await;
     if found_program {
@@ -57,7 +57,7 @@ async fn surf_internet(g: &mut InternalGameState<'_>, state: &mut GameState) {
             .player
             .status_for_subject_mut(Subject::ComputerScience)
             .more_problems_solved(1);
-    } else if state.player.brain < 5 && g.rng.roll_dice(3) {
+    } else if state.player.brain < 5 && g.rng.activities().roll_dice(3) {
         state.player.brain += 1;
     }
     misc::hour_pass(g, state, None).await
@@ -97,7 +97,9 @@ async fn play_mmheroes(g: &mut InternalGameState<'_>, state: &mut GameState) {
     ))
     .await;
 
-    state
-        .timetable
-        .randomize_from_day(state.current_day_index(), &mut g.rng);
+    state.timetable.randomize_from_day(
+        state.current_day_index(),
+        g.rng.timetable(),
+        g.rules,
+    );
 }
@@ -58,7 +58,7 @@ async fn study(g: &mut InternalGameState<'_>, state: &mut GameState) {
     g.set_screen_and_action_vec(GameScreen::Study(state.clone()), available_subjects);
     let subject_to_study = match g.wait_for_action().await {
         Action::DoStudy { subject, .. } => subject,
-        Action::DontStudy {} => return,
+        Action::DontStudy => return,
         action => illegal_action!(action),
     };
     let lecture_notes_available = state
@@ -110,14 +110,14 @@ async fn study_subject(
     } else {
         brain_or_stamina * 2 / 3
     };
-    *knowledge -= g.rng.random(brain_or_stamina / 2);
-    *knowledge += g.rng.random(health / 18);
+    *knowledge -= g.rng.activities().random(brain_or_stamina / 2);
+    *knowledge += g.rng.activities().random(health / 18);
     if use_lecture_notes {
         *knowledge += 10
     }
     assert!(*knowledge >= 0);
     assert!(state.player.stamina >= 0);
-    let mut health_penalty = 10 - g.rng.random(state.player.stamina);
+    let mut health_penalty = 10 - g.rng.activities().random(state.player.stamina);
     if health_penalty < 0 || use_lecture_notes {
         health_penalty = 0;
     }
@@ -132,7 +132,7 @@ async fn study_subject(
 }
 
 async fn rest(g: &mut InternalGameState<'_>, state: &mut GameState) {
-    state.player.health += g.rng.random_in_range(7..15);
+    state.player.health += g.rng.activities().random_in_range(7..15);
     misc::hour_pass(g, state, None).await
 }
 
@@ -198,7 +198,7 @@ pub(super) async fn invite_from_neighbor(
     use actions::InvitationFromNeighborAction::*;
     use GameScreen::NeighborInvites;
     use NeighborInvitation::*;
-    let invitation = g.rng.random_variant();
+    let invitation = g.rng.activities().random_variant();
     match g
         .set_screen_and_wait_for_action(NeighborInvites(InvitePrompt(
             state.clone(),
@@ -209,12 +209,13 @@ pub(super) async fn invite_from_neighbor(
         Accept => {
             g.set_screen_and_wait_for_any_key(NeighborInvites(LetsGo))
                 .await;
-            let party_duration = g.rng.random_in_range(3..6);
+            let party_duration = g.rng.activities().random_in_range(3..6);
             for _ in 0..party_duration {
                 misc::hour_pass(g, state, None).await;
-                let random_subject_status =
-                    state.player.status_for_subject_mut(g.rng.random_variant());
-                random_subject_status.knowledge -= g.rng.random(
+                let random_subject_status = state
+                    .player
+                    .status_for_subject_mut(g.rng.activities().random_variant());
+                random_subject_status.knowledge -= g.rng.activities().random(
                     ((random_subject_status.knowledge * 2) as f32)
                         .sqrt()
                         .round() as i16,
@@ -228,21 +229,21 @@ pub(super) async fn invite_from_neighbor(
                     random_subject_status.subject()
                 );
 
-                if state.player.charisma > g.rng.random(25) {
-                    state.player.health += g.rng.random_in_range(1..6);
+                if state.player.charisma > g.rng.activities().random(25) {
+                    state.player.health += g.rng.activities().random_in_range(1..6);
                 }
             }
 
             state.player.charisma += 1;
 
             if state.player.brain < 2 {
-                state.player.brain = g.rng.random_in_range(2..5);
+                state.player.brain = g.rng.activities().random_in_range(2..5);
             }
         }
         Deny => {
             g.set_screen_and_wait_for_any_key(NeighborInvites(TooBad))
                 .await;
-            state.player.charisma -= g.rng.random_in_range(1..3);
+            state.player.charisma -= g.rng.activities().random_in_range(1..3);
         }
     }
 }
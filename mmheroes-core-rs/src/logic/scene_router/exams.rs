@@ -36,7 +36,7 @@ pub(super) async fn enter_exam(
     state: &mut GameState,
     subject: Subject,
 ) {
-    if g.rng.roll_dice(2) {
+    if g.rng.exams().roll_dice(2) {
         exam_intro(g, state, subject).await;
     }
     state.player.set_last_exam(subject);
@@ -148,7 +148,7 @@ async fn exam_intro(
     match subject {
         Subject::AlgebraAndNumberTheory => match state.location() {
             Location::PUNK => {
-                let intro = if g.rng.roll_dice(3) {
+                let intro = if g.rng.exams().roll_dice(3) {
                     ExamIntro::AlgebraPunkWrongRoom
                 } else {
                     ExamIntro::AlgebraPunkBigCrowdedRoom
@@ -172,8 +172,8 @@ async fn exam_intro(
         Subject::ComputerScience => show_intro(g, ExamIntro::ComputerScience).await,
         Subject::English => show_intro(g, ExamIntro::English).await,
         Subject::PhysicalEducation => {
-            if g.rng.roll_dice(3) {
-                let lecture_topic = g.rng.random_variant();
+            if g.rng.exams().roll_dice(3) {
+                let lecture_topic = g.rng.exams().random_variant();
                 show_intro(g, ExamIntro::PhysicalEducation(Some(lecture_topic))).await;
                 state
                     .current_day_mut()
@@ -249,7 +249,7 @@ async fn exam(g: &mut InternalGameState<'_>, state: &mut GameState, subject: Sub
     loop {
         let day_index = state.current_day_index();
         let status = state.player.status_for_subject_mut(subject);
-        if status.solved_all_problems() && !status.passed() {
+        if status.solved_all_problems(g.rules) && !status.passed() {
             status.set_passed_exam_day_index(day_index);
             if exam_passed(g, state, subject).await == ExamResult::Exit
                 || state.player().cause_of_death().is_some()
@@ -321,7 +321,9 @@ async fn npc_try_approach(
                 // NPC не пристаёт более одного раза подряд.
                 continue;
             }
-            if classmate.annoyance() - times_approached / 2 - garlic <= g.rng.random(10) {
+            if g.rules.classmate(classmate).annoyance - times_approached / 2 - garlic
+                <= g.rng.exams().random(10)
+            {
                 continue;
             }
             if !state.classmates[classmate].is_at_exam(subject) {
@@ -340,7 +342,7 @@ async fn npc_try_approach(
             }
         }
 
-        if g.rng.roll_dice(2) {
+        if g.rng.exams().roll_dice(2) {
             break;
         }
     }
@@ -348,14 +350,14 @@ async fn npc_try_approach(
 }
 
 pub(in crate::logic) fn number_of_problems_accepted(
-    rng: &mut random::Rng,
+    rng: &mut impl Rng,
     state: &GameState,
     subject: Subject,
     in_train: bool,
 ) -> u8 {
     let mut mental_capacity = state.player.status_for_subject(subject).knowledge
         + rng.random(state.player.brain)
-        - subject.mental_load();
+        - state.rules.subject(subject).mental_load;
 
     if in_train {
         mental_capacity = mental_capacity * 3 / 4;
@@ -364,8 +366,9 @@ pub(in crate::logic) fn number_of_problems_accepted(
     mental_capacity -= rng.random(max(5 - state.player.health, 0));
 
     let accepted_problems = if mental_capacity > 0 {
-        ((mental_capacity as f32).sqrt() / subject.single_problem_mental_factor()).round()
-            as u8
+        ((mental_capacity as f32).sqrt()
+            / state.rules.subject(subject).single_problem_mental_factor)
+            .round() as u8
     } else {
         0
     };
@@ -374,7 +377,7 @@ pub(in crate::logic) fn number_of_problems_accepted(
         state
             .player
             .status_for_subject(subject)
-            .problems_remaining(),
+            .problems_remaining(state.rules),
         accepted_problems,
     )
 }
@@ -387,12 +390,13 @@ async fn suffer_exam(
 ) {
     let charisma = state.player.charisma;
     let mut solved_problems =
-        number_of_problems_accepted(&mut g.rng, state, subject, in_train);
+        number_of_problems_accepted(g.rng.exams(), state, subject, in_train);
 
-    let knowledge_penalty =
-        g.rng.random(subject.mental_load()) - g.rng.random(state.player.stamina);
+    let knowledge_penalty = g.rng.exams().random(g.rules.subject(subject).mental_load)
+        - g.rng.exams().random(state.player.stamina);
     let knowledge = &mut state.player.status_for_subject_mut(subject).knowledge;
-    *knowledge -= knowledge_penalty.clamp(0, *knowledge);
+    // Знание может быть отрицательным, тогда штрафа нет.
+    *knowledge -= knowledge_penalty.clamp(0, (*knowledge).max(0));
 
     let too_smart =
         subject == Subject::GeometryAndTopology && charisma * 2 + 26 < *knowledge;
@@ -426,7 +430,10 @@ async fn suffer_exam(
         state.player.stamina
     };
 
-    let health_penalty = max(subject.health_penalty() - g.rng.random(stamina), 0);
+    let health_penalty = max(
+        g.rules.subject(subject).health_penalty - g.rng.exams().random(stamina),
+        0,
+    );
     misc::decrease_health(
         state,
         health_penalty,
@@ -505,7 +512,7 @@ async fn exam_passed(
             .await;
             misc::decrease_health(
                 state,
-                g.rng.random(6),
+                g.rng.exams().random(6),
                 CauseOfDeath::DestroyedByVsemirnov,
             );
         }
@@ -529,7 +536,7 @@ async fn exam_passed(
             possible_feelings.push(EnglishExamFeeling::SmellOfGarlic);
             possible_feelings.push(EnglishExamFeeling::SmellOfGarlic);
 
-            let feeling = *g.rng.random_element(&possible_feelings);
+            let feeling = *g.rng.exams().random_element(&possible_feelings);
             g.set_screen_and_wait_for_any_key(GameScreen::Exam(
                 ExamScene::EnglishExamPassed(state.clone(), feeling),
             ))
@@ -555,22 +562,22 @@ async fn exam_passed(
                         .knowledge /= 2;
                 }
                 EnglishExamFeeling::ThoughtsAboutFairies => {
-                    state.player.brain -= g.rng.random_in_range(1..3);
+                    state.player.brain -= g.rng.exams().random_in_range(1..3);
                 }
                 EnglishExamFeeling::EveryoneWantsYouDead => {
-                    state.player.charisma -= g.rng.random_in_range(1..3);
+                    state.player.charisma -= g.rng.exams().random_in_range(1..3);
                 }
                 EnglishExamFeeling::StaminaGone => {
-                    state.player.stamina -= g.rng.random_in_range(1..3);
+                    state.player.stamina -= g.rng.exams().random_in_range(1..3);
                 }
                 EnglishExamFeeling::BrainGotBetter => {
-                    state.player.brain += g.rng.random_in_range(1..4);
+                    state.player.brain += g.rng.exams().random_in_range(1..4);
                 }
                 EnglishExamFeeling::LoveForTheWorld => {
-                    state.player.charisma += g.rng.random_in_range(1..4);
+                    state.player.charisma += g.rng.exams().random_in_range(1..4);
                 }
                 EnglishExamFeeling::ReadyForEverything => {
-                    state.player.stamina += g.rng.random_in_range(1..4);
+                    state.player.stamina += g.rng.exams().random_in_range(1..4);
                 }
                 EnglishExamFeeling::Money(EnglishExamFeelingMoney::Stolen) => {
                     state.player.money = Money(0);
@@ -579,9 +586,9 @@ async fn exam_passed(
                     state.player.money = Money(20);
                 }
                 EnglishExamFeeling::SmellOfGarlic => {
-                    let garlic = g.rng.random_in_range(1..5);
+                    let garlic = g.rng.exams().random_in_range(1..5);
                     state.player.garlic = garlic;
-                    state.player.charisma -= g.rng.random(garlic / 2);
+                    state.player.charisma -= g.rng.exams().random(garlic / 2);
                 }
                 EnglishExamFeeling::QuicklyFadedAway => (),
             }
@@ -612,7 +619,7 @@ async fn exam_ends(
                 && !state
                     .player
                     .status_for_subject(subject)
-                    .solved_all_problems()
+                    .solved_all_problems(g.rules)
             {
                 match g
                     .set_screen_and_wait_for_action(GameScreen::Exam(
@@ -635,8 +642,9 @@ async fn exam_ends(
                 // TODO: Написать на это тест
                 return ExamResult::Continue;
             }
-            if subject.mental_load() * 2 + state.current_time().0 as i16 * 6
-                < state.player.charisma * 3 + g.rng.random_in_range(20..40)
+            if g.rules.subject(subject).mental_load * 2
+                + state.current_time().0 as i16 * 6
+                < state.player.charisma * 3 + g.rng.exams().random_in_range(20..40)
             {
                 g.set_screen_and_wait_for_any_key(GameScreen::Exam(
                     ExamScene::ProfessorLingers(state.clone(), subject),
@@ -711,7 +719,7 @@ async fn exam_in_train(
                 // Баг в оригинальной реализации: на экран должно быть выведено
                 // "Хорошо, билет есть...", но поскольку нет вызова wait_for_key,
                 // эта надпись не успевает появиться на экране.
-            } else if train::inspectors(&mut g.rng, state) {
+            } else if train::inspectors(g.rng.travel(), state) {
                 g.set_screen_and_wait_for_any_key(GameScreen::BaltiyskiyRailwayStation(
                     BaltiyskiyRailwayStationScene::CaughtByInspectors,
                 ))
@@ -756,7 +764,7 @@ async fn classmate_wants_something(
 
     match selected_action {
         NpcApproachAction::Ignore => {
-            let feeling_bad = classmate.health_penalty() > 0;
+            let feeling_bad = g.rules.classmate(classmate).health_penalty > 0;
             g.set_screen_and_wait_for_any_key(GameScreen::Exam(
                 ExamScene::IgnoredClassmate { feeling_bad },
             ))
@@ -764,7 +772,7 @@ async fn classmate_wants_something(
             if feeling_bad {
                 misc::decrease_health(
                     state,
-                    classmate.health_penalty(),
+                    g.rules.classmate(classmate).health_penalty,
                     CauseOfDeath::BetterNotIgnoreClassmate(classmate),
                 );
             }
@@ -34,13 +34,13 @@ pub(in crate::logic) async fn handle_router_action(
 async fn rest(g: &mut InternalGameState<'_>, state: &mut GameState) {
     let money = state.player.money;
     let mut available_actions = ActionVec::new();
-    if money >= Money::cola_cost() {
+    if money >= g.rules.costs.cola {
         available_actions.push(Action::OrderCola);
     }
-    if money >= Money::soup_cost() {
+    if money >= g.rules.costs.soup {
         available_actions.push(Action::OrderSoup);
     }
-    if money >= Money::beer_cost() {
+    if money >= g.rules.costs.beer {
         available_actions.push(Action::OrderBeer);
     }
     available_actions.push(Action::RestByOurselvesInMausoleum);
@@ -52,32 +52,32 @@ async fn rest(g: &mut InternalGameState<'_>, state: &mut GameState) {
     let player = &mut state.player;
     match g.wait_for_action().await {
         Action::OrderCola => {
-            player.money -= Money::cola_cost();
-            player.health += g.rng.random(player.charisma) + 3;
+            player.money -= g.rules.costs.cola;
+            player.health += g.rng.activities().random(player.charisma) + 3;
         }
         Action::OrderSoup => {
-            player.money -= Money::soup_cost();
-            player.health += g.rng.random(player.charisma) + 5;
+            player.money -= g.rules.costs.soup;
+            player.health += g.rng.activities().random(player.charisma) + 5;
         }
         Action::OrderBeer => {
-            player.money -= Money::beer_cost();
-            if g.rng.roll_dice(3) {
+            player.money -= g.rules.costs.beer;
+            if g.rng.activities().roll_dice(3) {
                 player.brain -= 1;
             }
-            if g.rng.roll_dice(3) {
+            if g.rng.activities().roll_dice(3) {
                 player.charisma += 1;
             }
-            if g.rng.roll_dice(2) {
+            if g.rng.activities().roll_dice(2) {
                 player.stamina += 1;
             }
-            player.health += g.rng.random(player.charisma);
+            player.health += g.rng.activities().random(player.charisma);
             if player.brain <= 0 {
                 player.health = 0;
                 player.cause_of_death = Some(CauseOfDeath::BeerAlcoholism);
             }
         }
         Action::RestByOurselvesInMausoleum => {
-            player.health += g.rng.random(player.charisma);
+            player.health += g.rng.activities().random(player.charisma);
         }
         Action::NoRestIsNoGood => return,
         action => illegal_action!(action),
@@ -59,19 +59,28 @@ fn scene_pdmi(state: &GameState) -> ActionVec {
     available_actions
 }
 
-async fn scene_computer_class(
+/// Возвращает `true`, если компьютерный класс закрылся и игрок отправился в общагу.
+async fn computer_class_is_closing(
     g: &mut InternalGameState<'_>,
     state: &mut GameState,
-) -> Option<ActionVec> {
+) -> bool {
     if state.current_time() > Time::computer_class_closing() {
         g.set_screen_and_wait_for_any_key(GameScreen::ComputerClassClosing(
             state.clone(),
         ))
         .await;
         state.set_location(Location::Dorm);
-        misc::decrease_health(state, g.rng.random(5), CauseOfDeath::OnTheWayToDorm);
-        return None;
+        misc::decrease_health(
+            state,
+            g.rng.travel().random(5),
+            CauseOfDeath::OnTheWayToDorm,
+        );
+        return true;
     }
+    false
+}
+
+fn scene_computer_class(state: &GameState) -> ActionVec {
     let mut available_actions = ActionVec::new();
     if Location::ComputerClass.is_exam_here_now(
         Subject::ComputerScience,
@@ -92,13 +101,15 @@ async fn scene_computer_class(
         available_actions.push(Action::PlayMMHEROES);
     }
     available_actions.push(Action::IAmDone);
-    Some(available_actions)
+    available_actions
 }
 
-async fn scene_dorm(
+/// Возвращает `true`, если вместо главного меню в общаге произошло что-то другое:
+/// игрок уснул или к нему пришёл сосед.
+async fn something_happens_in_dorm(
     g: &mut InternalGameState<'_>,
     state: &mut GameState,
-) -> Option<ActionVec> {
+) -> bool {
     let time_to_sleep =
         Time((23 - core::cmp::max(50 - state.player.health, 0) / 12) as u8);
     let current_time = state.current_time();
@@ -106,16 +117,20 @@ async fn scene_dorm(
         g.set_screen_and_wait_for_any_key(GameScreen::CantStayAwake(state.clone()))
             .await;
         sleep::sleep(g, state).await;
-        return None;
+        return true;
     } else if current_time >= Time(18)
-        && g.rng.random(10) < 3
+        && g.rng.activities().random(10) < 3
         && !state.player.is_invited_to_party()
     {
         state.player.set_invited_to_party(true);
         dorm::invite_from_neighbor(g, state).await;
-        return None;
+        return true;
     }
-    Some(ActionVec::from([
+    false
+}
+
+fn scene_dorm() -> ActionVec {
+    ActionVec::from([
         Action::Study,
         Action::ViewTimetable,
         Action::Rest,
@@ -125,7 +140,7 @@ async fn scene_dorm(
         Action::GoToMausoleum,
         Action::IAmDone,
         Action::WhatToDo,
-    ]))
+    ])
 }
 
 fn scene_mausoleum(state: &GameState) -> ActionVec {
@@ -140,42 +155,65 @@ fn scene_mausoleum(state: &GameState) -> ActionVec {
     available_actions
 }
 
+/// Если `resumed` — `true`, то игра продолжается из сохранения, и главное меню
+/// показывается сразу: всё, что предшествует его показу, произошло ещё до сохранения.
 pub(super) async fn run(
     g: &mut InternalGameState<'_>,
     mut state: GameState,
+    mut resumed: bool,
 ) -> entry_point::GameEnd {
     loop {
-        let available_actions = match state.location() {
-            Location::PUNK => scene_punk(&state),
-            Location::PDMI => scene_pdmi(&state),
-            Location::ComputerClass => match scene_computer_class(g, &mut state).await {
-                Some(available_actions) => available_actions,
-                None => continue,
-            },
-            Location::Dorm => match scene_dorm(g, &mut state).await {
-                Some(available_actions) => available_actions,
-                None => continue,
-            },
-            Location::Mausoleum => scene_mausoleum(&state),
-        };
-        g.set_screen_and_action_vec(
-            GameScreen::SceneRouter(state.clone()),
-            available_actions,
-        );
-        let router_action = g.wait_for_action().await;
-        if router_action == Action::IAmDone {
-            match i_am_done(g, &state).await {
-                None => continue,
-                Some(game_end) => return game_end,
+        if !core::mem::take(&mut resumed) {
+            let interrupted = match state.location() {
+                Location::ComputerClass => computer_class_is_closing(g, &mut state).await,
+                Location::Dorm => something_happens_in_dorm(g, &mut state).await,
+                Location::PUNK | Location::PDMI | Location::Mausoleum => false,
+            };
+            if interrupted {
+                if state.player.cause_of_death.is_some() {
+                    return misc::game_end(g, &state).await;
+                }
+                continue;
             }
         }
-        handle_router_action(g, &mut state, router_action).await;
-        if state.player.cause_of_death.is_some() {
-            return misc::game_end(g, &state).await;
+        if let Some(game_end) = select_router_action(g, &mut state).await {
+            return game_end;
         }
     }
 }
 
+fn available_actions(state: &GameState) -> ActionVec {
+    match state.location() {
+        Location::PUNK => scene_punk(state),
+        Location::PDMI => scene_pdmi(state),
+        Location::ComputerClass => scene_computer_class(state),
+        Location::Dorm => scene_dorm(),
+        Location::Mausoleum => scene_mausoleum(state),
+    }
+}
+
+/// Показывает главное меню и выполняет выбранное действие. Возвращает `Some`,
+/// если после этого игра закончилась.
+async fn select_router_action(
+    g: &mut InternalGameState<'_>,
+    state: &mut GameState,
+) -> Option<entry_point::GameEnd> {
+    g.set_screen_and_action_vec(
+        GameScreen::SceneRouter(state.clone()),
+        available_actions(state),
+    );
+    g.set_save_point();
+    let router_action = g.wait_for_action().await;
+    if router_action == Action::IAmDone {
+        return i_am_done(g, state).await;
+    }
+    handle_router_action(g, state, router_action).await;
+    if state.player.cause_of_death.is_some() {
+        return Some(misc::game_end(g, state).await);
+    }
+    None
+}
+
 async fn handle_router_action(
     g: &mut InternalGameState<'_>,
     state: &mut GameState,
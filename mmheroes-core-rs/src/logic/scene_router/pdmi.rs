@@ -30,11 +30,11 @@ async fn go_to_cafe(g: &mut InternalGameState<'_>, state: &mut GameState) {
         g,
         state,
         &[
-            (Action::OrderCoffee, Money::drink_cost(), 3),
-            (Action::OrderPastry, Money::pastry_cost(), 6),
+            (Action::OrderCoffee, g.rules.costs.drink, 3),
+            (Action::OrderPastry, g.rules.costs.pastry, 6),
             (
                 Action::OrderCoffeeWithPastry,
-                Money::drink_with_pastry_cost(),
+                g.rules.costs.drink_with_pastry,
                 10,
             ),
         ],
@@ -57,9 +57,9 @@ async fn go_to_cafe(g: &mut InternalGameState<'_>, state: &mut GameState) {
         g,
         state,
         &[
-            (Action::OrderTea, Money::drink_cost(), 2),
-            (Action::OrderCake, Money::pastry_cost(), 4),
-            (Action::OrderTeaWithCake, Money::drink_with_pastry_cost(), 7),
+            (Action::OrderTea, g.rules.costs.drink, 2),
+            (Action::OrderCake, g.rules.costs.pastry, 4),
+            (Action::OrderTeaWithCake, g.rules.costs.drink_with_pastry, 7),
         ],
         Action::RestInCafePUNK,
         Action::ShouldntHaveComeToCafePUNK,
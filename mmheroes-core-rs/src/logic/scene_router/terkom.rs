@@ -3,6 +3,7 @@ use crate::logic::{
     misc, Action, CauseOfDeath, GameScreen, GameState, InternalGameState, Money, Player,
     Time,
 };
+use crate::random::Rng;
 use Terkom::AgainNoFreeComputers;
 
 #[derive(Debug, Clone, Copy)]
@@ -32,7 +33,7 @@ pub(super) async fn work(g: &mut InternalGameState<'_>, state: &mut GameState) {
         return;
     }
 
-    if g.rng.random(3) > 0 {
+    if g.rng.activities().random(3) > 0 {
         g.set_screen_and_wait_for_any_key(GameScreen::Terkom(
             state.clone(),
             SorryNoFreeComputers {
@@ -61,7 +62,7 @@ pub(super) async fn work(g: &mut InternalGameState<'_>, state: &mut GameState) {
         let hiccup = hiccup(state);
         match g.wait_for_action().await {
             Action::EarnAtTerkom => {
-                let income = earned_money(&mut g.rng, state.player());
+                let income = earned_money(g.rng.activities(), state.player());
                 g.set_screen_and_wait_for_any_key(GameScreen::Terkom(
                     state.clone(),
                     YouEarnedByWorking { income, hiccup },
@@ -84,7 +85,7 @@ pub(super) async fn work(g: &mut InternalGameState<'_>, state: &mut GameState) {
                 .await;
             }
             Action::SurfInternetAtTerkom => {
-                let income = earned_money(&mut g.rng, state.player());
+                let income = earned_money(g.rng.activities(), state.player());
                 g.set_screen_and_wait_for_any_key(GameScreen::Terkom(
                     state.clone(),
                     YouEarnedBySurfingInternet { income, hiccup },
@@ -115,7 +116,7 @@ pub(super) async fn work(g: &mut InternalGameState<'_>, state: &mut GameState) {
     }
 }
 
-fn earned_money(rng: &mut crate::random::Rng, player: &Player) -> Money {
+fn earned_money(rng: &mut impl Rng, player: &Player) -> Money {
     let mut income = rng.random(player.brain() + player.charisma());
     income = rng.random(income);
     income += 1;
@@ -42,7 +42,7 @@ pub(super) async fn go_to_pdmi(g: &mut InternalGameState<'_>, state: &mut GameSt
         return;
     }
 
-    let health_penalty = g.rng.random(10);
+    let health_penalty = g.rng.travel().random(10);
     state.set_location(Location::PDMI);
     let caught_by_inspectors =
         go_by_train(g, state, health_penalty, false, &GameScreen::TrainToPDMI).await;
@@ -53,7 +53,7 @@ pub(super) async fn go_to_pdmi(g: &mut InternalGameState<'_>, state: &mut GameSt
 }
 
 pub(super) async fn go_from_pdmi(g: &mut InternalGameState<'_>, state: &mut GameState) {
-    let health_penalty = g.rng.random(10);
+    let health_penalty = g.rng.travel().random(10);
     go_by_train(g, state, health_penalty, true, &GameScreen::TrainFromPDMI).await;
 
     state.set_location(Location::PUNK);
@@ -77,15 +77,15 @@ pub(super) async fn go_by_train(
     make_screen: &dyn Fn(GameState, TrainScene) -> GameScreen,
 ) -> bool {
     let ticket_cost = if back_from_pdmi {
-        Money::one_way_train_ticket_cost()
+        g.rules.costs.one_way_train_ticket
     } else {
-        Money::roundtrip_train_ticket_cost()
+        g.rules.costs.roundtrip_train_ticket
     };
     let no_money_for_ticket = state.player.money < ticket_cost;
     let caught_by_inspectors = if state.player.has_train_ticket() {
         false
     } else if no_money_for_ticket {
-        let caught_by_inspectors = inspectors(&mut g.rng, state);
+        let caught_by_inspectors = inspectors(g.rng.travel(), state);
         g.set_screen_and_wait_for_any_key(make_screen(
             state.clone(),
             GatecrashBecauseNoMoney {
@@ -111,7 +111,7 @@ pub(super) async fn go_by_train(
             .await
         {
             Action::TrainToPDMIGatecrash | Action::TrainFromPDMIGatecrash => {
-                let caught_by_inspectors = inspectors(&mut g.rng, state);
+                let caught_by_inspectors = inspectors(g.rng.travel(), state);
                 g.set_screen_and_wait_for_any_key(make_screen(
                     state.clone(),
                     GatecrashByChoice {
@@ -146,6 +146,6 @@ pub(super) async fn go_by_train(
     caught_by_inspectors
 }
 
-pub(in crate::logic) fn inspectors(rng: &mut Rng, state: &GameState) -> bool {
+pub(in crate::logic) fn inspectors(rng: &mut impl Rng, state: &GameState) -> bool {
     state.player.charisma < rng.random(10)
 }
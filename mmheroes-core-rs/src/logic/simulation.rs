@@ -0,0 +1,79 @@
+//! Управление игрой напрямую действиями (`Action`), без пользовательского интерфейса.
+//!
+//! В отличие от `GameUI`, здесь ничего не отрисовывается и не нужно нажимать
+//! клавиши: на каждом экране можно узнать список доступных действий и выполнить
+//! любое из них. Это позволяет прогонять очень много игр подряд, например,
+//! для подбора баланса.
+
+use super::*;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SimulationError {
+    /// Действие недоступно на текущем экране. Состояние игры не изменилось.
+    IllegalAction(Action),
+
+    /// Игра закончилась безвозвратно (`GameScreen::Terminal`), никакие действия
+    /// больше невозможны.
+    GameIsOver,
+}
+
+/// Игра, которой управляют напрямую действиями.
+///
+/// Экран `GameScreen::Intro` пропускается: сразу после создания текущим экраном
+/// становится первый экран, на котором от игрока требуется действие.
+pub struct Simulation<'game, G: ?Sized> {
+    state_holder: &'game StateHolder,
+    game: Pin<&'game mut G>,
+}
+
+impl<'game, G: Game + ?Sized> Simulation<'game, G> {
+    pub fn new(state_holder: &'game StateHolder, mut game: Pin<&'game mut G>) -> Self {
+        // Первое действие только запускает игру и само по себе ни на что не влияет.
+        game.as_mut().perform_action(Action::AnyKey);
+        Simulation { state_holder, game }
+    }
+
+    pub fn observable_state(&self) -> Ref<'_, ObservableGameState> {
+        self.state_holder.observable_state()
+    }
+
+    pub fn screen(&self) -> Ref<'_, GameScreen> {
+        Ref::map(self.observable_state(), ObservableGameState::screen)
+    }
+
+    pub fn available_actions(&self) -> Ref<'_, [Action]> {
+        Ref::map(
+            self.observable_state(),
+            ObservableGameState::available_actions,
+        )
+    }
+
+    /// См. [`StateHolder::save_game`].
+    pub fn save_game(&self) -> Option<SavedGame> {
+        self.state_holder.save_game()
+    }
+
+    /// См. [`StateHolder::state_hash`].
+    pub fn state_hash(&self) -> Option<u64> {
+        self.state_holder.state_hash()
+    }
+
+    /// Закончилась ли игра безвозвратно.
+    pub fn is_over(&self) -> bool {
+        matches!(*self.screen(), GameScreen::Terminal)
+    }
+
+    /// Выполняет действие на текущем экране.
+    ///
+    /// Если действие недоступно, игра остаётся в прежнем состоянии.
+    pub fn perform_action(&mut self, action: Action) -> Result<(), SimulationError> {
+        if self.is_over() {
+            return Err(SimulationError::GameIsOver);
+        }
+        if !self.available_actions().contains(&action) {
+            return Err(SimulationError::IllegalAction(action));
+        }
+        self.game.as_mut().perform_action(action);
+        Ok(())
+    }
+}
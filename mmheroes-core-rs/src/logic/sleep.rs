@@ -1,7 +1,8 @@
 use crate::logic::{
-    timetable, CauseOfDeath, Duration, GameScreen, GameState, InternalGameState,
-    Location, Subject, Time,
+    CauseOfDeath, Duration, GameScreen, GameState, InternalGameState, Location, Subject,
+    Time,
 };
+use crate::random::Rng;
 use strum::VariantArray;
 
 #[derive(Debug, Clone, strum::EnumDiscriminants)]
@@ -116,11 +117,12 @@ pub(in crate::logic) async fn sleep(
         return;
     }
     state.player.health = core::cmp::min(state.player.health, 40);
-    let health_gain = (state.player.health + g.rng.random_in_range(15..35)).min(50)
+    let health_gain = (state.player.health + g.rng.sleep().random_in_range(15..35))
+        .min(50)
         - state.player.health;
     assert!(health_gain >= 0, "negative health_gain ({})", health_gain,);
     state.player.health += health_gain;
-    let sleep_duration = 7 + g.rng.random(health_gain / 4);
+    let sleep_duration = 7 + g.rng.sleep().random(health_gain / 4);
     let new_time = state.current_time() + Duration(sleep_duration as i8);
 
     if new_time >= Time(24) {
@@ -149,7 +151,7 @@ pub(in crate::logic) async fn sleep(
         dream = DreamTheme::Djug;
     }
 
-    if g.rng.roll_dice(2) {
+    if g.rng.sleep().roll_dice(2) {
         match dream {
             DreamTheme::Stupid => {
                 stupid_dream(g).await;
@@ -158,7 +160,7 @@ pub(in crate::logic) async fn sleep(
                 djug_dream(g).await;
             }
             DreamTheme::SubjectRelated => {
-                if g.rng.roll_dice(3) {
+                if g.rng.sleep().roll_dice(3) {
                     g.set_screen_and_wait_for_any_key(GameScreen::Dreaming(
                         DreamScreen::SubjectRelated(state.player.last_exam()),
                     ))
@@ -168,7 +170,7 @@ pub(in crate::logic) async fn sleep(
         }
 
         if matches!(dream, DreamTheme::Stupid | DreamTheme::Djug) {
-            state.player.health = g.rng.random_in_range(10..20);
+            state.player.health = g.rng.sleep().random_in_range(10..20);
         }
     }
 
@@ -178,7 +180,7 @@ pub(in crate::logic) async fn sleep(
 
     if state.player.garlic > 0 {
         state.player.garlic -= 1;
-        state.player.charisma += g.rng.random(2);
+        state.player.charisma += g.rng.sleep().random(2);
     }
 }
 
@@ -190,8 +192,8 @@ async fn dream_phase(g: &mut InternalGameState<'_>, dream: DreamScreen) {
 async fn stupid_dream(g: &mut InternalGameState<'_>) {
     use DreamScreen::Stupid;
     use StupidDream::*;
-    let subject = g.rng.random_variant();
-    let scenario = g.rng.random_variant();
+    let subject = g.rng.sleep().random_variant();
+    let scenario = g.rng.sleep().random_variant();
     dream_phase(g, Stupid(Phase1(subject, scenario))).await;
     dream_phase(g, Stupid(Phase2)).await;
 }
@@ -203,15 +205,15 @@ async fn djug_dream(g: &mut InternalGameState<'_>) {
     dream_phase(g, Djug(Phase2)).await;
     dream_phase(g, Djug(Phase3)).await;
     dream_phase(g, Djug(Phase4)).await;
-    let quote = g.rng.random_variant();
+    let quote = g.rng.sleep().random_variant();
     dream_phase(g, Djug(Phase5(quote))).await;
     dream_phase(g, Djug(Phase6)).await;
 }
 
 fn die_if_time_out(state: &mut GameState) -> bool {
-    let last_day_index = timetable::NUM_DAYS as u8;
-    assert!(state.current_day_index() <= last_day_index);
-    let time_out = state.current_day_index() == last_day_index;
+    let num_days = state.timetable().length().days();
+    assert!(state.current_day_index() <= num_days);
+    let time_out = state.current_day_index() == num_days;
     if time_out {
         state.player.cause_of_death = Some(CauseOfDeath::TimeOut);
     };
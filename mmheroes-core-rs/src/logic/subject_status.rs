@@ -10,36 +10,45 @@ struct SubjectStatusBits {
     #[bits(7)]
     problems_done: u8,
 
-    #[bits(
-        3,
-        default = None,
-        from = SubjectStatusBits::passed_exam_day_index_from_bits,
-        into = SubjectStatusBits::passed_exam_day_index_to_bits
-    )]
-    passed_exam_day_index: Option<u8>,
+    /// Младшие три бита [`SubjectStatusBits::passed_exam_day_index`].
+    #[bits(3, default = NOT_PASSED & 0b111)]
+    passed_exam_day_index_low: u8,
 
     #[bits(1)]
     has_lecture_notes: bool,
 
-    #[bits(2)]
+    /// Старший бит [`SubjectStatusBits::passed_exam_day_index`]. Хранится
+    /// отдельно от младших, чтобы сохранения шестидневной сессии не отличались
+    /// от сохранений, сделанных до появления сессий другой длины.
+    #[bits(1)]
+    passed_exam_day_index_high: u8,
+
+    #[bits(1)]
     _padding: u16,
 }
 
+/// Значение [`SubjectStatusBits::passed_exam_day_index`], означающее, что зачёт
+/// не сдан. Раньше на индекс дня было три бита, и это было наибольшее значение,
+/// поэтому индексы начиная с него записываются на единицу больше.
+const NOT_PASSED: u8 = 7;
+
 impl SubjectStatusBits {
-    const fn passed_exam_day_index_from_bits(bits: u8) -> Option<u8> {
-        if bits == NOT_PASSED as u8 {
-            None
-        } else {
-            Some(bits)
+    const fn passed_exam_day_index(self) -> Option<u8> {
+        match self.passed_exam_day_index_low() | self.passed_exam_day_index_high() << 3 {
+            NOT_PASSED => None,
+            bits if bits < NOT_PASSED => Some(bits),
+            bits => Some(bits - 1),
         }
     }
 
-    //noinspection RsReplaceMatchExpr
-    const fn passed_exam_day_index_to_bits(passed_exam_day_index: Option<u8>) -> u8 {
-        match passed_exam_day_index {
-            Some(index) => index,
-            None => NOT_PASSED as u8,
-        }
+    fn set_passed_exam_day_index(&mut self, passed_exam_day_index: Option<u8>) {
+        let bits = match passed_exam_day_index {
+            Some(index) if index < NOT_PASSED => index,
+            Some(index) => index + 1,
+            None => NOT_PASSED,
+        };
+        self.set_passed_exam_day_index_low(bits & 0b111);
+        self.set_passed_exam_day_index_high(bits >> 3);
     }
 }
 
@@ -49,8 +58,6 @@ pub struct SubjectStatus {
     bits: SubjectStatusBits,
 }
 
-const NOT_PASSED: u16 = (1 << SubjectStatusBits::PASSED_EXAM_DAY_INDEX_BITS) - 1;
-
 impl SubjectStatus {
     pub(in crate::logic) fn new(subject: Subject, knowledge: BrainLevel) -> Self {
         Self {
@@ -71,14 +78,15 @@ impl SubjectStatus {
         self.bits.problems_done()
     }
 
-    pub fn problems_remaining(&self) -> u8 {
-        self.subject()
-            .required_problems()
+    pub fn problems_remaining(&self, rules: &Rules) -> u8 {
+        rules
+            .subject(self.subject())
+            .required_problems
             .saturating_sub(self.problems_done())
     }
 
-    pub fn solved_all_problems(&self) -> bool {
-        self.problems_done() >= self.subject().required_problems()
+    pub fn solved_all_problems(&self, rules: &Rules) -> bool {
+        self.problems_done() >= rules.subject(self.subject()).required_problems
     }
 
     pub(in crate::logic) fn more_problems_solved(&mut self, more: u8) {
@@ -86,7 +94,7 @@ impl SubjectStatus {
             .set_problems_done(self.bits.problems_done() + more);
     }
 
-    fn passed_exam_day_index(&self) -> Option<u8> {
+    pub(in crate::logic) fn passed_exam_day_index(&self) -> Option<u8> {
         self.bits.passed_exam_day_index()
     }
 
@@ -99,7 +107,10 @@ impl SubjectStatus {
     }
 
     pub(in crate::logic) fn set_passed_exam_day_index(&mut self, day_index: u8) {
-        assert!(day_index < NOT_PASSED as u8, "Too big day index");
+        assert!(
+            (day_index as usize) < timetable::MAX_NUM_DAYS,
+            "Too big day index"
+        );
         assert!(
             self.bits.passed_exam_day_index().is_none(),
             "Cannot pass an exam more than once"
@@ -115,6 +126,32 @@ impl SubjectStatus {
         assert!(!self.has_lecture_notes(), "Already has lecture notes");
         self.bits.set_has_lecture_notes(true);
     }
+
+    pub(in crate::logic) fn encode(&self, w: &mut save::SaveWriter) {
+        w.write_i16(self.knowledge);
+        w.write_u16(self.bits.into_bits());
+    }
+
+    pub(in crate::logic) fn decode(
+        r: &mut save::SaveReader,
+        subject: Subject,
+    ) -> Result<SubjectStatus, save::SavedGameError> {
+        let knowledge = r.read_i16()?;
+        let raw_bits = r.read_u16()?;
+        let bits = SubjectStatusBits::from_bits(raw_bits);
+        save::ensure_valid(
+            save::raw_field(
+                raw_bits.into(),
+                SubjectStatusBits::SUBJECT_OFFSET,
+                SubjectStatusBits::SUBJECT_BITS,
+            ) == subject as u32,
+        )?;
+        save::ensure_valid(
+            bits.passed_exam_day_index()
+                .is_none_or(|day_index| (day_index as usize) < timetable::MAX_NUM_DAYS),
+        )?;
+        Ok(SubjectStatus { knowledge, bits })
+    }
 }
 
 impl Debug for SubjectStatus {
@@ -146,4 +183,22 @@ mod tests {
 
         assert_eq!(result, "SubjectStatus { subject: Calculus, knowledge: 13, passed_exam_day_index: Some(3), problems_done: 3, has_lecture_notes: true }\n");
     }
+
+    #[test]
+    fn test_passed_exam_day_index() {
+        let status = SubjectStatus::new(Subject::English, 0);
+        assert_eq!(status.passed_exam_day_index(), None);
+        // Так же, как до появления сессий другой длины
+        assert_eq!(
+            status.bits.into_bits(),
+            0b111 << 10 | Subject::English as u16
+        );
+
+        for day_index in 0..timetable::MAX_NUM_DAYS as u8 {
+            let mut status = SubjectStatus::new(Subject::English, 0);
+            status.set_passed_exam_day_index(day_index);
+            assert_eq!(status.passed_exam_day_index(), Some(day_index));
+            assert!(!status.has_lecture_notes());
+        }
+    }
 }
@@ -2,6 +2,7 @@ use super::*;
 use strum::{EnumCount, FromRepr, VariantArray};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, FromRepr, EnumCount, VariantArray)]
+#[repr(u8)]
 pub enum Subject {
     AlgebraAndNumberTheory = 0,
     Calculus,
@@ -23,7 +24,7 @@ impl Subject {
     }
 
     pub(super) const fn from_bits(bits: u8) -> Subject {
-        match Subject::from_repr(bits as usize) {
+        match Subject::from_repr(bits) {
             Some(subject) => subject,
             None => panic!("Invalid subject bits."),
         }
@@ -42,17 +43,6 @@ impl Subject {
         }
     }
 
-    /// Количество задач, которые необходимо решить для получения зачёта по предмету.
-    pub const fn required_problems(self) -> u8 {
-        match self {
-            AlgebraAndNumberTheory => 12,
-            Calculus => 10,
-            GeometryAndTopology | English => 3,
-            ComputerScience => 2,
-            PhysicalEducation => 1,
-        }
-    }
-
     /// Количество дней в изначальном расписании, в которые может проходить зачёт по
     /// предмету.
     /// В течение игры, впрочем, расписание может меняться — могут добавляться новые дни.
@@ -83,67 +73,4 @@ impl Subject {
             PhysicalEducation => Duration(1),
         }
     }
-
-    /// Чем больше это число, тем больше знаний, интеллекта и здоровья нужно для того,
-    /// чтобы преподаватель зачёл задачу.
-    pub(super) const fn mental_load(self) -> BrainLevel {
-        match self {
-            AlgebraAndNumberTheory => 10,
-            Calculus => 8,
-            GeometryAndTopology => 4,
-            ComputerScience => 5,
-            English | PhysicalEducation => 7,
-        }
-    }
-
-    /// Чем больше это число, тем больше выносливости нужно чтобы не терять здоровье
-    /// при попытке сдать зачёт.
-    pub(super) const fn health_penalty(self) -> HealthLevel {
-        match self {
-            AlgebraAndNumberTheory => 17,
-            Calculus => 14,
-            GeometryAndTopology => 8,
-            ComputerScience => 6,
-            English => 10,
-            PhysicalEducation => 20,
-        }
-    }
-
-    /// Чем больше это число, тем больше mental capacity нужно для того,
-    /// чтобы зачли _одну_ задачу.
-    pub(super) const fn single_problem_mental_factor(self) -> f32 {
-        match self {
-            AlgebraAndNumberTheory | GeometryAndTopology | ComputerScience => 3.0,
-            Calculus => 2.0,
-            English | PhysicalEducation => 1.0,
-        }
-    }
-
-    /// В каких местах может проходить зачёт по предмету.
-    ///
-    /// При составлении расписания из этого массива выбирается случайный элемент.
-    pub(super) const fn exam_places(self) -> &'static [Location] {
-        use Location::*;
-        match self {
-            AlgebraAndNumberTheory => &[PUNK, PUNK, PDMI],
-            GeometryAndTopology => &[PUNK, PDMI, PDMI],
-            ComputerScience => &[ComputerClass, ComputerClass, ComputerClass],
-            Calculus | English | PhysicalEducation => &[PUNK, PUNK, PUNK],
-        }
-    }
-
-    /// Какой уровень знаний соответствует какой оценке по шкале этого препода.
-    pub(super) const fn assessment_bounds(
-        self,
-    ) -> &'static [(BrainLevel, KnowledgeAssessment)] {
-        use KnowledgeAssessment::*;
-        match self {
-            AlgebraAndNumberTheory => &[(11, Bad), (21, Satisfactory), (51, Good)],
-            Calculus => &[(9, Bad), (19, Satisfactory), (41, Good)],
-            GeometryAndTopology => &[(6, Bad), (11, Satisfactory), (31, Good)],
-            ComputerScience => &[(10, Bad), (16, Satisfactory), (31, Good)],
-            English => &[(5, Bad), (9, Satisfactory), (16, Good)],
-            PhysicalEducation => &[(5, Bad), (9, Satisfactory), (16, Good)],
-        }
-    }
 }
@@ -1,12 +1,60 @@
 pub use crate::logic::{Location, Subject};
 
-use crate::logic::{GameScreen, GameState, InternalGameState};
+use crate::logic::save::{self, SavedGameError};
+use crate::logic::{GameScreen, GameState, InternalGameState, Rules};
+use crate::random::Rng;
 use bitfield_struct::bitfield;
 use core::fmt::{Display, Formatter};
 use core::ops::{Add, AddAssign, Rem, Sub};
+use core::str::FromStr;
 use strum::EnumCount;
 
-pub const NUM_DAYS: usize = 6;
+/// Наибольшая продолжительность сессии в днях.
+pub const MAX_NUM_DAYS: usize = 14;
+
+// Записано числом, потому что cbindgen не вычисляет выражения.
+const _: () = assert!(MAX_NUM_DAYS == SemesterLength::MAX.days() as usize);
+
+/// Продолжительность сессии в днях.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SemesterLength(u8);
+
+// Без этого cbindgen выводит в заголовок пустые строки вместо констант ниже.
+/// cbindgen:ignore
+impl SemesterLength {
+    pub const MIN: SemesterLength = SemesterLength(3);
+
+    pub const MAX: SemesterLength = SemesterLength(14);
+
+    /// Шесть дней, как в оригинальной игре.
+    pub const NORMAL: SemesterLength = SemesterLength(6);
+
+    /// «Блиц»: самая короткая сессия.
+    pub const BLITZ: SemesterLength = SemesterLength::MIN;
+
+    /// «Марафон»: самая длинная сессия.
+    pub const MARATHON: SemesterLength = SemesterLength::MAX;
+
+    /// Возвращает `None`, если `days` не от [`SemesterLength::MIN`]
+    /// до [`SemesterLength::MAX`].
+    pub const fn new(days: u8) -> Option<SemesterLength> {
+        if days >= Self::MIN.0 && days <= Self::MAX.0 {
+            Some(SemesterLength(days))
+        } else {
+            None
+        }
+    }
+
+    pub const fn days(self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for SemesterLength {
+    fn default() -> Self {
+        SemesterLength::NORMAL
+    }
+}
 
 /// Количество часов, прошедших с полуночи.
 ///
@@ -109,11 +157,9 @@ impl TryFrom<u64> for Time {
     }
 }
 
-impl TryFrom<Time> for u64 {
-    type Error = core::convert::Infallible;
-
-    fn try_from(value: Time) -> Result<Self, Self::Error> {
-        Ok(u64::from(value.0))
+impl From<Time> for u64 {
+    fn from(value: Time) -> Self {
+        u64::from(value.0)
     }
 }
 
@@ -199,6 +245,38 @@ impl Exam {
     pub fn location(self) -> Location {
         self.bits.location()
     }
+
+    fn encode(self, w: &mut save::SaveWriter) {
+        w.write_u16(self.bits.into_bits());
+    }
+
+    fn decode(
+        r: &mut save::SaveReader,
+        subject: Subject,
+    ) -> Result<Exam, SavedGameError> {
+        let raw_bits = r.read_u16()?;
+        let exam = Exam {
+            bits: ExamBits::from_bits(raw_bits),
+        };
+        if exam == Exam::NO_EXAM {
+            return Ok(exam);
+        }
+        let raw_field =
+            |offset, width| save::raw_field(raw_bits.into(), offset, width) as usize;
+        save::ensure_valid(
+            raw_field(ExamBits::SUBJECT_OFFSET, ExamBits::SUBJECT_BITS)
+                == subject as usize,
+        )?;
+        save::ensure_valid(
+            Location::from_repr(raw_field(
+                ExamBits::LOCATION_OFFSET,
+                ExamBits::LOCATION_BITS,
+            ) as u8)
+            .is_some(),
+        )?;
+        save::ensure_valid(exam.from() < exam.to() && exam.to() <= Time(24))?;
+        Ok(exam)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -251,27 +329,56 @@ impl Day {
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Timetable {
-    days: [Day; NUM_DAYS],
+    /// Используются только первые `length` дней, остальные пустые.
+    days: [Day; MAX_NUM_DAYS],
+    length: SemesterLength,
 }
 
+/// Сколько дней расписания хранится в основной части сохранения. Остальные
+/// дни хранятся в конце сохранения, чтобы сохранения шестидневной сессии
+/// не отличались от сохранений, сделанных до появления сессий другой длины.
+const SAVED_DAYS: usize = SemesterLength::NORMAL.days() as usize;
+
+/// Размер дней расписания, которые не входят в основную часть сохранения,
+/// вместе с продолжительностью сессии.
+pub(in crate::logic) const SAVED_EXTENSION_SIZE: usize =
+    1 + (MAX_NUM_DAYS - SAVED_DAYS) * Subject::COUNT * size_of::<ExamBits>();
+
 impl Timetable {
-    pub(in crate::logic) fn random(rng: &mut crate::random::Rng) -> Timetable {
+    /// Дни без единого зачёта.
+    fn empty_days() -> [Day; MAX_NUM_DAYS] {
         let mut days = [const {
             Day {
                 index: 0,
                 exams: [const { Exam::NO_EXAM }; Subject::COUNT],
             }
-        }; NUM_DAYS];
+        }; MAX_NUM_DAYS];
 
         for (i, day) in days.iter_mut().enumerate() {
             day.index = i as u8;
         }
 
+        days
+    }
+
+    /// Случайное расписание сессии длиной `length`. Число дней, в которые
+    /// проходят зачёты по предмету, пропорционально продолжительности сессии.
+    pub(in crate::logic) fn random(
+        rng: &mut impl Rng,
+        rules: &Rules,
+        length: SemesterLength,
+    ) -> Timetable {
+        let mut days = Self::empty_days();
+        let num_days = length.days() as usize;
+
         for subject in Subject::all_subjects() {
-            let mut day_used = [false; NUM_DAYS];
-            for _ in 0..subject.exam_days() {
+            let mut day_used = [false; MAX_NUM_DAYS];
+            let exam_days = (subject.exam_days() as usize * num_days)
+                .div_ceil(SemesterLength::NORMAL.days() as usize)
+                .min(num_days);
+            for _ in 0..exam_days {
                 let day_idx = loop {
-                    let day = rng.random_in_range(0..NUM_DAYS);
+                    let day = rng.random_in_range(0..num_days);
                     if !day_used[day] {
                         day_used[day] = true;
                         break day;
@@ -290,36 +397,402 @@ impl Timetable {
                     subject,
                     exam_start_time,
                     exam_start_time + exam_duration,
-                    *rng.random_element(subject.exam_places()),
+                    *rng.random_element(&rules.subject(subject).exam_places),
                 );
                 days[day_idx].add_exam(exam);
             }
         }
-        Timetable { days }
+        Timetable { days, length }
     }
 
     pub(in crate::logic) fn randomize_from_day(
         &mut self,
         day_index: u8,
-        rng: &mut crate::random::Rng,
+        rng: &mut impl Rng,
+        rules: &Rules,
     ) {
         let old_timetable = self.clone();
-        *self = Timetable::random(rng);
+        *self = Timetable::random(rng, rules, self.length);
         for i in 0..=day_index {
             *self.day_mut(i) = old_timetable.day(i).clone();
         }
     }
 
+    pub fn length(&self) -> SemesterLength {
+        self.length
+    }
+
     pub fn day(&self, index: u8) -> &Day {
-        &self.days[index as usize]
+        &self.days()[index as usize]
     }
 
     pub(in crate::logic) fn day_mut(&mut self, index: u8) -> &mut Day {
-        &mut self.days[index as usize]
+        &mut self.days[..self.length.days() as usize][index as usize]
+    }
+
+    /// Индекс последнего дня сессии.
+    pub fn last_day_index(&self) -> u8 {
+        self.length.days() - 1
     }
 
     pub fn days(&self) -> &[Day] {
-        &self.days
+        &self.days[..self.length.days() as usize]
+    }
+
+    /// Записывает первые шесть дней расписания. Остальные дни записываются
+    /// в конец сохранения с помощью [`Timetable::encode_extension`].
+    pub(in crate::logic) fn encode(&self, w: &mut save::SaveWriter) {
+        for day in self.days[..SAVED_DAYS].iter() {
+            for exam in day.exams.iter() {
+                exam.encode(w);
+            }
+        }
+    }
+
+    pub(in crate::logic) fn encode_extension(&self, w: &mut save::SaveWriter) {
+        w.write_u8(self.length.days());
+        for day in self.days[SAVED_DAYS..].iter() {
+            for exam in day.exams.iter() {
+                exam.encode(w);
+            }
+        }
+    }
+
+    /// Читает первые шесть дней расписания шестидневной сессии. Если сохранение
+    /// сделано в версии формата, где бывают сессии другой длины, после этого
+    /// нужно вызвать [`Timetable::decode_extension`].
+    pub(in crate::logic) fn decode(
+        r: &mut save::SaveReader,
+    ) -> Result<Timetable, SavedGameError> {
+        let mut days = Self::empty_days();
+        for day in days[..SAVED_DAYS].iter_mut() {
+            for subject in Subject::all_subjects() {
+                day.exams[subject as usize] = Exam::decode(r, subject)?;
+            }
+        }
+        Ok(Timetable {
+            days,
+            length: SemesterLength::NORMAL,
+        })
+    }
+
+    pub(in crate::logic) fn decode_extension(
+        &mut self,
+        r: &mut save::SaveReader,
+    ) -> Result<(), SavedGameError> {
+        self.length =
+            SemesterLength::new(r.read_u8()?).ok_or(SavedGameError::InvalidData)?;
+        for day in self.days[SAVED_DAYS..].iter_mut() {
+            for subject in Subject::all_subjects() {
+                day.exams[subject as usize] = Exam::decode(r, subject)?;
+            }
+        }
+        // Дни после конца сессии всегда пустые, иначе у одинаковых состояний
+        // игры были бы разные хэши.
+        save::ensure_valid(
+            self.days[self.length.days() as usize..]
+                .iter()
+                .all(|day| day.exams().next().is_none()),
+        )
+    }
+}
+
+impl Display for Timetable {
+    /// Записывает расписание в текстовом формате (см. [`Timetable::parse`]).
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.length != SemesterLength::NORMAL {
+            writeln!(f, "days {}", self.length.days())?;
+        }
+        for day in self.days().iter() {
+            for exam in day.exams() {
+                writeln!(
+                    f,
+                    "{} {} {} {} {}",
+                    day.index + 1,
+                    subject_name(exam.subject()),
+                    exam.from(),
+                    exam.to(),
+                    location_name(exam.location()),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Timetable {
+    /// Читает расписание из текста. Каждая непустая строка — один зачёт:
+    /// номер дня (начиная с 1), предмет, час начала, час окончания и место.
+    /// Всё, что после `#`, — комментарий:
+    ///
+    /// ```text
+    /// # Матан в первый день
+    /// 1 calculus 10 13 punk
+    /// 2 computer_science 16 18 computer_class
+    /// ```
+    ///
+    /// Предметы называются так же, как в файле с правилами
+    /// (`algebra_and_number_theory`, `calculus`, `geometry_and_topology`,
+    /// `computer_science`, `english`, `physical_education`), места — `punk`,
+    /// `pdmi` и `computer_class`.
+    ///
+    /// Сессия по умолчанию длится шесть дней. Другую продолжительность можно
+    /// указать строкой `days <число дней>` перед зачётами.
+    ///
+    /// Зачёты проверяются по правилам `rules` (см. [`TimetableBuilder`]).
+    pub fn parse(text: &str, rules: &'static Rules) -> Result<Timetable, TimetableError> {
+        Timetable::parse_lines(text.lines(), rules)
+    }
+
+    /// Как [`Timetable::parse`], но строки текста уже разделены.
+    pub(crate) fn parse_lines<'a>(
+        lines: impl Iterator<Item = &'a str>,
+        rules: &'static Rules,
+    ) -> Result<Timetable, TimetableError> {
+        let mut builder = TimetableBuilder::with_rules(SemesterLength::NORMAL, rules);
+        let mut has_exams = false;
+        for (i, line) in lines.enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut fields = line.split_whitespace();
+            let Some(first) = fields.next() else {
+                continue;
+            };
+            let invalid_line = TimetableError::InvalidLine(i + 1);
+            if first == "days" {
+                let length = fields
+                    .next()
+                    .and_then(|days| u8::from_str(days).ok())
+                    .and_then(SemesterLength::new)
+                    .ok_or(invalid_line)?;
+                if has_exams || fields.next().is_some() {
+                    return Err(invalid_line);
+                }
+                builder = TimetableBuilder::with_rules(length, rules);
+                continue;
+            }
+            let mut next_field = || fields.next().ok_or(invalid_line);
+            let day = u8::from_str(first)
+                .ok()
+                .and_then(|day| day.checked_sub(1))
+                .ok_or(invalid_line)?;
+            let subject = subject_from_name(next_field()?).ok_or(invalid_line)?;
+            let from = u8::from_str(next_field()?).map_err(|_| invalid_line)?;
+            let to = u8::from_str(next_field()?).map_err(|_| invalid_line)?;
+            let location = location_from_name(next_field()?).ok_or(invalid_line)?;
+            if fields.next().is_some() {
+                return Err(invalid_line);
+            }
+            builder.add_exam(day, subject, Time(from), Time(to), location)?;
+            has_exams = true;
+        }
+        builder.build()
+    }
+}
+
+impl FromStr for Timetable {
+    type Err = TimetableError;
+
+    /// Читает расписание из текста (см. [`Timetable::parse`]) и проверяет его
+    /// по правилам оригинальной игры.
+    fn from_str(text: &str) -> Result<Timetable, TimetableError> {
+        Timetable::parse(text, &Rules::DEFAULT)
+    }
+}
+
+fn subject_name(subject: Subject) -> &'static str {
+    match subject {
+        Subject::AlgebraAndNumberTheory => "algebra_and_number_theory",
+        Subject::Calculus => "calculus",
+        Subject::GeometryAndTopology => "geometry_and_topology",
+        Subject::ComputerScience => "computer_science",
+        Subject::English => "english",
+        Subject::PhysicalEducation => "physical_education",
+    }
+}
+
+fn subject_from_name(name: &str) -> Option<Subject> {
+    Subject::all_subjects().find(|&subject| subject_name(subject) == name)
+}
+
+fn location_name(location: Location) -> &'static str {
+    match location {
+        Location::PUNK => "punk",
+        Location::PDMI => "pdmi",
+        Location::ComputerClass => "computer_class",
+        Location::Dorm => "dorm",
+        Location::Mausoleum => "mausoleum",
+    }
+}
+
+fn location_from_name(name: &str) -> Option<Location> {
+    [
+        Location::PUNK,
+        Location::PDMI,
+        Location::ComputerClass,
+        Location::Dorm,
+        Location::Mausoleum,
+    ]
+    .into_iter()
+    .find(|&location| location_name(location) == name)
+}
+
+/// Ошибка в расписании, составленном вручную. Дни в сообщениях об ошибках
+/// нумеруются с 1, как в текстовом формате расписания.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TimetableError {
+    /// Строку с этим номером (начиная с 1) не удалось разобрать.
+    InvalidLine(usize),
+
+    /// Дня с таким индексом в сессии нет.
+    InvalidDay(u8),
+
+    /// Второй зачёт по предмету в тот же день.
+    DuplicateExam { day: u8, subject: Subject },
+
+    /// Зачёт начинается раньше [`WORKDAY_BEGINS`], заканчивается позже
+    /// [`WORKDAY_ENDS`] или заканчивается не позже, чем начинается.
+    InvalidExamTime { day: u8, subject: Subject },
+
+    /// Зачёт длится меньше или больше, чем бывают зачёты по этому предмету.
+    InvalidExamDuration { day: u8, subject: Subject },
+
+    /// Зачёт по этому предмету не проходит в этом месте
+    /// (см. [`SubjectRules::exam_places`](crate::logic::rules::SubjectRules::exam_places)).
+    InvalidExamPlace { day: u8, subject: Subject },
+
+    /// В расписании нет ни одного зачёта по предмету.
+    NoExams(Subject),
+}
+
+impl Display for TimetableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            TimetableError::InvalidLine(line) => write!(f, "invalid line {}", line),
+            TimetableError::InvalidDay(day) => {
+                write!(f, "there is no day {} in the semester", day as usize + 1)
+            }
+            TimetableError::DuplicateExam { day, subject } => write!(
+                f,
+                "more than one exam in {} on day {}",
+                subject_name(subject),
+                day + 1
+            ),
+            TimetableError::InvalidExamTime { day, subject } => write!(
+                f,
+                "the exam in {} on day {} must take place between {} and {}",
+                subject_name(subject),
+                day + 1,
+                WORKDAY_BEGINS,
+                WORKDAY_ENDS
+            ),
+            TimetableError::InvalidExamDuration { day, subject } => write!(
+                f,
+                "the exam in {} on day {} must last from {} to {} hours",
+                subject_name(subject),
+                day + 1,
+                subject.exam_min_duration().0,
+                subject.exam_max_duration().0
+            ),
+            TimetableError::InvalidExamPlace { day, subject } => write!(
+                f,
+                "the exam in {} on day {} can't take place there",
+                subject_name(subject),
+                day + 1
+            ),
+            TimetableError::NoExams(subject) => {
+                write!(f, "there are no exams in {}", subject_name(subject))
+            }
+        }
+    }
+}
+
+/// Расписание, составленное вручную, а не с помощью генератора случайных чисел.
+/// Зачёты проверяются по тем же правилам, которые соблюдает случайное
+/// расписание: время и продолжительность зачёта, место, где он проходит,
+/// и то, что по каждому предмету есть хотя бы один зачёт.
+#[derive(Clone, Debug)]
+pub struct TimetableBuilder {
+    timetable: Timetable,
+    rules: &'static Rules,
+}
+
+impl TimetableBuilder {
+    /// Расписание шестидневной сессии без единого зачёта.
+    pub fn new() -> TimetableBuilder {
+        Self::with_length(SemesterLength::NORMAL)
+    }
+
+    /// Как [`TimetableBuilder::new`], но сессия длится `length` дней.
+    pub fn with_length(length: SemesterLength) -> TimetableBuilder {
+        Self::with_rules(length, &Rules::DEFAULT)
+    }
+
+    /// Как [`TimetableBuilder::with_length`], но места зачётов проверяются по
+    /// правилам `rules` вместо правил оригинальной игры. Правила должны быть
+    /// проверены с помощью [`Rules::validate`].
+    pub fn with_rules(length: SemesterLength, rules: &'static Rules) -> TimetableBuilder {
+        TimetableBuilder {
+            timetable: Timetable {
+                days: Timetable::empty_days(),
+                length,
+            },
+            rules,
+        }
+    }
+
+    /// Добавляет зачёт по предмету `subject` в день с индексом `day`
+    /// (начиная с 0) с `from` до `to` часов в месте `location`.
+    pub fn add_exam(
+        &mut self,
+        day: u8,
+        subject: Subject,
+        from: Time,
+        to: Time,
+        location: Location,
+    ) -> Result<&mut TimetableBuilder, TimetableError> {
+        let length = self.timetable.length.days() as usize;
+        let exams = &mut self.timetable.days[..length]
+            .get_mut(day as usize)
+            .ok_or(TimetableError::InvalidDay(day))?
+            .exams;
+        if exams[subject as usize].is_some() {
+            return Err(TimetableError::DuplicateExam { day, subject });
+        }
+        if from < WORKDAY_BEGINS || to > WORKDAY_ENDS || from >= to {
+            return Err(TimetableError::InvalidExamTime { day, subject });
+        }
+        let duration = Duration((to.0 - from.0) as i8);
+        if duration < subject.exam_min_duration()
+            || duration > subject.exam_max_duration()
+        {
+            return Err(TimetableError::InvalidExamDuration { day, subject });
+        }
+        if !self.rules.subject(subject).exam_places.contains(&location) {
+            return Err(TimetableError::InvalidExamPlace { day, subject });
+        }
+        exams[subject as usize] = Exam::new(subject, from, to, location);
+        Ok(self)
+    }
+
+    /// Готовое расписание. Если по какому-то предмету нет ни одного зачёта,
+    /// возвращает ошибку.
+    pub fn build(&self) -> Result<Timetable, TimetableError> {
+        let timetable = &self.timetable;
+        if let Some(subject) = Subject::all_subjects().find(|&subject| {
+            timetable
+                .days()
+                .iter()
+                .all(|day| day.exam(subject).is_none())
+        }) {
+            return Err(TimetableError::NoExams(subject));
+        }
+        Ok(timetable.clone())
+    }
+}
+
+impl Default for TimetableBuilder {
+    fn default() -> Self {
+        TimetableBuilder::new()
     }
 }
 
@@ -331,89 +804,318 @@ pub(in crate::logic) async fn show(g: &mut InternalGameState<'_>, state: &GameSt
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::random::Rng;
+    use crate::random::SplitMix64;
     use Location::*;
     use Subject::*;
 
     #[test]
     fn generate_random_timetable() {
-        let mut rng = Rng::new(42);
+        let mut rng = SplitMix64::new(42);
 
         {
-            let timetable = Timetable::random(&mut rng);
-
-            let expected = Timetable {
-                days: [
-                    Day {
-                        index: 0,
-                        exams: [
-                            Exam::NO_EXAM,
-                            Exam::new(Calculus, Time(13), Time(16), PUNK),
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::new(PhysicalEducation, Time(12), Time(13), PUNK),
-                        ],
-                    },
-                    Day {
-                        index: 1,
-                        exams: [
-                            Exam::new(AlgebraAndNumberTheory, Time(10), Time(12), PUNK),
-                            Exam::new(Calculus, Time(10), Time(13), PUNK),
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::new(English, Time(11), Time(13), PUNK),
-                            Exam::NO_EXAM,
-                        ],
-                    },
-                    Day {
-                        index: 2,
-                        exams: [
-                            Exam::new(AlgebraAndNumberTheory, Time(14), Time(17), PDMI),
-                            Exam::new(Calculus, Time(14), Time(17), PUNK),
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::new(PhysicalEducation, Time(12), Time(13), PUNK),
-                        ],
-                    },
-                    Day {
-                        index: 3,
-                        exams: [
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                        ],
-                    },
-                    Day {
-                        index: 4,
-                        exams: [
-                            Exam::new(AlgebraAndNumberTheory, Time(9), Time(12), PDMI),
-                            Exam::NO_EXAM,
-                            Exam::new(GeometryAndTopology, Time(12), Time(14), PDMI),
-                            Exam::new(ComputerScience, Time(16), Time(18), ComputerClass),
-                            Exam::new(English, Time(12), Time(14), PUNK),
-                            Exam::NO_EXAM,
-                        ],
-                    },
-                    Day {
-                        index: 5,
-                        exams: [
-                            Exam::new(AlgebraAndNumberTheory, Time(12), Time(14), PUNK),
-                            Exam::new(Calculus, Time(12), Time(15), PUNK),
-                            Exam::new(GeometryAndTopology, Time(14), Time(16), PDMI),
-                            Exam::new(ComputerScience, Time(11), Time(12), ComputerClass),
-                            Exam::NO_EXAM,
-                            Exam::NO_EXAM,
-                        ],
-                    },
-                ],
+            let timetable =
+                Timetable::random(&mut rng, &Rules::DEFAULT, SemesterLength::NORMAL);
+
+            let expected = [
+                Day {
+                    index: 0,
+                    exams: [
+                        Exam::NO_EXAM,
+                        Exam::new(Calculus, Time(13), Time(16), PUNK),
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::new(PhysicalEducation, Time(12), Time(13), PUNK),
+                    ],
+                },
+                Day {
+                    index: 1,
+                    exams: [
+                        Exam::new(AlgebraAndNumberTheory, Time(10), Time(12), PUNK),
+                        Exam::new(Calculus, Time(10), Time(13), PUNK),
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::new(English, Time(11), Time(13), PUNK),
+                        Exam::NO_EXAM,
+                    ],
+                },
+                Day {
+                    index: 2,
+                    exams: [
+                        Exam::new(AlgebraAndNumberTheory, Time(14), Time(17), PDMI),
+                        Exam::new(Calculus, Time(14), Time(17), PUNK),
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::new(PhysicalEducation, Time(12), Time(13), PUNK),
+                    ],
+                },
+                Day {
+                    index: 3,
+                    exams: [
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                    ],
+                },
+                Day {
+                    index: 4,
+                    exams: [
+                        Exam::new(AlgebraAndNumberTheory, Time(9), Time(12), PDMI),
+                        Exam::NO_EXAM,
+                        Exam::new(GeometryAndTopology, Time(12), Time(14), PDMI),
+                        Exam::new(ComputerScience, Time(16), Time(18), ComputerClass),
+                        Exam::new(English, Time(12), Time(14), PUNK),
+                        Exam::NO_EXAM,
+                    ],
+                },
+                Day {
+                    index: 5,
+                    exams: [
+                        Exam::new(AlgebraAndNumberTheory, Time(12), Time(14), PUNK),
+                        Exam::new(Calculus, Time(12), Time(15), PUNK),
+                        Exam::new(GeometryAndTopology, Time(14), Time(16), PDMI),
+                        Exam::new(ComputerScience, Time(11), Time(12), ComputerClass),
+                        Exam::NO_EXAM,
+                        Exam::NO_EXAM,
+                    ],
+                },
+            ];
+
+            assert_eq!(timetable.length(), SemesterLength::NORMAL);
+            assert_eq!(timetable.days(), &expected);
+            assert_eq!(timetable.to_string().parse(), Ok(timetable));
+        }
+    }
+
+    #[test]
+    fn random_timetable_length() {
+        let mut rng = SplitMix64::new(42);
+        for (length, calculus_days, english_days) in [
+            (SemesterLength::BLITZ, 2, 1),
+            (SemesterLength::NORMAL, 4, 2),
+            (SemesterLength::MARATHON, 10, 5),
+        ] {
+            let timetable = Timetable::random(&mut rng, &Rules::DEFAULT, length);
+            assert_eq!(timetable.length(), length);
+            assert_eq!(timetable.days().len(), length.days() as usize);
+            let exam_days = |subject| {
+                timetable
+                    .days()
+                    .iter()
+                    .filter(|day| day.exam(subject).is_some())
+                    .count()
             };
+            assert_eq!(exam_days(Calculus), calculus_days);
+            assert_eq!(exam_days(English), english_days);
+            assert_eq!(timetable.to_string().parse(), Ok(timetable));
+        }
+    }
+
+    #[test]
+    fn parse_timetable() {
+        let text = "
+            # Матан в первый день
+            1 calculus 10 13 punk
+            1 english 12 14 punk  # и английский
+            2 algebra_and_number_theory 9 12 pdmi
+            3 geometry_and_topology 11 12 pdmi
+            3 physical_education 17 18 punk
+
+            6 computer_science 16 18 computer_class
+        ";
+        let mut builder = TimetableBuilder::new();
+        builder
+            .add_exam(0, Calculus, Time(10), Time(13), PUNK)
+            .unwrap()
+            .add_exam(0, English, Time(12), Time(14), PUNK)
+            .unwrap()
+            .add_exam(1, AlgebraAndNumberTheory, Time(9), Time(12), PDMI)
+            .unwrap()
+            .add_exam(2, GeometryAndTopology, Time(11), Time(12), PDMI)
+            .unwrap()
+            .add_exam(2, PhysicalEducation, Time(17), Time(18), PUNK)
+            .unwrap()
+            .add_exam(5, ComputerScience, Time(16), Time(18), ComputerClass)
+            .unwrap();
+        let timetable = builder.build().unwrap();
+        assert_eq!(text.parse(), Ok(timetable.clone()));
+        assert_eq!(
+            timetable.to_string(),
+            "1 calculus 10 13 punk\n\
+             1 english 12 14 punk\n\
+             2 algebra_and_number_theory 9 12 pdmi\n\
+             3 geometry_and_topology 11 12 pdmi\n\
+             3 physical_education 17 18 punk\n\
+             6 computer_science 16 18 computer_class\n"
+        );
+
+        let text = "days 14\n\
+                    1 algebra_and_number_theory 9 11 punk\n\
+                    1 calculus 9 11 punk\n\
+                    1 geometry_and_topology 9 10 punk\n\
+                    1 computer_science 9 10 computer_class\n\
+                    1 physical_education 9 10 punk\n\
+                    14 english 12 14 punk\n";
+        let mut builder = TimetableBuilder::with_length(SemesterLength::MARATHON);
+        builder
+            .add_exam(0, AlgebraAndNumberTheory, Time(9), Time(11), PUNK)
+            .unwrap()
+            .add_exam(0, Calculus, Time(9), Time(11), PUNK)
+            .unwrap()
+            .add_exam(0, GeometryAndTopology, Time(9), Time(10), PUNK)
+            .unwrap()
+            .add_exam(0, ComputerScience, Time(9), Time(10), ComputerClass)
+            .unwrap()
+            .add_exam(0, PhysicalEducation, Time(9), Time(10), PUNK)
+            .unwrap()
+            .add_exam(13, English, Time(12), Time(14), PUNK)
+            .unwrap();
+        let timetable = builder.build().unwrap();
+        assert_eq!(text.parse(), Ok(timetable.clone()));
+        assert_eq!(timetable.to_string(), text);
+    }
+
+    #[test]
+    fn timetable_with_rules() {
+        let mut rules = Rules::DEFAULT;
+        rules.subjects.english.exam_places = [PDMI; 3];
+        let rules: &'static Rules = Box::leak(Box::new(rules));
+        let text = "
+            1 algebra_and_number_theory 9 11 punk
+            1 calculus 9 11 punk
+            1 geometry_and_topology 9 10 punk
+            1 computer_science 9 10 computer_class
+            1 physical_education 9 10 punk
+            2 english 12 14 pdmi
+        ";
+        let timetable = Timetable::parse(text, rules).unwrap();
+        assert_eq!(timetable.day(1).exam(English).unwrap().location(), PDMI);
+        assert_eq!(
+            text.parse::<Timetable>(),
+            Err(TimetableError::InvalidExamPlace {
+                day: 1,
+                subject: English
+            })
+        );
+
+        let mut builder = TimetableBuilder::with_rules(SemesterLength::NORMAL, rules);
+        assert_eq!(
+            builder
+                .add_exam(0, English, Time(12), Time(14), PUNK)
+                .unwrap_err(),
+            TimetableError::InvalidExamPlace {
+                day: 0,
+                subject: English
+            }
+        );
+    }
 
-            assert_eq!(timetable, expected);
+    #[test]
+    fn invalid_timetable() {
+        let parse = |text: &str| text.parse::<Timetable>().unwrap_err();
+        assert_eq!(parse("\n1 calculus 10 13"), TimetableError::InvalidLine(2));
+        assert_eq!(
+            parse("1 calculus 10 13 punk extra"),
+            TimetableError::InvalidLine(1)
+        );
+        assert_eq!(parse("1 matan 10 13 punk"), TimetableError::InvalidLine(1));
+        assert_eq!(
+            parse("0 calculus 10 13 punk"),
+            TimetableError::InvalidLine(1)
+        );
+        assert_eq!(
+            parse("1 calculus 10 13 home"),
+            TimetableError::InvalidLine(1)
+        );
+        assert_eq!(
+            parse("7 calculus 10 13 punk"),
+            TimetableError::InvalidDay(6)
+        );
+        assert_eq!(
+            parse("days 3\n4 calculus 10 13 punk"),
+            TimetableError::InvalidDay(3)
+        );
+        assert_eq!(parse("days 15"), TimetableError::InvalidLine(1));
+        assert_eq!(
+            parse("1 calculus 10 13 punk\ndays 3"),
+            TimetableError::InvalidLine(2)
+        );
+        assert_eq!(
+            parse("1 calculus 10 13 punk\n1 calculus 14 16 pdmi"),
+            TimetableError::DuplicateExam {
+                day: 0,
+                subject: Calculus
+            }
+        );
+        for text in [
+            "2 english 8 10 punk",
+            "2 english 17 19 punk",
+            "2 english 12 12 punk",
+        ] {
+            assert_eq!(
+                parse(text),
+                TimetableError::InvalidExamTime {
+                    day: 1,
+                    subject: English
+                }
+            );
         }
+        assert_eq!(
+            parse("3 english 12 14 mausoleum"),
+            TimetableError::InvalidExamPlace {
+                day: 2,
+                subject: English
+            }
+        );
+        // Английский бывает только в ПУНКе
+        assert_eq!(
+            parse("3 english 12 14 pdmi"),
+            TimetableError::InvalidExamPlace {
+                day: 2,
+                subject: English
+            }
+        );
+        for (text, subject) in [
+            ("1 calculus 10 14 punk", Calculus),
+            ("1 english 12 13 punk", English),
+        ] {
+            assert_eq!(
+                parse(text),
+                TimetableError::InvalidExamDuration { day: 0, subject }
+            );
+        }
+        assert_eq!(parse(""), TimetableError::NoExams(AlgebraAndNumberTheory));
+        assert_eq!(
+            parse("1 algebra_and_number_theory 9 11 punk\n1 calculus 10 13 punk"),
+            TimetableError::NoExams(GeometryAndTopology)
+        );
+        assert_eq!(
+            TimetableBuilder::new().build(),
+            Err(TimetableError::NoExams(AlgebraAndNumberTheory))
+        );
+        assert_eq!(
+            TimetableError::InvalidExamTime {
+                day: 1,
+                subject: English
+            }
+            .to_string(),
+            "the exam in english on day 2 must take place between 9 and 18"
+        );
+        assert_eq!(
+            TimetableError::InvalidExamDuration {
+                day: 0,
+                subject: Calculus
+            }
+            .to_string(),
+            "the exam in calculus on day 1 must last from 2 to 3 hours"
+        );
+        assert_eq!(
+            TimetableError::NoExams(English).to_string(),
+            "there are no exams in english"
+        );
     }
 }
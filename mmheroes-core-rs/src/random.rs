@@ -1,34 +1,19 @@
 use core::ops::{Bound, RangeBounds};
 use strum::VariantArray;
 
-pub(crate) struct Rng {
-    state: u64,
-}
-
-impl Rng {
-    pub(crate) fn new(seed: u64) -> Rng {
-        Rng { state: seed }
-    }
-
-    fn next(&mut self) -> u64 {
-        // http://xoshiro.di.unimi.it/splitmix64.c
-        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
-        let mut z = self.state;
-        z = (z ^ z.wrapping_shr(30)).wrapping_mul(0xbf58476d1ce4e5b9);
-        z = (z ^ z.wrapping_shr(27)).wrapping_mul(0x94d049bb133111eb);
-        z ^ z.wrapping_shr(31)
-    }
+/// Генератор случайных чисел.
+///
+/// Все случайные числа в игре получаются из [`Rng::next`], остальные методы
+/// выражаются через него.
+pub trait Rng {
+    /// Следующее случайное 64-битное число.
+    fn next(&mut self) -> u64;
 
-    pub(crate) fn random<B: TryFrom<u64> + TryInto<u64> + Copy>(
-        &mut self,
-        upper_bound: B,
-    ) -> B {
+    /// Случайное число от 0 до `upper_bound - 1` (0, если `upper_bound` — 0).
+    fn random_below(&mut self, upper_bound: u64) -> u64 {
         // https://github.com/apple/swift/blob/727e2220412ffa576738007404f46925d1c3f635/stdlib/public/core/Random.swift#L98-L106
-        let upper_bound = upper_bound
-            .try_into()
-            .unwrap_or_else(|_| panic!("upper bound should be convertible to u64"));
         if upper_bound == 0 {
-            return 0.try_into().unwrap_or_else(|_| panic!());
+            return 0;
         }
         let tmp = (u64::MAX % upper_bound) + 1;
         let range = if tmp == upper_bound { 0u64 } else { tmp };
@@ -36,17 +21,21 @@ impl Rng {
         loop {
             random = self.next();
             if random >= range {
-                break (random % upper_bound)
-                    .try_into()
-                    .unwrap_or_else(|_| panic!());
+                break random % upper_bound;
             }
         }
     }
 
-    pub(crate) fn random_in_range<
-        B: TryInto<u64> + TryFrom<u64> + Copy,
-        R: RangeBounds<B>,
-    >(
+    fn random<B: TryFrom<u64> + TryInto<u64> + Copy>(&mut self, upper_bound: B) -> B {
+        let upper_bound = upper_bound
+            .try_into()
+            .unwrap_or_else(|_| panic!("upper bound should be convertible to u64"));
+        self.random_below(upper_bound)
+            .try_into()
+            .unwrap_or_else(|_| panic!())
+    }
+
+    fn random_in_range<B: TryInto<u64> + TryFrom<u64> + Copy, R: RangeBounds<B>>(
         &mut self,
         range: R,
     ) -> B {
@@ -87,29 +76,350 @@ impl Rng {
             .unwrap_or_else(|_| panic!())
     }
 
-    pub(crate) fn random_element<'a, T>(&mut self, slice: &'a [T]) -> &'a T {
+    fn random_element<'a, T>(&mut self, slice: &'a [T]) -> &'a T {
         &slice[self.random(slice.len() as u64) as usize]
     }
 
-    pub(crate) fn random_variant<T: VariantArray + Clone + Copy>(&mut self) -> T {
+    fn random_variant<T: VariantArray + Clone + Copy>(&mut self) -> T {
         *self.random_element(T::VARIANTS)
     }
 
-    pub(crate) fn roll_dice<B: TryFrom<u64> + TryInto<u64> + Copy>(
-        &mut self,
-        sides: B,
-    ) -> bool {
+    fn roll_dice<B: TryFrom<u64> + TryInto<u64> + Copy>(&mut self, sides: B) -> bool {
         self.random(sides).try_into().unwrap_or_else(|_| panic!()) == 0u64
     }
 }
 
+/// Генератор [splitmix64](http://xoshiro.di.unimi.it/splitmix64.c).
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    /// Текущее состояние генератора. `SplitMix64::new(rng.state())` продолжит
+    /// ту же последовательность, что и `rng`.
+    pub(crate) fn state(&self) -> u64 {
+        self.state
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ z.wrapping_shr(30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ z.wrapping_shr(27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ z.wrapping_shr(31)
+    }
+}
+
+/// Генератор `Random` из Borland Pascal 7.0, на котором была написана
+/// оригинальная игра: линейный конгруэнтный генератор
+/// `RandSeed := RandSeed * 134775813 + 1`.
+pub(crate) struct BorlandPascal {
+    rand_seed: u32,
+}
+
+impl BorlandPascal {
+    pub(crate) fn new(rand_seed: u32) -> BorlandPascal {
+        BorlandPascal { rand_seed }
+    }
+
+    /// Текущее значение `RandSeed`.
+    pub(crate) fn state(&self) -> u32 {
+        self.rand_seed
+    }
+}
+
+impl Rng for BorlandPascal {
+    /// Новое значение `RandSeed`.
+    fn next(&mut self) -> u64 {
+        self.rand_seed = self.rand_seed.wrapping_mul(134775813).wrapping_add(1);
+        self.rand_seed as u64
+    }
+
+    /// `Random(upper_bound)`: старшее слово `RandSeed`, умноженное на
+    /// `upper_bound`, без младшего слова. В Borland Pascal у `Random` аргумент
+    /// типа `Word`, поэтому для больших `upper_bound` берётся весь `RandSeed`,
+    /// как в Delphi.
+    fn random_below(&mut self, upper_bound: u64) -> u64 {
+        let rand_seed = self.next();
+        if upper_bound <= u16::MAX as u64 {
+            ((rand_seed >> 16) * upper_bound) >> 16
+        } else {
+            ((rand_seed as u128 * upper_bound as u128) >> 32) as u64
+        }
+    }
+}
+
+/// Генератор одного из потоков случайных чисел, в зависимости от [`RngMode`].
+pub(crate) enum Generator {
+    SplitMix64(SplitMix64),
+    BorlandPascal(BorlandPascal),
+}
+
+impl Generator {
+    fn new(mode: RngMode, state: u64) -> Generator {
+        match mode {
+            RngMode::SeparateStreams | RngMode::SingleStream => {
+                Generator::SplitMix64(SplitMix64::new(state))
+            }
+            RngMode::BorlandPascal => {
+                Generator::BorlandPascal(BorlandPascal::new(state as u32))
+            }
+        }
+    }
+
+    fn state(&self) -> u64 {
+        match self {
+            Generator::SplitMix64(rng) => rng.state(),
+            Generator::BorlandPascal(rng) => rng.state() as u64,
+        }
+    }
+}
+
+impl Rng for Generator {
+    fn next(&mut self) -> u64 {
+        match self {
+            Generator::SplitMix64(rng) => rng.next(),
+            Generator::BorlandPascal(rng) => rng.next(),
+        }
+    }
+
+    fn random_below(&mut self, upper_bound: u64) -> u64 {
+        match self {
+            Generator::SplitMix64(rng) => rng.random_below(upper_bound),
+            Generator::BorlandPascal(rng) => rng.random_below(upper_bound),
+        }
+    }
+}
+
+/// Как игра получает случайные числа.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum RngMode {
+    /// У каждой подсистемы игры (расписание, однокурсники, зачёты, поездки,
+    /// сон и т. д.) свой генератор, зерно которого получается из зерна игры.
+    /// Лишнее случайное число в одной подсистеме не меняет того, что
+    /// происходит в остальных.
+    SeparateStreams,
+
+    /// Один генератор на всю игру. С ним игра с тем же зерном идёт так же,
+    /// как в старых версиях, а старые повторы и сохранения воспроизводятся.
+    #[default]
+    SingleStream,
+
+    /// Один генератор на всю игру, который работает как `Random` в Borland
+    /// Pascal 7.0, на котором была написана оригинальная игра. Младшие 32 бита
+    /// зерна игры — начальное значение `RandSeed`. Нужен, чтобы сверять
+    /// поведение игры с оригиналом.
+    BorlandPascal,
+}
+
+/// Подсистема игры со своим потоком случайных чисел в режиме
+/// [`RngMode::SeparateStreams`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, VariantArray)]
+enum Stream {
+    InitialParameters,
+    Timetable,
+    Classmates,
+    Conversations,
+    Exams,
+    Travel,
+    Activities,
+    Sleep,
+}
+
+/// Количество потоков случайных чисел в логике игры.
+pub(crate) const STREAM_COUNT: usize = Stream::VARIANTS.len();
+
+/// Номер потока, из которого берутся случайные числа для оформления интерфейса
+/// (цвета, «заикание» терминала в ТЕРКОМе и т. п.). Интерфейс не входит
+/// в состояние игры, поэтому этот поток хранится отдельно.
+const UI_FLAVOR_STREAM: usize = STREAM_COUNT;
+
+/// Зерно потока с номером `index`: зёрна потоков — это последовательные числа
+/// генератора, инициализированного зерном игры.
+fn stream_seed(seed: u64, index: usize) -> u64 {
+    let mut rng = SplitMix64::new(seed);
+    for _ in 0..index {
+        rng.next();
+    }
+    rng.next()
+}
+
+/// Состояние всех потоков случайных чисел. Его достаточно, чтобы продолжить
+/// игру с того же места.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct RngState {
+    pub(crate) mode: RngMode,
+
+    /// В режимах [`RngMode::SingleStream`] и [`RngMode::BorlandPascal`]
+    /// используется только первый элемент.
+    pub(crate) streams: [u64; STREAM_COUNT],
+}
+
+/// Потоки случайных чисел логики игры.
+pub(crate) struct RngStreams {
+    mode: RngMode,
+    streams: [Generator; STREAM_COUNT],
+}
+
+impl RngStreams {
+    pub(crate) fn new(seed: u64, mode: RngMode) -> RngStreams {
+        let streams = core::array::from_fn(|i| match mode {
+            RngMode::SeparateStreams => Generator::new(mode, stream_seed(seed, i)),
+            RngMode::SingleStream | RngMode::BorlandPascal => {
+                Generator::new(mode, if i == 0 { seed } else { 0 })
+            }
+        });
+        RngStreams { mode, streams }
+    }
+
+    pub(crate) fn restore(state: RngState) -> RngStreams {
+        RngStreams {
+            mode: state.mode,
+            streams: state
+                .streams
+                .map(|stream| Generator::new(state.mode, stream)),
+        }
+    }
+
+    pub(crate) fn state(&self) -> RngState {
+        RngState {
+            mode: self.mode,
+            streams: core::array::from_fn(|i| self.streams[i].state()),
+        }
+    }
+
+    fn stream(&mut self, stream: Stream) -> &mut Generator {
+        match self.mode {
+            RngMode::SeparateStreams => &mut self.streams[stream as usize],
+            RngMode::SingleStream | RngMode::BorlandPascal => &mut self.streams[0],
+        }
+    }
+
+    /// Начальные характеристики игрока.
+    pub(crate) fn initial_parameters(&mut self) -> &mut Generator {
+        self.stream(Stream::InitialParameters)
+    }
+
+    /// Составление расписания зачётов.
+    pub(crate) fn timetable(&mut self) -> &mut Generator {
+        self.stream(Stream::Timetable)
+    }
+
+    /// Перемещения однокурсников.
+    pub(crate) fn classmates(&mut self) -> &mut Generator {
+        self.stream(Stream::Classmates)
+    }
+
+    /// Разговоры с однокурсниками.
+    pub(crate) fn conversations(&mut self) -> &mut Generator {
+        self.stream(Stream::Conversations)
+    }
+
+    /// Сдача зачётов.
+    pub(crate) fn exams(&mut self) -> &mut Generator {
+        self.stream(Stream::Exams)
+    }
+
+    /// Дорога между локациями, в том числе электричка и контролёры.
+    pub(crate) fn travel(&mut self) -> &mut Generator {
+        self.stream(Stream::Travel)
+    }
+
+    /// Занятия в общаге, мавзолее, кафе, компьютерном классе и ТЕРКОМе.
+    pub(crate) fn activities(&mut self) -> &mut Generator {
+        self.stream(Stream::Activities)
+    }
+
+    /// Сон и сновидения.
+    pub(crate) fn sleep(&mut self) -> &mut Generator {
+        self.stream(Stream::Sleep)
+    }
+}
+
+/// Генератор случайных чисел для оформления интерфейса. В режиме
+/// [`RngMode::BorlandPascal`] это тоже `Random` из Borland Pascal.
+pub(crate) fn ui_flavor_rng(seed: u64, mode: RngMode) -> Generator {
+    match mode {
+        RngMode::SeparateStreams => {
+            Generator::new(mode, stream_seed(seed, UI_FLAVOR_STREAM))
+        }
+        RngMode::SingleStream | RngMode::BorlandPascal => Generator::new(mode, seed),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_streams() {
+        let mut single = RngStreams::new(42, RngMode::SingleStream);
+        let mut rng = SplitMix64::new(42);
+        assert_eq!(single.exams().next(), rng.next());
+        assert_eq!(single.travel().next(), rng.next());
+        assert_eq!(single.timetable().next(), rng.next());
+
+        // Числа из одного потока не влияют на другие
+        let mut separate = RngStreams::new(42, RngMode::SeparateStreams);
+        let mut other = RngStreams::new(42, RngMode::SeparateStreams);
+        separate.exams().next();
+        assert_eq!(separate.travel().next(), other.travel().next());
+        assert_ne!(separate.exams().next(), other.exams().next());
+
+        let mut restored = RngStreams::restore(separate.state());
+        assert_eq!(restored.state(), separate.state());
+        assert_eq!(restored.sleep().next(), separate.sleep().next());
+    }
+
+    #[test]
+    fn test_borland_pascal() {
+        let mut rng = BorlandPascal::new(0);
+        assert_eq!(rng.next(), 1);
+        assert_eq!(rng.next(), 134775814);
+        assert_eq!(rng.next(), 3698175007);
+        assert_eq!(rng.next(), 870078620);
+        assert_eq!(rng.state(), 870078620);
+
+        let mut rng = BorlandPascal::new(42);
+        let values: [u8; 10] = core::array::from_fn(|_| rng.random(10));
+        assert_eq!(values, [3, 8, 2, 1, 0, 8, 7, 0, 6, 9]);
+
+        let mut rng = BorlandPascal::new(12345);
+        let values: [u8; 6] = core::array::from_fn(|_| rng.random_in_range(15..20));
+        assert_eq!(values, [16, 16, 15, 17, 18, 16]);
+
+        // `Random(0)` тоже меняет `RandSeed`
+        let mut rng = BorlandPascal::new(0);
+        assert_eq!(rng.random(0), 0);
+        assert_eq!(rng.state(), 1);
+
+        let mut rng = BorlandPascal::new(7);
+        assert_eq!(rng.random(100000u32), 21965);
+
+        let mut streams = RngStreams::new(u64::MAX, RngMode::BorlandPascal);
+        let mut rng = BorlandPascal::new(u32::MAX);
+        assert_eq!(streams.exams().next(), rng.next());
+        assert_eq!(streams.sleep().next(), rng.next());
+        let state = streams.state();
+        assert_eq!(state.streams[0], rng.state() as u64);
+        assert_eq!(RngStreams::restore(state).travel().next(), rng.next());
+
+        // Оформление интерфейса тоже использует `Random`
+        let mut ui_flavor = ui_flavor_rng(42, RngMode::BorlandPascal);
+        let mut rng = BorlandPascal::new(42);
+        assert_eq!(ui_flavor.next(), rng.next());
+        assert_eq!(ui_flavor.random(10u8), rng.random(10u8));
+    }
+
     #[test]
     fn test_next() {
-        let mut rng1 = Rng::new(0);
+        let mut rng1 = SplitMix64::new(0);
         assert_eq!(rng1.next(), 16294208416658607535);
         assert_eq!(rng1.next(), 7960286522194355700);
         assert_eq!(rng1.next(), 487617019471545679);
@@ -117,7 +427,7 @@ mod tests {
         assert_eq!(rng1.next(), 1961750202426094747);
         assert_eq!(rng1.next(), 6038094601263162090);
 
-        let mut rng2 = Rng::new(42);
+        let mut rng2 = SplitMix64::new(42);
         assert_eq!(rng2.next(), 13679457532755275413);
         assert_eq!(rng2.next(), 2949826092126892291);
         assert_eq!(rng2.next(), 5139283748462763858);
@@ -125,7 +435,7 @@ mod tests {
         assert_eq!(rng2.next(), 701532786141963250);
         assert_eq!(rng2.next(), 16015981125662989062);
 
-        let mut rng3 = Rng::new(u64::MAX);
+        let mut rng3 = SplitMix64::new(u64::MAX);
         assert_eq!(rng3.next(), 16490336266968443936);
         assert_eq!(rng3.next(), 16834447057089888969);
         assert_eq!(rng3.next(), 4048727598324417001);
@@ -136,7 +446,7 @@ mod tests {
 
     #[test]
     fn test_random_number_with_upper_bound() {
-        let mut rng = Rng::new(0);
+        let mut rng = SplitMix64::new(0);
 
         assert_eq!(rng.random(3), 1);
         assert_eq!(rng.random(3), 0);
@@ -197,13 +507,13 @@ mod tests {
 
     #[test]
     fn test_random_number_with_zero_upper_bound() {
-        let mut rng = Rng::new(123);
+        let mut rng = SplitMix64::new(123);
         assert_eq!(rng.random(0), 0);
     }
 
     #[test]
     fn test_random_number_in_range() {
-        let mut rng = Rng::new(11419);
+        let mut rng = SplitMix64::new(11419);
 
         assert_eq!(rng.random_in_range(15..20), 16);
         assert_eq!(rng.random_in_range(15..20), 15);
@@ -31,6 +31,92 @@ pub(in crate::ui) fn dialog(
     }
 }
 
+/// Номер варианта диалога, который выбирается клавишей быстрого выбора `key`:
+/// `1`–`9` — первые девять вариантов, дальше латинские буквы в любом регистре.
+pub(in crate::ui) fn hotkey_option(key: char) -> Option<u8> {
+    match key {
+        '1'..='9' => Some(key as u8 - b'1'),
+        'a'..='z' => Some(key as u8 - b'a' + 9),
+        'A'..='Z' => Some(key as u8 - b'A' + 9),
+        _ => None,
+    }
+}
+
+/// Номер варианта, который выбирается клавишей Escape — «уйти», «отказаться»,
+/// «ничего не надо». В диалогах, где такого варианта нет, Escape ничего не делает.
+pub(in crate::ui) fn cancel_option(actions: &[Action]) -> Option<u8> {
+    let position = actions.iter().position(|action| {
+        matches!(
+            action,
+            Action::DontGoToProfessor
+                | Action::DontStudy
+                | Action::DontNeedAnythingFromSasha
+                | Action::NoRestIsNoGood
+                | Action::ExitTerkom
+                | Action::ExitExam
+                | Action::ShouldntHaveComeToCafePUNK
+                | Action::LeaveCafePDMI
+                | Action::GameEnd(actions::GameEndAction::NoIAmNotDone)
+                | Action::Help(actions::HelpAction::ThanksButNothing)
+        )
+    })?;
+    Some(position as u8)
+}
+
+/// Номер варианта диалога, текст которого выведен в клетке экрана `position`.
+/// Диалог начинается в клетке `start`, каждый вариант занимает отдельную строку.
+pub(in crate::ui) fn option_at(
+    locale: Locale,
+    rules: &'static Rules,
+    start: (Line, Column),
+    actions: &[Action],
+    position: (Line, Column),
+) -> Option<u8> {
+    struct CharCounter(usize);
+
+    impl core::fmt::Write for CharCounter {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+
+    let index = position.0.checked_sub(start.0)?;
+    let column = position.1.checked_sub(start.1)?;
+    let action = *actions.get(index as usize)?;
+    let mut width = CharCounter(0);
+    write_action_label(&mut width, locale, rules, action).ok()?;
+    ((column as usize) < width.0).then_some(index)
+}
+
+/// Записывает в `w` текст варианта `action` так, как он выводится в диалоге,
+/// но без цветов.
+pub(in crate::ui) fn write_action_label(
+    w: &mut impl core::fmt::Write,
+    locale: Locale,
+    rules: &'static Rules,
+    action: Action,
+) -> core::fmt::Result {
+    struct LabelWriter<'w, W> {
+        w: &'w mut W,
+        result: core::fmt::Result,
+    }
+
+    impl<W: core::fmt::Write> RendererRequestConsumer for LabelWriter<'_, W> {
+        fn consume_request(&mut self, request: RendererRequest) {
+            if let RendererRequest::WriteStr(s) = request {
+                if self.result.is_ok() {
+                    self.result = self.w.write_str(s);
+                }
+            }
+        }
+    }
+
+    let mut r = Renderer::new(LabelWriter { w, result: Ok(()) }, locale, rules);
+    display_action(&mut r, action, false);
+    r.request_consumer().result
+}
+
 fn display_action<RequestConsumer: RendererRequestConsumer>(
     r: &mut Renderer<RequestConsumer>,
     action: Action,
@@ -44,215 +130,374 @@ fn display_action<RequestConsumer: RendererRequestConsumer>(
         }
     };
     let option_name = match action {
-        Action::YesOrNo(actions::YesOrNoAction::Yes) => "Да",
-        Action::YesOrNo(actions::YesOrNoAction::No) => "Нет",
+        Action::YesOrNo(actions::YesOrNoAction::Yes) => l10n!(r, "Да", "Yes"),
+        Action::YesOrNo(actions::YesOrNoAction::No) => l10n!(r, "Нет", "No"),
         Action::InteractWithClassmate(classmate) => {
             set_color(r, Color::YellowBright);
-            write!(r, "{}", classmate_name(classmate));
+            write!(r, "{}", classmate_name(r.locale(), classmate));
             return;
         }
         Action::Exam(subject) => {
             if subject == Subject::ComputerScience {
                 set_color(r, Color::YellowBright);
-                write!(r, "{}", professor_name(subject));
+                write!(r, "{}", professor_name(r.locale(), subject));
                 return;
             } else {
-                professor_name(subject)
+                professor_name(r.locale(), subject)
             }
         }
-        Action::DontGoToProfessor => "Ни к кому",
-        Action::SelectPlayStyle(actions::PlayStyle::RandomStudent) => "Случайный студент",
-        Action::SelectPlayStyle(actions::PlayStyle::CleverStudent) => "Шибко умный",
-        Action::SelectPlayStyle(actions::PlayStyle::ImpudentStudent) => "Шибко наглый",
+        Action::DontGoToProfessor => l10n!(r, "Ни к кому", "Nobody"),
+        Action::SelectPlayStyle(actions::PlayStyle::RandomStudent) => {
+            l10n!(r, "Случайный студент", "Random student")
+        }
+        Action::SelectPlayStyle(actions::PlayStyle::CleverStudent) => {
+            l10n!(r, "Шибко умный", "Way too clever")
+        }
+        Action::SelectPlayStyle(actions::PlayStyle::ImpudentStudent) => {
+            l10n!(r, "Шибко наглый", "Way too cheeky")
+        }
         Action::SelectPlayStyle(actions::PlayStyle::SociableStudent) => {
-            "Шибко общительный"
+            l10n!(r, "Шибко общительный", "Way too sociable")
+        }
+        Action::SelectPlayStyle(actions::PlayStyle::GodMode) => {
+            l10n!(r, "GOD-режим", "GOD mode")
         }
-        Action::SelectPlayStyle(actions::PlayStyle::GodMode) => "GOD-режим",
-        Action::Study => "Готовиться",
+        Action::Study => l10n!(r, "Готовиться", "Study"),
         Action::DoStudy {
             subject,
             lecture_notes_available,
         } => {
             set_color(r, Color::CyanBright);
-            write!(r, "{}", subject_name(subject));
+            write!(r, "{}", subject_name(r.locale(), subject));
             if lecture_notes_available {
-                write!(r, " (к)")
+                write_l10n!(r, " (к)", " (n)")
             }
             return;
         }
-        Action::DontStudy => "Ни к чему",
+        Action::DontStudy => l10n!(r, "Ни к чему", "Nothing"),
         Action::UseLectureNotes(actions::UseLectureNotesAction::Yes) => {
-            "Воспользуюсь конспектом"
+            l10n!(r, "Воспользуюсь конспектом", "I'll use the lecture notes")
         }
         Action::UseLectureNotes(actions::UseLectureNotesAction::No) => {
-            "Буду учиться, как умею"
+            l10n!(r, "Буду учиться, как умею", "I'll study the way I can")
+        }
+        Action::RequestLectureNotesFromSasha(subject) => {
+            subject_name(r.locale(), subject)
+        }
+        Action::DontNeedAnythingFromSasha => {
+            l10n!(r, "Ничего не надо", "I don't need anything")
         }
-        Action::RequestLectureNotesFromSasha(subject) => subject_name(subject),
-        Action::DontNeedAnythingFromSasha => "Ничего не надо",
-        Action::ViewTimetable => "Посмотреть расписание",
-        Action::Rest => "Отдыхать",
-        Action::GoToBed => "Лечь спать",
+        Action::ViewTimetable => {
+            l10n!(r, "Посмотреть расписание", "Look at the timetable")
+        }
+        Action::Rest => l10n!(r, "Отдыхать", "Rest"),
+        Action::GoToBed => l10n!(r, "Лечь спать", "Go to bed"),
         Action::InvitationFromNeighbor(actions::InvitationFromNeighborAction::Accept) => {
-            "\"Угу, я сейчас!!!\""
+            l10n!(r, "\"Угу, я сейчас!!!\"", "\"Uh-huh, coming!!!\"")
         }
         Action::InvitationFromNeighbor(actions::InvitationFromNeighborAction::Deny) => {
-            "\"Не, извини, мне готовиться надо...\""
-        }
-        Action::GoFromPunkToDorm => "Пойти в общагу",
-        Action::GoFromDormToPunk => "Пойти на факультет",
-        Action::GoFromMausoleumToDorm => "Идти в общагу",
-        Action::RestByOurselvesInMausoleum => "Расслабляться будем своими силами.",
-        Action::NoRestIsNoGood => "Нет, отдыхать - это я зря сказал.",
-        Action::GoFromMausoleumToPunk => "Идти в ПУНК",
-        Action::GoToComputerClass => "Пойти в компьютерный класс",
-        Action::LeaveComputerClass => "Покинуть класс",
-        Action::GoToPDMI => "Поехать в ПОМИ",
-        Action::GoToPUNKFromPDMI => "Поехать в ПУНК",
-        Action::TrainToPDMIGatecrash => "Ехать зайцем",
+            l10n!(
+                r,
+                "\"Не, извини, мне готовиться надо...\"",
+                "\"Nah, sorry, I have to study...\""
+            )
+        }
+        Action::GoFromPunkToDorm => l10n!(r, "Пойти в общагу", "Go to the dorm"),
+        Action::GoFromDormToPunk => l10n!(r, "Пойти на факультет", "Go to the faculty"),
+        Action::GoFromMausoleumToDorm => l10n!(r, "Идти в общагу", "Go to the dorm"),
+        Action::RestByOurselvesInMausoleum => l10n!(
+            r,
+            "Расслабляться будем своими силами.",
+            "We'll relax on our own."
+        ),
+        Action::NoRestIsNoGood => l10n!(
+            r,
+            "Нет, отдыхать - это я зря сказал.",
+            "No, forget what I said about resting."
+        ),
+        Action::GoFromMausoleumToPunk => l10n!(r, "Идти в ПУНК", "Go to PUNK"),
+        Action::GoToComputerClass => {
+            l10n!(r, "Пойти в компьютерный класс", "Go to the computer class")
+        }
+        Action::LeaveComputerClass => l10n!(r, "Покинуть класс", "Leave the class"),
+        Action::GoToPDMI => l10n!(r, "Поехать в ПОМИ", "Go to PDMI"),
+        Action::GoToPUNKFromPDMI => l10n!(r, "Поехать в ПУНК", "Go to PUNK"),
+        Action::TrainToPDMIGatecrash => l10n!(r, "Ехать зайцем", "Ride without a ticket"),
         Action::TrainToPDMIBuyTicket => {
             set_color(r, Color::CyanBright);
-            return write!(
+            return write_l10n!(
                 r,
                 "Честно заплатить {} руб. за билет в оба конца",
-                Money::roundtrip_train_ticket_cost()
+                "Honestly pay {} rub. for a round-trip ticket",
+                r.rules().costs.roundtrip_train_ticket
             );
         }
-        Action::TrainFromPDMIGatecrash => "Нет, не будем",
-        Action::TrainFromPDMIBuyTicket => "Да, будем",
-        Action::GoToMausoleum => "Пойти в мавзолей",
-        Action::GoToCafePUNK => "Сходить в кафе",
-        Action::SurfInternet => "Провести 1 час в Inet'е",
-        Action::PlayMMHEROES => "Поиграть в MMHEROES",
-        Action::EarnAtTerkom => "Сидеть и зарабатывать деньги",
-        Action::SurfInternetAtTerkom => "Посидеть часок в Inet'e",
-        Action::ExitTerkom => "Выйти отсюда на \"свежий воздух\"",
-        Action::GoToProfessor => "Идти к преподу",
-        Action::SufferMore => "Мучаться дальше",
-        Action::ExitExam => "Бросить это дело",
+        Action::TrainFromPDMIGatecrash => l10n!(r, "Нет, не будем", "No, we won't"),
+        Action::TrainFromPDMIBuyTicket => l10n!(r, "Да, будем", "Yes, we will"),
+        Action::GoToMausoleum => l10n!(r, "Пойти в мавзолей", "Go to the mausoleum"),
+        Action::GoToCafePUNK => l10n!(r, "Сходить в кафе", "Go to the cafe"),
+        Action::SurfInternet => {
+            l10n!(r, "Провести 1 час в Inet'е", "Spend 1 hour on the Inet")
+        }
+        Action::PlayMMHEROES => l10n!(r, "Поиграть в MMHEROES", "Play MMHEROES"),
+        Action::EarnAtTerkom => {
+            l10n!(r, "Сидеть и зарабатывать деньги", "Sit and earn money")
+        }
+        Action::SurfInternetAtTerkom => {
+            l10n!(r, "Посидеть часок в Inet'e", "Spend an hour on the Inet")
+        }
+        Action::ExitTerkom => l10n!(
+            r,
+            "Выйти отсюда на \"свежий воздух\"",
+            "Get out into the \"fresh air\""
+        ),
+        Action::GoToProfessor => l10n!(r, "Идти к преподу", "Go to a professor"),
+        Action::SufferMore => l10n!(r, "Мучаться дальше", "Keep suffering"),
+        Action::ExitExam => l10n!(r, "Бросить это дело", "Give it up"),
         Action::ContinueSufferingWithExamInTrain(
             actions::ContinueSufferingWithExamInTrainAction::WantToSufferMore,
-        ) => "Да, я хочу еще помучаться",
+        ) => l10n!(
+            r,
+            "Да, я хочу еще помучаться",
+            "Yes, I want to suffer some more"
+        ),
         Action::ContinueSufferingWithExamInTrain(
             actions::ContinueSufferingWithExamInTrainAction::NoThanks,
-        ) => "Ну уж нет, спасибо!",
+        ) => l10n!(r, "Ну уж нет, спасибо!", "No way, thanks!"),
         Action::BaltiyskiyRailwayStation(
             actions::BaltiyskiyRailwayStationAction::GoToPUNK,
-        ) => "Домой, в ПУНК!",
+        ) => l10n!(r, "Домой, в ПУНК!", "Home, to PUNK!"),
         Action::BaltiyskiyRailwayStation(
             actions::BaltiyskiyRailwayStationAction::GoToPDMI,
-        ) => "Хочу в ПОМИ!",
-        Action::Rai(actions::RaiAction::YesOfCourse) => "\"Да, конечно\"",
-        Action::Rai(actions::RaiAction::NoSorry) => "\"Нет, извини...\"",
-        Action::Nil(actions::NilAction::YesOfCourse) => "\"Да, конечно\"",
-        Action::Nil(actions::NilAction::MaybeNextTime) => "\"Извини, в другой раз\"",
-        Action::BugSquasher(actions::BugSquasherAction::LetsGo) => "\"Давай!\"",
-        Action::BugSquasher(actions::BugSquasherAction::NoIWontPlay) => {
-            "\"Нет, не буду я в клоподавку ...\""
-        }
-        Action::Tennis(actions::TennisAction::Sure) => "\"Обязательно!\"",
-        Action::Tennis(actions::TennisAction::SorryMaybeLater) => "\"Извини, потом.\"",
+        ) => l10n!(r, "Хочу в ПОМИ!", "I want to go to PDMI!"),
+        Action::Rai(actions::RaiAction::YesOfCourse) => {
+            l10n!(r, "\"Да, конечно\"", "\"Yes, of course\"")
+        }
+        Action::Rai(actions::RaiAction::NoSorry) => {
+            l10n!(r, "\"Нет, извини...\"", "\"No, sorry...\"")
+        }
+        Action::Nil(actions::NilAction::YesOfCourse) => {
+            l10n!(r, "\"Да, конечно\"", "\"Yes, of course\"")
+        }
+        Action::Nil(actions::NilAction::MaybeNextTime) => {
+            l10n!(r, "\"Извини, в другой раз\"", "\"Sorry, maybe next time\"")
+        }
+        Action::BugSquasher(actions::BugSquasherAction::LetsGo) => {
+            l10n!(r, "\"Давай!\"", "\"Let's go!\"")
+        }
+        Action::BugSquasher(actions::BugSquasherAction::NoIWontPlay) => l10n!(
+            r,
+            "\"Нет, не буду я в клоподавку ...\"",
+            "\"No, I won't play Bug Squasher ...\""
+        ),
+        Action::Tennis(actions::TennisAction::Sure) => {
+            l10n!(r, "\"Обязательно!\"", "\"Sure!\"")
+        }
+        Action::Tennis(actions::TennisAction::SorryMaybeLater) => {
+            l10n!(r, "\"Извини, потом.\"", "\"Sorry, later.\"")
+        }
         Action::HelpFromAndrew(
             actions::HelpFromAndrewAction::YesAmIWorseThanEveryoneElse,
-        ) => "Да, чем я хуже других?",
+        ) => l10n!(
+            r,
+            "Да, чем я хуже других?",
+            "Yes, am I any worse than the others?"
+        ),
         Action::HelpFromAndrew(actions::HelpFromAndrewAction::IWillDoItMyself) => {
-            "Нет, я уж как-нибудь сам..."
+            l10n!(
+                r,
+                "Нет, я уж как-нибудь сам...",
+                "No, I'll manage somehow..."
+            )
+        }
+        Action::GoToWork => {
+            l10n!(r, "Пойти в ТЕРКОМ, поработать", "Go to TERKOM to work")
         }
-        Action::GoToWork => "Пойти в ТЕРКОМ, поработать",
-        Action::LookAtBaobab => "Посмотреть на баобаб",
-        Action::LookAtBulletinBoard => "Посмотреть на доску объявлений",
+        Action::LookAtBaobab => l10n!(r, "Посмотреть на баобаб", "Look at the baobab"),
+        Action::LookAtBulletinBoard => l10n!(
+            r,
+            "Посмотреть на доску объявлений",
+            "Look at the bulletin board"
+        ),
         Action::OrderCola => {
             set_color(r, Color::CyanBright);
-            write!(r, "Стакан колы за {} р.", Money::cola_cost());
+            write_l10n!(
+                r,
+                "Стакан колы за {} р.",
+                "A glass of cola for {} r.",
+                r.rules().costs.cola
+            );
             return;
         }
         Action::OrderSoup => {
             set_color(r, Color::CyanBright);
-            write!(r, "Суп, {} р. все удовольствие", Money::soup_cost());
+            write_l10n!(
+                r,
+                "Суп, {} р. все удовольствие",
+                "Soup, the whole pleasure for {} r.",
+                r.rules().costs.soup
+            );
             return;
         }
         Action::OrderBeer => {
             set_color(r, Color::CyanBright);
-            write!(r, "0,5 пива за {} р.", Money::beer_cost());
+            write_l10n!(
+                r,
+                "0,5 пива за {} р.",
+                "Half a liter of beer for {} r.",
+                r.rules().costs.beer
+            );
             return;
         }
         Action::OrderTea => {
             set_color(r, Color::CyanBright);
-            write!(r, "Чай за {} р.", Money::drink_cost());
+            write_l10n!(r, "Чай за {} р.", "Tea for {} r.", r.rules().costs.drink);
             return;
         }
         Action::OrderCake => {
             set_color(r, Color::CyanBright);
-            write!(r, "Кекс за {} р.", Money::pastry_cost());
+            write_l10n!(
+                r,
+                "Кекс за {} р.",
+                "A muffin for {} r.",
+                r.rules().costs.pastry
+            );
             return;
         }
         Action::OrderTeaWithCake => {
             set_color(r, Color::CyanBright);
-            write!(r, "Чай и выпечку, {} р.", Money::drink_with_pastry_cost());
+            write_l10n!(
+                r,
+                "Чай и выпечку, {} р.",
+                "Tea and a pastry, {} r.",
+                r.rules().costs.drink_with_pastry
+            );
             return;
         }
-        Action::RestInCafePUNK => "Просто посижу с приятелями.",
-        Action::ShouldntHaveComeToCafePUNK => "Я вообще зря сюда зашел.",
-        Action::GoToCafePDMI => "Пойти в кафе",
+        Action::RestInCafePUNK => l10n!(
+            r,
+            "Просто посижу с приятелями.",
+            "I'll just hang out with my friends."
+        ),
+        Action::ShouldntHaveComeToCafePUNK => l10n!(
+            r,
+            "Я вообще зря сюда зашел.",
+            "I shouldn't have come here at all."
+        ),
+        Action::GoToCafePDMI => l10n!(r, "Пойти в кафе", "Go to the cafe"),
         Action::OrderCoffee => {
             set_color(r, Color::CyanBright);
-            write!(r, "Кофе за {} р.", Money::drink_cost());
+            write_l10n!(
+                r,
+                "Кофе за {} р.",
+                "Coffee for {} r.",
+                r.rules().costs.drink
+            );
             return;
         }
         Action::OrderPastry => {
             set_color(r, Color::CyanBright);
-            write!(r, "Корж за {} р.", Money::pastry_cost());
+            write_l10n!(
+                r,
+                "Корж за {} р.",
+                "A shortcake for {} r.",
+                r.rules().costs.pastry
+            );
             return;
         }
         Action::OrderCoffeeWithPastry => {
             set_color(r, Color::CyanBright);
-            write!(r, "Кофе и выпечку, {} р.", Money::drink_with_pastry_cost());
+            write_l10n!(
+                r,
+                "Кофе и выпечку, {} р.",
+                "Coffee and a pastry, {} r.",
+                r.rules().costs.drink_with_pastry
+            );
             return;
         }
-        Action::RestInCafePDMI => "Ничего, просто просидеть здесь часок.",
-        Action::LeaveCafePDMI => "Совсем ничего. Бывает.",
+        Action::RestInCafePDMI => l10n!(
+            r,
+            "Ничего, просто просидеть здесь часок.",
+            "Nothing, just sit here for an hour."
+        ),
+        Action::LeaveCafePDMI => {
+            l10n!(r, "Совсем ничего. Бывает.", "Nothing at all. It happens.")
+        }
         Action::MmheroesFloppy(actions::MmheroesFloppyAction::WantToTestNewMMHEROES) => {
-            "ДА, КОНЕЧНО, ОЧЕНЬ ХОЧУ!"
+            l10n!(
+                r,
+                "ДА, КОНЕЧНО, ОЧЕНЬ ХОЧУ!",
+                "YES, OF COURSE, I REALLY WANT TO!"
+            )
         }
         Action::MmheroesFloppy(
             actions::MmheroesFloppyAction::DontWantToTestNewMMHEROES,
-        ) => "Нет, у меня нет на это времени...",
+        ) => l10n!(
+            r,
+            "Нет, у меня нет на это времени...",
+            "No, I don't have time for this..."
+        ),
         Action::TerkomEmployment(actions::TerkomEmploymentAction::Accept) => {
-            "Да, мне бы не помешало."
-        }
-        Action::TerkomEmployment(actions::TerkomEmploymentAction::Decline) => {
-            "Нет, я лучше поучусь уще чуток."
+            l10n!(r, "Да, мне бы не помешало.", "Yes, that wouldn't hurt.")
         }
+        Action::TerkomEmployment(actions::TerkomEmploymentAction::Decline) => l10n!(
+            r,
+            "Нет, я лучше поучусь уще чуток.",
+            "No, I'd rather study a bit more."
+        ),
         Action::NpcApproach(actions::NpcApproachAction::Ignore) => {
-            "Пытаться игнорировать"
+            l10n!(r, "Пытаться игнорировать", "Try to ignore")
         }
         Action::NpcApproach(actions::NpcApproachAction::TalkToClassmate(classmate)) => {
-            classmate_name(classmate)
+            classmate_name(r.locale(), classmate)
         }
         Action::IAmDone => {
             set_color(r, Color::BlueBright);
-            write!(r, "С меня хватит!");
+            write_l10n!(r, "С меня хватит!", "I've had enough!");
             return;
         }
-        Action::GameEnd(actions::GameEndAction::NoIAmNotDone) => "Нет, не хочу!",
+        Action::GameEnd(actions::GameEndAction::NoIAmNotDone) => {
+            l10n!(r, "Нет, не хочу!", "No, I don't want to!")
+        }
         Action::GameEnd(actions::GameEndAction::IAmCertainlyDone) => {
-            "Я же сказал: с меня хватит!"
+            l10n!(
+                r,
+                "Я же сказал: с меня хватит!",
+                "I told you: I've had enough!"
+            )
+        }
+        Action::TryAgain(actions::TryAgainAction::WantToTryAgain) => {
+            l10n!(r, "ДА!!! ДА!!! ДА!!!", "YES!!! YES!!! YES!!!")
         }
-        Action::TryAgain(actions::TryAgainAction::WantToTryAgain) => "ДА!!! ДА!!! ДА!!!",
         Action::TryAgain(actions::TryAgainAction::DontWantToTryAgain) => {
-            "Нет... Нет... Не-э-эт..."
+            l10n!(r, "Нет... Нет... Не-э-эт...", "No... No... No-o-o...")
         }
         Action::WhatToDo => {
             set_color(r, Color::BlueBright);
-            write!(r, "ЧТО ДЕЛАТЬ ???");
+            write_l10n!(r, "ЧТО ДЕЛАТЬ ???", "WHAT TO DO ???");
             return;
         }
-        Action::Help(actions::HelpAction::WhatToDoAtAll) => " А что вообще делать? ",
-        Action::Help(actions::HelpAction::AboutScreen) => " Об экране            ",
-        Action::Help(actions::HelpAction::WhereToGoAndWhy) => " Куда и зачем ходить? ",
-        Action::Help(actions::HelpAction::AboutProfessors) => " О преподавателях     ",
-        Action::Help(actions::HelpAction::AboutCharacters) => " О персонажах         ",
-        Action::Help(actions::HelpAction::AboutThisProgram) => " Об этой программе    ",
-        Action::Help(actions::HelpAction::ThanksButNothing) => " Спасибо, ничего      ",
+        Action::Help(actions::HelpAction::WhatToDoAtAll) => {
+            l10n!(r, " А что вообще делать? ", " What to do at all?  ")
+        }
+        Action::Help(actions::HelpAction::AboutScreen) => {
+            l10n!(r, " Об экране            ", " About the screen     ")
+        }
+        Action::Help(actions::HelpAction::WhereToGoAndWhy) => {
+            l10n!(r, " Куда и зачем ходить? ", " Where to go and why? ")
+        }
+        Action::Help(actions::HelpAction::AboutProfessors) => {
+            l10n!(r, " О преподавателях     ", " About professors     ")
+        }
+        Action::Help(actions::HelpAction::AboutCharacters) => {
+            l10n!(r, " О персонажах         ", " About characters     ")
+        }
+        Action::Help(actions::HelpAction::AboutThisProgram) => {
+            l10n!(r, " Об этой программе    ", " About this program   ")
+        }
+        Action::Help(actions::HelpAction::ThanksButNothing) => {
+            l10n!(r, " Спасибо, ничего      ", " Thanks, nothing      ")
+        }
         Action::AnyKey => panic!("Action {:?} cannot be used in a dialog", action),
     };
     set_color(r, Color::CyanBright);
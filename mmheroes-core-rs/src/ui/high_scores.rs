@@ -1,15 +1,29 @@
 use crate::logic::Money;
-use crate::ui::cp866_encoding;
+use crate::ui::{cp866_encoding, Locale};
 use crate::util::TinyString;
 
-pub type HighScore = (TinyString<128>, Money);
+pub type HighScoreName = TinyString<128>;
+
+pub type HighScore = (HighScoreName, Money);
 
 pub const SCORE_COUNT: usize = 5;
 pub const RECORD_SIZE: usize = 35;
 pub const BUFFER_SIZE: usize = SCORE_COUNT * RECORD_SIZE;
 
+/// Максимальная длина имени в таблице рекордов в символах.
 pub const MAX_NAME_LENGTH: usize = 32;
 
+/// Имя, которое попадает в таблицу рекордов, если игрок не ввёл никакого.
+pub const DEFAULT_NAME: &str = "Аноним";
+
+/// То же, что и [`DEFAULT_NAME`], но на языке `locale`.
+pub fn default_name(locale: Locale) -> &'static str {
+    match locale {
+        Locale::Russian => DEFAULT_NAME,
+        Locale::English => "Anonymous",
+    }
+}
+
 #[macro_export]
 macro_rules! high_scores {
     [
@@ -25,14 +39,51 @@ macro_rules! high_scores {
     };
 }
 
-pub(crate) fn default_high_scores() -> [HighScore; SCORE_COUNT] {
-    high_scores![
-        "Коля" => 400,
-        "Саша" => 280,
-        "Эндрю" => 180,
-        "Паша" => 100,
-        "Гриша" => 20,
-    ]
+pub(crate) fn default_high_scores(locale: Locale) -> [HighScore; SCORE_COUNT] {
+    match locale {
+        Locale::Russian => high_scores![
+            "Коля" => 400,
+            "Саша" => 280,
+            "Эндрю" => 180,
+            "Паша" => 100,
+            "Гриша" => 20,
+        ],
+        Locale::English => high_scores![
+            "Kolya" => 400,
+            "Sasha" => 280,
+            "Andrew" => 180,
+            "Pasha" => 100,
+            "Grisha" => 20,
+        ],
+    }
+}
+
+/// Попадает ли результат `score` в таблицу рекордов `scores`, отсортированную
+/// по убыванию.
+pub fn is_high_score(scores: &[HighScore; SCORE_COUNT], score: Money) -> bool {
+    scores.last().is_some_and(|(_, lowest)| score > *lowest)
+}
+
+/// Вставляет результат в таблицу рекордов `scores`, отсортированную по убыванию,
+/// вытесняя из неё самый низкий результат. При равенстве результатов новый
+/// оказывается ниже старых.
+///
+/// Возвращает позицию, на которую был вставлен результат, или `None`, если
+/// результат в таблицу рекордов не попадает.
+pub fn insert(
+    scores: &mut [HighScore; SCORE_COUNT],
+    name: &str,
+    score: Money,
+) -> Option<usize> {
+    if !is_high_score(scores, score) {
+        return None;
+    }
+    let position = scores
+        .iter()
+        .position(|(_, existing_score)| score > *existing_score)?;
+    scores[position..].rotate_right(1);
+    scores[position] = (TinyString::from(name), score);
+    Some(position)
 }
 
 pub fn decode(mut buffer: &[u8]) -> Option<[HighScore; SCORE_COUNT]> {
@@ -42,7 +93,7 @@ pub fn decode(mut buffer: &[u8]) -> Option<[HighScore; SCORE_COUNT]> {
         return None;
     }
 
-    let mut loaded = default_high_scores();
+    let mut loaded = default_high_scores(Locale::Russian);
     for high_score in loaded.iter_mut() {
         let name_length = min(buffer[0] as usize, MAX_NAME_LENGTH);
         buffer = &buffer[1..];
@@ -73,3 +124,40 @@ pub fn encode(scores: &[HighScore; SCORE_COUNT]) -> [u8; BUFFER_SIZE] {
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut scores = default_high_scores(Locale::Russian);
+
+        assert_eq!(insert(&mut scores, "Вася", Money(20)), None);
+        assert_eq!(insert(&mut scores, "Вася", Money(180)), Some(3));
+        assert_eq!(
+            scores,
+            high_scores![
+                "Коля" => 400,
+                "Саша" => 280,
+                "Эндрю" => 180,
+                "Вася" => 180,
+                "Паша" => 100,
+            ]
+        );
+
+        assert_eq!(insert(&mut scores, "Петя", Money(1000)), Some(0));
+        assert_eq!(
+            scores,
+            high_scores![
+                "Петя" => 1000,
+                "Коля" => 400,
+                "Саша" => 280,
+                "Эндрю" => 180,
+                "Вася" => 180,
+            ]
+        );
+        assert!(!is_high_score(&scores, Money(180)));
+        assert!(is_high_score(&scores, Money(181)));
+    }
+}
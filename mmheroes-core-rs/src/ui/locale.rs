@@ -0,0 +1,282 @@
+//! Язык, на котором выводится игра, и каталог названий, которые используются
+//! на многих экранах: предметы, преподаватели, одногруппники, места и даты.
+//!
+//! Тексты отдельных экранов переведены прямо в коде экранов с помощью макросов
+//! `l10n!`, `write_l10n!` и им подобных: оба варианта текста стоят рядом, и при
+//! изменении одного легко не забыть про другой.
+
+use crate::logic::timetable::Day;
+use crate::logic::{Classmate, Location, Subject};
+use core::fmt::{Display, Formatter};
+
+/// Язык интерфейса. Выбирается при создании [`GameUI`](crate::ui::GameUI).
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[repr(C)]
+pub enum Locale {
+    #[default]
+    Russian = 0,
+    English = 1,
+}
+
+impl TryFrom<u8> for Locale {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Locale::Russian),
+            1 => Ok(Locale::English),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Форма слова, согласованного с числом.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub(in crate::ui) enum PluralForm {
+    /// «1 задание», «21 задание», «1 task».
+    One,
+
+    /// «2 задания», «34 задания». В английском не используется.
+    Few,
+
+    /// «5 заданий», «11 заданий», «0 tasks», «2 tasks».
+    Many,
+}
+
+impl Locale {
+    pub(in crate::ui) fn plural_form(self, n: u32) -> PluralForm {
+        match self {
+            Locale::Russian => match (n % 10, n % 100) {
+                (1, 11) => PluralForm::Many,
+                (1, _) => PluralForm::One,
+                (2..=4, 12..=14) => PluralForm::Many,
+                (2..=4, _) => PluralForm::Few,
+                _ => PluralForm::Many,
+            },
+            Locale::English => {
+                if n == 1 {
+                    PluralForm::One
+                } else {
+                    PluralForm::Many
+                }
+            }
+        }
+    }
+
+    /// Выбирает форму слова, согласованную с `n`. Для русского языка нужны три
+    /// формы (для 1, 2 и 5), для английского — две (для 1 и 2).
+    pub(in crate::ui) fn plural<'a>(
+        self,
+        n: u32,
+        russian: [&'a str; 3],
+        english: [&'a str; 2],
+    ) -> &'a str {
+        match (self, self.plural_form(n)) {
+            (Locale::Russian, PluralForm::One) => russian[0],
+            (Locale::Russian, PluralForm::Few) => russian[1],
+            (Locale::Russian, PluralForm::Many) => russian[2],
+            (Locale::English, PluralForm::One) => english[0],
+            (Locale::English, _) => english[1],
+        }
+    }
+}
+
+pub fn professor_name(locale: Locale, subject: Subject) -> &'static str {
+    match locale {
+        Locale::Russian => match subject {
+            Subject::AlgebraAndNumberTheory => "Всемирнов М.А.",
+            Subject::Calculus => "Дубцов Е.С.",
+            Subject::GeometryAndTopology => "Подкорытов С.С.",
+            Subject::ComputerScience => "Климов А.А.",
+            Subject::English => "Влащенко Н.П.",
+            Subject::PhysicalEducation => "Альбинский Е.Г.",
+        },
+        Locale::English => match subject {
+            Subject::AlgebraAndNumberTheory => "Vsemirnov M.A.",
+            Subject::Calculus => "Dubtsov E.S.",
+            Subject::GeometryAndTopology => "Podkorytov S.S.",
+            Subject::ComputerScience => "Klimov A.A.",
+            Subject::English => "Vlaschenko N.P.",
+            Subject::PhysicalEducation => "Albinsky E.G.",
+        },
+    }
+}
+
+pub fn subject_name(locale: Locale, subject: Subject) -> &'static str {
+    match locale {
+        Locale::Russian => match subject {
+            Subject::AlgebraAndNumberTheory => "Алгебра и Т.Ч.",
+            Subject::Calculus => "Мат. Анализ",
+            Subject::GeometryAndTopology => "Геометрия и Топология",
+            Subject::ComputerScience => "Информатика",
+            Subject::English => "English",
+            Subject::PhysicalEducation => "Физ-ра",
+        },
+        Locale::English => match subject {
+            Subject::AlgebraAndNumberTheory => "Algebra & N.T.",
+            Subject::Calculus => "Calculus",
+            Subject::GeometryAndTopology => "Geometry & Topology",
+            Subject::ComputerScience => "Computer Science",
+            Subject::English => "English",
+            Subject::PhysicalEducation => "P.E.",
+        },
+    }
+}
+
+pub fn subject_short_name(locale: Locale, subject: Subject) -> &'static str {
+    match locale {
+        Locale::Russian => match subject {
+            Subject::AlgebraAndNumberTheory => "АиТЧ",
+            Subject::Calculus => "МатАн",
+            Subject::GeometryAndTopology => "ГиТ",
+            Subject::ComputerScience => "Инф",
+            Subject::English => "ИнЯз",
+            Subject::PhysicalEducation => "Физ-ра",
+        },
+        Locale::English => match subject {
+            Subject::AlgebraAndNumberTheory => "Alg",
+            Subject::Calculus => "Calc",
+            Subject::GeometryAndTopology => "Geom",
+            Subject::ComputerScience => "CS",
+            Subject::English => "Engl",
+            Subject::PhysicalEducation => "P.E.",
+        },
+    }
+}
+
+/// Короткое название места, как в расписании. Не длиннее 6 символов.
+pub fn location_name(locale: Locale, location: Location) -> &'static str {
+    match locale {
+        Locale::Russian => match location {
+            Location::Dorm => "Общага",
+            Location::PUNK => "ПУНК",
+            Location::Mausoleum => "Мавзолей",
+            Location::ComputerClass => "Компы",
+            Location::PDMI => "ПОМИ",
+        },
+        Locale::English => match location {
+            Location::Dorm => "Dorm",
+            Location::PUNK => "PUNK",
+            Location::Mausoleum => "Mausol",
+            Location::ComputerClass => "CompCl",
+            Location::PDMI => "PDMI",
+        },
+    }
+}
+
+/// Число и номер месяца дня сессии с индексом `day_index`. Первый день сессии —
+/// 22 мая, длинная сессия заканчивается в июне.
+pub(in crate::ui) fn calendar_date(day_index: usize) -> (usize, usize) {
+    const FIRST_DAY: usize = 22;
+    const DAYS_IN_MAY: usize = 31;
+    let day = FIRST_DAY + day_index;
+    if day > DAYS_IN_MAY {
+        (day - DAYS_IN_MAY, 6)
+    } else {
+        (day, 5)
+    }
+}
+
+/// Название месяца с номером `month` (в родительном падеже для русского языка).
+pub(in crate::ui) fn month_name(locale: Locale, month: usize) -> &'static str {
+    match (locale, month) {
+        (Locale::Russian, 5) => "мая",
+        (Locale::Russian, _) => "июня",
+        (Locale::English, 5) => "May",
+        (Locale::English, _) => "June",
+    }
+}
+
+/// Короткая дата дня в расписании: «22.5» или «5/22».
+pub struct DayDate {
+    locale: Locale,
+    day_index: usize,
+}
+
+impl Display for DayDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let (day, month) = calendar_date(self.day_index);
+        match self.locale {
+            Locale::Russian => write!(f, "{}.{}", day, month),
+            Locale::English => write!(f, "{}/{}", month, day),
+        }
+    }
+}
+
+pub fn day_date(locale: Locale, day: &Day) -> DayDate {
+    DayDate {
+        locale,
+        day_index: day.index(),
+    }
+}
+
+pub fn classmate_name(locale: Locale, classmate: Classmate) -> &'static str {
+    match locale {
+        Locale::Russian => match classmate {
+            Classmate::Kolya => "Коля",
+            Classmate::Pasha => "Паша",
+            Classmate::Diamond => "Diamond",
+            Classmate::RAI => "RAI",
+            Classmate::Misha => "Миша",
+            Classmate::Serj => "Серж",
+            Classmate::Sasha => "Саша",
+            Classmate::NiL => "NiL",
+            Classmate::Kuzmenko => "Кузьменко В.Г.",
+            Classmate::DJuG => "DJuG",
+            Classmate::Andrew => "Эндрю",
+            Classmate::Grisha => "Гриша",
+        },
+        Locale::English => match classmate {
+            Classmate::Kolya => "Kolya",
+            Classmate::Pasha => "Pasha",
+            Classmate::Diamond => "Diamond",
+            Classmate::RAI => "RAI",
+            Classmate::Misha => "Misha",
+            Classmate::Serj => "Serj",
+            Classmate::Sasha => "Sasha",
+            Classmate::NiL => "NiL",
+            Classmate::Kuzmenko => "Kuzmenko V.G.",
+            Classmate::DJuG => "DJuG",
+            Classmate::Andrew => "Andrew",
+            Classmate::Grisha => "Grisha",
+        },
+    }
+}
+
+pub(in crate::ui) fn problems_inflected(locale: Locale, problems: u8) -> &'static str {
+    locale.plural(
+        problems as u32,
+        ["задание", "задания", "заданий"],
+        ["task", "tasks"],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_russian_plural() {
+        let forms =
+            |n| Locale::Russian.plural(n, ["зачет", "зачета", "зачетов"], ["", ""]);
+        assert_eq!(forms(0), "зачетов");
+        assert_eq!(forms(1), "зачет");
+        assert_eq!(forms(2), "зачета");
+        assert_eq!(forms(4), "зачета");
+        assert_eq!(forms(5), "зачетов");
+        assert_eq!(forms(11), "зачетов");
+        assert_eq!(forms(12), "зачетов");
+        assert_eq!(forms(21), "зачет");
+        assert_eq!(forms(22), "зачета");
+        assert_eq!(forms(111), "зачетов");
+    }
+
+    #[test]
+    fn test_english_plural() {
+        let forms = |n| Locale::English.plural(n, ["", "", ""], ["exam", "exams"]);
+        assert_eq!(forms(0), "exams");
+        assert_eq!(forms(1), "exam");
+        assert_eq!(forms(2), "exams");
+        assert_eq!(forms(21), "exams");
+    }
+}
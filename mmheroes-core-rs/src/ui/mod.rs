@@ -14,6 +14,53 @@ macro_rules! writeln_colored {
     }};
 }
 
+/// Выбирает вариант текста (или любого другого выражения) для языка,
+/// на котором выводится игра.
+macro_rules! l10n {
+    ($renderer:expr, $russian:expr, $english:expr $(,)?) => {
+        match $renderer.locale() {
+            $crate::ui::Locale::Russian => $russian,
+            $crate::ui::Locale::English => $english,
+        }
+    };
+}
+
+/// То же, что и `write!`, но принимает строку формата для каждого языка.
+macro_rules! write_l10n {
+    ($renderer:expr, $russian:literal, $english:literal $(, $arg:expr)* $(,)?) => {
+        l10n!(
+            $renderer,
+            write!($renderer, $russian $(, $arg)*),
+            write!($renderer, $english $(, $arg)*),
+        )
+    };
+}
+
+/// То же, что и `writeln!`, но принимает строку формата для каждого языка.
+macro_rules! writeln_l10n {
+    ($renderer:expr, $russian:literal, $english:literal $(, $arg:expr)* $(,)?) => {
+        l10n!(
+            $renderer,
+            writeln!($renderer, $russian $(, $arg)*),
+            writeln!($renderer, $english $(, $arg)*),
+        )
+    };
+}
+
+macro_rules! write_colored_l10n {
+    ($color:ident, $renderer:expr, $($arg:tt)*) => {{
+        $renderer.set_color(Color::$color, Color::Black);
+        write_l10n!($renderer, $($arg)*)
+    }};
+}
+
+macro_rules! writeln_colored_l10n {
+    ($color:ident, $renderer:expr, $($arg:tt)*) => {{
+        $renderer.set_color(Color::$color, Color::Black);
+        writeln_l10n!($renderer, $($arg)*);
+    }};
+}
+
 mod screens;
 
 pub mod renderer;
@@ -23,11 +70,24 @@ use renderer::*;
 
 pub(crate) mod cp866_encoding;
 pub mod recording;
+// `pin!` в нём раскрывается в `super let`, а cbindgen такого синтаксиса не знает.
+#[cfg(not(cbindgen))]
+pub mod replay_verifier;
 
 pub mod high_scores;
 
+pub mod locale;
+use locale::{calendar_date, month_name, problems_inflected};
+pub use locale::{
+    classmate_name, day_date, location_name, professor_name, subject_name,
+    subject_short_name, Locale,
+};
+
 mod dialog;
 
+pub mod undo;
+use undo::UndoStack;
+
 use dialog::*;
 
 use high_scores::HighScore;
@@ -88,13 +148,60 @@ impl TryFrom<u8> for Color {
     }
 }
 
-#[repr(C)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Input {
     KeyUp,
     KeyDown,
     Enter,
     Other,
+
+    /// Ввод символа, например, имени для таблицы рекордов. Там, где текст не вводится,
+    /// работает так же, как `Other`.
+    Character(char),
+
+    /// Стирание последнего введённого символа. Там, где текст не вводится,
+    /// работает так же, как `Other`.
+    Backspace,
+
+    /// Отмена последнего хода (см. [`GameUI::undo`]). Если отменять нечего,
+    /// работает так же, как `Other`.
+    Undo,
+
+    /// Клавиша быстрого выбора: цифра или латинская буква. В диалоге сразу выбирает
+    /// вариант с этим номером — `1`–`9` для первых девяти вариантов, дальше `a`, `b`,
+    /// `c` и т. д. При вводе текста работает так же, как `Character`.
+    Hotkey(char),
+
+    /// В диалоге выбирает вариант «уйти» или «отказаться», если он есть
+    /// (например, [`Action::DontStudy`] или [`Action::ExitTerkom`]).
+    Escape,
+
+    /// В диалоге переводит курсор на первый вариант.
+    Home,
+
+    /// В диалоге переводит курсор на последний вариант.
+    End,
+
+    /// То же, что и `Home`: все варианты диалога помещаются на одном экране.
+    PageUp,
+
+    /// То же, что и `End`: все варианты диалога помещаются на одном экране.
+    PageDown,
+
+    /// Указатель мыши переместился на клетку экрана `(line, column)`. В диалоге
+    /// переводит курсор на вариант под указателем. В отличие от остальных клавиш,
+    /// на экране «Нажми любую клавишу» ничего не делает.
+    PointerAt {
+        line: Line,
+        column: Column,
+    },
+
+    /// Нажатие мыши на клетку экрана `(line, column)`. В диалоге выбирает вариант
+    /// под указателем, если он есть.
+    Click {
+        line: Line,
+        column: Column,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -104,6 +211,11 @@ enum WaitingState {
         current_choice: u8,
         start: (Line, Column),
     },
+    HighScoreName {
+        score: Money,
+        name: high_scores::HighScoreName,
+        start: (Line, Column),
+    },
 }
 
 #[repr(transparent)]
@@ -126,10 +238,11 @@ pub struct GameUI<
     renderer: Renderer<C>,
     state_holder: &'game StateHolder,
     game: core::pin::Pin<&'game mut G>,
-    rng: crate::random::Rng,
+    rng: crate::random::Generator,
     pub high_scores: [HighScore; high_scores::SCORE_COUNT],
     software_state: SoftwareState,
     input_recorder: Option<InputRecorder<InputSink>>,
+    undo_stack: UndoStack,
 }
 
 impl<
@@ -146,24 +259,184 @@ impl<
         high_scores: Option<[HighScore; high_scores::SCORE_COUNT]>,
         renderer_request_consumer: C,
         input_sink: Option<InputSink>,
+        locale: Locale,
     ) -> Self {
-        let default_high_scores = high_scores::default_high_scores();
+        let default_high_scores = high_scores::default_high_scores(locale);
         GameUI {
             seed,
-            renderer: Renderer::new(renderer_request_consumer),
+            renderer: Renderer::new(
+                renderer_request_consumer,
+                locale,
+                state_holder.rules(),
+            ),
             state_holder,
             game,
-            rng: crate::random::Rng::new(seed),
+            rng: crate::random::ui_flavor_rng(seed, state_holder.rng_mode()),
             high_scores: high_scores.unwrap_or(default_high_scores),
             software_state: SoftwareState::Healthy,
             input_recorder: input_sink.map(InputRecorder::new),
+            undo_stack: UndoStack::new(),
         }
     }
 
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn locale(&self) -> Locale {
+        self.renderer.locale()
+    }
+
     pub fn has_bug(&self) -> bool {
         !matches!(self.software_state, SoftwareState::Healthy)
     }
 
+    /// Хэш состояния игры (см. [`StateHolder::state_hash`]). `None`, если на экране
+    /// не главное меню локации или если в игре произошла ошибка.
+    pub fn state_hash(&self) -> Option<u64> {
+        if self.has_bug() {
+            None
+        } else {
+            self.state_holder.state_hash()
+        }
+    }
+
+    /// Можно ли сейчас отменить ход (см. [`GameUI::undo`]).
+    pub fn can_undo(&self) -> bool {
+        if self.has_bug() {
+            return false;
+        }
+        match self.state_holder.observable_state().screen() {
+            GameScreen::Terminal => false,
+            _ if self.is_at_last_snapshot() => self.undo_stack.len() > 1,
+            _ => self.undo_stack.len() > 0,
+        }
+    }
+
+    /// Отменяет последний ход: возвращает игру в главное меню локации, в котором
+    /// игрок был ход назад, вместе с состоянием генератора случайных чисел.
+    /// Если ход ещё не закончен (например, игрок умер по дороге), возвращает
+    /// в главное меню, с которого этот ход начался.
+    ///
+    /// Отменить можно не больше [`undo::UNDO_DEPTH`] ходов подряд. Отмена
+    /// записывается в запись нажатых клавиш как [`Input::Undo`].
+    ///
+    /// Возвращает `false`, если отменять нечего.
+    pub fn undo(&mut self) -> bool {
+        if !self.can_undo() {
+            return false;
+        }
+        self.continue_game(Input::Undo);
+        true
+    }
+
+    /// Клетка экрана, с которой начинается диалог. Варианты
+    /// из [`GameUI::available_actions`] выводятся с неё друг под другом.
+    /// `None`, если на экране нет диалога.
+    pub fn dialog_position(&self) -> Option<(Line, Column)> {
+        match self.renderer.waiting_state {
+            Some(WaitingState::Dialog { start, .. }) if !self.has_bug() => Some(start),
+            _ => None,
+        }
+    }
+
+    /// Варианты, из которых игрок сейчас выбирает в диалоге, сверху вниз.
+    /// Если на экране нет диалога, список пуст.
+    pub fn available_actions(&self) -> core::cell::Ref<'_, [Action]> {
+        let is_dialog = matches!(
+            self.renderer.waiting_state,
+            Some(WaitingState::Dialog { .. })
+        ) && !self.has_bug();
+        core::cell::Ref::map(self.state_holder.observable_state(), |state| {
+            if is_dialog {
+                state.available_actions()
+            } else {
+                &[]
+            }
+        })
+    }
+
+    /// Записывает в `w` текст варианта `action` так, как он выводится в диалоге.
+    pub fn write_action_label(
+        &self,
+        action: Action,
+        w: &mut impl core::fmt::Write,
+    ) -> core::fmt::Result {
+        write_action_label(w, self.locale(), self.renderer.rules(), action)
+    }
+
+    /// Выбирает в диалоге вариант `action` так же, как если бы игрок дошёл до него
+    /// стрелками и нажал Enter. В запись нажатых клавиш попадают именно эти нажатия,
+    /// поэтому запись можно воспроизвести как обычно.
+    ///
+    /// Возвращает `false`, если на экране нет диалога с таким вариантом.
+    pub fn perform_action(&mut self, action: Action) -> bool {
+        let Some(WaitingState::Dialog {
+            current_choice,
+            start,
+        }) = self.renderer.waiting_state
+        else {
+            return false;
+        };
+        let (option_count, choice) = {
+            let actions = self.available_actions();
+            match actions.iter().position(|&a| a == action) {
+                Some(choice) => (actions.len(), choice),
+                None => return false,
+            }
+        };
+        let down = (option_count + choice - current_choice as usize) % option_count;
+        let up = option_count - down;
+        let (key, presses) = if down <= up {
+            (Input::KeyDown, down)
+        } else {
+            (Input::KeyUp, up)
+        };
+        if let Some(input_recorder) = &mut self.input_recorder {
+            for _ in 0..presses {
+                input_recorder.record_input(key).unwrap();
+            }
+        }
+        self.renderer.waiting_state = Some(WaitingState::Dialog {
+            current_choice: choice as u8,
+            start,
+        });
+        self.continue_game(Input::Enter)
+    }
+
+    fn is_at_last_snapshot(&self) -> bool {
+        match self.undo_stack.last() {
+            Some(snapshot) => {
+                self.state_holder.state_hash() == Some(snapshot.state_hash())
+            }
+            None => false,
+        }
+    }
+
+    fn undo_impl(&mut self) -> bool {
+        if self.is_at_last_snapshot() {
+            self.undo_stack.pop();
+        }
+        let snapshot = self.undo_stack.last().expect("Nothing to undo").clone();
+        self.state_holder.restore_game(self.game.as_mut(), snapshot);
+        self.display_current_screen()
+    }
+
+    /// Запоминает игру, если игрок оказался в главном меню локации.
+    fn update_undo_stack(&mut self) {
+        use GameScreen::*;
+        match self.state_holder.observable_state().screen() {
+            // Началась новая игра, старые ходы отменять уже нельзя.
+            InitialParameters | Ding => self.undo_stack.clear(),
+            SceneRouter(_) if !self.is_at_last_snapshot() => {
+                if let Some(saved_game) = self.state_holder.save_game() {
+                    self.undo_stack.push(saved_game);
+                }
+            }
+            _ => (),
+        }
+    }
+
     pub fn continue_game(&mut self, input: Input) -> bool {
         if let Some(input_recorder) = &mut self.input_recorder {
             input_recorder.record_input(input).unwrap();
@@ -202,13 +475,18 @@ impl<
     }
 
     fn continue_game_impl(&mut self, input: Input) -> bool {
-        use GameScreen::*;
+        if input == Input::Undo && self.can_undo() {
+            return self.undo_impl();
+        }
 
         if let Some(ref waiting_state) = self.renderer.waiting_state {
             let waiting_state = waiting_state.clone();
 
             let action = match waiting_state {
-                WaitingState::PressAnyKey => Action::AnyKey,
+                WaitingState::PressAnyKey => match input {
+                    Input::PointerAt { .. } => return true, // Do nothing
+                    _ => Action::AnyKey,
+                },
                 WaitingState::Dialog {
                     current_choice,
                     start,
@@ -216,49 +494,109 @@ impl<
                     let borrowed_state = self.state_holder.observable_state();
                     let actions = borrowed_state.available_actions();
                     let option_count = actions.len() as u8;
-                    match input {
+                    let moved_choice = match input {
                         Input::KeyUp => {
-                            let current_choice =
-                                (option_count + current_choice - 1) % option_count;
-                            display_dialog(
-                                &mut self.renderer,
-                                start,
-                                Some(current_choice),
-                                actions,
-                            );
-                            self.renderer.waiting_state = Some(WaitingState::Dialog {
-                                current_choice,
-                                start,
-                            });
-                            return true;
+                            Some((option_count + current_choice - 1) % option_count)
                         }
-                        Input::KeyDown => {
-                            let current_choice =
-                                (option_count + current_choice + 1) % option_count;
-                            display_dialog(
-                                &mut self.renderer,
-                                start,
-                                Some(current_choice),
-                                actions,
-                            );
-                            self.renderer.waiting_state = Some(WaitingState::Dialog {
-                                current_choice,
-                                start,
-                            });
-                            return true;
+                        Input::KeyDown => Some((current_choice + 1) % option_count),
+                        Input::Home | Input::PageUp => Some(0),
+                        Input::End | Input::PageDown => Some(option_count - 1),
+                        Input::PointerAt { line, column } => option_at(
+                            self.renderer.locale(),
+                            self.renderer.rules(),
+                            start,
+                            actions,
+                            (line, column),
+                        ),
+                        _ => None,
+                    };
+                    if let Some(current_choice) = moved_choice {
+                        display_dialog(
+                            &mut self.renderer,
+                            start,
+                            Some(current_choice),
+                            actions,
+                        );
+                        self.renderer.waiting_state = Some(WaitingState::Dialog {
+                            current_choice,
+                            start,
+                        });
+                        return true;
+                    }
+                    let choice = match input {
+                        Input::Enter => Some(current_choice),
+                        Input::Hotkey(key) => {
+                            hotkey_option(key).filter(|&choice| choice < option_count)
+                        }
+                        Input::Escape => cancel_option(actions),
+                        Input::Click { line, column } => option_at(
+                            self.renderer.locale(),
+                            self.renderer.rules(),
+                            start,
+                            actions,
+                            (line, column),
+                        ),
+                        _ => None,
+                    };
+                    let Some(choice) = choice else {
+                        return true; // Do nothing
+                    };
+                    display_dialog(&mut self.renderer, start, None, actions);
+                    actions[choice as usize]
+                }
+                WaitingState::HighScoreName {
+                    score,
+                    mut name,
+                    start,
+                } => {
+                    match input {
+                        Input::Character(c) | Input::Hotkey(c)
+                            if !c.is_control()
+                                && name.chars().count()
+                                    < high_scores::MAX_NAME_LENGTH =>
+                        {
+                            name.push(c)
+                        }
+                        Input::Backspace => {
+                            name.pop();
                         }
                         Input::Enter => {
-                            display_dialog(&mut self.renderer, start, None, actions);
-                            actions[current_choice as usize]
+                            let name = match name.trim() {
+                                "" => high_scores::default_name(self.renderer.locale()),
+                                name => name,
+                            };
+                            high_scores::insert(&mut self.high_scores, name, score);
+                            self.renderer.waiting_state =
+                                Some(screens::high_scores::display_high_scores(
+                                    &mut self.renderer,
+                                    &self.high_scores,
+                                ));
+                            return true;
                         }
-                        Input::Other => return true, // Do nothing
+                        _ => return true,
                     }
+                    screens::high_scores::display_high_score_name(
+                        &mut self.renderer,
+                        start,
+                        &name,
+                    );
+                    self.renderer.waiting_state =
+                        Some(WaitingState::HighScoreName { score, name, start });
+                    return true;
                 }
             };
 
             self.game.as_mut().perform_action(action);
         }
 
+        let game_continues = self.display_current_screen();
+        self.update_undo_stack();
+        game_continues
+    }
+
+    fn display_current_screen(&mut self) -> bool {
+        use GameScreen::*;
+
         let new_waiting_state = match self.state_holder.observable_state().screen() {
             Intro => screens::initial::display_intro(&mut self.renderer),
             InitialParameters => screens::initial::display_initial_parameters(
@@ -459,13 +797,27 @@ impl<
             GameEnd(state) => {
                 screens::game_end::display_game_end(&mut self.renderer, state)
             }
+            HighScoreEntry(score) => {
+                if high_scores::is_high_score(&self.high_scores, *score) {
+                    screens::high_scores::display_high_score_name_prompt(
+                        &mut self.renderer,
+                        *score,
+                    )
+                } else {
+                    screens::high_scores::display_high_scores(
+                        &mut self.renderer,
+                        &self.high_scores,
+                    )
+                }
+            }
             WannaTryAgain => screens::game_end::display_wanna_try_again(
                 &mut self.renderer,
                 self.state_holder.observable_state().available_actions(),
             ),
             Disclaimer => screens::game_end::display_disclaimer(&mut self.renderer),
-            WhatToDo(_) => screens::help::display_what_to_do(
+            WhatToDo(state) => screens::help::display_what_to_do(
                 &mut self.renderer,
+                state,
                 self.state_holder.observable_state().available_actions(),
             ),
             AboutScreen(_) => screens::help::display_about_screen(
@@ -500,6 +852,10 @@ impl<
     pub fn request_consumer(&self) -> &C {
         self.renderer.request_consumer()
     }
+
+    pub fn request_consumer_mut(&mut self) -> &mut C {
+        self.renderer.request_consumer_mut()
+    }
 }
 
 impl<G, C, InputSink: core::fmt::Write + Display> GameUI<'_, G, C, InputSink> {
@@ -526,22 +882,11 @@ fn sleep(r: &mut Renderer<impl RendererRequestConsumer>, ms: Milliseconds) {
 fn wait_for_any_key(r: &mut Renderer<impl RendererRequestConsumer>) -> WaitingState {
     r.move_cursor_to(23, 0);
     r.set_color(Color::YellowBright, Color::Black);
-    write!(r, "Нажми любую клавишу ...");
+    write_l10n!(r, "Нажми любую клавишу ...", "Press any key ...");
     r.flush();
     WaitingState::PressAnyKey
 }
 
-pub fn professor_name(subject: Subject) -> &'static str {
-    match subject {
-        Subject::AlgebraAndNumberTheory => "Всемирнов М.А.",
-        Subject::Calculus => "Дубцов Е.С.",
-        Subject::GeometryAndTopology => "Подкорытов С.С.",
-        Subject::ComputerScience => "Климов А.А.",
-        Subject::English => "Влащенко Н.П.",
-        Subject::PhysicalEducation => "Альбинский Е.Г.",
-    }
-}
-
 #[derive(Copy, Clone, Debug)]
 pub enum Gender {
     Male,
@@ -558,69 +903,3 @@ pub fn professor_gender(subject: Subject) -> Gender {
         Subject::PhysicalEducation => Gender::Male,
     }
 }
-
-pub fn subject_name(subject: Subject) -> &'static str {
-    match subject {
-        Subject::AlgebraAndNumberTheory => "Алгебра и Т.Ч.",
-        Subject::Calculus => "Мат. Анализ",
-        Subject::GeometryAndTopology => "Геометрия и Топология",
-        Subject::ComputerScience => "Информатика",
-        Subject::English => "English",
-        Subject::PhysicalEducation => "Физ-ра",
-    }
-}
-
-pub fn subject_short_name(subject: Subject) -> &'static str {
-    match subject {
-        Subject::AlgebraAndNumberTheory => "АиТЧ",
-        Subject::Calculus => "МатАн",
-        Subject::GeometryAndTopology => "ГиТ",
-        Subject::ComputerScience => "Инф",
-        Subject::English => "ИнЯз",
-        Subject::PhysicalEducation => "Физ-ра",
-    }
-}
-
-impl Display for Location {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let name = match self {
-            Location::Dorm => "Общага",
-            Location::PUNK => "ПУНК",
-            Location::Mausoleum => "Мавзолей",
-            Location::ComputerClass => "Компы",
-            Location::PDMI => "ПОМИ",
-        };
-        f.write_str(name)
-    }
-}
-
-pub fn day_date(day: &Day) -> &'static str {
-    const DATES: [&str; timetable::NUM_DAYS] =
-        ["22.5", "23.5", "24.5", "25.5", "26.5", "27.5"];
-    DATES[day.index()]
-}
-
-pub fn classmate_name(classmate: Classmate) -> &'static str {
-    match classmate {
-        Classmate::Kolya => "Коля",
-        Classmate::Pasha => "Паша",
-        Classmate::Diamond => "Diamond",
-        Classmate::RAI => "RAI",
-        Classmate::Misha => "Миша",
-        Classmate::Serj => "Серж",
-        Classmate::Sasha => "Саша",
-        Classmate::NiL => "NiL",
-        Classmate::Kuzmenko => "Кузьменко В.Г.",
-        Classmate::DJuG => "DJuG",
-        Classmate::Andrew => "Эндрю",
-        Classmate::Grisha => "Гриша",
-    }
-}
-
-fn problems_inflected(problems: u8) -> &'static str {
-    match problems {
-        1 => "задание",
-        2..=4 => "задания",
-        _ => "заданий",
-    }
-}
@@ -1,6 +1,74 @@
-use crate::ui::Input;
+//! Запись нажатых клавиш и повторы игр.
+//!
+//! Запись нажатых клавиш — это строка вида `4r2↓r'В'а⌫@3`, где `↑`, `↓`, `r`
+//! (Enter), `.` (любая другая клавиша), `⌫`, `↶` (отмена хода), `⎋` (Escape),
+//! `⇱` (Home), `⇲` (End), `⇞` (Page Up) и `⇟` (Page Down) — нажатые клавиши,
+//! после `'` следует введённый символ, после `@` — клавиша быстрого выбора,
+//! а число перед клавишей — сколько раз подряд она была нажата. Перемещение
+//! указателя мыши на клетку экрана записывается как `{<строка>,<столбец>}`,
+//! а нажатие мыши — как `[<строка>,<столбец>]`.
+//!
+//! Одной записи недостаточно, чтобы воспроизвести игру: нужно ещё знать зерно,
+//! режим игры и таблицу рекордов. Поэтому есть ещё формат повтора
+//! (см. [`ReplayWriter`] и [`ReplayReader`]):
+//!
+//! ```text
+//! MMHEROES REPLAY 1
+//! engine 0.1.0
+//! seed 42
+//! mode normal
+//! rng separate
+//! days 6
+//! timetable 1 calculus 10 13 punk
+//! timetable 2 english 11 12 punk
+//! score 400 Коля
+//! score 280 Саша
+//! score 180 Эндрю
+//! score 100 Паша
+//! score 20 Гриша
+//!
+//! 4r2↓r
+//! #0123456789abcdef
+//! 3↓r'В'а
+//! ```
+//!
+//! Заголовок повтора состоит из строк вида `<ключ> <значение>` и заканчивается
+//! пустой строкой. Строки `rng`, `days`, `timetable` и `score` необязательны:
+//! если их нет, используются единый поток случайных чисел, обычная
+//! продолжительность сессии, случайное расписание и таблица рекордов
+//! по умолчанию. Неизвестные ключи пропускаются.
+//!
+//! Строки `timetable` — это расписание, заданное вручную, по строке
+//! на каждую строку текстового формата [`Timetable::parse`]. Пример выше
+//! сокращён: в настоящем расписании зачёты есть по всем предметам.
+//!
+//! После заголовка идёт запись нажатых клавиш, которая может быть разбита на
+//! несколько строк. Строки, начинающиеся с `#`, содержат хэш состояния игры
+//! в этот момент — по ним можно проверить, что повтор воспроизводится так же,
+//! как игра, которую записали.
+
+use crate::logic::{
+    Action, GameMode, Money, RngMode, Rules, SemesterLength, Timetable, TimetableError,
+};
+use crate::ui::high_scores::{HighScore, SCORE_COUNT};
+use crate::ui::{Input, Locale};
+use crate::util::TinyString;
 use core::fmt::{Result as FmtResult, Write};
 
+/// Символ, после которого в записи следует введённый символ (`Input::Character`).
+const CHARACTER_ESCAPE: char = '\'';
+
+/// Символ, после которого в записи следует клавиша быстрого выбора (`Input::Hotkey`).
+const HOTKEY_ESCAPE: char = '@';
+
+/// Скобки, в которых в записи следуют координаты указателя мыши (`Input::PointerAt`).
+const POINTER_START: char = '{';
+const POINTER_END: char = '}';
+
+/// Скобки, в которых в записи следуют координаты нажатия мыши (`Input::Click`).
+const CLICK_START: char = '[';
+const CLICK_END: char = ']';
+
 pub struct InputRecorder<Output> {
     output: Output,
     last_input: Option<(Input, usize)>,
@@ -30,17 +98,43 @@ impl<Output: Write> InputRecorder<Output> {
     pub fn flush(&mut self) -> FmtResult {
         if let Some((last_input, count)) = self.last_input {
             self.last_input = None;
+            if count != 1 {
+                write!(self.output, "{}", count)?;
+            }
             let mangled = match last_input {
                 Input::KeyUp => '↑',
                 Input::KeyDown => '↓',
                 Input::Enter => 'r',
                 Input::Other => '.',
+                Input::Character(c) => {
+                    self.output.write_char(CHARACTER_ESCAPE)?;
+                    c
+                }
+                Input::Backspace => '⌫',
+                Input::Undo => '↶',
+                Input::Hotkey(c) => {
+                    self.output.write_char(HOTKEY_ESCAPE)?;
+                    c
+                }
+                Input::Escape => '⎋',
+                Input::Home => '⇱',
+                Input::End => '⇲',
+                Input::PageUp => '⇞',
+                Input::PageDown => '⇟',
+                Input::PointerAt { line, column } => {
+                    return write!(
+                        self.output,
+                        "{POINTER_START}{line},{column}{POINTER_END}"
+                    );
+                }
+                Input::Click { line, column } => {
+                    return write!(
+                        self.output,
+                        "{CLICK_START}{line},{column}{CLICK_END}"
+                    );
+                }
             };
-            if count == 1 {
-                self.output.write_char(mangled)
-            } else {
-                write!(self.output, "{}{}", count, mangled)
-            }
+            self.output.write_char(mangled)
         } else {
             Ok(())
         }
@@ -49,6 +143,32 @@ impl<Output: Write> InputRecorder<Output> {
     pub fn output(&self) -> &Output {
         &self.output
     }
+
+    fn output_mut(&mut self) -> &mut Output {
+        &mut self.output
+    }
+}
+
+/// Клавиши, которые нужно нажать в `GameUI`, чтобы выбрать действие `action`
+/// на экране, где доступны действия `available_actions`. Курсор в диалоге
+/// изначально стоит на первом варианте, и до нужного варианта выбирается
+/// кратчайший путь.
+///
+/// Экран ввода имени для таблицы рекордов (`GameScreen::HighScoreEntry`) так
+/// пройти нельзя: там нужно ещё и ввести имя.
+///
+/// Возвращает `None`, если действие недоступно.
+pub fn inputs_for_action(
+    available_actions: &[Action],
+    action: Action,
+) -> Option<impl Iterator<Item = Input>> {
+    let index = available_actions.iter().position(|&a| a == action)?;
+    let (key, count) = if index <= available_actions.len() / 2 {
+        (Input::KeyDown, index)
+    } else {
+        (Input::KeyUp, available_actions.len() - index)
+    };
+    Some(core::iter::repeat_n(key, count).chain(core::iter::once(Input::Enter)))
 }
 
 pub enum NoInputRecording {}
@@ -74,10 +194,14 @@ pub enum InputRecordingParserError {
     UnknownCharacter {
         grapheme: usize,
     },
+    InvalidPosition {
+        grapheme: usize,
+    },
     UnexpectedEOF,
     Interrupted,
 }
 
+#[derive(Debug)]
 pub struct InputRecordingParser<'input> {
     input: &'input str,
 }
@@ -97,6 +221,13 @@ impl<'input> InputRecordingParser<'input> {
             '↓' => Ok(Input::KeyDown),
             'r' => Ok(Input::Enter),
             '.' => Ok(Input::Other),
+            '⌫' => Ok(Input::Backspace),
+            '↶' => Ok(Input::Undo),
+            '⎋' => Ok(Input::Escape),
+            '⇱' => Ok(Input::Home),
+            '⇲' => Ok(Input::End),
+            '⇞' => Ok(Input::PageUp),
+            '⇟' => Ok(Input::PageDown),
             _ => Err(InputRecordingParserError::UnknownCharacter { grapheme }),
         }
     }
@@ -106,40 +237,71 @@ impl<'input> InputRecordingParser<'input> {
         mut into: F,
     ) -> Result<(), InputRecordingParserError> {
         let mut number_start = None;
-        for (grapheme, (i, c)) in self.input.char_indices().enumerate() {
-            match number_start {
-                None => {
-                    if c.is_ascii_digit() {
-                        number_start = Some(i);
-                    } else {
-                        let input = self.demangle_input(grapheme, c)?;
-                        if !into(input) {
-                            return Err(InputRecordingParserError::Interrupted);
+        let mut chars = self.input.char_indices().enumerate();
+        while let Some((grapheme, (i, c))) = chars.next() {
+            if c.is_ascii_digit() {
+                number_start.get_or_insert(i);
+                continue;
+            }
+            let repeat_count = match number_start.take() {
+                None => 1,
+                // Встретили первый символ, не являющийся цифрой.
+                // Парсим число.
+                Some(start_position) => {
+                    match self.input[start_position..i].parse::<usize>() {
+                        Ok(number) => number,
+                        Err(error) => {
+                            return Err(InputRecordingParserError::ParseInt {
+                                grapheme,
+                                error,
+                            })
                         }
                     }
                 }
-                Some(start_position) => {
-                    if !c.is_ascii_digit() {
-                        // Встретили первый символ, не являющийся цифрой.
-                        // Парсим число.
-                        let parsed_number =
-                            match self.input[start_position..i].parse::<usize>() {
-                                Ok(number) => number,
-                                Err(error) => {
-                                    return Err(InputRecordingParserError::ParseInt {
-                                        grapheme,
-                                        error,
-                                    })
-                                }
-                            };
-                        number_start = None;
-                        let input = self.demangle_input(grapheme, c)?;
-                        for _ in 0..parsed_number {
-                            if !into(input) {
-                                return Err(InputRecordingParserError::Interrupted);
-                            }
-                        }
+            };
+            let input = match c {
+                CHARACTER_ESCAPE | HOTKEY_ESCAPE => {
+                    let escaped = match chars.next() {
+                        Some((_, (_, c))) => c,
+                        None => return Err(InputRecordingParserError::UnexpectedEOF),
+                    };
+                    if c == CHARACTER_ESCAPE {
+                        Input::Character(escaped)
+                    } else {
+                        Input::Hotkey(escaped)
+                    }
+                }
+                POINTER_START | CLICK_START => {
+                    let close = if c == POINTER_START {
+                        POINTER_END
+                    } else {
+                        CLICK_END
+                    };
+                    let rest = &self.input[i + c.len_utf8()..];
+                    let (position, _) = rest
+                        .split_once(close)
+                        .ok_or(InputRecordingParserError::UnexpectedEOF)?;
+                    // Пропускаем координаты и закрывающую скобку
+                    for _ in 0..=position.chars().count() {
+                        chars.next();
                     }
+                    let (line, column) = position
+                        .split_once(',')
+                        .and_then(|(line, column)| {
+                            Some((line.parse().ok()?, column.parse().ok()?))
+                        })
+                        .ok_or(InputRecordingParserError::InvalidPosition { grapheme })?;
+                    if c == POINTER_START {
+                        Input::PointerAt { line, column }
+                    } else {
+                        Input::Click { line, column }
+                    }
+                }
+                _ => self.demangle_input(grapheme, c)?,
+            };
+            for _ in 0..repeat_count {
+                if !into(input) {
+                    return Err(InputRecordingParserError::Interrupted);
                 }
             }
         }
@@ -152,6 +314,402 @@ impl<'input> InputRecordingParser<'input> {
     }
 }
 
+/// Версия движка, которая записывается в заголовок повтора.
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Версия формата повтора. Увеличивается при любом несовместимом изменении
+/// формата.
+pub const REPLAY_FORMAT_VERSION: u32 = 1;
+
+const REPLAY_SIGNATURE: &str = "MMHEROES REPLAY";
+
+/// Символ, с которого начинается строка с хэшем состояния игры.
+const STATE_HASH_PREFIX: char = '#';
+
+/// Начало строки заголовка с расписанием.
+const TIMETABLE_KEY: &str = "timetable ";
+
+/// Является ли `input` повтором, а не просто записью нажатых клавиш.
+pub fn is_replay(input: &str) -> bool {
+    input.starts_with(REPLAY_SIGNATURE)
+}
+
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Normal => "normal",
+        GameMode::SelectInitialParameters => "select",
+        GameMode::God => "god",
+    }
+}
+
+fn mode_from_name(name: &str) -> Option<GameMode> {
+    match name {
+        "normal" => Some(GameMode::Normal),
+        "select" => Some(GameMode::SelectInitialParameters),
+        "god" => Some(GameMode::God),
+        _ => None,
+    }
+}
+
+fn rng_mode_name(rng_mode: RngMode) -> &'static str {
+    match rng_mode {
+        RngMode::SeparateStreams => "separate",
+        RngMode::SingleStream => "single",
+        RngMode::BorlandPascal => "borland",
+    }
+}
+
+fn rng_mode_from_name(name: &str) -> Option<RngMode> {
+    match name {
+        "separate" => Some(RngMode::SeparateStreams),
+        "single" => Some(RngMode::SingleStream),
+        "borland" => Some(RngMode::BorlandPascal),
+        _ => None,
+    }
+}
+
+/// Всё, что кроме нажатых клавиш нужно, чтобы воспроизвести игру.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayHeader<'a> {
+    /// Версия движка, в которой был записан повтор. Если она отличается от
+    /// [`ENGINE_VERSION`], повтор может воспроизвестись не так, как игра, которую
+    /// записали.
+    pub engine_version: &'a str,
+
+    pub seed: u64,
+
+    pub mode: GameMode,
+
+    /// В повторах, записанных до появления этого поля, —
+    /// [`RngMode::SingleStream`].
+    pub rng_mode: RngMode,
+
+    /// В повторах, записанных до появления этого поля, —
+    /// [`SemesterLength::NORMAL`].
+    pub semester_length: SemesterLength,
+
+    /// Расписание, с которым началась игра (см.
+    /// [`StateHolder::with_timetable`](crate::logic::StateHolder::with_timetable)).
+    /// `None` — случайное.
+    pub timetable: Option<Timetable>,
+
+    /// Таблица рекордов на момент начала игры. От неё зависит, предложат ли
+    /// в конце игры ввести имя. `None` — таблица рекордов по умолчанию.
+    pub high_scores: Option<[HighScore; SCORE_COUNT]>,
+}
+
+impl ReplayHeader<'static> {
+    pub fn new(
+        seed: u64,
+        mode: GameMode,
+        rng_mode: RngMode,
+        semester_length: SemesterLength,
+        timetable: Option<Timetable>,
+        high_scores: Option<[HighScore; SCORE_COUNT]>,
+    ) -> Self {
+        ReplayHeader {
+            engine_version: ENGINE_VERSION,
+            seed,
+            mode,
+            rng_mode,
+            semester_length,
+            timetable,
+            high_scores,
+        }
+    }
+}
+
+/// Записывает повтор игры. Заголовок записывается сразу при создании.
+pub struct ReplayWriter<Output> {
+    recorder: InputRecorder<Output>,
+    at_line_start: bool,
+    last_state_hash: Option<u64>,
+}
+
+impl<Output: Write> ReplayWriter<Output> {
+    /// Возвращает ошибку, если её вернул `output` или если в имени из таблицы
+    /// рекордов есть перевод строки.
+    pub fn new(
+        mut output: Output,
+        header: &ReplayHeader,
+    ) -> Result<Self, core::fmt::Error> {
+        writeln!(output, "{} {}", REPLAY_SIGNATURE, REPLAY_FORMAT_VERSION)?;
+        writeln!(output, "engine {}", header.engine_version)?;
+        writeln!(output, "seed {}", header.seed)?;
+        writeln!(output, "mode {}", mode_name(header.mode))?;
+        writeln!(output, "rng {}", rng_mode_name(header.rng_mode))?;
+        writeln!(output, "days {}", header.semester_length.days())?;
+        if let Some(timetable) = &header.timetable {
+            let mut writer = TimetableLineWriter {
+                output: &mut output,
+                at_line_start: true,
+            };
+            write!(writer, "{}", timetable)?;
+        }
+        for (name, score) in header.high_scores.iter().flatten() {
+            if name.contains('\n') {
+                return Err(core::fmt::Error);
+            }
+            writeln!(output, "score {} {}", score.0, name)?;
+        }
+        writeln!(output)?;
+        Ok(ReplayWriter {
+            recorder: InputRecorder::new(output),
+            at_line_start: true,
+            last_state_hash: None,
+        })
+    }
+
+    pub fn record_input(&mut self, input: Input) -> FmtResult {
+        self.at_line_start = false;
+        self.recorder.record_input(input)
+    }
+
+    /// Записывает хэш состояния игры после всех записанных до этого нажатий.
+    pub fn record_state_hash(&mut self, hash: u64) -> FmtResult {
+        self.finish_line()?;
+        writeln!(
+            self.recorder.output_mut(),
+            "{}{:016x}",
+            STATE_HASH_PREFIX,
+            hash
+        )
+    }
+
+    /// Контрольная точка: записывает хэш состояния игры `state_hash`, если он есть
+    /// и отличается от последнего записанного. Удобно вызывать после каждого
+    /// нажатия с результатом [`GameUI::state_hash`](crate::ui::GameUI::state_hash) —
+    /// тогда хэш будет записываться каждый раз, когда игрок оказывается в главном
+    /// меню локации.
+    pub fn record_checkpoint(&mut self, state_hash: Option<u64>) -> FmtResult {
+        match state_hash {
+            Some(hash) if self.last_state_hash != state_hash => {
+                self.last_state_hash = state_hash;
+                self.record_state_hash(hash)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Дописывает всё, что ещё не записано. После этого повтор можно прочитать.
+    pub fn flush(&mut self) -> FmtResult {
+        self.finish_line()
+    }
+
+    pub fn output(&self) -> &Output {
+        self.recorder.output()
+    }
+
+    fn finish_line(&mut self) -> FmtResult {
+        self.recorder.flush()?;
+        if !self.at_line_start {
+            self.at_line_start = true;
+            self.recorder.output_mut().write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+/// Записывает расписание в текстовом формате, добавляя [`TIMETABLE_KEY`]
+/// в начало каждой строки.
+struct TimetableLineWriter<'a, Output> {
+    output: &'a mut Output,
+    at_line_start: bool,
+}
+
+impl<Output: Write> Write for TimetableLineWriter<'_, Output> {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        for line in s.split_inclusive('\n') {
+            if self.at_line_start {
+                self.output.write_str(TIMETABLE_KEY)?;
+            }
+            self.output.write_str(line)?;
+            self.at_line_start = line.ends_with('\n');
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReplayEvent {
+    Input(Input),
+
+    /// Хэш состояния игры после всех нажатий, прочитанных до этого.
+    StateHash(u64),
+}
+
+#[derive(Debug)]
+pub enum ReplayReaderError {
+    /// Данные не являются повтором.
+    InvalidSignature,
+
+    /// Повтор записан в версии формата, которую мы не поддерживаем.
+    UnsupportedVersion,
+
+    /// В заголовке нет обязательного поля.
+    MissingField(&'static str),
+
+    /// Строка повтора с номером `line` (начиная с 1) некорректна.
+    InvalidLine { line: usize },
+
+    /// Расписание в заголовке некорректно.
+    InvalidTimetable(TimetableError),
+
+    /// Не удалось разобрать запись нажатых клавиш в строке `line`.
+    InvalidInput {
+        line: usize,
+        error: InputRecordingParserError,
+    },
+
+    /// Обработчик событий попросил остановиться.
+    Interrupted,
+}
+
+/// Читает повтор игры, записанный с помощью [`ReplayWriter`].
+#[derive(Debug)]
+pub struct ReplayReader<'input> {
+    header: ReplayHeader<'input>,
+    body: &'input str,
+
+    /// Номер строки, с которой начинается `body`, начиная с 1.
+    body_first_line: usize,
+}
+
+impl<'input> ReplayReader<'input> {
+    /// Читает заголовок повтора.
+    pub fn new(input: &'input str) -> Result<Self, ReplayReaderError> {
+        let mut lines = input.split_inclusive('\n');
+        let mut consumed = 0usize;
+        let mut next_line = || {
+            let line = lines.next()?;
+            consumed += line.len();
+            Some(line.trim_end_matches(['\n', '\r']))
+        };
+
+        let version = next_line()
+            .and_then(|line| line.strip_prefix(REPLAY_SIGNATURE))
+            .and_then(|rest| rest.strip_prefix(' '))
+            .ok_or(ReplayReaderError::InvalidSignature)?;
+        if version.parse::<u32>() != Ok(REPLAY_FORMAT_VERSION) {
+            return Err(ReplayReaderError::UnsupportedVersion);
+        }
+
+        let mut engine_version = None;
+        let mut seed = None;
+        let mut mode = None;
+        let mut rng_mode = RngMode::SingleStream;
+        let mut semester_length = SemesterLength::NORMAL;
+        let mut has_timetable = false;
+        let mut high_scores =
+            crate::ui::high_scores::default_high_scores(Locale::Russian);
+        let mut high_score_count = 0;
+        let mut line_number = 1;
+        loop {
+            line_number += 1;
+            let line = next_line().ok_or(ReplayReaderError::MissingField("body"))?;
+            if line.is_empty() {
+                break;
+            }
+            let invalid_line = || ReplayReaderError::InvalidLine { line: line_number };
+            let (key, value) = line.split_once(' ').ok_or_else(invalid_line)?;
+            match key {
+                "engine" => engine_version = Some(value),
+                "seed" => seed = Some(value.parse().map_err(|_| invalid_line())?),
+                "mode" => mode = Some(mode_from_name(value).ok_or_else(invalid_line)?),
+                "rng" => rng_mode = rng_mode_from_name(value).ok_or_else(invalid_line)?,
+                "days" => {
+                    semester_length = value
+                        .parse()
+                        .ok()
+                        .and_then(SemesterLength::new)
+                        .ok_or_else(invalid_line)?
+                }
+                "timetable" => has_timetable = true,
+                "score" => {
+                    let (score, name) = value.split_once(' ').ok_or_else(invalid_line)?;
+                    let score = score.parse().map_err(|_| invalid_line())?;
+                    let high_score = high_scores
+                        .get_mut(high_score_count)
+                        .ok_or_else(invalid_line)?;
+                    *high_score = (TinyString::from(name), Money(score));
+                    high_score_count += 1;
+                }
+                _ => {}
+            }
+        }
+        let high_scores = match high_score_count {
+            0 => None,
+            SCORE_COUNT => Some(high_scores),
+            _ => return Err(ReplayReaderError::MissingField("score")),
+        };
+
+        // Повторы с правилами, отличными от правил оригинальной игры,
+        // не записываются.
+        let timetable = if has_timetable {
+            let lines = input[..consumed]
+                .lines()
+                .filter_map(|line| line.strip_prefix(TIMETABLE_KEY));
+            Some(
+                Timetable::parse_lines(lines, &Rules::DEFAULT)
+                    .map_err(ReplayReaderError::InvalidTimetable)?,
+            )
+        } else {
+            None
+        };
+
+        Ok(ReplayReader {
+            header: ReplayHeader {
+                engine_version: engine_version
+                    .ok_or(ReplayReaderError::MissingField("engine"))?,
+                seed: seed.ok_or(ReplayReaderError::MissingField("seed"))?,
+                mode: mode.ok_or(ReplayReaderError::MissingField("mode"))?,
+                rng_mode,
+                semester_length,
+                timetable,
+                high_scores,
+            },
+            body: &input[consumed..],
+            body_first_line: line_number + 1,
+        })
+    }
+
+    pub fn header(&self) -> &ReplayHeader<'input> {
+        &self.header
+    }
+
+    /// Читает нажатые клавиши и хэши состояния игры и передаёт их в `into`.
+    /// Если `into` возвращает `false`, чтение прекращается
+    /// с ошибкой [`ReplayReaderError::Interrupted`].
+    pub fn parse_all<F: FnMut(ReplayEvent) -> bool>(
+        &mut self,
+        mut into: F,
+    ) -> Result<(), ReplayReaderError> {
+        for (i, line) in self.body.lines().enumerate() {
+            let line_number = self.body_first_line + i;
+            if let Some(hash) = line.strip_prefix(STATE_HASH_PREFIX) {
+                let hash = u64::from_str_radix(hash, 16)
+                    .map_err(|_| ReplayReaderError::InvalidLine { line: line_number })?;
+                if !into(ReplayEvent::StateHash(hash)) {
+                    return Err(ReplayReaderError::Interrupted);
+                }
+                continue;
+            }
+            InputRecordingParser::new(line)
+                .parse_all(|input| into(ReplayEvent::Input(input)))
+                .map_err(|error| match error {
+                    InputRecordingParserError::Interrupted => {
+                        ReplayReaderError::Interrupted
+                    }
+                    error => ReplayReaderError::InvalidInput {
+                        line: line_number,
+                        error,
+                    },
+                })?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod tests {
@@ -159,6 +717,39 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_inputs_for_action() -> FmtResult {
+        let actions = [
+            Action::Study,
+            Action::ViewTimetable,
+            Action::Rest,
+            Action::GoToBed,
+            Action::IAmDone,
+        ];
+        let mut output = String::new();
+        let mut recorder = InputRecorder::new(&mut output);
+        for action in [
+            Action::Study,
+            Action::Rest,
+            Action::GoToBed,
+            Action::IAmDone,
+        ] {
+            for input in inputs_for_action(&actions, action).unwrap() {
+                recorder.record_input(input)?;
+            }
+        }
+        recorder.flush()?;
+        assert_eq!(output, "r2↓r2↑r↑r");
+        assert!(inputs_for_action(&actions, Action::GoFromDormToPunk).is_none());
+        assert_eq!(
+            inputs_for_action(&[Action::AnyKey], Action::AnyKey)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            [Input::Enter]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_recording() -> FmtResult {
         let mut output = String::new();
@@ -200,7 +791,7 @@ mod tests {
     #[test]
     fn test_successful_parsing() -> Result<(), InputRecordingParserError> {
         let input = "↓r↑.2↓5r2.12↑↓";
-        let mut parser = InputRecordingParser::new(&input);
+        let mut parser = InputRecordingParser::new(input);
         let mut parsed_input = Vec::new();
 
         parser.parse_all(|input| {
@@ -243,10 +834,330 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_characters() -> Result<(), InputRecordingParserError> {
+        let mut output = String::new();
+        let mut recorder = InputRecorder::new(&mut output);
+        let inputs = [
+            Input::Character('В'),
+            Input::Character('а'),
+            Input::Character('a'),
+            Input::Character('a'),
+            Input::Backspace,
+            Input::Character('4'),
+            Input::Character('\''),
+            Input::Enter,
+        ];
+        for input in inputs {
+            recorder.record_input(input).unwrap();
+        }
+        recorder.flush().unwrap();
+        assert_eq!(output, "'В'а2'a⌫'4''r");
+
+        let mut parsed_input = Vec::new();
+        InputRecordingParser::new(&output).parse_all(|input| {
+            parsed_input.push(input);
+            true
+        })?;
+        assert_eq!(parsed_input, inputs);
+
+        let result = InputRecordingParser::new("r2'").parse_all(|_| true);
+        assert_matches!(result, Err(InputRecordingParserError::UnexpectedEOF));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extended_keys() -> Result<(), InputRecordingParserError> {
+        let mut output = String::new();
+        let mut recorder = InputRecorder::new(&mut output);
+        let inputs = [
+            Input::Hotkey('3'),
+            Input::Hotkey('3'),
+            Input::Hotkey('b'),
+            Input::Escape,
+            Input::Home,
+            Input::End,
+            Input::End,
+            Input::PageUp,
+            Input::PageDown,
+            Input::Hotkey('@'),
+            Input::Character('@'),
+        ];
+        for input in inputs {
+            recorder.record_input(input).unwrap();
+        }
+        recorder.flush().unwrap();
+        assert_eq!(output, "2@3@b⎋⇱2⇲⇞⇟@@'@");
+
+        let mut parsed_input = Vec::new();
+        InputRecordingParser::new(&output).parse_all(|input| {
+            parsed_input.push(input);
+            true
+        })?;
+        assert_eq!(parsed_input, inputs);
+
+        let result = InputRecordingParser::new("r@").parse_all(|_| true);
+        assert_matches!(result, Err(InputRecordingParserError::UnexpectedEOF));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pointer() -> Result<(), InputRecordingParserError> {
+        let mut output = String::new();
+        let mut recorder = InputRecorder::new(&mut output);
+        let inputs = [
+            Input::PointerAt { line: 7, column: 0 },
+            Input::PointerAt {
+                line: 8,
+                column: 12,
+            },
+            Input::Click {
+                line: 8,
+                column: 12,
+            },
+            Input::Click {
+                line: 8,
+                column: 12,
+            },
+            Input::KeyDown,
+        ];
+        for input in inputs {
+            recorder.record_input(input).unwrap();
+        }
+        recorder.flush().unwrap();
+        assert_eq!(output, "{7,0}{8,12}2[8,12]↓");
+
+        let mut parsed_input = Vec::new();
+        InputRecordingParser::new(&output).parse_all(|input| {
+            parsed_input.push(input);
+            true
+        })?;
+        assert_eq!(parsed_input, inputs);
+
+        let result = InputRecordingParser::new("r[1,2").parse_all(|_| true);
+        assert_matches!(result, Err(InputRecordingParserError::UnexpectedEOF));
+        let result = InputRecordingParser::new("r{1,256}").parse_all(|_| true);
+        assert_matches!(
+            result,
+            Err(InputRecordingParserError::InvalidPosition { grapheme: 1 })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay_round_trip() -> Result<(), ReplayReaderError> {
+        let high_scores = crate::high_scores![
+            "Вася Пупкин" => 500,
+            "Коля" => 400,
+            "Саша" => 280,
+            "Эндрю" => 180,
+            "Паша" => 100,
+        ];
+        let header = ReplayHeader::new(
+            42,
+            GameMode::God,
+            RngMode::SeparateStreams,
+            SemesterLength::BLITZ,
+            None,
+            Some(high_scores),
+        );
+        let mut writer = ReplayWriter::new(String::new(), &header).unwrap();
+        let events = [
+            ReplayEvent::StateHash(0x1234),
+            ReplayEvent::Input(Input::Enter),
+            ReplayEvent::Input(Input::Enter),
+            ReplayEvent::Input(Input::KeyDown),
+            ReplayEvent::StateHash(u64::MAX),
+            ReplayEvent::StateHash(1),
+            ReplayEvent::Input(Input::Character('В')),
+            ReplayEvent::Input(Input::Backspace),
+        ];
+        for event in events {
+            match event {
+                ReplayEvent::Input(input) => writer.record_input(input).unwrap(),
+                ReplayEvent::StateHash(hash) => writer.record_state_hash(hash).unwrap(),
+            }
+        }
+        writer.flush().unwrap();
+        let output = writer.output();
+        assert_eq!(
+            output.replace(ENGINE_VERSION, "<version>"),
+            "MMHEROES REPLAY 1
+engine <version>
+seed 42
+mode god
+rng separate
+days 3
+score 500 Вася Пупкин
+score 400 Коля
+score 280 Саша
+score 180 Эндрю
+score 100 Паша
+
+#0000000000001234
+2r↓
+#ffffffffffffffff
+#0000000000000001
+'В⌫
+"
+        );
+
+        assert!(is_replay(output));
+        let mut reader = ReplayReader::new(output)?;
+        assert_eq!(reader.header(), &header);
+        let mut parsed_events = Vec::new();
+        reader.parse_all(|event| {
+            parsed_events.push(event);
+            true
+        })?;
+        assert_eq!(parsed_events, events);
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay_with_timetable() -> Result<(), ReplayReaderError> {
+        let timetable: Timetable = "
+            days 3
+            1 calculus 10 13 punk
+            1 english 12 14 punk
+            2 algebra_and_number_theory 9 12 pdmi
+            2 geometry_and_topology 11 12 pdmi
+            3 computer_science 16 18 computer_class
+            3 physical_education 17 18 punk
+        "
+        .parse()
+        .unwrap();
+        let header = ReplayHeader::new(
+            1,
+            GameMode::Normal,
+            RngMode::SingleStream,
+            SemesterLength::BLITZ,
+            Some(timetable),
+            None,
+        );
+        let mut writer = ReplayWriter::new(String::new(), &header).unwrap();
+        writer.record_input(Input::Enter).unwrap();
+        writer.flush().unwrap();
+        let output = writer.output();
+        assert_eq!(
+            output.replace(ENGINE_VERSION, "<version>"),
+            "MMHEROES REPLAY 1
+engine <version>
+seed 1
+mode normal
+rng single
+days 3
+timetable days 3
+timetable 1 calculus 10 13 punk
+timetable 1 english 12 14 punk
+timetable 2 algebra_and_number_theory 9 12 pdmi
+timetable 2 geometry_and_topology 11 12 pdmi
+timetable 3 computer_science 16 18 computer_class
+timetable 3 physical_education 17 18 punk
+
+r
+"
+        );
+        assert_eq!(ReplayReader::new(output)?.header(), &header);
+
+        let replay =
+            output.replace("timetable 3 computer_science", "timetable 3 chemistry");
+        assert_matches!(
+            ReplayReader::new(&replay),
+            Err(ReplayReaderError::InvalidTimetable(
+                TimetableError::InvalidLine(6)
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay_without_high_scores() -> Result<(), ReplayReaderError> {
+        let input = "MMHEROES REPLAY 1\r\nengine 0.0.1\r\nseed 7\r\nmode normal\r\n\
+                     future field\r\n\r\n3r\r\n↓";
+        let mut reader = ReplayReader::new(input)?;
+        assert_eq!(
+            reader.header(),
+            &ReplayHeader {
+                engine_version: "0.0.1",
+                seed: 7,
+                mode: GameMode::Normal,
+                rng_mode: RngMode::SingleStream,
+                semester_length: SemesterLength::NORMAL,
+                timetable: None,
+                high_scores: None,
+            }
+        );
+        let mut count = 0;
+        let result = reader.parse_all(|_| {
+            count += 1;
+            count < 2
+        });
+        assert_matches!(result, Err(ReplayReaderError::Interrupted));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_replays() {
+        assert!(!is_replay("4r2↓"));
+        assert_matches!(
+            ReplayReader::new("4r2↓"),
+            Err(ReplayReaderError::InvalidSignature)
+        );
+        assert_matches!(
+            ReplayReader::new("MMHEROES REPLAY 2\n"),
+            Err(ReplayReaderError::UnsupportedVersion)
+        );
+        assert_matches!(
+            ReplayReader::new("MMHEROES REPLAY 1\nengine 0.1.0\nmode normal\n\n"),
+            Err(ReplayReaderError::MissingField("seed"))
+        );
+        assert_matches!(
+            ReplayReader::new("MMHEROES REPLAY 1\nengine 0.1.0\nseed 1\nmode normal\n"),
+            Err(ReplayReaderError::MissingField("body"))
+        );
+        assert_matches!(
+            ReplayReader::new("MMHEROES REPLAY 1\nengine 0.1.0\nseed -1\n"),
+            Err(ReplayReaderError::InvalidLine { line: 3 })
+        );
+        assert_matches!(
+            ReplayReader::new("MMHEROES REPLAY 1\nengine 0.1.0\nseed 1\ndays 15\n\n"),
+            Err(ReplayReaderError::InvalidLine { line: 4 })
+        );
+        assert_matches!(
+            ReplayReader::new(
+                "MMHEROES REPLAY 1\nengine 0.1.0\nseed 1\nmode normal\nscore 1 A\n\n"
+            ),
+            Err(ReplayReaderError::MissingField("score"))
+        );
+
+        let mut reader = ReplayReader::new(
+            "MMHEROES REPLAY 1\nengine 0.1.0\nseed 1\nmode god\n\nr\n#xyz",
+        )
+        .unwrap();
+        assert_matches!(
+            reader.parse_all(|_| true),
+            Err(ReplayReaderError::InvalidLine { line: 7 })
+        );
+        let mut reader =
+            ReplayReader::new("MMHEROES REPLAY 1\nengine 0.1.0\nseed 1\nmode god\n\nr!")
+                .unwrap();
+        assert_matches!(
+            reader.parse_all(|_| true),
+            Err(ReplayReaderError::InvalidInput {
+                line: 6,
+                error: InputRecordingParserError::UnknownCharacter { grapheme: 1 }
+            })
+        );
+    }
+
     #[test]
     fn test_parsing_unexpected_eof() {
         let input = "↓↓2r13";
-        let mut parser = InputRecordingParser::new(&input);
+        let mut parser = InputRecordingParser::new(input);
         let mut parsed_input = Vec::new();
 
         let result = parser.parse_all(|input| {
@@ -265,7 +1176,7 @@ mod tests {
     #[test]
     fn test_parsing_unknown_character() {
         let input = "↓2r3!r";
-        let mut parser = InputRecordingParser::new(&input);
+        let mut parser = InputRecordingParser::new(input);
         let mut parsed_input = Vec::new();
 
         let result = parser.parse_all(|input| {
@@ -1,4 +1,5 @@
-use crate::ui::{Color, Milliseconds, WaitingState, TERMINAL_WIDTH};
+use crate::logic::Rules;
+use crate::ui::{Color, Locale, Milliseconds, WaitingState, TERMINAL_WIDTH};
 
 use core::fmt::Write;
 
@@ -30,15 +31,23 @@ pub(in crate::ui) struct Renderer<C> {
     request_consumer: C,
     line: Line,
     column: Column,
+    locale: Locale,
+    rules: &'static Rules,
     pub(in crate::ui) waiting_state: Option<WaitingState>,
 }
 
 impl<C: RendererRequestConsumer> Renderer<C> {
-    pub(in crate::ui) fn new(request_callback: C) -> Self {
+    pub(in crate::ui) fn new(
+        request_callback: C,
+        locale: Locale,
+        rules: &'static Rules,
+    ) -> Self {
         Renderer {
             request_consumer: request_callback,
             line: 0,
             column: 0,
+            locale,
+            rules,
             waiting_state: None,
         }
     }
@@ -47,6 +56,19 @@ impl<C: RendererRequestConsumer> Renderer<C> {
         &self.request_consumer
     }
 
+    pub(in crate::ui) fn request_consumer_mut(&mut self) -> &mut C {
+        &mut self.request_consumer
+    }
+
+    pub(in crate::ui) fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Правила игры. Нужны там, где выводятся цены, а состояния игры под рукой нет.
+    pub(in crate::ui) fn rules(&self) -> &'static Rules {
+        self.rules
+    }
+
     pub(in crate::ui) fn clear_screen(&mut self) {
         self.column = 0;
         self.line = 0;
@@ -123,6 +145,7 @@ impl<C: RendererRequestConsumer> Renderer<C> {
 mod tests {
     use super::*;
 
+    #[allow(clippy::upper_case_acronyms)]
     struct RRC {
         strings: Vec<String>,
     }
@@ -146,7 +169,7 @@ mod tests {
     #[allow(clippy::write_literal)]
     #[test]
     fn test_write() {
-        let mut r = Renderer::new(RRC::new());
+        let mut r = Renderer::new(RRC::new(), Locale::Russian, &Rules::DEFAULT);
         write!(r, "Hello, world!\n{} {}", 123, "string");
         assert_eq!(r.request_consumer.strings, ["Hello, world!\n123 string"]);
     }
@@ -0,0 +1,125 @@
+//! Проверка того, что повтор воспроизводится так же, как игра, которую записали.
+//!
+//! Во время записи повтора в него записываются хэши состояния игры в контрольных
+//! точках (см. [`ReplayWriter::record_checkpoint`]). При проверке повтор
+//! воспроизводится заново, и в тех же местах хэш состояния сравнивается
+//! с записанным. Так изменение поведения игры (например, после рефакторинга)
+//! обнаруживается на первом же шаге, где оно повлияло на состояние, а не только
+//! на тех экранах, которые проверяют тесты.
+//!
+//! [`ReplayWriter::record_checkpoint`]: crate::ui::recording::ReplayWriter::record_checkpoint
+
+use crate::logic::{create_game, StateHolder};
+use crate::ui::recording::{
+    NoInputRecording, ReplayEvent, ReplayReader, ReplayReaderError,
+};
+use crate::ui::renderer::{RendererRequest, RendererRequestConsumer};
+use crate::ui::{GameUI, Locale};
+
+/// Место, где повтор впервые разошёлся с записью.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReplayDivergence {
+    /// Хэш состояния в контрольной точке с номером `checkpoint` (начиная с 0)
+    /// не совпал с записанным. `actual` — `None`, если в этот момент на экране
+    /// не главное меню локации или в игре произошла ошибка.
+    StateHash {
+        step: usize,
+        checkpoint: usize,
+        expected: u64,
+        actual: Option<u64>,
+    },
+
+    /// Игра закончилась, а в повторе ещё остались нажатия.
+    GameEnded { step: usize },
+}
+
+impl ReplayDivergence {
+    /// Сколько нажатий из повтора было воспроизведено к моменту расхождения.
+    pub fn step(&self) -> usize {
+        match *self {
+            ReplayDivergence::StateHash { step, .. } => step,
+            ReplayDivergence::GameEnded { step } => step,
+        }
+    }
+}
+
+/// Результат проверки повтора.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ReplayVerification {
+    /// Сколько нажатий было воспроизведено.
+    pub steps: usize,
+
+    /// Сколько контрольных точек было проверено.
+    pub checkpoints: usize,
+
+    /// Первое расхождение с записью. `None`, если повтор воспроизвёлся в точности.
+    pub divergence: Option<ReplayDivergence>,
+}
+
+struct NullRendererRequestConsumer;
+
+impl RendererRequestConsumer for NullRendererRequestConsumer {
+    fn consume_request(&mut self, _request: RendererRequest) {}
+}
+
+/// Воспроизводит повтор `replay` с зерном, режимами, продолжительностью сессии,
+/// расписанием и таблицей рекордов из его заголовка и сверяет состояние игры с записанными
+/// хэшами. Воспроизведение останавливается на первом расхождении.
+///
+/// Возвращает ошибку, только если повтор не удалось прочитать.
+pub fn verify_replay(replay: &str) -> Result<ReplayVerification, ReplayReaderError> {
+    let mut reader = ReplayReader::new(replay)?;
+    let header = reader.header().clone();
+    let mut state_holder = StateHolder::new(header.mode)
+        .with_rng_mode(header.rng_mode)
+        .with_semester_length(header.semester_length);
+    if let Some(timetable) = header.timetable {
+        state_holder = state_holder.with_timetable(timetable);
+    }
+    let game = core::pin::pin!(create_game(header.seed, &state_holder));
+    let mut game_ui = GameUI::new(
+        &state_holder,
+        game,
+        header.seed,
+        header.high_scores,
+        NullRendererRequestConsumer,
+        None::<NoInputRecording>,
+        Locale::Russian,
+    );
+
+    let mut verification = ReplayVerification {
+        steps: 0,
+        checkpoints: 0,
+        divergence: None,
+    };
+    let mut game_is_over = false;
+    let result = reader.parse_all(|event| {
+        let step = verification.steps;
+        match event {
+            ReplayEvent::Input(_) if game_is_over => {
+                verification.divergence = Some(ReplayDivergence::GameEnded { step });
+            }
+            ReplayEvent::Input(input) => {
+                game_is_over = !game_ui.continue_game(input);
+                verification.steps += 1;
+            }
+            ReplayEvent::StateHash(expected) => {
+                let actual = game_ui.state_hash();
+                if actual != Some(expected) {
+                    verification.divergence = Some(ReplayDivergence::StateHash {
+                        step,
+                        checkpoint: verification.checkpoints,
+                        expected,
+                        actual,
+                    });
+                }
+                verification.checkpoints += 1;
+            }
+        }
+        verification.divergence.is_none()
+    });
+    match result {
+        Ok(()) | Err(ReplayReaderError::Interrupted) => Ok(verification),
+        Err(error) => Err(error),
+    }
+}
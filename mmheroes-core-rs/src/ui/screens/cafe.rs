@@ -11,9 +11,16 @@ pub(in crate::ui) fn display_cafe(
     r.move_cursor_to(7, 0);
 
     let (line, prompt) = match state.location() {
-        Location::PUNK => (10, "Что брать будем?"),
-        Location::PDMI => (9, "Что брать будем?"),
-        Location::Mausoleum => (10, "Выбери себе способ \"культурного отдыха\"."),
+        Location::PUNK => (10, l10n!(r, "Что брать будем?", "What are we having?")),
+        Location::PDMI => (9, l10n!(r, "Что брать будем?", "What are we having?")),
+        Location::Mausoleum => (
+            10,
+            l10n!(
+                r,
+                "Выбери себе способ \"культурного отдыха\".",
+                "Choose your way of \"cultural leisure\".",
+            ),
+        ),
         _ => unreachable!(),
     };
     writeln_colored!(White, r, "{}", prompt);
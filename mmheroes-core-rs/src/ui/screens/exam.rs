@@ -2,12 +2,13 @@ use crate::logic::scene_router::exams::{
     BenefitsOfRunning, EnglishExamFeeling, EnglishExamFeelingMoney, ExamIntro, ExamScene,
 };
 use crate::logic::{Action, GameState, Subject};
+use crate::random::Rng;
 use crate::ui::dialog::dialog;
 use crate::ui::renderer::{Renderer, RendererRequestConsumer};
 use crate::ui::screens::scene_router;
 use crate::ui::{
     classmate_name, problems_inflected, professor_name, screens, sleep, wait_for_any_key,
-    Color, Milliseconds, WaitingState,
+    Color, Locale, Milliseconds, WaitingState,
 };
 
 pub(in crate::ui) fn display_exam_intro(
@@ -17,7 +18,7 @@ pub(in crate::ui) fn display_exam_intro(
     r.clear_screen();
     match intro {
         ExamIntro::AlgebraPunkBigCrowdedRoom => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 Green,
                 r,
                 "\
@@ -27,11 +28,19 @@ pub(in crate::ui) fn display_exam_intro(
 (кажется, прикладники со второго курса).
 За столом около доски сидит М. А. Всемирнов
 и принимает зачет у студентов.
-Ты решаешь не терять времени даром и присоединиться к остальным."
+Ты решаешь не терять времени даром и присоединиться к остальным.",
+                "\
+A big lecture hall meant for the whole year seems packed with people.
+Not only your groupmates are here,
+but also some people you barely know
+(looks like second-year applied math students).
+M. A. Vsemirnov sits at the desk near the blackboard
+taking the test from students.
+You decide not to waste time and join the others."
             );
         }
         ExamIntro::AlgebraPunkWrongRoom => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 Green,
                 r,
                 "\
@@ -40,33 +49,50 @@ pub(in crate::ui) fn display_exam_intro(
 Сие своебразие проявляется, в первую очередь, значком
 с надписью: \"НЕ СТРЕЛЯЕЙТЕ В ПРЕПОДА - ОБУЧАЕТ КАК УМЕЕТ\".
 \"А вы к кому? Максим Александрович в аудитории напротив!\"
-Похоже, ты не туда попал. Ты извиняешься и идешь к Всемирнову."
+Похоже, ты не туда попал. Ты извиняешься и идешь к Всемирнову.",
+                "\
+You enter a small room packed with people.
+A rather peculiar teacher sits near the blackboard.
+The peculiarity shows, first of all, in a badge
+that reads: \"DON'T SHOOT THE TEACHER - HE TEACHES AS BEST HE CAN\".
+\"Who are you looking for? Maxim Alexandrovich is across the hall!\"
+Looks like you've got the wrong room. You apologize and go to Vsemirnov."
             )
         }
         ExamIntro::AlgebraPdmi => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 Red,
                 r,
                 "\
 Маленький кабинет в ПОМИ заполнен людьми.
 И, как ни странно, почти все они хотят одного и того же.
 Похоже, ты тоже хочешь именно этого -
-РАЗДЕЛАТЬСЯ НАКОНЕЦ С ЗАЧЕТОМ ПО АЛГЕБРЕ!"
+РАЗДЕЛАТЬСЯ НАКОНЕЦ С ЗАЧЕТОМ ПО АЛГЕБРЕ!",
+                "\
+A small office in PDMI is full of people.
+And, oddly enough, almost all of them want the same thing.
+Looks like you want exactly that too -
+TO BE DONE WITH THE ALGEBRA TEST AT LAST!"
             )
         }
         ExamIntro::Calculus => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 CyanBright,
                 r,
                 "\
 В обычной \"групповой\" аудитории сидят около 15 человек.
 В центре их внимания находится Е.С. Дубцов,
 принимающий зачет по матанализу.
-Ты получаешь задание и садишься за свободную парту."
+Ты получаешь задание и садишься за свободную парту.",
+                "\
+About 15 people sit in an ordinary \"group\" classroom.
+The center of their attention is E.S. Dubtsov,
+taking the calculus test.
+You get your task and sit down at a free desk."
             );
         }
         ExamIntro::GeometryPunk => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 BlueBright,
                 r,
                 "\
@@ -76,11 +102,19 @@ pub(in crate::ui) fn display_exam_intro(
 В центре всего этого хаоса находится
 (или, скорее, постоянно перемещается)
 Подкорытов-младший.
-Ты радуешься, что смог застать его на факультете!"
+Ты радуешься, что смог застать его на факультете!",
+                "\
+A small, half-empty classroom.
+The blackboard, the walls and, it seems, even the floor
+are covered with all kinds of geometric statements.
+In the middle of all this chaos is
+(or rather, keeps moving around)
+Podkorytov Jr.
+You are glad you managed to catch him at the faculty!"
             )
         }
         ExamIntro::GeometryPdmi => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
                 "\
@@ -88,18 +122,25 @@ pub(in crate::ui) fn display_exam_intro(
 Кроме них, в комнате ты видишь Подкорытова-младшего,
 а также - полного седоволосого лысеющего господина,
 издающего характерные пыхтящие звуки.
-Ты надеешься, что все это скоро кончится..."
+Ты надеешься, что все это скоро кончится...",
+                "\
+About 10 students have gathered in a small PDMI office.
+Besides them, you see Podkorytov Jr. in the room,
+and also a stout, gray-haired, balding gentleman
+making characteristic puffing sounds.
+You hope all this will be over soon..."
             )
         }
         ExamIntro::ComputerScience => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
-                "Климов А.А. сидит и тоскует по халявному Inet'у."
+                "Климов А.А. сидит и тоскует по халявному Inet'у.",
+                "Klimov A.A. sits there pining for free Internet."
             );
         }
         ExamIntro::English => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 YellowBright,
                 r,
                 "\
@@ -110,25 +151,50 @@ pub(in crate::ui) fn display_exam_intro(
 Рядом с небольшой доской висит изображение Эйфелевой башни,
 чуть дальше - странное изображение,
 обладающее непостижимым метафизическим смыслом.
-Похоже, сейчас ты будешь сдавать зачет по английскому."
+Похоже, сейчас ты будешь сдавать зачет по английскому.",
+                "\
+On the third floor of the Math & Mech building,
+in one of the rooms of the foreign languages department,
+N.P. Vlaschenko has settled in.
+The walls of the room look somehow strange.
+Next to a small blackboard hangs a picture of the Eiffel Tower,
+a bit further - a strange picture
+with an unfathomable metaphysical meaning.
+Looks like you are about to take the English test."
             );
         }
         ExamIntro::PhysicalEducation(lecture) => {
             r.set_color(Color::WhiteBright, Color::Black);
             if let Some(lecture_topic) = lecture {
                 let topic_text = match lecture_topic {
-                    BenefitsOfRunning::NationalEconomy => "для народного хозяйства",
-                    BenefitsOfRunning::PersonalLife => "для личной жизни",
-                    BenefitsOfRunning::ScientificResearch => "для научной работы",
+                    BenefitsOfRunning::NationalEconomy => {
+                        l10n!(r, "для народного хозяйства", "for the national economy")
+                    }
+                    BenefitsOfRunning::PersonalLife => {
+                        l10n!(r, "для личной жизни", "for your personal life")
+                    }
+                    BenefitsOfRunning::ScientificResearch => {
+                        l10n!(r, "для научной работы", "for scientific research")
+                    }
                     BenefitsOfRunning::BuildingCommunism => {
-                        "для коммунистического строительства"
+                        l10n!(
+                            r,
+                            "для коммунистического строительства",
+                            "for building communism"
+                        )
+                    }
+                    BenefitsOfRunning::StudyAndEntertainment => {
+                        l10n!(r, "для учебы и досуга", "for study and leisure")
                     }
-                    BenefitsOfRunning::StudyAndEntertainment => "для учебы и досуга",
                     BenefitsOfRunning::EscapingFromInspectors => {
-                        "для спасения от контроллеров"
+                        l10n!(
+                            r,
+                            "для спасения от контроллеров",
+                            "for escaping from ticket inspectors"
+                        )
                     }
                 };
-                writeln!(
+                writeln_l10n!(
                     r,
                     "\
 Альбинский проводит лекцию о пользе бега
@@ -136,17 +202,29 @@ pub(in crate::ui) fn display_exam_intro(
 
 Похоже, он, как всегда, немного увлекся.
 Немного в нашем случае - 1 час.
+",
+                    "\
+Albinsky gives a lecture on the benefits of running
+{}.
+
+Looks like he got a little carried away, as usual.
+A little in our case means 1 hour.
 ",
                     topic_text
                 );
             }
-            writeln!(
+            writeln_l10n!(
                 r,
                 "\
 Альбинский просит тебя замерить пульс.
 Назвав первое пришедшее в замученную математикой голову число,
 ты отправляешься мотать круги в парке,
-в котором, вообще-то, \"запрещены спортивные мероприятия\"."
+в котором, вообще-то, \"запрещены спортивные мероприятия\".",
+                "\
+Albinsky asks you to take your pulse.
+Having named the first number that came to your math-tortured head,
+you go off running laps in the park,
+where, by the way, \"sports events are prohibited\"."
             )
         }
     }
@@ -157,7 +235,7 @@ pub(in crate::ui) fn display_exam_intro(
 
 pub(in crate::ui) fn display_exam(
     r: &mut Renderer<impl RendererRequestConsumer>,
-    rng: &mut crate::random::Rng,
+    rng: &mut impl Rng,
     available_actions: &[Action],
     scene: &ExamScene,
 ) -> WaitingState {
@@ -187,11 +265,12 @@ pub(in crate::ui) fn display_exam(
         }
         ExamScene::ClassmateWantsSomething(state, _, classmate) => {
             writeln!(r);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
                 "К тебе пристает {}. Что будешь делать?",
-                classmate_name(*classmate)
+                "{} is pestering you. What will you do?",
+                classmate_name(r.locale(), *classmate)
             );
             let line = r.get_cursor_position().0 + 2;
             scene_router::display_short_today_timetable(r, line, state);
@@ -201,19 +280,40 @@ pub(in crate::ui) fn display_exam(
         ExamScene::IgnoredClassmate { feeling_bad } => {
             if *feeling_bad {
                 r.move_cursor_to(21, 0);
-                writeln_colored!(White, r, "Тебе как-то нехорошо ...");
+                writeln_colored_l10n!(
+                    White,
+                    r,
+                    "Тебе как-то нехорошо ...",
+                    "You feel kind of unwell ..."
+                );
             }
             wait_for_any_key(r)
         }
         ExamScene::ProfessorLeaves(_, subject) => {
             r.move_cursor_to(22, 0);
-            write_colored!(RedBright, r, "{} уходит", professor_name(*subject));
+            write_colored_l10n!(
+                RedBright,
+                r,
+                "{} уходит",
+                "{} is leaving",
+                professor_name(r.locale(), *subject)
+            );
             wait_for_any_key(r)
         }
         ExamScene::PromptExamInTrain(state, subject) => {
             r.move_cursor_to(11, 0);
-            writeln_colored!(RedBright, r, "{} уходит.", professor_name(*subject));
-            writeln!(r, "Пойти за ним на электричку?");
+            writeln_colored_l10n!(
+                RedBright,
+                r,
+                "{} уходит.",
+                "{} is leaving.",
+                professor_name(r.locale(), *subject)
+            );
+            writeln_l10n!(
+                r,
+                "Пойти за ним на электричку?",
+                "Follow them to the train?"
+            );
             scene_router::display_short_today_timetable(r, 11, state);
             r.move_cursor_to(14, 0);
             dialog(r, available_actions)
@@ -233,7 +333,12 @@ pub(in crate::ui) fn display_exam(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(13, 0);
-            writeln_colored!(White, r, "Всемирнов принимает зачет даже в электричке!");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Всемирнов принимает зачет даже в электричке!",
+                "Vsemirnov takes the test even on the train!"
+            );
             display_suffering(r, *solved_problems, false)
         }
         ExamScene::CaughtByInspectorsEmptyScreenBug => {
@@ -242,11 +347,12 @@ pub(in crate::ui) fn display_exam(
         }
         ExamScene::ProfessorLingers(_, subject) => {
             r.move_cursor_to(22, 0);
-            write_colored!(
+            write_colored_l10n!(
                 RedBright,
                 r,
                 "{} задерживается еще на час.",
-                professor_name(*subject)
+                "{} stays for another hour.",
+                professor_name(r.locale(), *subject)
             );
             wait_for_any_key(r)
         }
@@ -254,126 +360,231 @@ pub(in crate::ui) fn display_exam(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(7, 0);
-            writeln_colored!(White, r, "Всемирнов медленно рисует минус ...");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Всемирнов медленно рисует минус ...",
+                "Vsemirnov slowly draws a minus ..."
+            );
             sleep(r, Milliseconds(1000));
-            writeln!(
+            writeln_l10n!(
                 r,
-                "И так же медленно пририсовывает к нему вертикальную палочку!"
+                "И так же медленно пририсовывает к нему вертикальную палочку!",
+                "And just as slowly adds a vertical stroke to it!"
+            );
+            writeln_l10n!(r, "Уф! Ну и шуточки у него!", "Phew! What a joker!");
+            writeln_l10n!(
+                r,
+                "Хорошо хоть, зачет поставил...",
+                "At least he passed you..."
             );
-            writeln!(r, "Уф! Ну и шуточки у него!");
-            writeln!(r, "Хорошо хоть, зачет поставил...");
             wait_for_any_key(r)
         }
         ExamScene::EnglishExamPassed(state, feeling) => {
             display_exam_header(r, state, Subject::English);
             r.move_cursor_to(8, 0);
-            write_colored!(White, r, "Влащенко Н.П.:");
-            writeln_colored!(WhiteBright, r, "\"Закройте глаза ...\"");
-            writeln_colored!(White, r, "Ты послушно закрываешь глаза.");
+            write_colored_l10n!(White, r, "Влащенко Н.П.:", "Vlaschenko N.P.:");
+            writeln_colored_l10n!(
+                WhiteBright,
+                r,
+                "\"Закройте глаза ...\"",
+                "\"Close your eyes ...\""
+            );
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Ты послушно закрываешь глаза.",
+                "You obediently close your eyes."
+            );
             sleep(r, Milliseconds(1000));
-            writeln_colored!(WhiteBright, r, "\"Октройте глаза ...\"");
+            writeln_colored_l10n!(
+                WhiteBright,
+                r,
+                "\"Октройте глаза ...\"",
+                "\"Oppen your eyes ...\""
+            );
             writeln_random_color(
                 r,
                 rng,
-                "Ты видишь Влащенко Н.П. в костюме сказочной феи.",
+                l10n!(
+                    r,
+                    "Ты видишь Влащенко Н.П. в костюме сказочной феи.",
+                    "You see Vlaschenko N.P. in a fairy costume."
+                ),
             );
             writeln_random_color(
                 r,
                 rng,
-                "Влащенко Н.П. касается тебя указкой (она же - волшебная палочка ...)",
+                l10n!(r, "Влащенко Н.П. касается тебя указкой (она же - волшебная палочка ...)", "Vlaschenko N.P. touches you with a pointer (a magic wand, that is ...)"),
             );
             writeln_random_color(
                 r,
                 rng,
-                "Ты чувствуешь, что с тобой происходит что-то сверхъестественное.",
+                l10n!(
+                    r,
+                    "Ты чувствуешь, что с тобой происходит что-то сверхъестественное.",
+                    "You feel something supernatural is happening to you."
+                ),
             );
             match feeling {
                 EnglishExamFeeling::ReallyBad => {
-                    writeln_random_color(r, rng, "Тебе сильно поплохело.");
+                    writeln_random_color(
+                        r,
+                        rng,
+                        l10n!(r, "Тебе сильно поплохело.", "You feel really sick."),
+                    );
                 }
                 EnglishExamFeeling::SomeplaceElse => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Ты почувствовал себя где-то в другом месте.",
+                        l10n!(
+                            r,
+                            "Ты почувствовал себя где-то в другом месте.",
+                            "You felt like you were someplace else."
+                        ),
                     );
                 }
                 EnglishExamFeeling::ForgotAlgebra => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Ты чувствуешь, что подзабыл алгебру...",
+                        l10n!(
+                            r,
+                            "Ты чувствуешь, что подзабыл алгебру...",
+                            "You feel you have forgotten some algebra..."
+                        ),
                     );
                 }
                 EnglishExamFeeling::ForgotCalculus => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Ты чувствуешь, что анализ придется учить заново.",
+                        l10n!(
+                            r,
+                            "Ты чувствуешь, что анализ придется учить заново.",
+                            "You feel you will have to learn calculus all over again."
+                        ),
                     );
                 }
                 EnglishExamFeeling::ThoughtsAboutFairies => {
                     writeln_random_color(
                         r,
                         rng,
-                        "В голову постоянно лезут мысли о всяких феях...",
+                        l10n!(
+                            r,
+                            "В голову постоянно лезут мысли о всяких феях...",
+                            "You keep thinking about all sorts of fairies..."
+                        ),
                     );
                 }
                 EnglishExamFeeling::EveryoneWantsYouDead => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Ты чувствуешь, что все вокруг жаждут твоей смерти.",
+                        l10n!(
+                            r,
+                            "Ты чувствуешь, что все вокруг жаждут твоей смерти.",
+                            "You feel everyone around wants you dead."
+                        ),
                     );
                 }
                 EnglishExamFeeling::StaminaGone => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Куда-то подевалась твоя уверенность в себе.",
+                        l10n!(
+                            r,
+                            "Куда-то подевалась твоя уверенность в себе.",
+                            "Your self-confidence has gone somewhere."
+                        ),
                     );
                 }
                 EnglishExamFeeling::BrainGotBetter => {
-                    writeln_random_color(r, rng, "Голова стала работать заметно лучше.");
+                    writeln_random_color(
+                        r,
+                        rng,
+                        l10n!(
+                            r,
+                            "Голова стала работать заметно лучше.",
+                            "Your head works noticeably better now."
+                        ),
+                    );
                 }
                 EnglishExamFeeling::LoveForTheWorld => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Ты проникся любовью к окружающему миру.",
+                        l10n!(
+                            r,
+                            "Ты проникся любовью к окружающему миру.",
+                            "You are filled with love for the world around you."
+                        ),
                     );
                 }
                 EnglishExamFeeling::ReadyForEverything => {
-                    writeln_random_color(r, rng, "Ты готов к любым испытаниям.");
+                    writeln_random_color(
+                        r,
+                        rng,
+                        l10n!(
+                            r,
+                            "Ты готов к любым испытаниям.",
+                            "You are ready for any challenge."
+                        ),
+                    );
                 }
                 EnglishExamFeeling::Money(EnglishExamFeelingMoney::Stolen) => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Пока твои глаза были закрыты, кто-то утащил твои деньги!!!",
+                        l10n!(
+                            r,
+                            "Пока твои глаза были закрыты, кто-то утащил твои деньги!!!",
+                            "While your eyes were closed, somebody stole your money!!!"
+                        ),
                     );
                 }
                 EnglishExamFeeling::Money(EnglishExamFeelingMoney::Found) => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Ты нашел в своем кармане какие-то деньги!",
+                        l10n!(
+                            r,
+                            "Ты нашел в своем кармане какие-то деньги!",
+                            "You found some money in your pocket!"
+                        ),
                     );
                 }
                 EnglishExamFeeling::SmellOfGarlic => {
                     writeln_random_color(
                         r,
                         rng,
-                        "Ты чувствуешь, что от тебя сильно несет чесноком.",
+                        l10n!(
+                            r,
+                            "Ты чувствуешь, что от тебя сильно несет чесноком.",
+                            "You feel you reek of garlic."
+                        ),
                     );
                     writeln_random_color(
                         r,
                         rng,
-                        "Не знаю, выветрится ли такой сильный запах...",
+                        l10n!(
+                            r,
+                            "Не знаю, выветрится ли такой сильный запах...",
+                            "Not sure such a strong smell will ever wear off..."
+                        ),
                     );
                 }
                 EnglishExamFeeling::QuicklyFadedAway => {
-                    writeln_random_color(r, rng, "Странное чувство быстро прошло.");
+                    writeln_random_color(
+                        r,
+                        rng,
+                        l10n!(
+                            r,
+                            "Странное чувство быстро прошло.",
+                            "The strange feeling quickly passed."
+                        ),
+                    );
                 }
             }
             wait_for_any_key(r)
@@ -381,7 +592,12 @@ pub(in crate::ui) fn display_exam(
         ExamScene::ExamPassed(state, subject) => {
             display_exam_header(r, state, *subject);
             r.move_cursor_to(9, 0);
-            writeln_colored!(Green, r, "Твоя зачетка пополнилась еще одной записью.");
+            writeln_colored_l10n!(
+                Green,
+                r,
+                "Твоя зачетка пополнилась еще одной записью.",
+                "Your record book has one more entry."
+            );
             wait_for_any_key(r)
         }
     }
@@ -389,7 +605,7 @@ pub(in crate::ui) fn display_exam(
 
 fn writeln_random_color(
     r: &mut Renderer<impl RendererRequestConsumer>,
-    rng: &mut crate::random::Rng,
+    rng: &mut impl Rng,
     s: &str,
 ) {
     let colors = [
@@ -415,10 +631,15 @@ fn display_exam_header(
     if state
         .player()
         .status_for_subject(subject)
-        .solved_all_problems()
+        .solved_all_problems(state.rules())
     {
         r.move_cursor_to(6, 0);
-        write_colored!(Green, r, "У вас все зачтено, можете быть свободны.");
+        write_colored_l10n!(
+            Green,
+            r,
+            "У вас все зачтено, можете быть свободны.",
+            "You have passed everything, you may go."
+        );
     }
 }
 
@@ -429,27 +650,28 @@ fn display_exam_info(
 ) {
     display_exam_header(r, state, subject);
     r.move_cursor_to(7, 0);
-    writeln_colored!(
+    writeln_colored_l10n!(
         YellowBright,
         r,
         "Сейчас тебя истязает {}.",
-        professor_name(subject)
+        "You are being tortured by {}.",
+        professor_name(r.locale(), subject)
     );
 
     let mut num_classmates_here = state.classmates().filter_by_exam(subject).count();
     if num_classmates_here > 0 {
-        write_colored!(
-            White,
-            r,
-            "Кроме тебя, здесь еще {} ",
-            if num_classmates_here == 1 {
-                "сидит"
-            } else {
-                "сидят"
-            }
-        );
+        let intro = match (r.locale(), num_classmates_here) {
+            (Locale::Russian, 1) => "Кроме тебя, здесь еще сидит ",
+            (Locale::Russian, _) => "Кроме тебя, здесь еще сидят ",
+            (Locale::English, _) => "Also here: ",
+        };
+        write_colored!(White, r, "{}", intro);
         for classmate_info in state.classmates().filter_by_exam(subject) {
-            write!(r, "{}", classmate_name(classmate_info.classmate()));
+            write!(
+                r,
+                "{}",
+                classmate_name(r.locale(), classmate_info.classmate())
+            );
             num_classmates_here -= 1;
 
             // TODO: Сделать перенос строки не так топорно, добавить тесты
@@ -459,7 +681,7 @@ fn display_exam_info(
             if num_classmates_here == 0 {
                 writeln!(r, ".")
             } else if num_classmates_here == 1 {
-                write!(r, " и ")
+                write_l10n!(r, " и ", " and ")
             } else {
                 write!(r, ", ")
             }
@@ -475,17 +697,27 @@ pub(in crate::ui) fn display_exam_router(
 ) -> WaitingState {
     display_exam_info(r, state, subject);
     let problems_done = state.player().status_for_subject(subject).problems_done();
-    let problems_required = subject.required_problems();
+    let problems_required = state.rules().subject(subject).required_problems;
     r.move_cursor_to(6, 0);
     if problems_done == 0 {
-        writeln_colored!(White, r, "У тебя еще ничего не зачтено.")
+        writeln_colored_l10n!(
+            White,
+            r,
+            "У тебя еще ничего не зачтено.",
+            "You haven't passed anything yet."
+        )
     } else if problems_done < problems_required {
-        write_colored!(White, r, "Зачтено ");
+        write_colored_l10n!(White, r, "Зачтено ", "Passed ");
         write_colored!(WhiteBright, r, "{}", problems_done);
-        write_colored!(White, r, " задач из ");
+        write_colored_l10n!(White, r, " задач из ", " tasks of ");
         writeln_colored!(WhiteBright, r, "{}", problems_required);
     } else {
-        writeln_colored!(Green, r, "У тебя уже все зачтено.")
+        writeln_colored_l10n!(
+            Green,
+            r,
+            "У тебя уже все зачтено.",
+            "You have already passed everything."
+        )
     }
     scene_router::display_short_today_timetable(r, 11, state);
     r.move_cursor_to(11, 0);
@@ -498,22 +730,34 @@ pub(in crate::ui) fn display_suffering(
     too_smart: bool,
 ) -> WaitingState {
     if too_smart {
-        write_colored!(White, r, "Подкорытов:");
-        write_colored!(
+        write_colored_l10n!(White, r, "Подкорытов:", "Podkorytov:");
+        write_colored_l10n!(
             WhiteBright,
             r,
-            "\"Чего-то я не понимаю... Похоже, Вы меня лечите...\""
+            "\"Чего-то я не понимаю... Похоже, Вы меня лечите...\"",
+            "\"I don\'t get something... Looks like you are pulling my leg...\""
         );
     } else {
-        write_colored!(MagentaBright, r, "Мучаешься ...\n");
+        write_colored_l10n!(MagentaBright, r, "Мучаешься ...\n", "Suffering ...\n");
     }
     r.move_cursor_to(20, 0);
     if solved_problems > 0 {
-        write_colored!(Green, r, "Тебе зачли еще ");
+        write_colored_l10n!(Green, r, "Тебе зачли еще ", "You have passed ");
         write_colored!(WhiteBright, r, "{}", solved_problems);
-        write_colored!(Green, r, " {}!", problems_inflected(solved_problems));
+        write_colored_l10n!(
+            Green,
+            r,
+            " {}!",
+            " more {}!",
+            problems_inflected(r.locale(), solved_problems)
+        );
     } else {
-        write_colored!(RedBright, r, "Твои мучения были напрасны.");
+        write_colored_l10n!(
+            RedBright,
+            r,
+            "Твои мучения были напрасны.",
+            "Your suffering was in vain."
+        );
     }
     wait_for_any_key(r)
 }
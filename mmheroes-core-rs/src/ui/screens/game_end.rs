@@ -1,4 +1,4 @@
-use crate::logic::{Action, CauseOfDeath, GameState};
+use crate::logic::{Action, CauseOfDeath, GameState, Player};
 use crate::ui::{renderer::Renderer, *};
 
 pub(in crate::ui) fn display_i_am_done(
@@ -6,8 +6,18 @@ pub(in crate::ui) fn display_i_am_done(
     available_actions: &[Action],
 ) -> WaitingState {
     r.clear_screen();
-    writeln_colored!(White, r, "Ну, может не надо так резко...");
-    writeln_colored!(White, r, "Ты что, серьезно хочешь закончить игру?");
+    writeln_colored_l10n!(
+        White,
+        r,
+        "Ну, может не надо так резко...",
+        "Well, maybe don't be so hasty..."
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        "Ты что, серьезно хочешь закончить игру?",
+        "What, do you seriously want to end the game?"
+    );
     writeln!(r);
     dialog(r, available_actions)
 }
@@ -19,55 +29,139 @@ pub(in crate::ui) fn display_game_end_dead(
     use CauseOfDeath::*;
     r.clear_screen();
     r.set_color(Color::RedBright, Color::Black);
-    writeln!(r, "Легче лбом колоть орехи,");
-    writeln!(r, "чем учиться на МАТ-МЕХе.");
+    writeln_l10n!(
+        r,
+        "Легче лбом колоть орехи,",
+        "It's easier to crack nuts with your forehead"
+    );
+    writeln_l10n!(r, "чем учиться на МАТ-МЕХе.", "than to study at MATH-MECH.");
     r.set_color(Color::MagentaBright, Color::Black);
     match cause {
-        OnTheWayToPUNK => writeln!(r, "Умер по пути на факультет."),
-        OnTheWayToMausoleum => writeln!(r, "Умер по пути в мавзолей."),
-        OnTheWayToDorm => writeln!(r, "Умер по пути домой. Бывает."),
-        FellFromStairs => writeln!(r, "Упал с лестницы у главного входа."),
-        Burnout => writeln!(r, "Сгорел на работе."),
-        Overstudied => writeln!(r, "Заучился."),
-        StudiedTooWell => writeln!(r, "Зубрежка до добра не доводит!"),
-        CouldntLeaveTheComputer => writeln!(r, "Не смог расстаться с компьютером."),
-        CorpseFoundInTheTrain => writeln!(r, "В электричке нашли бездыханное тело."),
-        KilledByInspectors => writeln!(r, "Контролеры жизни лишили."),
-        FellAsleepInTheTrain => writeln!(r, "Заснул в электричке и не проснулся."),
-        SplitPersonality => writeln!(r, "Раздвоение ложной личности."),
-        BeerAlcoholism => writeln!(r, "Пивной алкоголизм, батенька..."),
-        DrankTooMuch => writeln!(r, "Спился."),
-        DrankTooMuchBeer => writeln!(r, "Губит людей не пиво, а избыток пива."),
-        Altruism => writeln!(r, "Альтруизм не довел до добра."),
-        TurnedToVegetable => writeln!(r, "Превратился в овощ."),
+        OnTheWayToPUNK => writeln_l10n!(
+            r,
+            "Умер по пути на факультет.",
+            "Died on the way to the faculty."
+        ),
+        OnTheWayToMausoleum => writeln_l10n!(
+            r,
+            "Умер по пути в мавзолей.",
+            "Died on the way to the mausoleum."
+        ),
+        OnTheWayToDorm => writeln_l10n!(
+            r,
+            "Умер по пути домой. Бывает.",
+            "Died on the way home. It happens."
+        ),
+        FellFromStairs => writeln_l10n!(
+            r,
+            "Упал с лестницы у главного входа.",
+            "Fell down the stairs at the main entrance."
+        ),
+        Burnout => writeln_l10n!(r, "Сгорел на работе.", "Burned out at work."),
+        Overstudied => writeln_l10n!(r, "Заучился.", "Overstudied."),
+        StudiedTooWell => writeln_l10n!(
+            r,
+            "Зубрежка до добра не доводит!",
+            "Cramming leads to no good!"
+        ),
+        CouldntLeaveTheComputer => writeln_l10n!(
+            r,
+            "Не смог расстаться с компьютером.",
+            "Couldn't part with the computer."
+        ),
+        CorpseFoundInTheTrain => writeln_l10n!(
+            r,
+            "В электричке нашли бездыханное тело.",
+            "A lifeless body was found on the train."
+        ),
+        KilledByInspectors => writeln_l10n!(
+            r,
+            "Контролеры жизни лишили.",
+            "The ticket inspectors took your life."
+        ),
+        FellAsleepInTheTrain => writeln_l10n!(
+            r,
+            "Заснул в электричке и не проснулся.",
+            "Fell asleep on the train and never woke up."
+        ),
+        SplitPersonality => {
+            writeln_l10n!(r, "Раздвоение ложной личности.", "Split false personality.")
+        }
+        BeerAlcoholism => writeln_l10n!(
+            r,
+            "Пивной алкоголизм, батенька...",
+            "Beer alcoholism, my dear fellow..."
+        ),
+        DrankTooMuch => writeln_l10n!(r, "Спился.", "Drank yourself to death."),
+        DrankTooMuchBeer => writeln_l10n!(
+            r,
+            "Губит людей не пиво, а избыток пива.",
+            "It's not beer that kills people, it's too much beer."
+        ),
+        Altruism => writeln_l10n!(
+            r,
+            "Альтруизм не довел до добра.",
+            "Altruism led to no good."
+        ),
+        TurnedToVegetable => {
+            writeln_l10n!(r, "Превратился в овощ.", "Turned into a vegetable.")
+        }
         TorturedByProfessor(subject) => {
+            let professor = professor_name(r.locale(), subject);
             let verb_ending = match professor_gender(subject) {
                 Gender::Male => "",
                 Gender::Female => "а",
             };
-            writeln!(r, "{} замучил{}.", professor_name(subject), verb_ending)
+            l10n!(
+                r,
+                writeln!(r, "{} замучил{}.", professor, verb_ending),
+                writeln!(r, "{} tortured you to death.", professor),
+            )
         }
-        DestroyedByVsemirnov => writeln!(r, "Всемирнов М.А. изничтожил."),
-        FairyWasNotInTheMood => writeln!(r, "Фея была явно не в настроении."),
+        DestroyedByVsemirnov => writeln_l10n!(
+            r,
+            "Всемирнов М.А. изничтожил.",
+            "Vsemirnov M.A. annihilated you."
+        ),
+        FairyWasNotInTheMood => writeln_l10n!(
+            r,
+            "Фея была явно не в настроении.",
+            "The fairy was clearly not in the mood."
+        ),
         BetterNotIgnoreClassmate(classmate) => {
-            writeln!(
+            writeln_l10n!(
                 r,
                 "{} лучше игнорировать не надо.",
-                classmate_name(classmate)
+                "{} shouldn't be ignored.",
+                classmate_name(r.locale(), classmate)
             );
         }
-        KilledByRai => writeln!(r, "RAI замочил."),
-        ExhaustedByMisha => writeln!(r, "Загонял тебя Миша."),
-        DontTalkToDjug => writeln!(r, "Не общайся с тормозами!"),
-        DjugIsDeadly => writeln!(r, "DJuG - это смертельно!"),
+        KilledByRai => writeln_l10n!(r, "RAI замочил.", "RAI wasted you."),
+        ExhaustedByMisha => writeln_l10n!(r, "Загонял тебя Миша.", "Misha wore you out."),
+        DontTalkToDjug => {
+            writeln_l10n!(r, "Не общайся с тормозами!", "Don't talk to slowpokes!")
+        }
+        DjugIsDeadly => writeln_l10n!(r, "DJuG - это смертельно!", "DJuG is deadly!"),
         AndrewCanDefendHimself => {
-            writeln!(r, "Эндрю тоже умеет отбиваться от разных нехороших людей.")
+            writeln_l10n!(
+                r,
+                "Эндрю тоже умеет отбиваться от разных нехороших людей.",
+                "Andrew also knows how to fight off all sorts of bad people."
+            )
         }
-        Paranoia => writeln!(r, "Бурно прогрессирующая паранойя."),
-        TimeOut => writeln!(r, "Время вышло."),
-        Suicide => writeln!(r, "Вышел сам."),
+        Paranoia => writeln_l10n!(
+            r,
+            "Бурно прогрессирующая паранойя.",
+            "Rapidly progressing paranoia."
+        ),
+        TimeOut => writeln_l10n!(r, "Время вышло.", "Time is up."),
+        Suicide => writeln_l10n!(r, "Вышел сам.", "Quit on your own."),
         SoftwareBug => {
-            writeln!(r, "Раздавлен безжалостной ошибкой в программе.")
+            writeln_l10n!(
+                r,
+                "Раздавлен безжалостной ошибкой в программе.",
+                "Crushed by a ruthless bug in the program."
+            )
         }
     }
     wait_for_any_key(r)
@@ -75,21 +169,54 @@ pub(in crate::ui) fn display_game_end_dead(
 
 fn display_game_end_alive(
     r: &mut Renderer<impl RendererRequestConsumer>,
+    player: &Player,
 ) -> WaitingState {
     r.clear_screen();
-    // TODO: Display proper text based on the final state
-    // (cause of expelling, or congratulation)
-    writeln_colored!(MagentaBright, r, "Уффффф! Во всяком случае, ты еще живой.");
-    writeln!(r);
-    write_colored!(RedBright, r, "У тебя нет целых ");
-    write_colored!(
-        WhiteBright,
-        r,
-        "{}",
-        6 /* TODO: actual number of exams remaining */
-    );
-    writeln_colored!(RedBright, r, " зачетов!");
-    writeln_colored!(MagentaBright, r, "ТЫ ОТЧИСЛЕН!");
+    let exams_left = player.exams_left();
+    if exams_left > 0 {
+        writeln_colored_l10n!(
+            MagentaBright,
+            r,
+            "Уффффф! Во всяком случае, ты еще живой.",
+            "Phewww! At any rate, you're still alive."
+        );
+        writeln!(r);
+        // В оригинале «целых» и «зачетов» стоят при любом числе больше одного.
+        let (adjective, exams) = match exams_left {
+            1 => ("целого", l10n!(r, "зачета", "exam")),
+            _ => ("целых", l10n!(r, "зачетов", "exams")),
+        };
+        l10n!(
+            r,
+            write_colored!(RedBright, r, "У тебя нет {} ", adjective),
+            write_colored!(RedBright, r, "You haven't passed "),
+        );
+        write_colored!(WhiteBright, r, "{}", exams_left);
+        writeln_colored!(RedBright, r, " {}!", exams);
+        writeln_colored_l10n!(MagentaBright, r, "ТЫ ОТЧИСЛЕН!", "YOU ARE EXPELLED!");
+    } else {
+        writeln_colored_l10n!(
+            Green,
+            r,
+            "Поздравляю: ты можешь считать себя настоящим героем Мат-Меха!",
+            "Congratulations: you can consider yourself a true hero of MathMech!"
+        );
+        writeln!(r);
+        writeln_colored_l10n!(
+            YellowBright,
+            r,
+            "Ты успешно сдал все зачеты.",
+            "You have successfully passed all the exams."
+        );
+        write_colored_l10n!(
+            YellowBright,
+            r,
+            "За время сессии ты заработал ",
+            "During the exam week you have earned "
+        );
+        write_colored!(WhiteBright, r, "{}", player.score());
+        writeln_colored_l10n!(YellowBright, r, " руб.", " rub.");
+    }
 
     wait_for_any_key(r)
 }
@@ -101,7 +228,7 @@ pub(in crate::ui) fn display_game_end(
     if let Some(cause_of_death) = state.player().cause_of_death() {
         display_game_end_dead(r, cause_of_death)
     } else {
-        display_game_end_alive(r)
+        display_game_end_alive(r, state.player())
     }
 }
 
@@ -110,7 +237,7 @@ pub(in crate::ui) fn display_wanna_try_again(
     available_actions: &[Action],
 ) -> WaitingState {
     r.clear_screen();
-    writeln_colored!(White, r, "Хочешь попробовать еще?");
+    writeln_colored_l10n!(White, r, "Хочешь попробовать еще?", "Want to try again?");
     writeln!(r);
     writeln!(r);
 
@@ -124,44 +251,67 @@ pub(in crate::ui) fn display_disclaimer(
     writeln_colored!(Green, r, "DISCLAIMER");
     writeln!(r);
     r.set_color(Color::BlueBright, Color::Black);
-    writeln!(
+    writeln_l10n!(
         r,
-        "1.) Все персонажи реальны. Эта программа является лишь неким отражением"
+        "1.) Все персонажи реальны. Эта программа является лишь неким отражением",
+        "1.) All characters are real. This program is merely a reflection"
     );
-    writeln!(r, "    мнения ее автора об окружающей действительности.");
-    writeln!(
+    writeln_l10n!(
         r,
-        "    Автор не ставил цели оценить чью-либо линию поведения."
+        "    мнения ее автора об окружающей действительности.",
+        "    of its author's opinion about the surrounding reality."
+    );
+    writeln_l10n!(
+        r,
+        "    Автор не ставил цели оценить чью-либо линию поведения.",
+        "    The author did not aim to judge anyone's behavior."
     );
     writeln!(r);
-    writeln!(
+    writeln_l10n!(
+        r,
+        "2.) Почти все события реальны. Естественно, многие из них",
+        "2.) Almost all events are real. Naturally, many of them"
+    );
+    writeln_l10n!(
         r,
-        "2.) Почти все события реальны. Естественно, многие из них"
+        "    представлены в несколько аллегорическом виде.",
+        "    are presented in a somewhat allegorical form."
     );
-    writeln!(r, "    представлены в несколько аллегорическом виде.");
     writeln!(r);
-    writeln!(
+    writeln_l10n!(
         r,
-        "3.) Все совпадения с другими реальными зачетными неделями,"
+        "3.) Все совпадения с другими реальными зачетными неделями,",
+        "3.) Any coincidences with other real exam weeks"
     );
-    writeln!(
+    writeln_l10n!(
         r,
-        "    проведенными кем-либо в каком-либо ВУЗе, лишь подчеркивают"
+        "    проведенными кем-либо в каком-либо ВУЗе, лишь подчеркивают",
+        "    spent by anyone at any university only emphasize"
+    );
+    writeln_l10n!(
+        r,
+        "    реалистичность взглядов автора на реальность.",
+        "    the realism of the author's views on reality."
     );
-    writeln!(r, "    реалистичность взглядов автора на реальность.");
     writeln!(r);
     writeln!(r);
     r.set_color(Color::RedBright, Color::Black);
-    writeln!(
+    writeln_l10n!(
+        r,
+        "*.) Если вы нашли в данной программе ошибку (любую, включая опечатки),",
+        "*.) If you have found a bug in this program (any, including typos),"
+    );
+    writeln_l10n!(
         r,
-        "*.) Если вы нашли в данной программе ошибку (любую, включая опечатки),"
+        "    Ваши комментарии будут очень полезны.",
+        "    your comments will be very helpful."
     );
-    writeln!(r, "    Ваши комментарии будут очень полезны.");
     writeln!(r);
     r.set_color(Color::Gray, Color::Black);
-    writeln!(
+    writeln_l10n!(
         r,
-        "Автор не несет ответственность за психическое состояние игрока."
+        "Автор не несет ответственность за психическое состояние игрока.",
+        "The author is not responsible for the player's mental state."
     );
 
     wait_for_any_key(r)
@@ -175,12 +325,16 @@ pub(in crate::ui) fn display_software_bug<StepLog: Display>(
 ) -> WaitingState {
     r.clear_screen();
     r.set_color(Color::WhiteBright, Color::Gray);
-    writeln!(r, "В программе буга!");
+    writeln_l10n!(r, "В программе буга!", "There's a bug in the program!");
     writeln!(r, "{cause}");
-    writeln!(r, "Зерно: {seed}");
+    writeln_l10n!(r, "Зерно: {seed}", "Seed: {seed}");
     if let Some(steps) = steps {
-        writeln!(r, "Шаги: {steps}");
+        writeln_l10n!(r, "Шаги: {steps}", "Steps: {steps}");
     }
-    writeln!(r, "Срочно обратитесь к разработчику ;)");
+    writeln_l10n!(
+        r,
+        "Срочно обратитесь к разработчику ;)",
+        "Contact the developer immediately ;)"
+    );
     wait_for_any_key(r)
 }
@@ -1,55 +1,102 @@
-use crate::logic::Action;
+use crate::logic::{Action, GameState};
 use crate::ui::{renderer::Renderer, *};
 
 pub(in crate::ui) fn display_what_to_do(
     r: &mut Renderer<impl RendererRequestConsumer>,
+    state: &GameState,
     available_actions: &[Action],
 ) -> WaitingState {
     r.clear_screen();
-    write_colored!(White, r, "Есть всего ");
-    write_colored!(YellowBright, r, "6 дней");
-    write_colored!(White, r, ". За это время надо успеть получить ");
-    write_colored!(YellowBright, r, "6 зачетов");
+    let num_days = state.timetable().length().days();
+    let days =
+        r.locale()
+            .plural(num_days.into(), ["день", "дня", "дней"], ["day", "days"]);
+    write_colored_l10n!(White, r, "Есть всего ", "You have only ");
+    write_colored!(YellowBright, r, "{} {}", num_days, days);
+    write_colored_l10n!(
+        White,
+        r,
+        ". За это время надо успеть получить ",
+        ". In that time you have to get "
+    );
+    write_colored_l10n!(YellowBright, r, "6 зачетов", "6 passes");
     writeln_colored!(White, r, ".");
 
-    write_colored!(White, r, "Чтобы получить ");
-    write_colored!(YellowBright, r, "зачет");
-    write_colored!(White, r, ", можно успешно сдать сколько-то ");
-    write_colored!(YellowBright, r, "заданий");
+    write_colored_l10n!(White, r, "Чтобы получить ", "To get a ");
+    write_colored_l10n!(YellowBright, r, "зачет", "pass");
+    write_colored_l10n!(
+        White,
+        r,
+        ", можно успешно сдать сколько-то ",
+        ", you can successfully hand in some "
+    );
+    write_colored_l10n!(YellowBright, r, "заданий", "tasks");
     writeln_colored!(White, r, ".");
 
-    write_colored!(
+    write_colored_l10n!(
         White,
         r,
-        "Чтобы сдать несколько заданий, можно чего-то знать и "
+        "Чтобы сдать несколько заданий, можно чего-то знать и ",
+        "To hand in some tasks, you can know something and "
     );
-    write_colored!(YellowBright, r, "прийти к преподу");
+    write_colored_l10n!(YellowBright, r, "прийти к преподу", "go to the professor");
     writeln_colored!(White, r, ".");
 
-    write_colored!(White, r, "Чтобы чего-то знать, можно ");
-    write_colored!(YellowBright, r, "готовиться");
+    write_colored_l10n!(
+        White,
+        r,
+        "Чтобы чего-то знать, можно ",
+        "To know something, you can "
+    );
+    write_colored_l10n!(YellowBright, r, "готовиться", "study");
     writeln_colored!(White, r, ".");
 
-    write_colored!(White, r, "Преподавателей надо искать по ");
-    write_colored!(YellowBright, r, "расписанию");
+    write_colored_l10n!(
+        White,
+        r,
+        "Преподавателей надо искать по ",
+        "Look for the professors according to the "
+    );
+    write_colored_l10n!(YellowBright, r, "расписанию", "timetable");
     writeln_colored!(White, r, ".");
 
-    write_colored!(White, r, "Пока готовишься или сдаешь, ");
-    write_colored!(YellowBright, r, "самочуствие");
-    writeln_colored!(White, r, " ухудшается.");
+    write_colored_l10n!(
+        White,
+        r,
+        "Пока готовишься или сдаешь, ",
+        "While you study or take tests, your "
+    );
+    write_colored_l10n!(YellowBright, r, "самочуствие", "health");
+    writeln_colored_l10n!(White, r, " ухудшается.", " gets worse.");
 
-    write_colored!(White, r, "Чтобы улучшить самочуствие, можно ");
-    write_colored!(YellowBright, r, "отдыхать");
+    write_colored_l10n!(
+        White,
+        r,
+        "Чтобы улучшить самочуствие, можно ",
+        "To improve your health, you can "
+    );
+    write_colored_l10n!(YellowBright, r, "отдыхать", "rest");
     writeln_colored!(White, r, ".");
 
-    write_colored!(White, r, "Всякие ");
-    write_colored!(YellowBright, r, "дополнительные персонажи");
-    writeln_colored!(White, r, " могут помогать, а могут мешать.");
+    write_colored_l10n!(White, r, "Всякие ", "Various ");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "дополнительные персонажи",
+        "other characters"
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        " могут помогать, а могут мешать.",
+        " can help you, or they can get in your way."
+    );
 
-    write_colored!(
+    write_colored_l10n!(
         RedBright,
         r,
-        "Альтернативные варианты есть почти везде, но они тоже чего-то стоят"
+        "Альтернативные варианты есть почти везде, но они тоже чего-то стоят",
+        "There are alternatives almost everywhere, but they have a cost too"
     );
     writeln_colored!(White, r, ".");
 
@@ -61,61 +108,80 @@ pub(in crate::ui) fn display_about_screen(
     available_actions: &[Action],
 ) -> WaitingState {
     r.clear_screen();
-    write_colored!(White, r, "В левом верхнем углу - игровые ");
-    write_colored!(YellowBright, r, "дата");
-    write_colored!(White, r, " и ");
-    write_colored!(YellowBright, r, "время");
+    write_colored_l10n!(
+        White,
+        r,
+        "В левом верхнем углу - игровые ",
+        "The top left corner shows the game "
+    );
+    write_colored_l10n!(YellowBright, r, "дата", "date");
+    write_colored_l10n!(White, r, " и ", " and ");
+    write_colored_l10n!(YellowBright, r, "время", "time");
     writeln_colored!(White, r, ",");
 
-    write_colored!(White, r, "твое состояние (");
-    write_colored!(YellowBright, r, "здоровье");
+    write_colored_l10n!(White, r, "твое состояние (", "your condition (");
+    write_colored_l10n!(YellowBright, r, "здоровье", "health");
     write_colored!(White, r, ", ");
-    write_colored!(YellowBright, r, "качества");
+    write_colored_l10n!(YellowBright, r, "качества", "qualities");
     write_colored!(White, r, "), ");
-    write_colored!(YellowBright, r, "деньги");
+    write_colored_l10n!(YellowBright, r, "деньги", "money");
     writeln_colored!(White, r, ".");
 
-    write_colored!(White, r, "В правом верхнем углу - твои ");
-    write_colored!(YellowBright, r, "навыки");
-    writeln_colored!(White, r, " по предметам.");
+    write_colored_l10n!(
+        White,
+        r,
+        "В правом верхнем углу - твои ",
+        "The top right corner shows your "
+    );
+    write_colored_l10n!(YellowBright, r, "навыки", "skills");
+    writeln_colored_l10n!(White, r, " по предметам.", " in each subject.");
 
-    write_colored!(White, r, "Навыки оцениваются двояко: по ");
-    write_colored!(YellowBright, r, "\"общей шкале\"");
-    writeln_colored!(White, r, " (число)");
+    write_colored_l10n!(
+        White,
+        r,
+        "Навыки оцениваются двояко: по ",
+        "Skills are rated in two ways: on the "
+    );
+    write_colored_l10n!(YellowBright, r, "\"общей шкале\"", "\"general scale\"");
+    writeln_colored_l10n!(White, r, " (число)", " (a number)");
 
-    write_colored!(White, r, "и по ");
-    write_colored!(
+    write_colored_l10n!(White, r, "и по ", "and on the ");
+    write_colored_l10n!(
         YellowBright,
         r,
-        "шкале требований конкретного преподавателя"
+        "шкале требований конкретного преподавателя",
+        "scale of the particular professor's requirements"
     );
-    writeln_colored!(White, r, " (\"оценка\").");
+    writeln_colored_l10n!(White, r, " (\"оценка\").", " (a \"grade\").");
 
-    writeln_colored!(
+    writeln_colored_l10n!(
         White,
         r,
-        "Ниже навыков - мини-расписание на этот день + сданные задачи."
+        "Ниже навыков - мини-расписание на этот день + сданные задачи.",
+        "Below the skills is a mini-timetable for today + the tasks you've passed."
     );
 
-    writeln_colored!(
+    writeln_colored_l10n!(
         White,
         r,
-        "Полное расписание можно посмотреть в общаге (выбрать в меню)."
+        "Полное расписание можно посмотреть в общаге (выбрать в меню).",
+        "You can see the full timetable in the dorm (choose it in the menu)."
     );
 
-    writeln_colored!(
+    writeln_colored_l10n!(
         White,
         r,
-        "Наконец, слева в нижней половине экрана - текущее меню."
+        "Наконец, слева в нижней половине экрана - текущее меню.",
+        "Finally, the current menu is on the left in the lower half of the screen."
     );
 
     writeln!(r);
 
-    write_colored!(Green, r, " СОСТОЯНИЕ     ");
-    writeln_colored!(WhiteBright, r, "НАВЫКИ");
-    writeln_colored!(YellowBright, r, " СИТУАЦИЯ");
-    write_colored!(CyanBright, r, " МЕНЮ          ");
-    writeln_colored!(RedBright, r, "РАСПИСАНИЕ");
+    write_colored_l10n!(Green, r, " СОСТОЯНИЕ     ", " CONDITION     ");
+    writeln_colored_l10n!(WhiteBright, r, "НАВЫКИ", "SKILLS");
+    writeln_colored_l10n!(YellowBright, r, " СИТУАЦИЯ", " SITUATION");
+    write_colored_l10n!(CyanBright, r, " МЕНЮ          ", " MENU          ");
+    writeln_colored_l10n!(RedBright, r, "РАСПИСАНИЕ", "TIMETABLE");
 
     help_dialog(r, available_actions)
 }
@@ -125,50 +191,88 @@ pub(in crate::ui) fn display_where_to_go_and_why(
     available_actions: &[Action],
 ) -> WaitingState {
     r.clear_screen();
-    write_colored!(White, r, "В ");
-    write_colored!(YellowBright, r, "общаге");
-    writeln_colored!(White, r, " ты готовишься и отдыхаешь.");
+    write_colored_l10n!(White, r, "В ", "In the ");
+    write_colored_l10n!(YellowBright, r, "общаге", "dorm");
+    writeln_colored_l10n!(
+        White,
+        r,
+        " ты готовишься и отдыхаешь.",
+        " you study and rest."
+    );
 
-    write_colored!(White, r, "На ");
-    write_colored!(YellowBright, r, "факультете(~=ПУНК)");
-    writeln_colored!(White, r, " ты бегаешь по преподам и ищешь приятелей.");
+    write_colored_l10n!(White, r, "На ", "At the ");
+    write_colored_l10n!(YellowBright, r, "факультете(~=ПУНК)", "faculty (~=PUNK)");
+    writeln_colored_l10n!(
+        White,
+        r,
+        " ты бегаешь по преподам и ищешь приятелей.",
+        " you chase professors and look for friends."
+    );
 
-    write_colored!(White, r, "Чтобы попасть в ");
-    write_colored!(YellowBright, r, "компьюетрный класс");
-    writeln_colored!(White, r, ", надо прийти на факультет.");
+    write_colored_l10n!(White, r, "Чтобы попасть в ", "To get to the ");
+    write_colored_l10n!(YellowBright, r, "компьюетрный класс", "computer class");
+    writeln_colored_l10n!(
+        White,
+        r,
+        ", надо прийти на факультет.",
+        ", you have to go to the faculty."
+    );
 
-    writeln_colored!(
+    writeln_colored_l10n!(
         White,
         r,
-        "В компьютерном классе ты сдаешь зачет по информатике и ищешь друзей."
+        "В компьютерном классе ты сдаешь зачет по информатике и ищешь друзей.",
+        "In the computer class you take the CS test and look for friends."
     );
 
-    write_colored!(YellowBright, r, "Мавзолей");
-    writeln_colored!(
+    write_colored_l10n!(YellowBright, r, "Мавзолей", "The Mausoleum");
+    writeln_colored_l10n!(
         White,
         r,
-        " - это такая столовая. Там ты отдыхаешь и ищешь приятелей."
+        " - это такая столовая. Там ты отдыхаешь и ищешь приятелей.",
+        " is a kind of canteen. There you rest and look for friends."
     );
 
-    write_colored!(YellowBright, r, "ПОМИ");
-    writeln_colored!(
+    write_colored_l10n!(YellowBright, r, "ПОМИ", "PDMI");
+    writeln_colored_l10n!(
         White,
         r,
-        " - Петербургское Отделение Математического Института РАН."
+        " - Петербургское Отделение Математического Института РАН.",
+        " is the St. Petersburg Department of the Math Institute of the RAS."
     );
 
-    writeln_colored!(White, r, "В ПОМИ ты будешь искать преподов и приятелей.");
+    writeln_colored_l10n!(
+        White,
+        r,
+        "В ПОМИ ты будешь искать преподов и приятелей.",
+        "In PDMI you will look for professors and friends."
+    );
 
-    write_colored!(White, r, "В ПОМИ надо ехать на электричке, это занимает ");
-    write_colored!(YellowBright, r, "1 час");
+    write_colored_l10n!(
+        White,
+        r,
+        "В ПОМИ надо ехать на электричке, это занимает ",
+        "You have to take a train to PDMI, it takes "
+    );
+    write_colored_l10n!(YellowBright, r, "1 час", "1 hour");
     writeln_colored!(White, r, ".");
 
-    write_colored!(White, r, "Если ехать зайцем - то может оказаться, что и ");
-    write_colored!(YellowBright, r, "2 часа");
+    write_colored_l10n!(
+        White,
+        r,
+        "Если ехать зайцем - то может оказаться, что и ",
+        "If you ride without a ticket, it may take as much as "
+    );
+    write_colored_l10n!(YellowBright, r, "2 часа", "2 hours");
     writeln_colored!(White, r, ".");
 
-    write_colored!(White, r, "Кроме того, ");
-    write_colored!(RedBright, r, "поездка отнимает и здоровье тоже");
+    write_colored_l10n!(White, r, "Кроме того, ", "Besides, ");
+    write_colored_l10n!(
+        RedBright,
+        r,
+        "поездка отнимает и здоровье тоже",
+        "the trip takes its toll on your health too"
+    );
     writeln_colored!(White, r, ".");
 
     help_dialog(r, available_actions)
@@ -179,23 +283,83 @@ pub(in crate::ui) fn display_about_professors(
     available_actions: &[Action],
 ) -> WaitingState {
     r.clear_screen();
-    write_colored!(YellowBright, r, "Всемирнов М.А., алгебра");
-    writeln_colored!(White, r, " - очень серьезный и весьма строгий.");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Всемирнов М.А., алгебра",
+        "Vsemirnov M.A., algebra"
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - очень серьезный и весьма строгий.",
+        " - very serious and quite strict."
+    );
 
-    write_colored!(YellowBright, r, "Дубцов Е.С., матан");
-    writeln_colored!(White, r, " - не очень строгий и с некоторой халявой.");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Дубцов Е.С., матан",
+        "Dubtsov E.S., calculus"
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - не очень строгий и с некоторой халявой.",
+        " - not very strict, sometimes lets things slide."
+    );
 
-    write_colored!(YellowBright, r, "Подкорытов С.С., геометрия");
-    writeln_colored!(White, r, " - замещает Дуткевича Ю.Г.. Почти без проблем.");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Подкорытов С.С., геометрия",
+        "Podkorytov S.S., geometry"
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - замещает Дуткевича Ю.Г.. Почти без проблем.",
+        " - substitutes for Dutkevich Yu.G. Almost no problem."
+    );
 
-    write_colored!(YellowBright, r, "Климов А.А., информатика");
-    writeln_colored!(White, r, " - без проблем, но трудно найти.");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Климов А.А., информатика",
+        "Klimov A.A., CS"
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - без проблем, но трудно найти.",
+        " - no problem, but hard to find."
+    );
 
-    write_colored!(YellowBright, r, "Влащенко Н.П., English");
-    writeln_colored!(White, r, " - без проблем, но с некоторым своеобразием.");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Влащенко Н.П., English",
+        "Vlaschenko N.P., English"
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - без проблем, но с некоторым своеобразием.",
+        " - no problem, but somewhat peculiar."
+    );
 
-    write_colored!(YellowBright, r, "Альбинский Е.Г., Физ-ра");
-    writeln_colored!(White, r, " - без проблем, но от физ-ры сильно устаешь.");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Альбинский Е.Г., Физ-ра",
+        "Albinsky E.G., P.E."
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - без проблем, но от физ-ры сильно устаешь.",
+        " - no problem, but P.E. wears you out."
+    );
 
     help_dialog(r, available_actions)
 }
@@ -206,76 +370,95 @@ pub(in crate::ui) fn display_about_characters(
 ) -> WaitingState {
     r.clear_screen();
     write_colored!(YellowBright, r, "Diamond");
-    writeln_colored!(
+    writeln_colored_l10n!(
         White,
         r,
-        " - автор игры \"Герои Мата и Меха\" (MMHEROES), знает всё о ее \"фичах\"."
+        " - автор игры \"Герои Мата и Меха\" (MMHEROES), знает всё о ее \"фичах\".",
+        " - author of \"Heroes of Mat & Mech\" (MMHEROES), knows all its \"features\"."
     );
 
-    write_colored!(YellowBright, r, "Миша");
-    writeln_colored!(
+    write_colored_l10n!(YellowBright, r, "Миша", "Misha");
+    writeln_colored_l10n!(
         White,
         r,
-        " - когда-то альфа-тестер; понимает в стратегии получения зачетов."
+        " - когда-то альфа-тестер; понимает в стратегии получения зачетов.",
+        " - a former alpha tester; knows the strategy of getting passes."
     );
 
-    write_colored!(YellowBright, r, "Серж");
-    writeln_colored!(
+    write_colored_l10n!(YellowBright, r, "Серж", "Serj");
+    writeln_colored_l10n!(
         White,
         r,
-        " - еще один экс-альфа-тестер и просто хороший товарищ."
+        " - еще один экс-альфа-тестер и просто хороший товарищ.",
+        " - another ex-alpha tester and just a good buddy."
     );
 
-    write_colored!(YellowBright, r, "Паша");
-    writeln_colored!(
+    write_colored_l10n!(YellowBright, r, "Паша", "Pasha");
+    writeln_colored_l10n!(
         White,
         r,
-        " - староста. Самый нужный в конце семестра человек."
+        " - староста. Самый нужный в конце семестра человек.",
+        " - the class head. The most needed person at the end of the semester."
     );
 
     write_colored!(YellowBright, r, "RAI");
-    writeln_colored!(
+    writeln_colored_l10n!(
         White,
         r,
-        " - простой студент. Не любит, когда кто-то НЕ ХОЧЕТ ему помогать."
+        " - простой студент. Не любит, когда кто-то НЕ ХОЧЕТ ему помогать.",
+        " - an ordinary student. Doesn't like it when someone DOESN'T WANT to help."
     );
 
-    write_colored!(YellowBright, r, "Эндрю");
-    writeln_colored!(
+    write_colored_l10n!(YellowBright, r, "Эндрю", "Andrew");
+    writeln_colored_l10n!(
         White,
         r,
-        " - то же студент. Можно попробовать обратиться к нему за помощью."
+        " - то же студент. Можно попробовать обратиться к нему за помощью.",
+        " - also a student. You can try asking him for help."
     );
 
-    write_colored!(YellowBright, r, "Саша");
-    writeln_colored!(
+    write_colored_l10n!(YellowBright, r, "Саша", "Sasha");
+    writeln_colored_l10n!(
         White,
         r,
-        " - еще один студент; подробно и разборчиво конспектирует лекции."
+        " - еще один студент; подробно и разборчиво конспектирует лекции.",
+        " - another student; takes detailed and legible lecture notes."
     );
 
     write_colored!(YellowBright, r, "NiL");
-    writeln_colored!(
+    writeln_colored_l10n!(
         White,
         r,
-        " - девушка из вольнослушателей. Часто эксплуатирует чужие мозги."
+        " - девушка из вольнослушателей. Часто эксплуатирует чужие мозги.",
+        " - a girl auditing the course. Often exploits other people's brains."
     );
 
-    write_colored!(YellowBright, r, "Коля");
-    writeln_colored!(White, r, " - студент, большой любитель алгебры и выпивки.");
+    write_colored_l10n!(YellowBright, r, "Коля", "Kolya");
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - студент, большой любитель алгебры и выпивки.",
+        " - a student, a big fan of algebra and booze."
+    );
 
-    write_colored!(YellowBright, r, "Гриша");
-    writeln_colored!(White, r, " - студент-пофигист. Любит пиво и халяву.");
+    write_colored_l10n!(YellowBright, r, "Гриша", "Grisha");
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - студент-пофигист. Любит пиво и халяву.",
+        " - a student who doesn't care. Loves beer and freebies."
+    );
 
-    write_colored!(YellowBright, r, "Кузьменко В.Г.");
-    writeln_colored!(
+    write_colored_l10n!(YellowBright, r, "Кузьменко В.Г.", "Kuzmenko V.G.");
+    writeln_colored_l10n!(
         White,
         r,
-        " - преподает информатику у другой половины 19-й группы."
+        " - преподает информатику у другой половины 19-й группы.",
+        " - teaches CS to the other half of group 19."
     );
 
     write_colored!(YellowBright, r, "DJuG");
-    writeln_colored!(White, r, " - угадайте, кто ;)");
+    writeln_colored_l10n!(White, r, " - угадайте, кто ;)", " - guess who ;)");
 
     help_dialog(r, available_actions)
 }
@@ -288,40 +471,73 @@ pub(in crate::ui) fn display_about_this_program(
     writeln_colored!(WhiteBright, r, "CrWMM Development Team:");
     writeln!(r);
 
-    write_colored!(YellowBright, r, "Дмитрий Петров (aka Diamond)");
-    writeln_colored!(White, r, " - автор идеи, главный программист");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Дмитрий Петров (aka Diamond)",
+        "Dmitry Petrov (aka Diamond)"
+    );
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - автор идеи, главный программист",
+        " - idea author, lead programmer"
+    );
 
-    write_colored!(YellowBright, r, "Константин Буленков");
-    writeln_colored!(White, r, " - портирование");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Константин Буленков",
+        "Konstantin Bulenkov"
+    );
+    writeln_colored_l10n!(White, r, " - портирование", " - porting");
 
-    write_colored!(YellowBright, r, "Ваня Павлик");
-    writeln_colored!(White, r, " - тестирование, веб-страничка");
+    write_colored_l10n!(YellowBright, r, "Ваня Павлик", "Vanya Pavlik");
+    writeln_colored_l10n!(
+        White,
+        r,
+        " - тестирование, веб-страничка",
+        " - testing, web page"
+    );
 
-    write_colored!(YellowBright, r, "Алексей Румянцев (aka RAI)");
-    writeln_colored!(White, r, " - retired веб-мастер");
+    write_colored_l10n!(
+        YellowBright,
+        r,
+        "Алексей Румянцев (aka RAI)",
+        "Alexey Rumyantsev (aka RAI)"
+    );
+    writeln_colored_l10n!(White, r, " - retired веб-мастер", " - retired webmaster");
 
-    writeln_colored!(
+    writeln_colored_l10n!(
         White,
         r,
-        "Мнение авторов не всегда совпадает с высказываниями персонажей."
+        "Мнение авторов не всегда совпадает с высказываниями персонажей.",
+        "The authors' opinions do not always match what the characters say."
     );
     writeln!(r);
 
-    write_colored!(CyanBright, r, "Если запустить ");
+    write_colored_l10n!(CyanBright, r, "Если запустить ", "If you run ");
     write_colored!(WhiteBright, r, "mmheroes");
-    writeln_colored!(
+    writeln_colored_l10n!(
         CyanBright,
         r,
-        " с хоть каким параметром, у тебя будет возможность"
+        " с хоть каким параметром, у тебя будет возможность",
+        " with any parameter at all, you will be able"
     );
 
-    writeln_colored!(
+    writeln_colored_l10n!(
         CyanBright,
         r,
-        "выбрать личный профиль своего \"героя\"; например,"
+        "выбрать личный профиль своего \"героя\"; например,",
+        "to choose a personal profile for your \"hero\"; for example,"
     );
     writeln_colored!(Green, r, "           mmheroes z#11");
-    writeln_colored!(CyanBright, r, "Появится менюшка, в которой все и так ясно.");
+    writeln_colored_l10n!(
+        CyanBright,
+        r,
+        "Появится менюшка, в которой все и так ясно.",
+        "A little menu will appear, and everything there is self-explanatory."
+    );
 
     help_dialog(r, available_actions)
 }
@@ -331,6 +547,11 @@ fn help_dialog(
     available_actions: &[Action],
 ) -> WaitingState {
     r.move_cursor_to(13, 0);
-    writeln_colored!(White, r, "Что тебя интересует?");
+    writeln_colored_l10n!(
+        White,
+        r,
+        "Что тебя интересует?",
+        "What are you interested in?"
+    );
     dialog(r, available_actions)
 }
@@ -1,4 +1,6 @@
-use crate::ui::{high_scores::HighScore, renderer::Renderer, *};
+use crate::logic::Money;
+use crate::ui::high_scores::{HighScore, HighScoreName, MAX_NAME_LENGTH};
+use crate::ui::{renderer::Renderer, *};
 
 pub(in crate::ui) fn display_high_scores(
     r: &mut Renderer<impl RendererRequestConsumer>,
@@ -10,15 +12,56 @@ pub(in crate::ui) fn display_high_scores(
     writeln!(r, "      *********                         *********");
     writeln!(r, "               *************************");
     r.set_color(Color::YellowBright, Color::Black);
-    writeln!(r, "Вот имена тех, кто прошел это наводящее ужас испытание:");
+    writeln_l10n!(
+        r,
+        "Вот имена тех, кто прошел это наводящее ужас испытание:",
+        "Here are the names of those who passed this terrifying ordeal:",
+    );
     writeln!(r);
-    writeln!(r, "    ГЕРОЙ            ЗАРАБОТАЛ");
+    writeln_l10n!(
+        r,
+        "    ГЕРОЙ            ЗАРАБОТАЛ",
+        "    HERO             EARNED",
+    );
     r.set_color(Color::WhiteBright, Color::Black);
     for (i, (name, score)) in high_scores.iter().enumerate() {
         r.move_cursor_to((i + 6) as u8, 3);
         write!(r, "{}", name);
         r.move_cursor_to((i + 6) as u8, 24);
-        write!(r, "{} руб.", score);
+        write_l10n!(r, "{} руб.", "{} rub.", score);
     }
     wait_for_any_key(r)
 }
+
+pub(in crate::ui) fn display_high_score_name_prompt(
+    r: &mut Renderer<impl RendererRequestConsumer>,
+    score: Money,
+) -> WaitingState {
+    r.clear_screen();
+    writeln_colored_l10n!(
+        WhiteBright,
+        r,
+        "Твое имя будет вписано в историю Мат-Меха!",
+        "Your name will go down in the history of MathMech!",
+    );
+    writeln!(r);
+    write_colored_l10n!(YellowBright, r, "Как тебя зовут? ", "What's your name? ");
+    let start = r.get_cursor_position();
+    let name = HighScoreName::new();
+    display_high_score_name(r, start, &name);
+    WaitingState::HighScoreName { score, name, start }
+}
+
+pub(in crate::ui) fn display_high_score_name(
+    r: &mut Renderer<impl RendererRequestConsumer>,
+    start: (Line, Column),
+    name: &HighScoreName,
+) {
+    let (line, column) = start;
+    r.move_cursor_to(line, column);
+    r.set_color(Color::WhiteBright, Color::Black);
+    let length = name.chars().count();
+    write!(r, "{}{:2$}", &**name, "", MAX_NAME_LENGTH - length);
+    r.move_cursor_to(line, column + length as Column);
+    r.flush();
+}
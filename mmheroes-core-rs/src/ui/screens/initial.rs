@@ -6,17 +6,20 @@ pub(in crate::ui) fn display_intro(
 ) -> WaitingState {
     r.clear_screen();
     r.set_color(Color::Gray, Color::Black);
-    writeln!(
+    writeln_l10n!(
         r,
-        "                                                Нам понятен этот смех"
+        "                                                Нам понятен этот смех",
+        "                                         We understand the laughter",
     );
-    writeln!(
+    writeln_l10n!(
         r,
-        "                                                Не попавших на Мат-Мех"
+        "                                                Не попавших на Мат-Мех",
+        "                                         Of those who didn't get into MathMech",
     );
-    writeln!(
+    writeln_l10n!(
         r,
-        "                                                  (надпись на парте)"
+        "                                                  (надпись на парте)",
+        "                                           (written on a school desk)",
     );
     writeln!(r);
     writeln!(r);
@@ -46,13 +49,21 @@ pub(in crate::ui) fn display_intro(
     writeln!(r);
     writeln!(r);
     r.set_color(Color::RedBright, Color::Black);
-    writeln!(r, "                             ГЕРОИ МАТА И МЕХА ;)");
+    writeln_l10n!(
+        r,
+        "                             ГЕРОИ МАТА И МЕХА ;)",
+        "                         HEROES OF MATH AND MECHANICS ;)",
+    );
     writeln!(r);
     writeln!(r);
     r.set_color(Color::CyanBright, Color::Black);
     writeln!(r, "(P) CrWMM Development Team, 2001.");
-    writeln!(r, "Версия gamma3.14.");
-    writeln!(r, "Загляните на нашу страничку: mmheroes.chat.ru !");
+    writeln_l10n!(r, "Версия gamma3.14.", "Version gamma3.14.");
+    writeln_l10n!(
+        r,
+        "Загляните на нашу страничку: mmheroes.chat.ru !",
+        "Visit our home page: mmheroes.chat.ru !",
+    );
     wait_for_any_key(r)
 }
 
@@ -64,7 +75,11 @@ pub(in crate::ui) fn display_initial_parameters(
     assert!(mode == GameMode::God || mode == GameMode::SelectInitialParameters);
     r.clear_screen();
     r.set_color(Color::White, Color::Black);
-    writeln!(r, "Выбери начальные параметры своего \"героя\":");
+    writeln_l10n!(
+        r,
+        "Выбери начальные параметры своего \"героя\":",
+        "Choose the initial parameters of your \"hero\":",
+    );
     writeln!(r);
 
     dialog(r, available_actions)
@@ -76,28 +91,50 @@ pub(in crate::ui) fn display_ding(
 ) -> WaitingState {
     r.clear_screen();
     r.set_color(Color::Green, Color::Black);
-    writeln!(r, "ДЗИНЬ!");
+    writeln_l10n!(r, "ДЗИНЬ!", "RING!");
     sleep(r, Milliseconds(500));
     r.set_color(Color::YellowBright, Color::Black);
-    writeln!(r, "ДДДЗЗЗЗЗИИИИИИННННННЬ !!!!");
+    writeln_l10n!(r, "ДДДЗЗЗЗЗИИИИИИННННННЬ !!!!", "RRRIIIIINNNNNNGGGGGG !!!!");
     sleep(r, Milliseconds(700));
     r.set_color(Color::RedBright, Color::Black);
-    writeln!(r, "ДДДДДДЗЗЗЗЗЗЗЗЗЗЗЗЗИИИИИИИИИИННННННННННННЬ !!!!!!!!!!");
+    writeln_l10n!(
+        r,
+        "ДДДДДДЗЗЗЗЗЗЗЗЗЗЗЗЗИИИИИИИИИИННННННННННННЬ !!!!!!!!!!",
+        "RRRRRRRRIIIIIIIIIIIIINNNNNNNNNNNNNGGGGGGGGGGGG !!!!!!!!!!",
+    );
     sleep(r, Milliseconds(1000));
     r.set_color(Color::White, Color::Black);
     if !recursion {
-        writeln!(r, "Ты просыпаешься от звонка будильника 22-го мая в 8:00.");
+        writeln_l10n!(
+            r,
+            "Ты просыпаешься от звонка будильника 22-го мая в 8:00.",
+            "You wake up to the alarm clock ringing on May 22 at 8:00.",
+        );
     }
-    writeln!(r, "Неожиданно ты осознаешь, что началась зачетная неделя,");
-    writeln!(
+    writeln_l10n!(
         r,
-        "а твоя готовность к этому моменту практически равна нулю."
+        "Неожиданно ты осознаешь, что началась зачетная неделя,",
+        "Suddenly you realize that the exam week has begun,",
     );
-    writeln!(r, "Натягивая на себя скромное одеяние студента,");
-    writeln!(
+    writeln_l10n!(
+        r,
+        "а твоя готовность к этому моменту практически равна нулю.",
+        "and your readiness for it is practically zero.",
+    );
+    writeln_l10n!(
+        r,
+        "Натягивая на себя скромное одеяние студента,",
+        "Pulling on the modest attire of a student,",
+    );
+    writeln_l10n!(
+        r,
+        "ты всматриваешься в заботливо оставленное соседом на стене",
+        "you peer at the timetable your roommate kindly left on the wall:",
+    );
+    writeln_l10n!(
         r,
-        "ты всматриваешься в заботливо оставленное соседом на стене"
+        "расписание: когда и где можно найти искомого препода ?",
+        "when and where can the professor you need be found ?",
     );
-    writeln!(r, "расписание: когда и где можно найти искомого препода ?");
     wait_for_any_key(r)
 }
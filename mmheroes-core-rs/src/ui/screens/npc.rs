@@ -12,21 +12,28 @@ use crate::logic::*;
 use crate::ui::{renderer::Renderer, screens::scene_router, *};
 
 fn solved_algebra_problems(r: &mut Renderer<impl RendererRequestConsumer>) {
-    writeln_colored!(
+    writeln_colored_l10n!(
         WhiteBright,
         r,
-        "\"У тебя остались нерешенные задачи по Всемирнову? Давай сюда!\""
+        "\"У тебя остались нерешенные задачи по Всемирнову? Давай сюда!\"",
+        "\"Got any unsolved problems for Vsemirnov left? Hand them over!\""
     );
-    write_colored!(White, r, "Коля решил тебе еще ");
+    write_colored_l10n!(White, r, "Коля решил тебе еще ", "Kolya has solved ");
     write_colored!(WhiteBright, r, "{}", 2);
-    writeln_colored!(White, r, " задачи по алгебре!");
+    writeln_colored_l10n!(
+        White,
+        r,
+        " задачи по алгебре!",
+        " more algebra problems for you!"
+    );
 }
 
 fn brake_fluid(r: &mut Renderer<impl RendererRequestConsumer>) {
-    writeln_colored!(
+    writeln_colored_l10n!(
         MagentaBright,
         r,
-        "Коля достает тормозную жидкость, и вы распиваете еще по стакану."
+        "Коля достает тормозную жидкость, и вы распиваете еще по стакану.",
+        "Kolya takes out the brake fluid, and you both down another glass."
     );
 }
 
@@ -43,10 +50,11 @@ pub(in crate::ui) fn display_kolya_interaction(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(7, 0);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
-                "Коля смотрит на тебя немного окосевшими глазами."
+                "Коля смотрит на тебя немного окосевшими глазами.",
+                "Kolya looks at you with slightly cross-eyed eyes."
             );
         }
         _ => (),
@@ -58,10 +66,11 @@ pub(in crate::ui) fn display_kolya_interaction(
             return wait_for_any_key(r);
         }
         PromptOatTincture | BrakeFluidNoMoney => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 WhiteBright,
                 r,
-                "\"Знаешь, пиво, конечно, хорошо, но настойка овса - лучше!\""
+                "\"Знаешь, пиво, конечно, хорошо, но настойка овса - лучше!\"",
+                "\"You know, beer is fine, of course, but oat tincture is better!\""
             );
         }
         _ => {}
@@ -70,7 +79,12 @@ pub(in crate::ui) fn display_kolya_interaction(
     match interaction {
         SolvedAlgebraProblemsForFree => unreachable!(),
         PromptOatTincture => {
-            writeln_colored!(White, r, "Заказать Коле настойку овса?");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Заказать Коле настойку овса?",
+                "Order Kolya an oat tincture?"
+            );
             scene_router::display_short_today_timetable(r, 11, state);
             r.move_cursor_to(14, 0);
             return dialog(r, available_actions);
@@ -86,15 +100,21 @@ pub(in crate::ui) fn display_kolya_interaction(
         }
         BrakeFluidBecauseRefused => {
             r.move_cursor_to(18, 0);
-            writeln_colored!(WhiteBright, r, "\"Зря, ой, зря ...\"");
+            writeln_colored_l10n!(
+                WhiteBright,
+                r,
+                "\"Зря, ой, зря ...\"",
+                "\"Big mistake, oh, big mistake ...\""
+            );
             brake_fluid(r);
         }
         Altruism => {
             r.move_cursor_to(18, 0);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
-                "Твой альтруизм навсегда останется в памяти потомков."
+                "Твой альтруизм навсегда останется в памяти потомков.",
+                "Your altruism will forever remain in the memory of posterity."
             );
         }
     }
@@ -112,13 +132,28 @@ pub(in crate::ui) fn display_pasha_interaction(
     r.move_cursor_to(7, 0);
     match interaction {
         PashaInteraction::Stipend => {
-            write_colored!(White, r, "Паша вручает тебе твою стипуху за май: ");
+            write_colored_l10n!(
+                White,
+                r,
+                "Паша вручает тебе твою стипуху за май: ",
+                "Pasha hands you your stipend for May: "
+            );
             write_colored!(WhiteBright, r, "{}", Money::stipend());
-            write_colored!(White, r, " руб.");
+            write_colored_l10n!(White, r, " руб.", " rub.");
         }
         PashaInteraction::Inspiration => {
-            writeln_colored!(Green, r, "Паша воодушевляет тебя на великие дела.");
-            writeln_colored!(RedBright, r, "Вместе с этим он немного достает тебя.");
+            writeln_colored_l10n!(
+                Green,
+                r,
+                "Паша воодушевляет тебя на великие дела.",
+                "Pasha inspires you to great deeds."
+            );
+            writeln_colored_l10n!(
+                RedBright,
+                r,
+                "Вместе с этим он немного достает тебя.",
+                "At the same time, he gets on your nerves a little."
+            );
         }
     }
     wait_for_any_key(r)
@@ -145,40 +180,45 @@ pub(in crate::ui) fn display_grisha_interaction(
 
     match interaction {
         PromptEmploymentAtTerkom => {
-            writeln_colored!(
-                YellowBright,
-                r,
-                "\"А ты не хочешь устроиться в ТЕРКОМ? Может, кое-чего подзаработаешь...\""
+            writeln_colored_l10n!(YellowBright, r, "\"А ты не хочешь устроиться в ТЕРКОМ? Может, кое-чего подзаработаешь...\"", "\"Would you like to get a job at TERKOM? Maybe you could earn a bit...\""
             );
             writeln!(r);
             dialog(r, available_actions)
         }
         CongratulationsYouAreNowEmployed => {
             r.move_cursor_to(13, 0);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
-                "\"Поздравляю, теперь ты можешь идти в \"контору\"!\""
+                "\"Поздравляю, теперь ты можешь идти в \"контору\"!\"",
+                "\"Congratulations, now you can go to the \"office\"!\""
             );
             wait_for_any_key(r)
         }
         AsYouWantButDontOverstudy => {
             r.move_cursor_to(13, 0);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
-                "\"Как хочешь. Только смотри, не заучись там ...\""
+                "\"Как хочешь. Только смотри, не заучись там ...\"",
+                "\"As you wish. Just be careful not to overstudy there ...\""
             );
             wait_for_any_key(r)
         }
         ProxyAddress => {
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
-                "\"Кстати, я тут знаю один качественно работающий прокси-сервер...\""
+                "\"Кстати, я тут знаю один качественно работающий прокси-сервер...\"",
+                "\"By the way, I know of a proxy server that really works...\""
             );
             writeln!(r);
-            writeln_colored!(White, r, "Ты записываешь адрес. Вдруг пригодится?");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Ты записываешь адрес. Вдруг пригодится?",
+                "You write down the address. It might come in handy."
+            );
             wait_for_any_key(r)
         }
         RandomReply {
@@ -187,46 +227,98 @@ pub(in crate::ui) fn display_grisha_interaction(
             hour_pass,
         } => {
             let text = match reply {
-                WantFreebie => "Хочу халявы!",
-                FreebieComeToMe => "Прийди же, о халява!",
-                FreebieExists => "Халява есть - ее не может не быть.",
+                WantFreebie => l10n!(r, "Хочу халявы!", "I want freebies!"),
+                FreebieComeToMe => {
+                    l10n!(r, "Прийди же, о халява!", "Come to me, o freebie!")
+                }
+                FreebieExists => l10n!(
+                    r,
+                    "Халява есть - ее не может не быть.",
+                    "Freebies exist - they cannot not exist."
+                ),
                 LetsOrganizeFreebieLoversClub => {
-                    "Давай организуем клуб любетелей халявы!"
+                    l10n!(
+                        r,
+                        "Давай организуем клуб любетелей халявы!",
+                        "Let's start a freebie lovers' club!"
+                    )
                 }
                 NoNeedToStudyToGetDiploma => {
-                    "Чтобы получить диплом, учиться совершенно необязательно!"
+                    l10n!(
+                        r,
+                        "Чтобы получить диплом, учиться совершенно необязательно!",
+                        "You don't need to study at all to get a diploma!"
+                    )
                 }
-                YouStudiedDidItHelp => "Ну вот, ты готовился... Помогло это тебе?",
+                YouStudiedDidItHelp => l10n!(
+                    r,
+                    "Ну вот, ты готовился... Помогло это тебе?",
+                    "So you studied... Did it help you?"
+                ),
                 ThirdYearStudentsDontAttendLectures => {
-                    "На третьем курсе на лекции уже никто не ходит. Почти никто."
+                    l10n!(r, "На третьем курсе на лекции уже никто не ходит. Почти никто.", "In the third year nobody goes to lectures anymore. Almost nobody.")
                 }
-                TakeExampleFromKolya => "Вот, бери пример с Коли.",
+                TakeExampleFromKolya => l10n!(
+                    r,
+                    "Вот, бери пример с Коли.",
+                    "Here, follow Kolya's example."
+                ),
                 HateLevTolstoy => {
-                    "Ненавижу Льва Толстого! Вчера \"Войну и мир\" <йк> ксерил..."
+                    l10n!(r, "Ненавижу Льва Толстого! Вчера \"Войну и мир\" <йк> ксерил...", "I hate Leo Tolstoy! Yesterday I <hic> photocopied \"War and Peace\"...")
                 }
-                DontGoToPDMI => "А в ПОМИ лучше вообще не ездить!",
+                DontGoToPDMI => l10n!(
+                    r,
+                    "А в ПОМИ лучше вообще не ездить!",
+                    "And better not go to PDMI at all!"
+                ),
                 NamesOfFreebieLovers => {
-                    "Имена главных халявчиков и алкоголиков висят на баобабе."
+                    l10n!(
+                        r,
+                        "Имена главных халявчиков и алкоголиков висят на баобабе.",
+                        "The names of the top slackers and drunks hang on the baobab."
+                    )
                 }
-                SitHereAndChill => "Правильно, лучше посидим здесь и оттянемся!",
+                SitHereAndChill => l10n!(
+                    r,
+                    "Правильно, лучше посидим здесь и оттянемся!",
+                    "Right, let's just sit here and chill!"
+                ),
                 NoNeedToTakeLectureNotes => {
-                    "Конспектировать ничего не надо. В мире есть ксероксы!"
+                    l10n!(
+                        r,
+                        "Конспектировать ничего не надо. В мире есть ксероксы!",
+                        "No need to take notes. There are photocopiers in the world!"
+                    )
                 }
                 CantBeExpelledInFourthYear => {
-                    "А с четвертого курса вылететь уже почти невозможно."
+                    l10n!(
+                        r,
+                        "А с четвертого курса вылететь уже почти невозможно.",
+                        "And from the fourth year it's almost impossible to be expelled."
+                    )
                 }
-                MechanicsHaveFreebie => "Вот у механиков - у них халява!",
+                MechanicsHaveFreebie => l10n!(
+                    r,
+                    "Вот у механиков - у них халява!",
+                    "Now the mechanics - they've got freebies!"
+                ),
             };
-            write_colored!(White, r, "Гриша:");
+            write_colored_l10n!(White, r, "Гриша:", "Grisha:");
             writeln_colored!(YellowBright, r, "\"{}\"", text);
             if drink_beer {
-                writeln_colored!(White, r, "И еще по пиву...");
+                writeln_colored_l10n!(
+                    White,
+                    r,
+                    "И еще по пиву...",
+                    "And another beer..."
+                );
             }
             if hour_pass {
-                writeln_colored!(
+                writeln_colored_l10n!(
                     White,
                     r,
-                    "И еще один час прошел в бесплодных разговорах..."
+                    "И еще один час прошел в бесплодных разговорах...",
+                    "And another hour passed in fruitless talk..."
                 );
             }
             wait_for_any_key(r)
@@ -246,12 +338,13 @@ pub(in crate::ui) fn display_sasha_interaction(
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(7, 0);
             r.set_color(Color::YellowBright, Color::Black);
-            write!(
+            write_l10n!(
                 r,
-                "Ты встретил Сашу! Говорят, у него классные конспекты ..."
+                "Ты встретил Сашу! Говорят, у него классные конспекты ...",
+                "You met Sasha! They say he has great lecture notes ..."
             );
             r.move_cursor_to(8, 0);
-            write!(r, "Чего тебе надо от Саши?");
+            write_l10n!(r, "Чего тебе надо от Саши?", "What do you want from Sasha?");
             r.move_cursor_to(10, 0);
             dialog(r, available_actions)
         }
@@ -259,25 +352,23 @@ pub(in crate::ui) fn display_sasha_interaction(
             r.move_cursor_to(14, 0);
             match interaction {
                 SashaInteraction::SuitYourself => {
-                    write_colored!(White, r, "Как знаешь...");
+                    write_colored_l10n!(White, r, "Как знаешь...", "Suit yourself...");
                 }
                 _ => {
-                    write_colored!(White, r, "Саша:");
+                    write_colored_l10n!(White, r, "Саша:", "Sasha:");
                     match interaction {
                         SashaInteraction::ChooseSubject
                         | SashaInteraction::SuitYourself => unreachable!(),
                         SashaInteraction::YesIHaveTheLectureNotes => {
-                            write_colored!(
+                            write_colored_l10n!(
                                 WhiteBright,
                                 r,
-                                "\"Да, у меня с собой этот конспект ...\""
+                                "\"Да, у меня с собой этот конспект ...\"",
+                                "\"Yes, I have these notes with me ...\""
                             );
                         }
                         SashaInteraction::SorryGaveToSomeoneElse => {
-                            write_colored!(
-                                WhiteBright,
-                                r,
-                                "\"Ох, извини, кто-то другой уже позаимствовал ...\""
+                            write_colored_l10n!(WhiteBright, r, "\"Ох, извини, кто-то другой уже позаимствовал ...\"", "\"Oh, sorry, someone else has already borrowed them ...\""
                             );
                         }
                     }
@@ -298,7 +389,7 @@ pub(in crate::ui) fn display_kuzmenko_interaction(
     r.clear_screen();
     scene_router::display_header_stats(r, state);
     r.move_cursor_to(7, 0);
-    write_colored!(White, r, "Кузьменко:");
+    write_colored_l10n!(White, r, "Кузьменко:", "Kuzmenko:");
     let reply = match interaction {
         AdditionalComputerScienceExam { day_index } => {
             let exam = state
@@ -306,43 +397,94 @@ pub(in crate::ui) fn display_kuzmenko_interaction(
                 .day(day_index)
                 .exam(Subject::ComputerScience)
                 .unwrap();
-            writeln_colored!(
+            writeln_colored_l10n!(
                 WhiteBright,
                 r,
-                "\"Вы знаете, Климова можно найти в компьютерном классе"
+                "\"Вы знаете, Климова можно найти в компьютерном классе",
+                "\"You know, you can find Klimov in the computer class"
             );
-            // Первый день недели — 22-е мая.
-            writeln_colored!(
+            let (day, month) = calendar_date(day_index as usize);
+            writeln_colored_l10n!(
                 WhiteBright,
                 r,
-                "{}-го мая с {} по {}ч..\"",
-                22 + day_index,
+                "{0}-го {1} с {2} по {3}ч..\"",
+                "on {1} {0}, from {2} to {3}h..\"",
+                day,
+                month_name(r.locale(), month),
                 exam.from(),
                 exam.to()
             );
             return wait_for_any_key(r);
         }
         RandomReply(FormatFloppy) => {
-            "... отформатировать дискету так, чтобы 1ый сектор был 5ым ..."
+            l10n!(
+                r,
+                "... отформатировать дискету так, чтобы 1ый сектор был 5ым ...",
+                "... format a floppy so that the 1st sector is the 5th ..."
+            )
         }
         RandomReply(FiltersInWindows) => {
-            "А Вы нигде не видели литературы по фильтрам в Windows?"
+            l10n!(
+                r,
+                "А Вы нигде не видели литературы по фильтрам в Windows?",
+                "Haven't you seen any literature on filters in Windows?"
+            )
         }
         RandomReply(ByteVisualization) => {
-            "... написать визуализацию байта на ассемблере за 11 байт ..."
-        }
-        RandomReply(OlegPliss) => "У вас Олег Плисс ведет какие-нибудь занятия?",
-        RandomReply(BillGatesMustDie) => "Bill Gates = must die = кабысдох (рус.).",
-        RandomReply(MonitorJournal) => "Вы читали журнал \"Монитор\"? Хотя вряд ли...",
-        RandomReply(MmheroesBP7) => "Я слышал, что mmHeroes написана на BP 7.0.",
-        RandomReply(CSeminar) => "Записывайтесь на мой семинар по языку Си!",
-        RandomReply(ThirdYear) => "На третьем курсе я буду вести у вас спецвычпрактикум.",
-        RandomReply(STAR) => "Интересно, когда они снова наладят STAR?",
+            l10n!(
+                r,
+                "... написать визуализацию байта на ассемблере за 11 байт ...",
+                "... write a byte visualization in assembly in 11 bytes ..."
+            )
+        }
+        RandomReply(OlegPliss) => l10n!(
+            r,
+            "У вас Олег Плисс ведет какие-нибудь занятия?",
+            "Does Oleg Pliss teach any classes for you?"
+        ),
+        RandomReply(BillGatesMustDie) => l10n!(
+            r,
+            "Bill Gates = must die = кабысдох (рус.).",
+            "Bill Gates = must die = kabysdokh (rus.)."
+        ),
+        RandomReply(MonitorJournal) => l10n!(
+            r,
+            "Вы читали журнал \"Монитор\"? Хотя вряд ли...",
+            "Have you read the \"Monitor\" magazine? Unlikely though..."
+        ),
+        RandomReply(MmheroesBP7) => l10n!(
+            r,
+            "Я слышал, что mmHeroes написана на BP 7.0.",
+            "I heard mmHeroes is written in BP 7.0."
+        ),
+        RandomReply(CSeminar) => l10n!(
+            r,
+            "Записывайтесь на мой семинар по языку Си!",
+            "Sign up for my seminar on the C language!"
+        ),
+        RandomReply(ThirdYear) => l10n!(
+            r,
+            "На третьем курсе я буду вести у вас спецвычпрактикум.",
+            "In the third year I will teach you the special computing practicum."
+        ),
+        RandomReply(STAR) => l10n!(
+            r,
+            "Интересно, когда они снова наладят STAR?",
+            "I wonder when they will fix STAR again?"
+        ),
         RandomReply(GetYourselvesAnEmail) => {
-            "Получите себе ящик rambler'e или на mail.ru !"
+            l10n!(
+                r,
+                "Получите себе ящик rambler'e или на mail.ru !",
+                "Get yourselves a mailbox on rambler or mail.ru !"
+            )
         }
         RandomReply(TerekhovSenior) => {
-            "А разве Терехов-старший ничего не рассказывает про IBM PC?"
+            l10n!(
+                r,
+                "А разве Терехов-старший ничего не рассказывает про IBM PC?",
+                "Doesn't Terekhov Sr. tell you anything about the IBM PC?"
+            )
         }
     };
     writeln_colored!(WhiteBright, r, "\"{}\"", reply);
@@ -363,10 +505,11 @@ pub(crate) fn display_diamond_interaction(
         r.clear_screen();
         scene_router::display_header_stats(r, state);
         r.move_cursor_to(7, 0);
-        writeln_colored!(
+        writeln_colored_l10n!(
             YellowBright,
             r,
-            "Wow! Ты только что встретил автора <Heroes of MAT-MEX == MMHEROES>!"
+            "Wow! Ты только что встретил автора <Heroes of MAT-MEX == MMHEROES>!",
+            "Wow! You have just met the author of <Heroes of MAT-MEX == MMHEROES>!"
         );
         writeln!(r);
         write!(r, "Diamond:");
@@ -375,88 +518,217 @@ pub(crate) fn display_diamond_interaction(
     match interaction {
         WannaTestNewMMHEROES => {
             start();
-            writeln!(r, "\"Хочешь по-тестить новую версию Heroes of MAT-MEX?\"");
+            writeln_l10n!(
+                r,
+                "\"Хочешь по-тестить новую версию Heroes of MAT-MEX?\"",
+                "\"Want to test the new version of Heroes of MAT-MEX?\""
+            );
             scene_router::display_short_today_timetable(r, 11, state);
             r.move_cursor_to(11, 0);
             dialog(r, available_actions)
         }
         HereIsTheFloppy => {
             r.move_cursor_to(15, 0);
-            write_colored!(White, r, "\"Ну и ладушки! Вот тебе дискетка...\"");
+            write_colored_l10n!(
+                White,
+                r,
+                "\"Ну и ладушки! Вот тебе дискетка...\"",
+                "\"All right then! Here is a floppy for you...\""
+            );
             wait_for_any_key(r)
         }
         SorryForBothering => {
             r.move_cursor_to(15, 0);
-            write_colored!(White, r, "\"Извини, что побеспокоил.\"");
+            write_colored_l10n!(
+                White,
+                r,
+                "\"Извини, что побеспокоил.\"",
+                "\"Sorry to bother you.\""
+            );
             wait_for_any_key(r)
         }
         Reply(reply) => {
             start();
             let text = match reply {
-                KolyaWillHelpWithAlgebra => "Коля поможет с алгеброй.",
+                KolyaWillHelpWithAlgebra => l10n!(
+                    r,
+                    "Коля поможет с алгеброй.",
+                    "Kolya will help with algebra."
+                ),
                 MishaWillTellEveryoneHowGoodYouAre => {
-                    "Миша расскажет всем, какой ты хороший."
+                    l10n!(
+                        r,
+                        "Миша расскажет всем, какой ты хороший.",
+                        "Misha will tell everyone how good you are."
+                    )
+                }
+                PashaIsYourHeadman => {
+                    l10n!(r, "Паша - твой староста.", "Pasha is your class head.")
                 }
-                PashaIsYourHeadman => "Паша - твой староста.",
-                BetterAvoidDJuG => "С DJuGом лучше не сталкиваться.",
-                RAIWontLeaveYouAlone => "RAI не отстанет, лучше решить ему чего-нибудь.",
+                BetterAvoidDJuG => l10n!(
+                    r,
+                    "С DJuGом лучше не сталкиваться.",
+                    "Better not run into DJuG."
+                ),
+                RAIWontLeaveYouAlone => l10n!(
+                    r,
+                    "RAI не отстанет, лучше решить ему чего-нибудь.",
+                    "RAI won't leave you alone, better solve something for him."
+                ),
                 KolyaIsAlwaysInMausoleum => {
-                    "Коля все время сидит в мавзолее и оттягивается."
+                    l10n!(
+                        r,
+                        "Коля все время сидит в мавзолее и оттягивается.",
+                        "Kolya sits in the Mausoleum all the time, chilling."
+                    )
+                }
+                WatchYourHealth => {
+                    l10n!(r, "Следи за своим здоровьем!!!", "Watch your health!!!")
                 }
-                WatchYourHealth => "Следи за своим здоровьем!!!",
                 IfYouMeetSashaTalkToHim => {
-                    "Если встретишь Сашу - ОБЯЗАТЕЛЬНО заговори с ним."
+                    l10n!(
+                        r,
+                        "Если встретишь Сашу - ОБЯЗАТЕЛЬНО заговори с ним.",
+                        "If you meet Sasha - BE SURE to talk to him."
+                    )
                 }
                 IfTroubleThinkingTalkWithRAI => {
-                    "Если плохо думается, попробуй поговорить с RAI."
+                    l10n!(
+                        r,
+                        "Если плохо думается, попробуй поговорить с RAI.",
+                        "If you have trouble thinking, try talking to RAI."
+                    )
                 }
                 BeSureYouCanDrinkBeforeGoingToKolya => {
-                    "Идя к Коле, будь уверен, что можешь пить с ним."
+                    l10n!(
+                        r,
+                        "Идя к Коле, будь уверен, что можешь пить с ним.",
+                        "When going to Kolya, make sure you can drink with him."
+                    )
                 }
                 ExpectSurprisesOnEnglishExam => {
-                    "Получая зачет по английскому, будь готов к неожиданностям."
+                    l10n!(
+                        r,
+                        "Получая зачет по английскому, будь готов к неожиданностям.",
+                        "When taking the English test, be ready for surprises."
+                    )
                 }
-                TalksWithSerj => "Иногда разговоры с Сержем приносят ощутимую пользу.",
-                AndrewCanHelpButNotAlways => "Эндрю может помочь, но не всегда...",
+                TalksWithSerj => l10n!(
+                    r,
+                    "Иногда разговоры с Сержем приносят ощутимую пользу.",
+                    "Sometimes talking to Serj is of real use."
+                ),
+                AndrewCanHelpButNotAlways => l10n!(
+                    r,
+                    "Эндрю может помочь, но не всегда...",
+                    "Andrew can help, but not always..."
+                ),
                 KuzmenkoKnowsAboutKlimov => {
-                    "Кузьменко иногда знает о Климове больше, чем сам Климов."
+                    l10n!(
+                        r,
+                        "Кузьменко иногда знает о Климове больше, чем сам Климов.",
+                        "Kuzmenko sometimes knows more about Klimov than Klimov himself."
+                    )
                 }
                 DontRushWritingBugReports => {
-                    "Не спеши слать гневные письма о багах:\
+                    l10n!(
+                        r,
+                        "Не спеши слать гневные письма о багах:\
                     \nзагляни на mmheroes.chat.ru,\
-                    \nможет быть, все уже в порядке!"
+                    \nможет быть, все уже в порядке!",
+                        "Don't rush to send angry bug reports:\
+                    \nvisit mmheroes.chat.ru,\
+                    \nmaybe everything is fine already!"
+                    )
                 }
                 SerjSometimesAppearsInMausoleum => {
-                    "Серж тоже иногда забегает в мавзолей."
+                    l10n!(
+                        r,
+                        "Серж тоже иногда забегает в мавзолей.",
+                        "Serj also drops by the Mausoleum sometimes."
+                    )
                 }
                 DontOverstudyTopology => {
-                    "Не переучи топологию, а то Подкорытов-младший не поймет."
+                    l10n!(
+                        r,
+                        "Не переучи топологию, а то Подкорытов-младший не поймет.",
+                        "Don't overstudy topology, or Podkorytov Jr. won't get it."
+                    )
                 }
-                YouCanGetAJobInTERKOM => "Можешь устроиться в ТЕРКОМ по знакомству.",
-                GrishaWorksAtTERKOM => "Гриша работает ( ;*) ) в ТЕРКОМе.",
-                YouCanEarnMoneyAtTERKOM => "В ТЕРКОМЕ можно заработать какие-то деньги.",
-                GrishaSometimesAppearsInMausoleum => "Гриша иногда бывает в Мавзолее.",
+                YouCanGetAJobInTERKOM => l10n!(
+                    r,
+                    "Можешь устроиться в ТЕРКОМ по знакомству.",
+                    "You can get a job at TERKOM through connections."
+                ),
+                GrishaWorksAtTERKOM => l10n!(
+                    r,
+                    "Гриша работает ( ;*) ) в ТЕРКОМе.",
+                    "Grisha works ( ;*) ) at TERKOM."
+                ),
+                YouCanEarnMoneyAtTERKOM => l10n!(
+                    r,
+                    "В ТЕРКОМЕ можно заработать какие-то деньги.",
+                    "You can earn some money at TERKOM."
+                ),
+                GrishaSometimesAppearsInMausoleum => l10n!(
+                    r,
+                    "Гриша иногда бывает в Мавзолее.",
+                    "Grisha is sometimes in the Mausoleum."
+                ),
                 DontLikeTimetable => {
-                    "Не нравится расписание? Подумай о чем-нибудь парадоксальном."
+                    l10n!(
+                        r,
+                        "Не нравится расписание? Подумай о чем-нибудь парадоксальном.",
+                        "Don't like the timetable? Think of something paradoxical."
+                    )
                 }
-                NiLPaysForHelpBut => "NiL дает деньги за помощь, но...",
+                NiLPaysForHelpBut => l10n!(
+                    r,
+                    "NiL дает деньги за помощь, но...",
+                    "NiL pays for help, but..."
+                ),
                 DontKnowWhenLinuxPortWillBeReady => {
-                    "Честно, не знаю, когда будет готов порт под Linux..."
+                    l10n!(
+                        r,
+                        "Честно, не знаю, когда будет готов порт под Linux...",
+                        "Honestly, I don't know when the Linux port will be ready..."
+                    )
                 }
                 NeedNewFeaturesForMMHEROES => {
-                    "Срочно! Нужны новые фишки для \"Зачетной недели\" !"
+                    l10n!(
+                        r,
+                        "Срочно! Нужны новые фишки для \"Зачетной недели\" !",
+                        "Urgent! New features needed for \"Test Week\" !"
+                    )
                 }
                 SendIdeasAndBugReports => {
-                    "Пожелания, идеи, bug report'ы шлите на mmheroes@chat.ru !"
+                    l10n!(
+                        r,
+                        "Пожелания, идеи, bug report'ы шлите на mmheroes@chat.ru !",
+                        "Send your wishes, ideas and bug reports to mmheroes@chat.ru !"
+                    )
                 }
                 SendGreetingsToKostyaBulenkov => {
-                    "Встретишь Костю Буленкова - передай ему большой привет!"
+                    l10n!(
+                        r,
+                        "Встретишь Костю Буленкова - передай ему большой привет!",
+                        "If you meet Kostya Bulenkov - say hi to him from me!"
+                    )
                 }
-                ThanksVanyaPavlik => "Большое спасибо Ване Павлику за mmheroes.chat.ru !",
+                ThanksVanyaPavlik => l10n!(
+                    r,
+                    "Большое спасибо Ване Павлику за mmheroes.chat.ru !",
+                    "Many thanks to Vanya Pavlik for mmheroes.chat.ru !"
+                ),
             };
             writeln_colored!(WhiteBright, r, "\"{}\"", text);
             if diamond_leaves {
-                writeln_colored!(White, r, "Diamond убегает по своим делам ...");
+                writeln_colored_l10n!(
+                    White,
+                    r,
+                    "Diamond убегает по своим делам ...",
+                    "Diamond runs off on his own business ..."
+                );
             }
             wait_for_any_key(r)
         }
@@ -473,68 +745,163 @@ pub(crate) fn display_serj_interaction(
     r.clear_screen();
     scene_router::display_header_stats(r, state);
     r.move_cursor_to(7, 0);
-    write_colored!(White, r, "Серж: ");
+    write_colored_l10n!(White, r, "Серж: ", "Serj: ");
     let reply = match interaction {
-        HaveSomeKefir => "На, глотни кефирчику.",
-        IKnowWhereToCutInThePark => "Я знаю, где срезать в парке на физ-ре!",
+        HaveSomeKefir => l10n!(r, "На, глотни кефирчику.", "Here, have a sip of kefir."),
+        IKnowWhereToCutInThePark => l10n!(
+            r,
+            "Я знаю, где срезать в парке на физ-ре!",
+            "I know where to cut corners in the park during P.E.!"
+        ),
         RandomReply(GuiMmheroes) => {
-            "Помнится, когда-то была еще графическая версия mmHeroes..."
+            l10n!(
+                r,
+                "Помнится, когда-то была еще графическая версия mmHeroes...",
+                "I remember there once was a graphical version of mmHeroes..."
+            )
         }
         RandomReply(IWasABetaTester) => {
-            "Я был бета-тестером первой версии mmHeroes (тогда еще CRWMM19)!"
+            l10n!(
+                r,
+                "Я был бета-тестером первой версии mmHeroes (тогда еще CRWMM19)!",
+                "I was a beta tester of the first mmHeroes (back then it was CRWMM19)!"
+            )
         }
         RandomReply(HowGreatThatDiamondWroteANewVersion) => {
-            "Как здорово, что Diamond написал новую версию!"
+            l10n!(
+                r,
+                "Как здорово, что Diamond написал новую версию!",
+                "How great that Diamond has written a new version!"
+            )
         }
-        RandomReply(HaveYouAlreadyGotStipendFromPasha) => "Ты уже получил деньги у Паши?",
-        RandomReply(TryEasyExamsFirst) => "Попробуй для начала легкие зачеты.",
+        RandomReply(HaveYouAlreadyGotStipendFromPasha) => l10n!(
+            r,
+            "Ты уже получил деньги у Паши?",
+            "Have you got your money from Pasha yet?"
+        ),
+        RandomReply(TryEasyExamsFirst) => l10n!(
+            r,
+            "Попробуй для начала легкие зачеты.",
+            "Try the easy tests first."
+        ),
         RandomReply(HaventYouPassedEnglishExam) => {
-            "Ты еще не получил зачет по английскому?"
+            l10n!(
+                r,
+                "Ты еще не получил зачет по английскому?",
+                "Haven't you passed English yet?"
+            )
         }
         RandomReply(WantToRestAnywhereGetMoney) => {
-            "Хочешь отдыхать, где угодно? Заимей деньги!"
+            l10n!(
+                r,
+                "Хочешь отдыхать, где угодно? Заимей деньги!",
+                "Want to rest anywhere you like? Get some money!"
+            )
         }
         RandomReply(MoneyCantBuyHappiness) => {
-            "Не в деньгах счастье. Но они действуют успокаивающе."
+            l10n!(
+                r,
+                "Не в деньгах счастье. Но они действуют успокаивающе.",
+                "Money can't buy happiness. But it has a calming effect."
+            )
         }
-        RandomReply(AlwaysCrowdedOnVsemirnov) => "На Всемирнове всегда толпа народу.",
-        RandomReply(VlaschenkoIsOriginalLady) => "Влащенко - дама весьма оригинальная.",
+        RandomReply(AlwaysCrowdedOnVsemirnov) => l10n!(
+            r,
+            "На Всемирнове всегда толпа народу.",
+            "There's always a crowd at Vsemirnov's."
+        ),
+        RandomReply(VlaschenkoIsOriginalLady) => l10n!(
+            r,
+            "Влащенко - дама весьма оригинальная.",
+            "Vlaschenko is a rather original lady."
+        ),
         RandomReply(WhenWillNewVersionBeReady) => {
-            "Интересно, когда будет готова следующая версия?"
+            l10n!(
+                r,
+                "Интересно, когда будет готова следующая версия?",
+                "I wonder when the next version will be ready?"
+            )
         }
         RandomReply(HealthInCafe) => {
-            "Здоровье в кафе повышается в зависимости от наличия денег."
+            l10n!(
+                r,
+                "Здоровье в кафе повышается в зависимости от наличия денег.",
+                "How much health the cafe restores depends on how much money you have."
+            )
         }
-        RandomReply(IfOnlyIKnewProxyAddress) => "Если бы я знал адрес хорошего proxy...",
+        RandomReply(IfOnlyIKnewProxyAddress) => l10n!(
+            r,
+            "Если бы я знал адрес хорошего proxy...",
+            "If only I knew the address of a good proxy..."
+        ),
         RandomReply(StarIsKaput) => {
-            "STAR временно накрылся. Хорошо бы узнать адрес другого proxy..."
+            l10n!(
+                r,
+                "STAR временно накрылся. Хорошо бы узнать адрес другого proxy...",
+                "STAR is down for now. I'd like to know the address of another proxy..."
+            )
         }
         RandomReply(GrishaKnowsProxyAddress) => {
-            "Я подозреваю, что Гриша знает адресок теркомовского proxy."
+            l10n!(
+                r,
+                "Я подозреваю, что Гриша знает адресок теркомовского proxy.",
+                "I suspect Grisha knows the address of TERKOM's proxy."
+            )
         }
         RandomReply(DiamondSpendsAllHisFreeTimeOnTheGame) => {
-            "А Diamond все свободное время дописывает свою игрушку!"
+            l10n!(
+                r,
+                "А Diamond все свободное время дописывает свою игрушку!",
+                "And Diamond spends all his free time finishing his game!"
+            )
         }
         RandomReply(NextTermTerekhovJrWillTeachCS) => {
-            "В следующем семестре информатику будет вести Терехов-младший."
+            l10n!(
+                r,
+                "В следующем семестре информатику будет вести Терехов-младший.",
+                "Next semester Terekhov Jr. will teach CS."
+            )
         }
         RandomReply(DiamondWantsToRewriteItInJava) => {
-            "Diamond хочет переписать это все на Java."
+            l10n!(
+                r,
+                "Diamond хочет переписать это все на Java.",
+                "Diamond wants to rewrite all this in Java."
+            )
         }
         RandomReply(MishaWillTellYouTheStrategy) => {
-            "Миша проконсультирует тебя о стратегии."
+            l10n!(
+                r,
+                "Миша проконсультирует тебя о стратегии.",
+                "Misha will advise you on strategy."
+            )
         }
         RandomReply(TalkWithDiamondHeKnowsALot) => {
-            "Поговори с Diamond'ом, он много ценного скажет."
+            l10n!(
+                r,
+                "Поговори с Diamond'ом, он много ценного скажет.",
+                "Talk to Diamond, he will tell you a lot of useful things."
+            )
+        }
+        RandomReply(FightUntilTheEnd) => {
+            l10n!(r, "Борись до конца!", "Fight to the end!")
         }
-        RandomReply(FightUntilTheEnd) => "Борись до конца!",
         RandomReply(SometimesThereIsFreebieWithDubtsov) => {
-            "У Дубцова иногда бывает халява."
+            l10n!(
+                r,
+                "У Дубцова иногда бывает халява.",
+                "Dubtsov sometimes lets things slide."
+            )
         }
     };
     writeln_colored!(WhiteBright, r, "\"{}\"", reply);
     if serj_leaves {
-        writeln_colored!(White, r, "Серж уходит куда-то по своим делам ...");
+        writeln_colored_l10n!(
+            White,
+            r,
+            "Серж уходит куда-то по своим делам ...",
+            "Serj goes off somewhere on his own business ..."
+        );
     }
     wait_for_any_key(r)
 }
@@ -549,30 +916,50 @@ pub(crate) fn display_rai_interaction(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(7, 0);
-            writeln_colored!(White, r, "RAI не реагирует на твои позывы.");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "RAI не реагирует на твои позывы.",
+                "RAI doesn't respond to your attempts."
+            );
         }
         RaiInteraction::PromptWillYouHelpMe(state) => {
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(9, 0);
             write_colored!(White, r, "RAI:");
-            writeln_colored!(WhiteBright, r, "\"Ты мне поможешь?\"");
+            writeln_colored_l10n!(
+                WhiteBright,
+                r,
+                "\"Ты мне поможешь?\"",
+                "\"Will you help me?\""
+            );
             scene_router::display_short_today_timetable(r, 11, state);
             r.move_cursor_to(11, 0);
             return dialog(r, available_actions);
         }
         RaiInteraction::TakeIt => {
             r.move_cursor_to(14, 0);
-            writeln_colored!(MagentaBright, r, "\"Ах, так! Получай! Получай!\"");
-            writeln_colored!(White, r, "RAI делает тебе больно ...");
+            writeln_colored_l10n!(
+                MagentaBright,
+                r,
+                "\"Ах, так! Получай! Получай!\"",
+                "\"Oh, is that so! Take that! Take that!\""
+            );
+            writeln_colored_l10n!(
+                White,
+                r,
+                "RAI делает тебе больно ...",
+                "RAI hurts you ..."
+            );
         }
         RaiInteraction::YouHelped => {
             r.move_cursor_to(14, 0);
-            writeln_colored!(Green, r, "Ты помог RAI.");
+            writeln_colored_l10n!(Green, r, "Ты помог RAI.", "You helped RAI.");
         }
         RaiInteraction::Fail => {
             r.move_cursor_to(14, 0);
-            writeln_colored!(White, r, "Ничего не вышло.");
+            writeln_colored_l10n!(White, r, "Ничего не вышло.", "It didn't work out.");
         }
     }
     wait_for_any_key(r)
@@ -588,27 +975,38 @@ pub(crate) fn display_nil_interaction(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(7, 0);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 CyanBright,
                 r,
-                "\"Маладой чилавек, вы мне не паможите решить задачу?"
+                "\"Маладой чилавек, вы мне не паможите решить задачу?",
+                "\"Yung man, cud yoo help me solv a problem?"
+            );
+            writeln_l10n!(
+                r,
+                "А то я сигодня ни в зуб нагой ...\"",
+                "Coz todai I dont get it at al ...\""
             );
-            writeln!(r, "А то я сигодня ни в зуб нагой ...\"");
             r.move_cursor_to(10, 0);
             return dialog(r, available_actions);
         }
         NilInteraction::RefusedToHelp => (),
         NilInteraction::ThanksHereIsYourMoney(reward) => {
             r.move_cursor_to(13, 0);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 YellowBright,
                 r,
                 "\"Ой, спасибо! Вот вам {reward} руб. за это...\"",
+                "\"Oh, thank you! Here is {reward} rub. for that...\"",
             );
         }
         NilInteraction::DidntWorkOut => {
             r.move_cursor_to(13, 0);
-            writeln_colored!(MagentaBright, r, "У тебя ничего не вышло.")
+            writeln_colored_l10n!(
+                MagentaBright,
+                r,
+                "У тебя ничего не вышло.",
+                "You didn't manage it."
+            )
         }
     }
     wait_for_any_key(r)
@@ -631,92 +1029,192 @@ pub(crate) fn display_misha_interaction(
     }
     match interaction {
         PromptBugSquasher(_) => {
-            write_colored!(White, r, "Миша : ");
-            writeln_colored!(WhiteBright, r, "\"Слушай, хватит мучаться! Прервись!");
-            writeln!(r, "Давай в клоподавку сыграем!\"");
+            write_colored_l10n!(White, r, "Миша : ", "Misha : ");
+            writeln_colored_l10n!(
+                WhiteBright,
+                r,
+                "\"Слушай, хватит мучаться! Прервись!",
+                "\"Listen, stop torturing yourself! Take a break!"
+            );
+            writeln_l10n!(
+                r,
+                "Давай в клоподавку сыграем!\"",
+                "Let's play bug squasher!\""
+            );
             r.move_cursor_to(11, 0);
             dialog(r, available_actions)
         }
         PlayedBugSquasherWithMisha => {
             r.move_cursor_to(14, 0);
-            writeln_colored!(Green, r, "Ты сыграл с Мишей партию в клоподавку.");
+            writeln_colored_l10n!(
+                Green,
+                r,
+                "Ты сыграл с Мишей партию в клоподавку.",
+                "You played a game of bug squasher with Misha."
+            );
             wait_for_any_key(r)
         }
         TooBad => {
             r.move_cursor_to(14, 0);
-            writeln_colored!(WhiteBright, r, "\"Зря, очень зря!\"");
+            writeln_colored_l10n!(
+                WhiteBright,
+                r,
+                "\"Зря, очень зря!\"",
+                "\"Too bad, really too bad!\""
+            );
             wait_for_any_key(r)
         }
         PromptTennis(_) => {
-            write_colored!(White, r, "Миша : ");
-            writeln_colored!(
+            write_colored_l10n!(White, r, "Миша : ", "Misha : ");
+            writeln_colored_l10n!(
                 WhiteBright,
                 r,
-                "\"Слушай, а ведь в ТЕРКОМе есть столик для тенниса. Сыграем?\""
+                "\"Слушай, а ведь в ТЕРКОМе есть столик для тенниса. Сыграем?\"",
+                "\"Listen, there's a table tennis table at TERKOM. Shall we play?\""
             );
             r.move_cursor_to(11, 0);
             dialog(r, available_actions)
         }
         PlayedTennisWithMisha => {
             r.move_cursor_to(14, 0);
-            writeln_colored!(Green, r, "Ты сыграл с Мишей в теннис.");
+            writeln_colored_l10n!(
+                Green,
+                r,
+                "Ты сыграл с Мишей в теннис.",
+                "You played tennis with Misha."
+            );
             wait_for_any_key(r)
         }
         NoWorries => {
             r.move_cursor_to(14, 0);
-            writeln_colored!(WhiteBright, r, "\"Ничего, я на тебя не в обиде.\"");
+            writeln_colored_l10n!(
+                WhiteBright,
+                r,
+                "\"Ничего, я на тебя не в обиде.\"",
+                "\"That's OK, no hard feelings.\""
+            );
             wait_for_any_key(r)
         }
         RandomReply(_, reply) => {
-            write_colored!(White, r, "Миша:");
+            write_colored_l10n!(White, r, "Миша:", "Misha:");
             let reply_text = match reply {
-                TooBadNowhereToPlayBugSquasher => "Эх, жаль, негде сыграть в клоподавку!",
-                AlwaysPayAttentionToHealth => "Всегда следи за здоровьем!",
+                TooBadNowhereToPlayBugSquasher => l10n!(
+                    r,
+                    "Эх, жаль, негде сыграть в клоподавку!",
+                    "Eh, too bad there's nowhere to play bug squasher!"
+                ),
+                AlwaysPayAttentionToHealth => {
+                    l10n!(r, "Всегда следи за здоровьем!", "Always watch your health!")
+                }
                 BrainLevelAffectsExamSuccess => {
-                    "Мозги влияют на подготовку и сдачу зачетов."
+                    l10n!(
+                        r,
+                        "Мозги влияют на подготовку и сдачу зачетов.",
+                        "Brains affect studying and passing tests."
+                    )
                 }
                 TheMoreStaminaTheLessHealthYouSpend => {
-                    "Чем больше выносливость, тем меньше здоровья ты тратишь."
+                    l10n!(
+                        r,
+                        "Чем больше выносливость, тем меньше здоровья ты тратишь.",
+                        "The more stamina you have, the less health you spend."
+                    )
                 }
                 TheMoreCharismaTheBetterRelationshipsWithPeople => {
-                    "Чем больше твоя харизма, тем лучше у тебя отношения с людьми."
+                    l10n!(r, "Чем больше твоя харизма, тем лучше у тебя отношения с людьми.", "The more charisma you have, the better your relations with people.")
                 }
                 ImportanceOfCharacteristicAffectsGameStyle => {
-                    "Важность конкретного качества сильно зависит от стиля игры."
+                    l10n!(
+                        r,
+                        "Важность конкретного качества сильно зависит от стиля игры.",
+                        "How important each quality is depends a lot on your play style."
+                    )
                 }
                 CharismaHelpsGetAnything => {
-                    "Харизма помогает получить что угодно от кого угодно."
+                    l10n!(
+                        r,
+                        "Харизма помогает получить что угодно от кого угодно.",
+                        "Charisma helps you get anything from anyone."
+                    )
                 }
                 TheMoreCharismaTheMoreYouAreApproached => {
-                    "Чем больше харизма, тем чаще к тебе пристают."
+                    l10n!(
+                        r,
+                        "Чем больше харизма, тем чаще к тебе пристают.",
+                        "The more charisma, the more often people pester you."
+                    )
                 }
                 TheLessStaminaTheMorePainfulStudyingIs => {
-                    "Чем меньше выносливость, тем больнее учиться."
+                    l10n!(
+                        r,
+                        "Чем меньше выносливость, тем больнее учиться.",
+                        "The less stamina, the more painful studying is."
+                    )
                 }
                 TheMoreBrainTheMoreEasyToPrepare => {
-                    "Чем больше мозги, тем легче готовиться."
+                    l10n!(
+                        r,
+                        "Чем больше мозги, тем легче готовиться.",
+                        "The more brains, the easier it is to study."
+                    )
                 }
                 InternetSometimesImprovesBrain => {
-                    "Сидение в Inet'e иногда развивает мозги."
+                    l10n!(
+                        r,
+                        "Сидение в Inet'e иногда развивает мозги.",
+                        "Surfing the Internet sometimes develops your brain."
+                    )
                 }
                 IfTiredOfDyingTryAnotherStrategy => {
-                    "Если тебе надоело умирать - попробуй другую стратегию."
+                    l10n!(
+                        r,
+                        "Если тебе надоело умирать - попробуй другую стратегию.",
+                        "If you're tired of dying - try another strategy."
+                    )
                 }
-                WantFreebieGetCharisma => "Хочешь халявы - набирай харизму.",
+                WantFreebieGetCharisma => l10n!(
+                    r,
+                    "Хочешь халявы - набирай харизму.",
+                    "Want freebies - build up charisma."
+                ),
                 WantAchieveEverythingYourselfImproveBrain => {
-                    "Хочешь добиться всего сам - развивай мозги."
+                    l10n!(
+                        r,
+                        "Хочешь добиться всего сам - развивай мозги.",
+                        "Want to achieve everything yourself - develop your brain."
+                    )
                 }
                 InMausoleumKnowingWhenToStopIsImportant => {
-                    "В \"Мавзолее\" важно знать меру..."
+                    l10n!(
+                        r,
+                        "В \"Мавзолее\" важно знать меру...",
+                        "In the \"Mausoleum\" it's important to know when to stop..."
+                    )
                 }
                 CharismaAndStaminaSaveFromPersonalityDisorder => {
-                    "От раздвоения личности спасают харизма и выносливость."
+                    l10n!(
+                        r,
+                        "От раздвоения личности спасают харизма и выносливость.",
+                        "Charisma and stamina save you from a split personality."
+                    )
                 }
                 YouGetStupidFromInteractingWithNil => {
-                    "От любого общения с NiL ты тупеешь!"
+                    l10n!(
+                        r,
+                        "От любого общения с NiL ты тупеешь!",
+                        "Any contact with NiL makes you dumber!"
+                    )
                 }
-                GrishaCanHelpWithEmployment => "Гриша может помочь с трудоустройством.",
-                NpcMovementsArePredictable => "Перемещения студентов предсказуемы.",
+                GrishaCanHelpWithEmployment => l10n!(
+                    r,
+                    "Гриша может помочь с трудоустройством.",
+                    "Grisha can help you get a job."
+                ),
+                NpcMovementsArePredictable => l10n!(
+                    r,
+                    "Перемещения студентов предсказуемы.",
+                    "The movements of students are predictable."
+                ),
             };
             writeln_colored!(WhiteBright, r, "\"{reply_text}\"");
             wait_for_any_key(r)
@@ -732,10 +1230,11 @@ pub(crate) fn display_djug_interaction<C: RendererRequestConsumer>(
     scene_router::display_header_stats(r, state);
     r.move_cursor_to(7, 0);
     write_colored!(White, r, "DJuG:");
-    writeln_colored!(
+    writeln_colored_l10n!(
         WhiteBright,
         r,
-        "\"У Вас какой-то школьный метод решения задач...\""
+        "\"У Вас какой-то школьный метод решения задач...\"",
+        "\"Your method of solving problems is somewhat schoolboyish...\""
     );
     wait_for_any_key(r)
 }
@@ -752,59 +1251,136 @@ pub(crate) fn display_andrew_interaction<C: RendererRequestConsumer>(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(7, 0);
-            writeln_colored!(White, r, "Обратиться к Эндрю за помощью?");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Обратиться к Эндрю за помощью?",
+                "Ask Andrew for help?"
+            );
             r.move_cursor_to(9, 0);
             dialog(r, available_actions)
         }
         RandomReply(reply) => {
             r.move_cursor_to(12, 0);
-            write_colored!(White, r, "Эндрю: ");
+            write_colored_l10n!(White, r, "Эндрю: ", "Andrew: ");
             let reply_text = match reply {
                 TellDiamondTooLittleDescriptions => {
-                    "Скажи Diamond'у, что маловато описалова!"
+                    l10n!(
+                        r,
+                        "Скажи Diamond'у, что маловато описалова!",
+                        "Tell Diamond there are too few descriptions!"
+                    )
                 }
                 DiamondThoughtAboutRewritingThisInJavaScript => {
-                    "А еще Diamond думал переписать это на JavaScript."
+                    l10n!(
+                        r,
+                        "А еще Diamond думал переписать это на JavaScript.",
+                        "And Diamond also thought about rewriting this in JavaScript."
+                    )
                 }
                 IKnowTheWinningStrategy => {
-                    "А я знаю выигрышную стратегию! Если только не замочат..."
+                    l10n!(
+                        r,
+                        "А я знаю выигрышную стратегию! Если только не замочат...",
+                        "And I know the winning strategy! Unless they kill me..."
+                    )
                 }
-                ThisIsHappeningInMay1998 => "Вообще-то, все это происходит в мае 1998 г.",
+                ThisIsHappeningInMay1998 => l10n!(
+                    r,
+                    "Вообще-то, все это происходит в мае 1998 г.",
+                    "Actually, all this is happening in May 1998."
+                ),
                 ISawASignOnATable => {
-                    "Я видел надпись на парте: ЗАКОН ВСЕМИРНОВА ТЯГОТЕНИЯ"
+                    l10n!(
+                        r,
+                        "Я видел надпись на парте: ЗАКОН ВСЕМИРНОВА ТЯГОТЕНИЯ",
+                        "I saw writing on a desk: VSEMIRNOV'S LAW OF GRAVITATION"
+                    )
+                }
+                VisitMmheroesWebsite => {
+                    l10n!(r, "Загляни на mmheroes.chat.ru!", "Visit mmheroes.chat.ru!")
                 }
-                VisitMmheroesWebsite => "Загляни на mmheroes.chat.ru!",
                 DontSuggestRewritingThisInProlog => {
-                    "Только не предлагай Diamond'у переписать все на Прологе!"
+                    l10n!(
+                        r,
+                        "Только не предлагай Diamond'у переписать все на Прологе!",
+                        "Just do not suggest Diamond rewrite it all in Prolog!"
+                    )
                 }
-                WhenWillLinuxPortBeReady => "Ну когда же будет порт под Linux?",
-                VmwareSuxx => "VMWARE - SUXX... Но под ним идут Heroes of Mat & Mech!",
+                WhenWillLinuxPortBeReady => l10n!(
+                    r,
+                    "Ну когда же будет порт под Linux?",
+                    "So when will the Linux port be ready?"
+                ),
+                VmwareSuxx => l10n!(
+                    r,
+                    "VMWARE - SUXX... Но под ним идут Heroes of Mat & Mech!",
+                    "VMWARE - SUXX... But Heroes of Mat & Mech runs under it!"
+                ),
                 SeemsLikeMyStrategyIsNotWorkingOut => {
-                    "Похоже, что моя стратегия обламывается..."
+                    l10n!(
+                        r,
+                        "Похоже, что моя стратегия обламывается...",
+                        "Looks like my strategy is falling apart..."
+                    )
                 }
-                Gamma314ThereIsSomethingInIt => "Ух ты! Гамма 3.14 - в этом что-то есть.",
+                Gamma314ThereIsSomethingInIt => l10n!(
+                    r,
+                    "Ух ты! Гамма 3.14 - в этом что-то есть.",
+                    "Wow! Gamma 3.14 - there is something to it."
+                ),
                 MaybeDiamondIsCrazyAboutEllipsis => {
-                    "Может быть, Diamond'а просто заклинило на многоточиях?"
+                    l10n!(
+                        r,
+                        "Может быть, Diamond'а просто заклинило на многоточиях?",
+                        "Maybe Diamond is just stuck on ellipses?"
+                    )
                 }
                 YouCanEarnMoneyByDoingNothing => {
-                    "Говорят, можно зарабатывать деньги, почти ничего не делая."
+                    l10n!(
+                        r,
+                        "Говорят, можно зарабатывать деньги, почти ничего не делая.",
+                        "They say you can earn money doing almost nothing."
+                    )
                 }
                 SometimesItsHardForMe => {
-                    "Вот, иногда мне приходится тяжко - когда пристают всякие..."
+                    l10n!(r, "Вот, иногда мне приходится тяжко - когда пристают всякие...", "Sometimes it's hard for me - when all sorts of people pester me...")
                 }
                 IsItGoodThatManyRepliesAreAboutTheGame => {
-                    "Хорошо ли, что многие реплики персонажей посвящены самой игре?"
+                    l10n!(r, "Хорошо ли, что многие реплики персонажей посвящены самой игре?", "Is it good that so many of the characters' lines are about the game?")
                 }
-                HelpMeIWantInternet => "Помогите мне! Хочу в Inet!",
-                WhatNothing => "А что? А ничего.",
+                HelpMeIWantInternet => l10n!(
+                    r,
+                    "Помогите мне! Хочу в Inet!",
+                    "Help me! I want to go online!"
+                ),
+                WhatNothing => l10n!(r, "А что? А ничего.", "So what? So nothing."),
                 IfItsBurgundyYouAreDreaming => {
-                    "Если оно цвета бордо - значит, оно тебе снится."
+                    l10n!(
+                        r,
+                        "Если оно цвета бордо - значит, оно тебе снится.",
+                        "If it's burgundy - then you're dreaming it."
+                    )
+                }
+                HappyMathMechDay => {
+                    l10n!(r, "Всех с ДНЕМ МАТ-МЕХА!", "Happy MATH-MECH DAY, everyone!")
                 }
-                HappyMathMechDay => "Всех с ДНЕМ МАТ-МЕХА!",
-                ThinkOfAPhraseForACharacter => "Придумай свою фразу для персонажа!",
-                MmheroesIs120kOfSources => "120К исходников - вот что такое mmHeroes!",
+                ThinkOfAPhraseForACharacter => l10n!(
+                    r,
+                    "Придумай свою фразу для персонажа!",
+                    "Think up your own phrase for a character!"
+                ),
+                MmheroesIs120kOfSources => l10n!(
+                    r,
+                    "120К исходников - вот что такое mmHeroes!",
+                    "120K of sources - that is what mmHeroes is!"
+                ),
                 MmheroesIs120kOfSloppySources => {
-                    "120К весьма кривых исходников - вот что такое mmHeroes!"
+                    l10n!(
+                        r,
+                        "120К весьма кривых исходников - вот что такое mmHeroes!",
+                        "120K of rather sloppy sources - that is what mmHeroes is!"
+                    )
                 }
             };
             writeln_colored!(WhiteBright, r, "\"{reply_text}\"");
@@ -815,22 +1391,28 @@ pub(crate) fn display_andrew_interaction<C: RendererRequestConsumer>(
             prediction,
         } => {
             r.move_cursor_to(12, 0);
-            write_colored!(White, r, "Эндрю: ");
-            write_colored!(
+            write_colored_l10n!(White, r, "Эндрю: ", "Andrew: ");
+            write_colored_l10n!(
                 WhiteBright,
                 r,
                 "\"Я подозреваю, что {}",
-                professor_name(*subject)
+                "\"I suspect that {}",
+                professor_name(r.locale(), *subject)
             );
             if *prediction > 0 {
-                writeln!(
+                writeln_l10n!(
                     r,
                     " зачтет тебе за 1 заход {} {}.\"",
+                    " will pass {} {} for you in one go.\"",
                     *prediction,
-                    problems_inflected(*prediction)
+                    problems_inflected(r.locale(), *prediction)
                 );
             } else {
-                writeln!(r, " ничего тебе не засчитает.\"");
+                writeln_l10n!(
+                    r,
+                    " ничего тебе не засчитает.\"",
+                    " won't pass anything for you.\""
+                );
             }
             wait_for_any_key(r)
         }
@@ -839,27 +1421,55 @@ pub(crate) fn display_andrew_interaction<C: RendererRequestConsumer>(
             no_problems_remaining,
         } => {
             r.move_cursor_to(12, 0);
-            writeln_colored!(White, r, "Эндрю вглядывается в твои задачки,");
-            writeln!(r, "и начинает думать очень громко...");
-            writeln!(
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Эндрю вглядывается в твои задачки,",
+                "Andrew peers at your problems"
+            );
+            writeln_l10n!(
                 r,
-                "Пока Эндрю так напрягается, ты не можешь ни на чем сосредоточиться!"
+                "и начинает думать очень громко...",
+                "and starts thinking very loudly..."
+            );
+            writeln_l10n!(
+                r,
+                "Пока Эндрю так напрягается, ты не можешь ни на чем сосредоточиться!",
+                "While Andrew strains so hard, you can't concentrate on anything!"
             );
             if *solved_by_andrew > 0 {
-                write!(r, "Эндрю решил тебе ");
+                write_l10n!(r, "Эндрю решил тебе ", "Andrew has solved ");
                 write_colored!(WhiteBright, r, "{}", *solved_by_andrew);
-                writeln_colored!(White, r, " {}!", problems_inflected(*solved_by_andrew));
+                writeln_colored!(
+                    White,
+                    r,
+                    " {}!",
+                    problems_inflected(r.locale(), *solved_by_andrew)
+                );
                 if *no_problems_remaining {
-                    writeln!(r, "Надо будет подойти с зачеткой!");
+                    writeln_l10n!(
+                        r,
+                        "Надо будет подойти с зачеткой!",
+                        "You will need to come with your record book!"
+                    );
                 }
             } else {
-                writeln!(r, "У Эндрю ничего не вышло...")
+                writeln_l10n!(
+                    r,
+                    "У Эндрю ничего не вышло...",
+                    "Andrew didn't manage anything..."
+                )
             }
             wait_for_any_key(r)
         }
         AndrewIgnoresYou => {
             r.move_cursor_to(12, 0);
-            writeln_colored!(RedBright, r, "Эндрю тебя игнорирует!");
+            writeln_colored_l10n!(
+                RedBright,
+                r,
+                "Эндрю тебя игнорирует!",
+                "Andrew ignores you!"
+            );
             wait_for_any_key(r)
         }
     }
@@ -15,19 +15,35 @@ pub(in crate::ui) fn display_scene_router(
 
     match state.location() {
         Location::PUNK => {
-            write!(r, "Ты на факультете. Что делать?");
+            write_l10n!(
+                r,
+                "Ты на факультете. Что делать?",
+                "You are at the faculty. What to do?"
+            );
         }
         Location::PDMI => {
-            write!(r, "Ты в ПОМИ. Что делать?");
+            write_l10n!(r, "Ты в ПОМИ. Что делать?", "You are in PDMI. What to do?");
         }
         Location::ComputerClass => {
-            write!(r, "Ты в компьютерном классе. Что делать?");
+            write_l10n!(
+                r,
+                "Ты в компьютерном классе. Что делать?",
+                "You are in the computer class. What to do?"
+            );
         }
         Location::Dorm => {
-            write!(r, "Ты в общаге. Что делать?");
+            write_l10n!(
+                r,
+                "Ты в общаге. Что делать?",
+                "You are in the dorm. What to do?"
+            );
         }
         Location::Mausoleum => {
-            write!(r, "Ты в мавзолее. Что делать?");
+            write_l10n!(
+                r,
+                "Ты в мавзолее. Что делать?",
+                "You are in the mausoleum. What to do?"
+            );
         }
     }
 
@@ -45,7 +61,7 @@ pub(in crate::ui) fn display_study_options(
     display_short_today_timetable(r, 9, state);
     r.set_color(Color::White, Color::Black);
     r.move_cursor_to(7, 0);
-    writeln!(r, "К чему готовиться?");
+    writeln_l10n!(r, "К чему готовиться?", "What to study for?");
     r.move_cursor_to(9, 0);
     dialog(r, available_actions)
 }
@@ -63,33 +79,53 @@ pub(in crate::ui::screens) fn display_header_stats(
     r: &mut Renderer<impl RendererRequestConsumer>,
     state: &GameState,
 ) {
-    display_character_stats(r, state.current_day(), state.current_time(), state.player());
-    display_knowledge(r, state.player());
+    display_character_stats(r, state.current_day(), state.current_time(), state);
+    display_knowledge(r, state.player(), state.rules());
 }
 
 fn display_character_stats(
     r: &mut Renderer<impl RendererRequestConsumer>,
     today: &Day,
     now: Time,
-    player: &Player,
+    state: &GameState,
 ) {
-    write_colored!(White, r, "Сегодня ");
-    // Первый день недели — 22-е мая.
-    write_colored!(WhiteBright, r, "{}", today.index() + 22);
-    write_colored!(White, r, "е мая; ");
+    let player = state.player();
+    let (day, month) = calendar_date(today.index());
+    let month = month_name(r.locale(), month);
+    l10n!(
+        r,
+        {
+            write_colored!(White, r, "Сегодня ");
+            write_colored!(WhiteBright, r, "{}", day);
+            write_colored!(White, r, "е {}; ", month);
+        },
+        {
+            write_colored!(White, r, "Today is {} ", month);
+            write_colored!(WhiteBright, r, "{}", day);
+            write_colored!(White, r, "; ");
+        },
+    );
     write_colored!(WhiteBright, r, "{}:00", now);
     r.move_cursor_to(0, 25);
-    writeln_colored!(MagentaBright, r, "Версия gamma3.14");
+    writeln_colored_l10n!(MagentaBright, r, "Версия gamma3.14", "Version gamma3.14");
 
-    write_colored!(White, r, "Самочувствие: ");
-    match HealthAssessment::from_health_level(player.health()) {
-        HealthAssessment::LivingDead => write_colored!(Magenta, r, "живой труп"),
-        HealthAssessment::TimeToDie => write_colored!(Red, r, "пора помирать ..."),
-        HealthAssessment::Bad => write_colored!(Red, r, "плохое"),
-        HealthAssessment::SoSo => write_colored!(YellowBright, r, "так себе"),
-        HealthAssessment::Average => write_colored!(YellowBright, r, "среднее"),
-        HealthAssessment::Good => write_colored!(Green, r, "хорошее"),
-        HealthAssessment::Great => write_colored!(Green, r, "отличное"),
+    write_colored_l10n!(White, r, "Самочувствие: ", "Health: ");
+    match HealthAssessment::from_health_level(player.health(), state.rules()) {
+        HealthAssessment::LivingDead => {
+            write_colored_l10n!(Magenta, r, "живой труп", "living dead")
+        }
+        HealthAssessment::TimeToDie => {
+            write_colored_l10n!(Red, r, "пора помирать ...", "time to die ...")
+        }
+        HealthAssessment::Bad => write_colored_l10n!(Red, r, "плохое", "bad"),
+        HealthAssessment::SoSo => {
+            write_colored_l10n!(YellowBright, r, "так себе", "so-so")
+        }
+        HealthAssessment::Average => {
+            write_colored_l10n!(YellowBright, r, "среднее", "average")
+        }
+        HealthAssessment::Good => write_colored_l10n!(Green, r, "хорошее", "good"),
+        HealthAssessment::Great => write_colored_l10n!(Green, r, "отличное", "excellent"),
     }
     if cfg!(debug_assertions) {
         // Выводим точное значение для удобства тестирования
@@ -97,40 +133,72 @@ fn display_character_stats(
     }
     writeln!(r);
 
-    write_colored!(White, r, "Финансы: ");
+    write_colored_l10n!(White, r, "Финансы: ", "Money: ");
     if player.money() > Money::zero() {
         write_colored!(WhiteBright, r, "{}", player.money());
-        writeln_colored!(White, r, " руб.");
+        writeln_colored_l10n!(White, r, " руб.", " rub.");
     } else if !player.got_stipend() {
-        writeln_colored!(RedBright, r, "Надо получить деньги за май...");
+        writeln_colored_l10n!(
+            RedBright,
+            r,
+            "Надо получить деньги за май...",
+            "Need to get the money for May..."
+        );
     } else {
-        writeln_colored!(White, r, "Ты успел потратить все деньги.");
+        writeln_colored_l10n!(
+            White,
+            r,
+            "Ты успел потратить все деньги.",
+            "You've already spent all the money."
+        );
     }
 
-    match BrainAssessment::from_brain_level(player.brain()) {
-        BrainAssessment::ClinicalBrainDeath => {
-            write_colored!(Magenta, r, "Клиническая смерть мозга")
-        }
-        BrainAssessment::BrainIsAlmostNonFunctioning => {
-            write_colored!(Magenta, r, "Голова просто никакая")
-        }
-        BrainAssessment::ThinkingIsAlmostImpossible => {
-            write_colored!(RedBright, r, "Думать практически невозможно")
-        }
+    match BrainAssessment::from_brain_level(player.brain(), state.rules()) {
+        BrainAssessment::ClinicalBrainDeath => write_colored_l10n!(
+            Magenta,
+            r,
+            "Клиническая смерть мозга",
+            "Clinical brain death"
+        ),
+        BrainAssessment::BrainIsAlmostNonFunctioning => write_colored_l10n!(
+            Magenta,
+            r,
+            "Голова просто никакая",
+            "Your head is just useless"
+        ),
+        BrainAssessment::ThinkingIsAlmostImpossible => write_colored_l10n!(
+            RedBright,
+            r,
+            "Думать практически невозможно",
+            "Thinking is nearly impossible"
+        ),
         BrainAssessment::ThinkingIsDifficult => {
-            write_colored!(RedBright, r, "Думать трудно")
-        }
-        BrainAssessment::BrainIsAlmostOK => {
-            write_colored!(YellowBright, r, "Голова почти в норме")
+            write_colored_l10n!(RedBright, r, "Думать трудно", "Thinking is hard")
         }
-        BrainAssessment::BrainIsOK => write_colored!(YellowBright, r, "Голова в норме"),
-        BrainAssessment::BrainIsFresh => write_colored!(Green, r, "Голова свежая"),
-        BrainAssessment::ExtraordinaryEaseOfThought => {
-            write_colored!(Green, r, "Легкость в мыслях необыкновенная")
+        BrainAssessment::BrainIsAlmostOK => write_colored_l10n!(
+            YellowBright,
+            r,
+            "Голова почти в норме",
+            "Your head is almost OK"
+        ),
+        BrainAssessment::BrainIsOK => {
+            write_colored_l10n!(YellowBright, r, "Голова в норме", "Your head is OK")
         }
-        BrainAssessment::ContactTheDeveloper => {
-            write_colored!(CyanBright, r, "Обратитесь к разработчику ;)")
+        BrainAssessment::BrainIsFresh => {
+            write_colored_l10n!(Green, r, "Голова свежая", "Your head is fresh")
         }
+        BrainAssessment::ExtraordinaryEaseOfThought => write_colored_l10n!(
+            Green,
+            r,
+            "Легкость в мыслях необыкновенная",
+            "Extraordinary ease of thought"
+        ),
+        BrainAssessment::ContactTheDeveloper => write_colored_l10n!(
+            CyanBright,
+            r,
+            "Обратитесь к разработчику ;)",
+            "Contact the developer ;)"
+        ),
     }
     if cfg!(debug_assertions) {
         // Выводим точное значение для удобства тестирования
@@ -139,30 +207,51 @@ fn display_character_stats(
     writeln!(r);
 
     match StaminaAssessment::from_stamina_level(player.stamina()) {
-        StaminaAssessment::MamaTakeMeBack => {
-            write_colored!(Magenta, r, "Мама, роди меня обратно!")
-        }
-        StaminaAssessment::CompletelyOverstudied => {
-            write_colored!(Magenta, r, "Окончательно заучился")
-        }
-        StaminaAssessment::ICantTakeIt => {
-            write_colored!(RedBright, r, "Я так больше немогууу!")
-        }
-        StaminaAssessment::IWishItAllEndedSoon => {
-            write_colored!(RedBright, r, "Скорее бы все это кончилось...")
-        }
-        StaminaAssessment::ALittleMoreAndThenRest => {
-            write_colored!(YellowBright, r, "Еще немного и пора отдыхать")
-        }
+        StaminaAssessment::MamaTakeMeBack => write_colored_l10n!(
+            Magenta,
+            r,
+            "Мама, роди меня обратно!",
+            "Mommy, take me back!"
+        ),
+        StaminaAssessment::CompletelyOverstudied => write_colored_l10n!(
+            Magenta,
+            r,
+            "Окончательно заучился",
+            "Completely overstudied"
+        ),
+        StaminaAssessment::ICantTakeIt => write_colored_l10n!(
+            RedBright,
+            r,
+            "Я так больше немогууу!",
+            "I can't take it anymoooore!"
+        ),
+        StaminaAssessment::IWishItAllEndedSoon => write_colored_l10n!(
+            RedBright,
+            r,
+            "Скорее бы все это кончилось...",
+            "I wish it all ended soon..."
+        ),
+        StaminaAssessment::ALittleMoreAndThenRest => write_colored_l10n!(
+            YellowBright,
+            r,
+            "Еще немного и пора отдыхать",
+            "A little more and then a rest"
+        ),
         StaminaAssessment::ABitTired => {
-            write_colored!(YellowBright, r, "Немного устал")
-        }
-        StaminaAssessment::ReadyForEverything => {
-            write_colored!(Green, r, "Готов к труду и обороне")
-        }
-        StaminaAssessment::GreatThingsAwaitUs => {
-            write_colored!(Green, r, "Нас ждут великие дела")
+            write_colored_l10n!(YellowBright, r, "Немного устал", "A bit tired")
         }
+        StaminaAssessment::ReadyForEverything => write_colored_l10n!(
+            Green,
+            r,
+            "Готов к труду и обороне",
+            "Ready for work and defense"
+        ),
+        StaminaAssessment::GreatThingsAwaitUs => write_colored_l10n!(
+            Green,
+            r,
+            "Нас ждут великие дела",
+            "Great things await us"
+        ),
     }
     if cfg!(debug_assertions) {
         // Выводим точное значение для удобства тестирования
@@ -171,27 +260,45 @@ fn display_character_stats(
     writeln!(r);
 
     match CharismaAssessment::from_charisma_level(player.charisma()) {
-        CharismaAssessment::VeryIntroverted => {
-            write_colored!(Magenta, r, "Очень замкнутый товарищ")
-        }
-        CharismaAssessment::PreferSolitariness => {
-            write_colored!(Magenta, r, "Предпочитаешь одиночество")
-        }
-        CharismaAssessment::VeryHardToTalkToPeople => {
-            write_colored!(RedBright, r, "Тебе трудно общаться с людьми")
-        }
-        CharismaAssessment::NotEasyToTalkToPeople => {
-            write_colored!(RedBright, r, "Тебе непросто общаться с людьми")
-        }
-        CharismaAssessment::Normal => {
-            write_colored!(YellowBright, r, "Ты нормально относишься к окружающим")
-        }
+        CharismaAssessment::VeryIntroverted => write_colored_l10n!(
+            Magenta,
+            r,
+            "Очень замкнутый товарищ",
+            "A very withdrawn fellow"
+        ),
+        CharismaAssessment::PreferSolitariness => write_colored_l10n!(
+            Magenta,
+            r,
+            "Предпочитаешь одиночество",
+            "You prefer solitude"
+        ),
+        CharismaAssessment::VeryHardToTalkToPeople => write_colored_l10n!(
+            RedBright,
+            r,
+            "Тебе трудно общаться с людьми",
+            "You find people hard to talk to"
+        ),
+        CharismaAssessment::NotEasyToTalkToPeople => write_colored_l10n!(
+            RedBright,
+            r,
+            "Тебе непросто общаться с людьми",
+            "Talking to people isn't easy"
+        ),
+        CharismaAssessment::Normal => write_colored_l10n!(
+            YellowBright,
+            r,
+            "Ты нормально относишься к окружающим",
+            "You get along with people fine"
+        ),
         CharismaAssessment::ManyFriends => {
-            write_colored!(Green, r, "У тебя много друзей")
-        }
-        CharismaAssessment::TonsOfFriends => {
-            write_colored!(Green, r, "У тебя очень много друзей")
+            write_colored_l10n!(Green, r, "У тебя много друзей", "You have many friends")
         }
+        CharismaAssessment::TonsOfFriends => write_colored_l10n!(
+            Green,
+            r,
+            "У тебя очень много друзей",
+            "You have tons of friends"
+        ),
     }
 
     if cfg!(debug_assertions) {
@@ -211,29 +318,34 @@ fn color_for_assessment(assessment: KnowledgeAssessment) -> Color {
     }
 }
 
-fn display_knowledge(r: &mut Renderer<impl RendererRequestConsumer>, player: &Player) {
+fn display_knowledge(
+    r: &mut Renderer<impl RendererRequestConsumer>,
+    player: &Player,
+    rules: &Rules,
+) {
     for (i, subject) in Subject::all_subjects().enumerate() {
         let line = i as Line;
         r.move_cursor_to(line, 44);
-        write_colored!(CyanBright, r, "{}", subject_name(subject));
+        write_colored!(CyanBright, r, "{}", subject_name(r.locale(), subject));
 
         let knowledge = player.status_for_subject(subject).knowledge();
         r.move_cursor_to(line, 66);
         r.set_color(
-            color_for_assessment(KnowledgeAssessment::absolute(knowledge)),
+            color_for_assessment(KnowledgeAssessment::absolute(knowledge, rules)),
             Color::Black,
         );
         write!(r, "{}", knowledge);
 
-        let relative_assessment = KnowledgeAssessment::relative(knowledge, subject);
+        let relative_assessment =
+            KnowledgeAssessment::relative(knowledge, subject, rules);
         r.move_cursor_to(line, 70);
         r.set_color(color_for_assessment(relative_assessment), Color::Black);
         let assessment_description = match relative_assessment {
-            KnowledgeAssessment::Bad => "Плохо",
-            KnowledgeAssessment::Satisfactory => "Удовл.",
-            KnowledgeAssessment::Good => "Хорошо",
+            KnowledgeAssessment::Bad => l10n!(r, "Плохо", "Bad"),
+            KnowledgeAssessment::Satisfactory => l10n!(r, "Удовл.", "Fair"),
+            KnowledgeAssessment::Good => l10n!(r, "Хорошо", "Good"),
             KnowledgeAssessment::VeryGood => unreachable!(),
-            KnowledgeAssessment::Excellent => "Отлично",
+            KnowledgeAssessment::Excellent => l10n!(r, "Отлично", "Excellent"),
         };
         write!(r, "{}", assessment_description);
     }
@@ -254,11 +366,11 @@ pub(in crate::ui::screens) fn display_short_today_timetable<
             r.set_color(if passed { if_passed } else { if_not_passed }, Color::Black)
         };
         set_color_if_passed(r, Color::Blue, Color::CyanBright);
-        write!(r, "{}", subject_short_name(subject));
+        write!(r, "{}", subject_short_name(r.locale(), subject));
         r.move_cursor_to(line, 57);
         set_color_if_passed(r, Color::Magenta, Color::RedBright);
         if let Some(exam) = state.current_day().exam(subject) {
-            write!(r, "{}", exam.location());
+            write!(r, "{}", location_name(r.locale(), exam.location()));
             set_color_if_passed(r, Color::Gray, Color::WhiteBright);
             r.move_cursor_to(line, 63);
             write!(r, "{}-{}", exam.from(), exam.to());
@@ -268,7 +380,7 @@ pub(in crate::ui::screens) fn display_short_today_timetable<
 
         r.move_cursor_to(line, 71);
         let problems_done = state.player().status_for_subject(subject).problems_done();
-        let problems_required = subject.required_problems();
+        let problems_required = state.rules().subject(subject).required_problems;
         let problems_color = if problems_done == 0 {
             Color::White
         } else if problems_done >= problems_required {
@@ -288,10 +400,11 @@ pub(in crate::ui) fn display_surfing_internet(
 ) -> WaitingState {
     if found_program {
         r.move_cursor_to(19, 0);
-        write_colored!(
+        write_colored_l10n!(
             CyanBright,
             r,
-            "Ух ты! Ты нашел програмку, которая нужна для Климова!"
+            "Ух ты! Ты нашел програмку, которая нужна для Климова!",
+            "Wow! You've found the little program that Klimov needs!"
         );
     }
     wait_for_any_key(r)
@@ -306,10 +419,18 @@ pub(in crate::ui) fn display_available_professors(
     display_header_stats(r, state);
     r.move_cursor_to(7, 0);
     match state.location() {
-        Location::PUNK => {
-            writeln_colored!(White, r, "Ты сейчас на факультете. К кому идти?")
-        }
-        Location::PDMI => writeln_colored!(White, r, "Ты сейчас в ПОМИ. К кому идти?"),
+        Location::PUNK => writeln_colored_l10n!(
+            White,
+            r,
+            "Ты сейчас на факультете. К кому идти?",
+            "You are at the faculty now. Whom to go to?"
+        ),
+        Location::PDMI => writeln_colored_l10n!(
+            White,
+            r,
+            "Ты сейчас в ПОМИ. К кому идти?",
+            "You are in PDMI now. Whom to go to?"
+        ),
         _ => unreachable!("В этой локации нельзя ходить к преподам"),
     }
     writeln!(r);
@@ -323,7 +444,12 @@ pub(in crate::ui) fn display_computer_class_closing(
     r.clear_screen();
     display_header_stats(r, state);
     r.move_cursor_to(7, 0);
-    writeln_colored!(White, r, "Класс закрывается. Пошли домой!");
+    writeln_colored_l10n!(
+        White,
+        r,
+        "Класс закрывается. Пошли домой!",
+        "The class is closing. Let's go home!"
+    );
     wait_for_any_key(r)
 }
 
@@ -339,27 +465,35 @@ pub(in crate::ui) fn display_invitation_from_neighbor(
             display_header_stats(r, state);
             r.move_cursor_to(7, 0);
             let occasion_text = match occasion {
-                Birthday => "на свой День Рожденья",
-                DiscoParty => "на дискотеку в \"Шайбе\"",
-                PlayMafia => "поиграть в мафию",
-                PlayQuake => "по-Quakать",
+                Birthday => l10n!(r, "на свой День Рожденья", "to his birthday party"),
+                DiscoParty => {
+                    l10n!(r, "на дискотеку в \"Шайбе\"", "to a disco at the \"Puck\"")
+                }
+                PlayMafia => l10n!(r, "поиграть в мафию", "to play Mafia"),
+                PlayQuake => l10n!(r, "по-Quakать", "to play some Quake"),
             };
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
-                "К тебе ломится сосед и приглашает тебя {occasion_text}."
+                "К тебе ломится сосед и приглашает тебя {occasion_text}.",
+                "Your neighbor bursts in and invites you {occasion_text}.",
             );
             r.move_cursor_to(9, 0);
             dialog(r, available_actions)
         }
         LetsGo => {
             r.move_cursor_to(13, 0);
-            writeln_colored!(White, r, "\"Пошли оттягиваться!\"");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "\"Пошли оттягиваться!\"",
+                "\"Let's party!\""
+            );
             wait_for_any_key(r)
         }
         TooBad => {
             r.move_cursor_to(13, 0);
-            writeln_colored!(White, r, "\"Ну и зря!\"");
+            writeln_colored_l10n!(White, r, "\"Ну и зря!\"", "\"Your loss!\"");
             wait_for_any_key(r)
         }
     }
@@ -374,42 +508,84 @@ pub(in crate::ui) fn display_play_mmheroes(
         Ding => screens::initial::display_ding(r, true),
         Wait => {
             r.clear_screen();
-            writeln_colored!(WhiteBright, r, "!!!!!! СТОП! !!!!!!");
+            writeln_colored_l10n!(
+                WhiteBright,
+                r,
+                "!!!!!! СТОП! !!!!!!",
+                "!!!!!! STOP! !!!!!!"
+            );
             writeln!(r);
-            writeln!(r, "ЧТО-ТО ТАКОЕ ТЫ УЖЕ ВИДЕЛ!!!");
-            writeln!(r, "Оглядевшись вокруг, ты осознаешь, что, вроде бы,");
-            writeln!(
+            writeln_l10n!(
+                r,
+                "ЧТО-ТО ТАКОЕ ТЫ УЖЕ ВИДЕЛ!!!",
+                "YOU'VE SEEN SOMETHING LIKE THIS BEFORE!!!"
+            );
+            writeln_l10n!(
                 r,
-                "экстраординарного не произошло. Ты просто играешь в компьютерную"
+                "Оглядевшись вокруг, ты осознаешь, что, вроде бы,",
+                "Looking around, you realize that nothing"
             );
-            writeln!(
+            writeln_l10n!(
                 r,
-                "игру не самого лучшего качества, в которой тебе вдруг предложили..."
+                "экстраординарного не произошло. Ты просто играешь в компьютерную",
+                "extraordinary seems to have happened. You're just playing a computer"
+            );
+            writeln_l10n!(
+                r,
+                "игру не самого лучшего качества, в которой тебе вдруг предложили...",
+                "game of not the best quality, in which you were suddenly offered..."
+            );
+            writeln_l10n!(
+                r,
+                "СЫГРАТЬ В ЭТУ САМУЮ ИГРУ! [...]",
+                "TO PLAY THIS VERY GAME! [...]"
             );
-            writeln!(r, "СЫГРАТЬ В ЭТУ САМУЮ ИГРУ! [...]");
             r.flush();
             WaitingState::PressAnyKey
         }
         NotEveryoneCanSurviveThis => {
             writeln!(r);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 YellowBright,
                 r,
-                "Не каждый способен пережить такое потрясение."
+                "Не каждый способен пережить такое потрясение.",
+                "Not everyone can survive such a shock."
+            );
+            writeln_l10n!(
+                r,
+                "Постепенно к тебе приходит осознание того, что",
+                "Gradually you come to realize that"
+            );
+            writeln_l10n!(
+                r,
+                "на самом деле, все это - компьютерная игра, и, следовательно,",
+                "in fact, all this is a computer game, and, therefore,"
+            );
+            writeln_l10n!(
+                r,
+                "эти события происходят только в твоем воображении.",
+                "these events are happening only in your imagination."
+            );
+            writeln_l10n!(
+                r,
+                "Вовремя выйдя из странного трансцендентального состояния,",
+                "Having come out of the strange transcendental state just in time,"
+            );
+            writeln_l10n!(
+                r,
+                "ты обнаруживаешь себя в компьютерном классе Мат-Меха.",
+                "you find yourself in the MathMech computer class."
             );
-            writeln!(r, "Постепенно к тебе приходит осознание того, что");
-            writeln!(
+            writeln_l10n!(
                 r,
-                "на самом деле, все это - компьютерная игра, и, следовательно,"
+                "Правда, мир вокруг тебя, похоже, несколько иной,",
+                "However, the world around you seems somewhat different"
             );
-            writeln!(r, "эти события происходят только в твоем воображении.");
-            writeln!(
+            writeln_l10n!(
                 r,
-                "Вовремя выйдя из странного трансцендентального состояния,"
+                "нежели он был час минут назад...",
+                "from what it was an hour minutes ago..."
             );
-            writeln!(r, "ты обнаруживаешь себя в компьютерном классе Мат-Меха.");
-            writeln!(r, "Правда, мир вокруг тебя, похоже, несколько иной,");
-            writeln!(r, "нежели он был час минут назад...");
             wait_for_any_key(r)
         }
     }
@@ -423,20 +599,37 @@ pub(in crate::ui) fn display_midnight(
     r.set_color(Color::White, Color::Black);
     match state.location() {
         Location::PUNK => {
-            writeln!(r, "Вахтерша глядит на тебя странными глазами:");
-            writeln!(
+            writeln_l10n!(
                 r,
-                "что может делать бедный студент в университете в полночь?"
+                "Вахтерша глядит на тебя странными глазами:",
+                "The watchwoman looks at you with strange eyes:"
+            );
+            writeln_l10n!(
+                r,
+                "что может делать бедный студент в университете в полночь?",
+                "what could a poor student be doing at the university at midnight?"
+            );
+            writeln_l10n!(
+                r,
+                "Не зная ответ на этот вопрос, ты спешишь в общагу.",
+                "Not knowing the answer to this question, you hurry to the dorm."
             );
-            writeln!(r, "Не зная ответ на этот вопрос, ты спешишь в общагу.");
         }
         Location::PDMI => {
-            writeln!(r, "Ты глядишь на часы и видишь: уже полночь!");
-            writeln!(r, "На последней электричке ты едешь домой, в общагу.");
+            writeln_l10n!(
+                r,
+                "Ты глядишь на часы и видишь: уже полночь!",
+                "You look at your watch and see: it's already midnight!"
+            );
+            writeln_l10n!(
+                r,
+                "На последней электричке ты едешь домой, в общагу.",
+                "You take the last train home, to the dorm."
+            );
         }
         Location::Mausoleum => {
-            writeln!(r, "Мавзолей закрывается.");
-            writeln!(r, "Пора домой!");
+            writeln_l10n!(r, "Мавзолей закрывается.", "The mausoleum is closing.");
+            writeln_l10n!(r, "Пора домой!", "Time to go home!");
         }
         Location::ComputerClass | Location::Dorm => unreachable!(),
     }
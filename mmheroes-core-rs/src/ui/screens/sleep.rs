@@ -9,7 +9,12 @@ pub(in crate::ui) fn display_dont_want_to_sleep(
     r: &mut Renderer<impl RendererRequestConsumer>,
 ) -> WaitingState {
     r.move_cursor_to(21, 0);
-    write_colored!(White, r, "Тебя чего-то не тянет по-спать...");
+    write_colored_l10n!(
+        White,
+        r,
+        "Тебя чего-то не тянет по-спать...",
+        "You somehow don't feel like sleeping..."
+    );
     wait_for_any_key(r)
 }
 
@@ -34,51 +39,119 @@ fn stupid_dream(
             r.set_color(Color::MagentaBright, Color::Black);
             let subject_text = match subject {
                 StupidDreamSubject::PinkElephants => {
-                    "Розовые слоники с блестящими крылышками"
+                    l10n!(
+                        r,
+                        "Розовые слоники с блестящими крылышками",
+                        "Pink elephants with shiny little wings"
+                    )
                 }
-                StupidDreamSubject::GreenMen => "Зеленые человечки с длинными антеннами",
-                StupidDreamSubject::Sheep => "Овечки с ослепительно-белой шерстью",
+                StupidDreamSubject::GreenMen => l10n!(
+                    r,
+                    "Зеленые человечки с длинными антеннами",
+                    "Little green men with long antennae"
+                ),
+                StupidDreamSubject::Sheep => l10n!(
+                    r,
+                    "Овечки с ослепительно-белой шерстью",
+                    "Sheep with dazzling white wool"
+                ),
             };
             writeln!(r, "{subject_text}");
-            writeln!(r, "сидят с окосевшими глазами в Мавзолее");
+            writeln_l10n!(
+                r,
+                "сидят с окосевшими глазами в Мавзолее",
+                "sit cross-eyed in the Mausoleum"
+            );
             let scenario_text = match scenario {
                 StupidDreamScenario::ComputingDeterminant => {
-                    "считают определитель матрицы 10 на 10"
+                    l10n!(
+                        r,
+                        "считают определитель матрицы 10 на 10",
+                        "computing the determinant of a 10 by 10 matrix"
+                    )
                 }
                 StupidDreamScenario::ComputingJordanMatrix => {
-                    "ищут Жорданову форму матрицы"
+                    l10n!(
+                        r,
+                        "ищут Жорданову форму матрицы",
+                        "looking for the Jordan form of a matrix"
+                    )
                 }
                 StupidDreamScenario::RaiseMatrixToPower => {
-                    "возводят матрицы в 239-ю степень"
+                    l10n!(
+                        r,
+                        "возводят матрицы в 239-ю степень",
+                        "raising matrices to the 239th power"
+                    )
                 }
                 StupidDreamScenario::SolvingLinearSystem => {
-                    "решают линейную систему уравнений с параметрами"
+                    l10n!(
+                        r,
+                        "решают линейную систему уравнений с параметрами",
+                        "solving a linear system of equations with parameters"
+                    )
                 }
                 StupidDreamScenario::ProvingIrreducibilityOfPolynomial => {
-                    "доказывают неприводимость многочлена 10-й степени над Z"
+                    l10n!(
+                        r,
+                        "доказывают неприводимость многочлена 10-й степени над Z",
+                        "proving the irreducibility of a degree 10 polynomial over Z"
+                    )
                 }
                 StupidDreamScenario::ProvingConvergenceOfIntegral => {
-                    "доказывают сходимость неопределенного интеграла с параметрами"
+                    l10n!(
+                        r,
+                        "доказывают сходимость неопределенного интеграла с параметрами",
+                        "proving the convergence of an improper integral with parameters"
+                    )
                 }
                 StupidDreamScenario::ComputingSumOfSeries => {
-                    "считают сумму ряда с параметрами"
+                    l10n!(
+                        r,
+                        "считают сумму ряда с параметрами",
+                        "computing the sum of a series with parameters"
+                    )
                 }
                 StupidDreamScenario::Differentiate => {
-                    "дифференцируют, дифференцируют, дифференцирую"
+                    l10n!(
+                        r,
+                        "дифференцируют, дифференцируют, дифференцирую",
+                        "differentiating, differentiating, differentiatin"
+                    )
                 }
-                StupidDreamScenario::TakingIntegrals => "берут интергалы не отдают их",
+                StupidDreamScenario::TakingIntegrals => l10n!(
+                    r,
+                    "берут интергалы не отдают их",
+                    "taking integrals and not giving them back"
+                ),
                 StupidDreamScenario::SolvingMathematicalProblems => {
-                    "решают задачи по математической болтологии"
+                    l10n!(
+                        r,
+                        "решают задачи по математической болтологии",
+                        "solving problems in mathematical chatterology"
+                    )
                 }
             };
-            writeln!(r, "и {scenario_text}");
+            writeln_l10n!(r, "и {scenario_text}", "and {scenario_text}");
         }
         StupidDream::Phase2 => {
             writeln!(r);
-            writeln!(r, "Господи! Ну и присниться же такое!");
-            writeln!(r, "За то теперь ты точно знаешь,");
-            writeln!(r, "что снится студентам-математикам,");
-            writeln!(r, "когда они вне кондиции");
+            writeln_l10n!(
+                r,
+                "Господи! Ну и присниться же такое!",
+                "Good Lord! What a thing to dream about!"
+            );
+            writeln_l10n!(
+                r,
+                "За то теперь ты точно знаешь,",
+                "But now you know for sure"
+            );
+            writeln_l10n!(
+                r,
+                "что снится студентам-математикам,",
+                "what math students dream about"
+            );
+            writeln_l10n!(r, "когда они вне кондиции", "when they are out of shape");
         }
     }
     writeln!(r, "...");
@@ -94,45 +167,105 @@ fn djug_dream(
         DjugDream::Phase1 => {
             r.clear_screen();
             r.set_color(Color::MagentaBright, Color::Black);
-            writeln!(r, "\"Здравствуйте!\" ...");
+            writeln_l10n!(r, "\"Здравствуйте!\" ...", "\"Hello!\" ...");
         }
         DjugDream::Phase2 => {
-            writeln!(r, "Оно большое ...");
+            writeln_l10n!(r, "Оно большое ...", "It is big ...");
         }
         DjugDream::Phase3 => {
-            writeln!(r, "Оно пыхтит! ...");
+            writeln_l10n!(r, "Оно пыхтит! ...", "It is puffing! ...");
         }
         DjugDream::Phase4 => {
-            writeln!(r, "Оно медленно ползет прямо на тебя!!! ...");
+            writeln_l10n!(
+                r,
+                "Оно медленно ползет прямо на тебя!!! ...",
+                "It is slowly crawling right at you!!! ..."
+            );
         }
         DjugDream::Phase5(quote) => {
-            writeln!(r, "Оно говорит человеческим голосом:");
+            writeln_l10n!(
+                r,
+                "Оно говорит человеческим голосом:",
+                "It speaks with a human voice:"
+            );
             r.set_color(Color::White, Color::Black);
             match quote {
                 DjugQuote::DeathButton => {
-                    writeln!(r, "\"Молодой человек. Когда-нибудь Вы вырастете");
-                    writeln!(r, "и будете работать на большой машине.");
-                    writeln!(r, "Вам надо будет нажать кнопку жизни,");
-                    writeln!(r, "а Вы нажмете кнопку смерти ...\"");
+                    writeln_l10n!(
+                        r,
+                        "\"Молодой человек. Когда-нибудь Вы вырастете",
+                        "\"Young man. Someday you will grow up"
+                    );
+                    writeln_l10n!(
+                        r,
+                        "и будете работать на большой машине.",
+                        "and you will work on a big machine."
+                    );
+                    writeln_l10n!(
+                        r,
+                        "Вам надо будет нажать кнопку жизни,",
+                        "You will need to press the button of life,"
+                    );
+                    writeln_l10n!(
+                        r,
+                        "а Вы нажмете кнопку смерти ...\"",
+                        "and you will press the button of death ...\""
+                    );
                 }
                 DjugQuote::HowManyDevilsFitANeedleTip => {
-                    writeln!(r, "\"Это в средневековье ученые спорили,");
-                    writeln!(r, "сколько чертей может поместиться");
-                    writeln!(r, "на кончике иглы...\"");
+                    writeln_l10n!(
+                        r,
+                        "\"Это в средневековье ученые спорили,",
+                        "\"It was in the Middle Ages that scholars argued"
+                    );
+                    writeln_l10n!(
+                        r,
+                        "сколько чертей может поместиться",
+                        "how many devils can fit"
+                    );
+                    writeln_l10n!(
+                        r,
+                        "на кончике иглы...\"",
+                        "on the tip of a needle...\""
+                    );
                 }
                 DjugQuote::DifferentWaysOfSolvingProblems => {
-                    writeln!(r, "\"Задачи можно решать по-разному.");
-                    writeln!(r, "Можно устно, можно на бумажке,");
-                    writeln!(r, "можно - играя в крестики-нолики...");
-                    writeln!(r, "А можно - просто списать ответ в конце задачника!\"");
+                    writeln_l10n!(
+                        r,
+                        "\"Задачи можно решать по-разному.",
+                        "\"Problems can be solved in different ways."
+                    );
+                    writeln_l10n!(
+                        r,
+                        "Можно устно, можно на бумажке,",
+                        "Orally, on a piece of paper,"
+                    );
+                    writeln_l10n!(
+                        r,
+                        "можно - играя в крестики-нолики...",
+                        "or by playing tic-tac-toe..."
+                    );
+                    writeln_l10n!(
+                        r,
+                        "А можно - просто списать ответ в конце задачника!\"",
+                        "Or you can just copy the answer from the back of the book!\""
+                    );
                 }
             }
             writeln_colored!(MagentaBright, r, "...");
         }
         DjugDream::Phase6 => {
             writeln!(r);
-            writeln!(r, "Уффф... Что-то сегодня опять какие-то гадости снятся.");
-            writeln!(r, "Все, пора завязывать с этим. Нельзя так много учиться.");
+            writeln_l10n!(
+                r,
+                "Уффф... Что-то сегодня опять какие-то гадости снятся.",
+                "Phew... Some nasty dreams again tonight."
+            );
+            writeln_l10n!(
+                r,
+                "Все, пора завязывать с этим. Нельзя так много учиться.",
+                "That's it, time to quit. You can't study this much."
+            );
         }
     }
     r.flush();
@@ -147,21 +280,45 @@ fn subject_related_dream(
     r.set_color(Color::MagentaBright, Color::Black);
     match last_exam {
         Subject::AlgebraAndNumberTheory => {
-            writeln!(r, "Ты слышишь мягкий, ненавязчивый голос:");
-            writeln!(r, "\"А Вы действительно правильно выбрали");
-            writeln!(r, " себе специальность?\"");
+            writeln_l10n!(
+                r,
+                "Ты слышишь мягкий, ненавязчивый голос:",
+                "You hear a soft, unobtrusive voice:"
+            );
+            writeln_l10n!(
+                r,
+                "\"А Вы действительно правильно выбрали",
+                "\"Are you really sure you have chosen"
+            );
+            writeln_l10n!(r, " себе специальность?\"", " the right major?\"");
         }
         Subject::Calculus => {
-            writeln!(r, "\"Интеграл...\"");
-            writeln!(r, "\"Какой интеграл?\"");
-            writeln!(r, "\"Да вот же он, мы его только что стерли!\"");
+            writeln_l10n!(r, "\"Интеграл...\"", "\"The integral...\"");
+            writeln_l10n!(r, "\"Какой интеграл?\"", "\"What integral?\"");
+            writeln_l10n!(
+                r,
+                "\"Да вот же он, мы его только что стерли!\"",
+                "\"Right there, we've just erased it!\""
+            );
         }
         Subject::GeometryAndTopology => {
-            writeln!(r, "\"Вы, конечно, великий парильщик.");
-            writeln!(r, " Но эту задачу я Вам засчитаю.\"");
+            writeln_l10n!(
+                r,
+                "\"Вы, конечно, великий парильщик.",
+                "\"You are, of course, a great bluffer."
+            );
+            writeln_l10n!(
+                r,
+                " Но эту задачу я Вам засчитаю.\"",
+                " But I will accept this problem.\""
+            );
         }
         Subject::ComputerScience => {
-            writeln!(r, "\"А что, у нас сегодня разве аудиторное занятие?\"");
+            writeln_l10n!(
+                r,
+                "\"А что, у нас сегодня разве аудиторное занятие?\"",
+                "\"What, do we have a class in the classroom today?\""
+            );
         }
         Subject::English => {
             writeln!(r, "\"Well, last time I found a pencil left by one of you.");
@@ -170,21 +327,28 @@ fn subject_related_dream(
             writeln!(r, " I am a lady, not your computer!\"");
         }
         Subject::PhysicalEducation => {
-            writeln!(
+            writeln_l10n!(
                 r,
-                "\"В следующем семестре вы должны будете написать реферат"
+                "\"В следующем семестре вы должны будете написать реферат",
+                "\"Next semester you will have to write an essay"
             );
-            writeln!(
+            writeln_l10n!(
                 r,
-                " на тему \"Бег в мировой литературе\". В качестве первоисточника"
+                " на тему \"Бег в мировой литературе\". В качестве первоисточника",
+                " on \"Running in world literature\". As the primary source"
+            );
+            writeln_l10n!(
+                r,
+                " можете взять одноименный роман Булгакова.\"",
+                " you can take the novel by Bulgakov with the same name.\""
             );
-            writeln!(r, " можете взять одноименный роман Булгакова.\"");
         }
     }
     writeln!(r);
-    writeln!(
+    writeln_l10n!(
         r,
-        "Ну все, похоже, заучился - если преподы по ночам снятся..."
+        "Ну все, похоже, заучился - если преподы по ночам снятся...",
+        "That's it, you've overstudied - if professors visit you in your dreams..."
     );
     r.flush();
     WaitingState::PressAnyKey
@@ -197,6 +361,11 @@ pub(in crate::ui) fn display_cant_stay_awake(
     r.clear_screen();
     screens::scene_router::display_header_stats(r, state);
     r.move_cursor_to(7, 0);
-    writeln_colored!(White, r, "Тебя неумолимо клонит ко сну ...");
+    writeln_colored_l10n!(
+        White,
+        r,
+        "Тебя неумолимо клонит ко сну ...",
+        "You are inexorably getting sleepy ..."
+    );
     wait_for_any_key(r)
 }
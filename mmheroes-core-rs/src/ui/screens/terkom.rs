@@ -1,6 +1,6 @@
 use crate::logic::scene_router::terkom::Terkom;
 use crate::logic::{Action, GameState, Money};
-use crate::random;
+use crate::random::Rng;
 use crate::ui::dialog::dialog;
 use crate::ui::renderer::{Renderer, RendererRequestConsumer};
 use crate::ui::screens::scene_router;
@@ -9,7 +9,7 @@ use crate::ui::{wait_for_any_key, Color, WaitingState};
 pub(in crate::ui) fn display_terkom(
     r: &mut Renderer<impl RendererRequestConsumer>,
     available_actions: &[Action],
-    rng: &mut random::Rng,
+    rng: &mut impl Rng,
     state: &GameState,
     screen: Terkom,
 ) -> WaitingState {
@@ -23,10 +23,19 @@ pub(in crate::ui) fn display_terkom(
                 r,
                 rng,
                 hiccup,
-                "\"Извини, парень, свободных кумпутеров нет.",
+                l10n!(
+                    r,
+                    "\"Извини, парень, свободных кумпутеров нет.",
+                    "\"Sorry, pal, no free compooters."
+                ),
             );
             writeln!(r);
-            write_with_hiccup(r, rng, hiccup, "Пойди поучись пока.\"");
+            write_with_hiccup(
+                r,
+                rng,
+                hiccup,
+                l10n!(r, "Пойди поучись пока.\"", "Go study for now.\""),
+            );
             writeln!(r);
             wait_for_any_key(r)
         }
@@ -35,7 +44,16 @@ pub(in crate::ui) fn display_terkom(
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(7, 0);
             r.set_color(Color::CyanBright, Color::Black);
-            write_with_hiccup(r, rng, hiccup, "\"Сказано же, нет свободных компов!\"");
+            write_with_hiccup(
+                r,
+                rng,
+                hiccup,
+                l10n!(
+                    r,
+                    "\"Сказано же, нет свободных компов!\"",
+                    "\"I told you, no free computers!\""
+                ),
+            );
             writeln!(r);
             wait_for_any_key(r)
         }
@@ -43,9 +61,18 @@ pub(in crate::ui) fn display_terkom(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(7, 0);
-            writeln_colored!(White, r, "Ты сидишь за свободным компом");
-            writeln!(r, "в тереховской \"конторе\".");
-            writeln!(r, "Что делать будем?");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Ты сидишь за свободным компом",
+                "You are sitting at a free computer"
+            );
+            writeln_l10n!(
+                r,
+                "в тереховской \"конторе\".",
+                "in Terekhov\'s \"office\"."
+            );
+            writeln_l10n!(r, "Что делать будем?", "What shall we do?");
             scene_router::display_short_today_timetable(r, 7, state);
             r.move_cursor_to(11, 0);
             dialog(r, available_actions)
@@ -57,7 +84,12 @@ pub(in crate::ui) fn display_terkom(
         }
         Terkom::YouEarnedBySurfingInternet { income, hiccup } => {
             r.move_cursor_to(18, 0);
-            writeln_colored!(White, r, "Вот здорово - мы сидим, а денежки-то идут!");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Вот здорово - мы сидим, а денежки-то идут!",
+                "How great - we're sitting here, and the money keeps coming!"
+            );
             write_income(r, rng, hiccup, income);
             wait_for_any_key(r)
         }
@@ -68,55 +100,112 @@ pub(in crate::ui) fn display_terkom(
                 r,
                 rng,
                 hiccup,
-                "По неизвестной причине, в помещении ТЕРКОМА",
+                l10n!(
+                    r,
+                    "По неизвестной причине, в помещении ТЕРКОМА",
+                    "For an unknown reason, on the TERKOM premises"
+                ),
             );
             writeln!(r);
             write_with_hiccup(
                 r,
                 rng,
                 hiccup,
-                "MMHEROES не оказывают никакого метафизического воздействия",
+                l10n!(
+                    r,
+                    "MMHEROES не оказывают никакого метафизического воздействия",
+                    "MMHEROES has no metaphysical effect whatsoever"
+                ),
             );
             writeln!(r);
-            write_with_hiccup(r, rng, hiccup, "на окружающий мир...");
+            write_with_hiccup(
+                r,
+                rng,
+                hiccup,
+                l10n!(r, "на окружающий мир...", "on the world around..."),
+            );
             writeln!(r);
             r.flush();
             WaitingState::PressAnyKey
         }
         Terkom::MmheroesPhase2 { hiccup } => {
-            write_with_hiccup(r, rng, hiccup, "Оглядевшись вокруг, ты обнаруживаешь,");
+            write_with_hiccup(
+                r,
+                rng,
+                hiccup,
+                l10n!(
+                    r,
+                    "Оглядевшись вокруг, ты обнаруживаешь,",
+                    "Looking around, you discover"
+                ),
+            );
             writeln!(r);
-            write_with_hiccup(r, rng, hiccup, "что все товарищи, здесь собравшиеся,");
+            write_with_hiccup(
+                r,
+                rng,
+                hiccup,
+                l10n!(
+                    r,
+                    "что все товарищи, здесь собравшиеся,",
+                    "that all the comrades gathered here"
+                ),
+            );
             writeln!(r);
-            writeln!(r, "РУБЯТСЯ В MMHEROES!");
-            write_with_hiccup(r, rng, hiccup, "Возможно, они все пытаются халявить,");
+            writeln_l10n!(r, "РУБЯТСЯ В MMHEROES!", "ARE HOOKED ON MMHEROES!");
+            write_with_hiccup(
+                r,
+                rng,
+                hiccup,
+                l10n!(
+                    r,
+                    "Возможно, они все пытаются халявить,",
+                    "Perhaps they are all trying to slack off,"
+                ),
+            );
             writeln!(r);
             write_with_hiccup(
                 r,
                 rng,
                 hiccup,
-                "пытаются играть по \"тривиальному\" алгоритму,",
+                l10n!(
+                    r,
+                    "пытаются играть по \"тривиальному\" алгоритму,",
+                    "trying to play by the \"trivial\" algorithm,"
+                ),
             );
             writeln!(r);
             write_with_hiccup(
                 r,
                 rng,
                 hiccup,
-                "который срабатывает, увы, далеко, не всегда...",
+                l10n!(
+                    r,
+                    "который срабатывает, увы, далеко, не всегда...",
+                    "which, alas, works far from always..."
+                ),
             );
             wait_for_any_key(r)
         }
         Terkom::Leaving { hiccup } => {
             r.move_cursor_to(16, 0);
             r.set_color(Color::White, Color::Black);
-            write_with_hiccup(r, rng, hiccup, "Уходим ...");
+            write_with_hiccup(r, rng, hiccup, l10n!(r, "Уходим ...", "Leaving ..."));
             writeln!(r);
             wait_for_any_key(r)
         }
         Terkom::EndOfWorkDay { hiccup } => {
             r.move_cursor_to(19, 0);
             r.set_color(Color::White, Color::Black);
-            write_with_hiccup(r, rng, hiccup, "Рабочий день закончился, все по домам.");
+            write_with_hiccup(
+                r,
+                rng,
+                hiccup,
+                l10n!(
+                    r,
+                    "Рабочий день закончился, все по домам.",
+                    "The working day is over, everyone goes home."
+                ),
+            );
             writeln!(r);
             wait_for_any_key(r)
         }
@@ -125,7 +214,7 @@ pub(in crate::ui) fn display_terkom(
 
 fn write_with_hiccup(
     r: &mut Renderer<impl RendererRequestConsumer>,
-    rng: &mut random::Rng,
+    rng: &mut impl Rng,
     hiccup: u8,
     s: &str,
 ) {
@@ -135,7 +224,7 @@ fn write_with_hiccup(
         if is_first {
             is_first = false;
         } else if rng.roll_dice(hiccup) {
-            write!(r, " <йк> ");
+            write_l10n!(r, " <йк> ", " <hic> ");
             hiccup -= 1;
             if hiccup < 1 {
                 hiccup = 2;
@@ -149,12 +238,17 @@ fn write_with_hiccup(
 
 fn write_income(
     r: &mut Renderer<impl RendererRequestConsumer>,
-    rng: &mut random::Rng,
+    rng: &mut impl Rng,
     hiccup: u8,
     income: Money,
 ) {
     r.set_color(Color::White, Color::Black);
-    write_with_hiccup(r, rng, hiccup, "Тебе накапало ");
+    write_with_hiccup(
+        r,
+        rng,
+        hiccup,
+        l10n!(r, "Тебе накапало ", "You have earned "),
+    );
     write_colored!(WhiteBright, r, "{}", income);
-    writeln_colored!(White, r, " руб.");
+    writeln_colored_l10n!(White, r, " руб.", " rub.");
 }
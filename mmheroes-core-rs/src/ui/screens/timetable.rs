@@ -5,24 +5,29 @@ use strum::EnumCount;
 
 fn output_remaining_problems(
     r: &mut Renderer<impl RendererRequestConsumer>,
-    timetable: &timetable::Timetable,
+    state: &GameState,
     subject_status: &SubjectStatus,
 ) {
     let (line, column) = r.get_cursor_position();
-    let problems_remaining = subject_status.problems_remaining();
-    if let Some(passed_day) = subject_status.passed_exam_day(timetable) {
-        write_colored!(WhiteBright, r, "ЗАЧЕТ");
+    let problems_remaining = subject_status.problems_remaining(state.rules());
+    if let Some(passed_day) = subject_status.passed_exam_day(state.timetable()) {
+        write_colored_l10n!(WhiteBright, r, "ЗАЧЕТ", "PASSED");
         r.move_cursor_to(line + 1, column);
-        write!(r, "{}", day_date(passed_day))
+        write!(r, "{}", day_date(r.locale(), passed_day))
     } else if problems_remaining == 0 {
-        write_colored!(White, r, "Подойти с");
+        write_colored_l10n!(White, r, "Подойти с", "Come with");
         r.move_cursor_to(line + 1, column);
-        write!(r, "зачеткой")
+        write_l10n!(r, "зачеткой", "the record")
     } else {
-        write_colored!(White, r, "Осталось");
+        write_colored_l10n!(White, r, "Осталось", "Remaining");
         r.move_cursor_to(line + 1, column);
         write_colored!(WhiteBright, r, "{}", problems_remaining);
-        write_colored!(White, r, " {}", problems_inflected(problems_remaining));
+        write_colored!(
+            White,
+            r,
+            " {}",
+            problems_inflected(r.locale(), problems_remaining)
+        );
     }
 }
 
@@ -32,24 +37,31 @@ fn output_remaining_exams(
 ) {
     assert!(number_of_exams <= Subject::COUNT);
 
-    let mut output = |a, b| {
-        r.set_color(Color::White, Color::Black);
-        write!(r, "{} ", a);
-        r.set_color(Color::YellowBright, Color::Black);
-        write!(r, "{}", number_of_exams);
-        r.set_color(Color::White, Color::Black);
-        write!(r, " {}", b)
-    };
+    if number_of_exams == 0 {
+        r.set_color(Color::WhiteBright, Color::Black);
+        write_l10n!(r, "Все уже сдано!", "Everything is passed!");
+        return;
+    }
 
-    match number_of_exams {
-        0 => {
-            r.set_color(Color::WhiteBright, Color::Black);
-            write!(r, "Все уже сдано!")
-        }
-        1 => output("Остался", "зачет!"),
-        2..=4 => output("Осталось", "зачета."),
-        _ => output("Осталось", "зачетов."),
+    let locale = r.locale();
+    let n = number_of_exams as u32;
+    r.set_color(Color::White, Color::Black);
+    let prefix = locale.plural(n, ["Остался", "Осталось", "Осталось"], ["", ""]);
+    if !prefix.is_empty() {
+        write!(r, "{} ", prefix);
     }
+    r.set_color(Color::YellowBright, Color::Black);
+    write!(r, "{}", number_of_exams);
+    r.set_color(Color::White, Color::Black);
+    write!(
+        r,
+        " {}",
+        locale.plural(
+            n,
+            ["зачет!", "зачета.", "зачетов."],
+            ["exam left!", "exams left."]
+        )
+    )
 }
 
 const TIMETABLE_START_X: Column = 0;
@@ -59,6 +71,22 @@ const TIMETABLE_COLUMN_WIDTH: Column = 7;
 const TIMETABLE_ROW_HEIGHT: Line = 3;
 const TIMETABLE_REMAINING_PROBLEMS_X: Column = 70;
 
+/// Сколько дней помещается на экране. Если семестр длиннее, показываются
+/// дни начиная с сегодняшнего.
+const TIMETABLE_VISIBLE_DAYS: usize = ((TIMETABLE_REMAINING_PROBLEMS_X
+    - TIMETABLE_DAYS_START_X)
+    / TIMETABLE_COLUMN_WIDTH) as usize;
+
+fn visible_days(state: &GameState) -> &[Day] {
+    let days = state.timetable().days();
+    let first_day = state
+        .current_day()
+        .index()
+        .min(days.len().saturating_sub(TIMETABLE_VISIBLE_DAYS));
+    let last_day = days.len().min(first_day + TIMETABLE_VISIBLE_DAYS);
+    &days[first_day..last_day]
+}
+
 fn display_timetable_cell(
     r: &mut Renderer<impl RendererRequestConsumer>,
     day: &Day,
@@ -76,7 +104,7 @@ fn display_timetable_cell(
         if passed {
             r.set_color(Color::Blue, Color::Black);
         }
-        write!(r, "{}", exam.location());
+        write!(r, "{}", location_name(r.locale(), exam.location()));
         r.move_cursor_to(line + 1, column);
         write!(r, "{}-{}", exam.from(), exam.to())
     } else {
@@ -101,12 +129,12 @@ pub(in crate::ui) fn display_timetable(
         let line = (i as Line) * TIMETABLE_ROW_HEIGHT + TIMETABLE_START_Y;
         r.move_cursor_to(line, TIMETABLE_START_X);
         r.set_color(Color::Green, Color::Black);
-        writeln!(r, "{}", professor_name(subject));
+        writeln!(r, "{}", professor_name(r.locale(), subject));
         r.move_cursor_to(line + 1, TIMETABLE_START_X);
         r.set_color(Color::CyanBright, Color::Black);
-        write!(r, "{}", subject_name(subject));
+        write!(r, "{}", subject_name(r.locale(), subject));
 
-        for (j, day) in state.timetable().days().iter().enumerate() {
+        for (j, day) in visible_days(state).iter().enumerate() {
             r.move_cursor_to(
                 line,
                 (j as Column) * TIMETABLE_COLUMN_WIDTH + TIMETABLE_DAYS_START_X,
@@ -121,20 +149,16 @@ pub(in crate::ui) fn display_timetable(
         }
 
         r.move_cursor_to(line, TIMETABLE_REMAINING_PROBLEMS_X);
-        output_remaining_problems(
-            r,
-            state.timetable(),
-            state.player().status_for_subject(subject),
-        );
+        output_remaining_problems(r, state, state.player().status_for_subject(subject));
     }
 
     r.set_color(Color::CyanBright, Color::Black);
-    for (i, day) in state.timetable().days().iter().enumerate() {
+    for (i, day) in visible_days(state).iter().enumerate() {
         r.move_cursor_to(
             0,
             (i as Column) * TIMETABLE_COLUMN_WIDTH + TIMETABLE_DAYS_START_X,
         );
-        write!(r, "{}", day_date(day));
+        write!(r, "{}", day_date(r.locale(), day));
     }
 
     r.move_cursor_to(22, 0);
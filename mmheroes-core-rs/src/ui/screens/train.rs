@@ -28,10 +28,18 @@ pub(in crate::ui) fn display_train_to_pdmi(
         &|r, caught_by_inspectors| {
             r.set_color(Color::White, Color::Black);
             if caught_by_inspectors {
-                writeln!(r, "Тебя заловили контролеры!");
-                write!(r, "Высадили в Красных зорях, гады!");
+                writeln_l10n!(
+                    r,
+                    "Тебя заловили контролеры!",
+                    "The ticket inspectors caught you!"
+                );
+                write_l10n!(
+                    r,
+                    "Высадили в Красных зорях, гады!",
+                    "They kicked you off at Krasnye Zori, the bastards!"
+                );
             } else {
-                writeln!(r, "Уф, доехал!");
+                writeln_l10n!(r, "Уф, доехал!", "Phew, made it!");
             }
         },
     )
@@ -48,12 +56,17 @@ pub(in crate::ui) fn display_train_algebra_exam(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(11, 0);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 White,
                 r,
-                "Есть надежда, что в электричке удастся что-то еще решить."
+                "Есть надежда, что в электричке удастся что-то еще решить.",
+                "There's hope you'll manage to solve something else on the train."
+            );
+            writeln_l10n!(
+                r,
+                "Правда, зачетной ведомости с собой туда не взять...",
+                "Though you can't take the grade sheet there..."
             );
-            writeln!(r, "Правда, зачетной ведомости с собой туда не взять...");
         }
         TrainScene::GatecrashByChoice { .. } | TrainScene::BoughtRoundTripTicket => (),
         TrainScene::NoPointToGoToPDMI => unreachable!(),
@@ -68,13 +81,26 @@ pub(in crate::ui) fn display_train_algebra_exam(
         Color::RedBright,
         &|r, caught_by_inspectors| {
             if caught_by_inspectors {
-                writeln_colored!(MagentaBright, r, "Тебя заловили контролеры!");
+                writeln_colored_l10n!(
+                    MagentaBright,
+                    r,
+                    "Тебя заловили контролеры!",
+                    "The ticket inspectors caught you!"
+                );
                 match interaction {
                     TrainScene::GatecrashBecauseNoMoney { .. } => {
-                        writeln!(r, "Высадили в Красных зорях, гады!");
+                        writeln_l10n!(
+                            r,
+                            "Высадили в Красных зорях, гады!",
+                            "They kicked you off at Krasnye Zori, the bastards!"
+                        );
                     }
                     TrainScene::GatecrashByChoice { .. } => {
-                        writeln!(r, "И со Всемирновым ты ничего не успел...");
+                        writeln_l10n!(
+                            r,
+                            "И со Всемирновым ты ничего не успел...",
+                            "And you didn't get anything done with Vsemirnov..."
+                        );
                     }
                     _ => (),
                 }
@@ -103,9 +129,21 @@ fn display_train<R: RendererRequestConsumer>(
                 r.clear_screen();
             }
             r.set_color(Color::White, Color::Black);
-            writeln!(r, "Здравый смысл подсказывает тебе, что в такое время");
-            writeln!(r, "ты там никого уже не найдешь.");
-            write!(r, "Не будем зря тратить здоровье на поездку в ПОМИ.");
+            writeln_l10n!(
+                r,
+                "Здравый смысл подсказывает тебе, что в такое время",
+                "Common sense tells you that at this time"
+            );
+            writeln_l10n!(
+                r,
+                "ты там никого уже не найдешь.",
+                "you won't find anyone there anymore."
+            );
+            write_l10n!(
+                r,
+                "Не будем зря тратить здоровье на поездку в ПОМИ.",
+                "Let's not waste our health on a trip to PDMI."
+            );
             wait_for_any_key(r)
         }
         TrainScene::GatecrashBecauseNoMoney {
@@ -113,7 +151,11 @@ fn display_train<R: RendererRequestConsumer>(
         } => {
             r.move_cursor_to(start_line, 0);
             r.set_color(no_money_color, Color::Black);
-            writeln!(r, "Денег у тебя нет, пришлось ехать зайцем...");
+            writeln_l10n!(
+                r,
+                "Денег у тебя нет, пришлось ехать зайцем...",
+                "You have no money, so you had to ride without a ticket..."
+            );
             gatecrash(r, caught_by_inspectors);
             wait_for_any_key(r)
         }
@@ -142,15 +184,29 @@ pub(in crate::ui) fn display_baltiyskiy_railway_station(
             r.clear_screen();
             scene_router::display_header_stats(r, state);
             r.move_cursor_to(11, 0);
-            writeln_colored!(CyanBright, r, "Ты в Питере, на Балтийском вокзале.");
-            writeln!(r, "Куда направляемся?");
+            writeln_colored_l10n!(
+                CyanBright,
+                r,
+                "Ты в Питере, на Балтийском вокзале.",
+                "You are in St. Petersburg, at the Baltiysky railway station."
+            );
+            writeln_l10n!(r, "Куда направляемся?", "Where are we heading?");
             r.move_cursor_to(14, 0);
             dialog(r, available_actions)
         }
         BaltiyskiyRailwayStationScene::CaughtByInspectors => {
             r.move_cursor_to(19, 0);
-            writeln_colored!(White, r, "Тебя заловили контролеры!");
-            writeln!(r, "Высадили в Красных зорях, гады!");
+            writeln_colored_l10n!(
+                White,
+                r,
+                "Тебя заловили контролеры!",
+                "The ticket inspectors caught you!"
+            );
+            writeln_l10n!(
+                r,
+                "Высадили в Красных зорях, гады!",
+                "They kicked you off at Krasnye Zori, the bastards!"
+            );
             wait_for_any_key(r)
         }
     }
@@ -168,11 +224,12 @@ pub(in crate::ui) fn display_train_from_pdmi(
             scene_router::display_header_stats(r, state);
             scene_router::display_short_today_timetable(r, 9, state);
             r.move_cursor_to(7, 0);
-            writeln_colored!(
+            writeln_colored_l10n!(
                 YellowBright,
                 r,
                 "Едем в ПУНК, билета нет. Будем покупать билет ({} рублей)?",
-                Money::one_way_train_ticket_cost()
+                "Going to PUNK, no ticket. Shall we buy a ticket ({} rubles)?",
+                state.rules().costs.one_way_train_ticket
             );
             r.move_cursor_to(9, 0);
             dialog(r, available_actions)
@@ -184,9 +241,13 @@ pub(in crate::ui) fn display_train_from_pdmi(
             caught_by_inspectors,
         } => {
             r.move_cursor_to(21, 0);
-            write_colored!(White, r, "Едем зайцем... ");
+            write_colored_l10n!(White, r, "Едем зайцем... ", "Riding ticketless... ");
             if caught_by_inspectors {
-                writeln!(r, "Контролеры поймали! Высадили в Красных Зорях!");
+                writeln_l10n!(
+                    r,
+                    "Контролеры поймали! Высадили в Красных Зорях!",
+                    "Inspectors caught you! Kicked off at Krasnye Zori!"
+                );
             }
             wait_for_any_key(r)
         }
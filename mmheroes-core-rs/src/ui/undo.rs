@@ -0,0 +1,60 @@
+//! Отмена ходов.
+//!
+//! Каждый раз, когда игрок оказывается в главном меню локации, игра сохраняется
+//! в стек. Отмена хода возвращает игру к последнему сохранению в стеке (или
+//! к предпоследнему, если игрок и так в главном меню из последнего сохранения).
+
+use crate::logic::SavedGame;
+
+/// Сколько последних ходов можно отменить.
+pub const UNDO_DEPTH: usize = 16;
+
+/// Стек сохранений ограниченной глубины: когда он заполнен, самое старое
+/// сохранение вытесняется.
+pub(in crate::ui) struct UndoStack {
+    snapshots: [Option<SavedGame>; UNDO_DEPTH],
+    top: usize,
+    len: usize,
+}
+
+impl UndoStack {
+    pub(in crate::ui) fn new() -> Self {
+        UndoStack {
+            snapshots: [const { None }; UNDO_DEPTH],
+            top: 0,
+            len: 0,
+        }
+    }
+
+    pub(in crate::ui) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(in crate::ui) fn last(&self) -> Option<&SavedGame> {
+        if self.len == 0 {
+            None
+        } else {
+            self.snapshots[self.top].as_ref()
+        }
+    }
+
+    pub(in crate::ui) fn push(&mut self, snapshot: SavedGame) {
+        self.top = (self.top + 1) % UNDO_DEPTH;
+        self.snapshots[self.top] = Some(snapshot);
+        self.len = core::cmp::min(self.len + 1, UNDO_DEPTH);
+    }
+
+    pub(in crate::ui) fn pop(&mut self) -> Option<SavedGame> {
+        if self.len == 0 {
+            return None;
+        }
+        let snapshot = self.snapshots[self.top].take();
+        self.top = (self.top + UNDO_DEPTH - 1) % UNDO_DEPTH;
+        self.len -= 1;
+        snapshot
+    }
+
+    pub(in crate::ui) fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
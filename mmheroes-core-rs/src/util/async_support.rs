@@ -155,6 +155,36 @@ pub(crate) async fn prompt<Input: Any + Unpin, Output: Any + Unpin>(
     .await
 }
 
+/// Выполняет `future`, пока в `interruption` не появится значение. Как только оно
+/// появляется, `future` прерывается (при следующем опросе), а значение
+/// возвращается как `Err`.
+pub(crate) fn interruptible<F: Future, T>(
+    future: F,
+    interruption: &RefCell<Option<T>>,
+) -> Interruptible<'_, F, T> {
+    Interruptible {
+        future,
+        interruption,
+    }
+}
+
+pub(crate) struct Interruptible<'a, F, T> {
+    future: F,
+    interruption: &'a RefCell<Option<T>>,
+}
+
+impl<F: Future, T> Future for Interruptible<'_, F, T> {
+    type Output = core::result::Result<F::Output, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(value) = self.interruption.borrow_mut().take() {
+            return Poll::Ready(Err(value));
+        }
+        let pinned_future = unsafe { self.map_unchecked_mut(|this| &mut this.future) };
+        pinned_future.poll(cx).map(Ok)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,7 +196,7 @@ mod tests {
     fn test_basic() {
         let input_collector = RefCell::new(Vec::new());
         let future_data = RefCell::new(None);
-        let mut executor = PromptingExecutor::new(
+        let executor = PromptingExecutor::new(
             async {
                 input_collector.borrow_mut().push(">");
                 for i in 0..2 {
@@ -239,8 +269,7 @@ mod tests {
     #[should_panic]
     fn test_panics_if_resumed_after_finishing() {
         let future_data = RefCell::new(None);
-        let mut executor =
-            PromptingExecutor::<_, (), ()>::new(async { 42 }, &future_data);
+        let executor = PromptingExecutor::<_, (), ()>::new(async { 42 }, &future_data);
         let mut pinned_executor = pin!(executor);
         let _ = pinned_executor.as_mut().resume_with_input(());
         let _ = pinned_executor.as_mut().resume_with_input(());
@@ -20,7 +20,16 @@ impl<const CAPACITY: usize> TinyString<CAPACITY> {
                 .extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes()),
         }
     }
+
+    pub(crate) fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        for _ in 0..ch.len_utf8() {
+            self.v.pop();
+        }
+        Some(ch)
+    }
 }
+
 impl<const CAPACITY: usize> From<&str> for TinyString<CAPACITY> {
     fn from(s: &str) -> Self {
         Self {
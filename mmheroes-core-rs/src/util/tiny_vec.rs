@@ -33,6 +33,14 @@ impl<T, const CAPACITY: usize> TinyVec<T, CAPACITY> {
         self.count += 1;
     }
 
+    pub(crate) fn pop(&mut self) -> Option<T> {
+        if self.count == 0 {
+            return None;
+        }
+        self.count -= 1;
+        Some(unsafe { self.storage[self.count].as_ptr().read() })
+    }
+
     pub(crate) fn clear(&mut self) {
         for element in self.storage.iter_mut().take(self.count) {
             unsafe { core::ptr::drop_in_place(element.as_mut_ptr()) }
@@ -207,6 +215,18 @@ mod tests {
         assert_eq!(&*cloned, [1, 3, 5, 7, 9]);
     }
 
+    #[test]
+    fn test_pop() {
+        let mut v = tiny_vec!(capacity: 16, [1, 3, 5]);
+        assert_eq!(v.pop(), Some(5));
+        assert_eq!(v.pop(), Some(3));
+        v.push(7);
+        assert_eq!(&*v, [1, 7]);
+        assert_eq!(v.pop(), Some(7));
+        assert_eq!(v.pop(), Some(1));
+        assert_eq!(v.pop(), None);
+    }
+
     #[test]
     fn test_clear() {
         use core::cell::RefCell;
@@ -0,0 +1,262 @@
+mod common;
+
+use assert_matches::assert_matches;
+use common::*;
+use mmheroes_core::logic::actions::PlayStyle;
+use mmheroes_core::logic::{create_game, Action, GameMode, GameScreen, StateHolder};
+use mmheroes_core::ui::{GameUI, Input, Locale};
+
+fn label<G: mmheroes_core::logic::Game>(
+    game_ui: &TestGameUI<G>,
+    action: Action,
+) -> String {
+    let mut label = String::new();
+    game_ui.write_action_label(action, &mut label).unwrap();
+    label
+}
+
+/// Строка и столбец экрана, с которых начинается текст `text`.
+fn position_of<G: mmheroes_core::logic::Game>(
+    game_ui: &TestGameUI<G>,
+    text: &str,
+) -> (u8, u8) {
+    let screen = game_ui.request_consumer().to_string();
+    screen
+        .lines()
+        .enumerate()
+        .find_map(|(line, s)| {
+            let offset = s.find(text)?;
+            Some((line as u8, s[..offset].chars().count() as u8))
+        })
+        .unwrap()
+}
+
+#[test]
+fn available_actions_in_dialog() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+
+    // На экране «Нажми любую клавишу» выбирать нечего
+    assert!(game_ui.available_actions().is_empty());
+    assert_eq!(game_ui.dialog_position(), None);
+    assert!(!game_ui.perform_action(Action::AnyKey));
+
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    assert_eq!(
+        game_ui.dialog_position(),
+        Some(position_of(game_ui, "Готовиться"))
+    );
+    assert_eq!(
+        &*game_ui.available_actions(),
+        &[
+            Action::Study,
+            Action::ViewTimetable,
+            Action::Rest,
+            Action::GoToBed,
+            Action::GoFromDormToPunk,
+            Action::GoToPDMI,
+            Action::GoToMausoleum,
+            Action::IAmDone,
+            Action::WhatToDo,
+        ]
+    );
+    assert_eq!(label(game_ui, Action::Study), "Готовиться");
+    assert_eq!(label(game_ui, Action::IAmDone), "С меня хватит!");
+
+    // Варианта нет в диалоге
+    assert!(!game_ui.perform_action(Action::GoFromPunkToDorm));
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    );
+
+    assert!(game_ui.perform_action(Action::GoFromDormToPunk));
+    assert_eq!(
+        state.game_state().location(),
+        mmheroes_core::logic::Location::PUNK
+    );
+}
+
+#[test]
+fn action_labels_are_localized() {
+    initialize_game!((0, GameMode::Normal, None, Locale::English) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    assert_eq!(label(game_ui, Action::Study), "Study");
+    assert_eq!(
+        label(game_ui, Action::GoFromDormToPunk),
+        "Go to the faculty"
+    );
+}
+
+#[test]
+fn performed_actions_are_recorded_as_key_presses() {
+    let state_holder = StateHolder::new(GameMode::Normal);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut game_ui = GameUI::new(
+        &state_holder,
+        game,
+        0,
+        None,
+        TestRendererRequestConsumer::new(),
+        Some(String::new()),
+        Locale::Russian,
+    );
+    // Доходим до общаги
+    for _ in 0..4 {
+        game_ui.continue_game(Input::Enter);
+    }
+    // Ближе дойти до последнего варианта стрелкой вверх
+    assert!(game_ui.perform_action(Action::WhatToDo));
+    assert!(game_ui.perform_action(Action::Help(
+        mmheroes_core::logic::actions::HelpAction::ThanksButNothing
+    )));
+    // Выбор того же варианта, на котором стоит курсор, — просто Enter
+    game_ui.continue_game(Input::KeyDown);
+    assert!(game_ui.perform_action(Action::ViewTimetable));
+    game_ui.continue_game(Input::Enter);
+    assert!(game_ui.perform_action(Action::GoFromDormToPunk));
+    let state_hash = game_ui.state_hash();
+    assert!(state_hash.is_some());
+    game_ui.flush_input_recorder().unwrap();
+    let recording = game_ui.recorded_input().unwrap().clone();
+    assert_eq!(recording, "4r↑r↑r↓2r4↓r");
+
+    initialize_game!((0, GameMode::Normal) => state, replayed_game_ui);
+    // Первое нажатие уже сделано при создании игры
+    replay_game(replayed_game_ui, &format!("3{}", &recording[1..]));
+    assert_eq!(replayed_game_ui.state_hash(), state_hash);
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    );
+}
+
+#[test]
+fn hotkeys_select_options() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+
+    // Такого варианта нет
+    game_ui.continue_game(Input::Hotkey('z'));
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    );
+    assert_eq!(
+        state.game_state().location(),
+        mmheroes_core::logic::Location::Dorm
+    );
+
+    // «Пойти на факультет» — пятый вариант
+    game_ui.continue_game(Input::Hotkey('5'));
+    assert_eq!(
+        state.game_state().location(),
+        mmheroes_core::logic::Location::PUNK
+    );
+}
+
+#[test]
+fn escape_chooses_cancel_option() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+
+    // В общаге отказываться не от чего
+    game_ui.continue_game(Input::Escape);
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    );
+
+    game_ui.continue_game(Input::Hotkey('1'));
+    assert_matches!(state.observable_state().screen(), GameScreen::Study(_));
+    assert_eq!(game_ui.available_actions().last(), Some(&Action::DontStudy));
+    game_ui.continue_game(Input::Escape);
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    );
+}
+
+#[test]
+fn home_and_end_jump_to_menu_ends() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+
+    game_ui.continue_game(Input::End);
+    game_ui.continue_game(Input::Enter);
+    assert_matches!(state.observable_state().screen(), GameScreen::WhatToDo(_));
+
+    game_ui.continue_game(Input::Escape);
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    );
+
+    game_ui.continue_game(Input::PageDown);
+    game_ui.continue_game(Input::Home);
+    game_ui.continue_game(Input::Enter);
+    assert_matches!(state.observable_state().screen(), GameScreen::Study(_));
+}
+
+#[test]
+fn pointer_moves_cursor_and_click_chooses() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+
+    // Перемещение мыши — не нажатие клавиши
+    assert_matches!(state.observable_state().screen(), GameScreen::Intro);
+    game_ui.continue_game(Input::PointerAt { line: 0, column: 0 });
+    assert_matches!(state.observable_state().screen(), GameScreen::Intro);
+
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    let (line, column) = position_of(game_ui, "Пойти на факультет");
+
+    // Мимо вариантов
+    game_ui.continue_game(Input::Click {
+        line: line + 5,
+        column,
+    });
+    game_ui.continue_game(Input::Click {
+        line,
+        column: column + 18,
+    });
+    game_ui.continue_game(Input::PointerAt { line: 0, column });
+    game_ui.continue_game(Input::Enter);
+    assert_matches!(state.observable_state().screen(), GameScreen::Study(_));
+    game_ui.continue_game(Input::Escape);
+
+    game_ui.continue_game(Input::PointerAt {
+        line: line + 1,
+        column: column + 3,
+    });
+    game_ui.continue_game(Input::Enter);
+    assert_eq!(
+        state.game_state().location(),
+        mmheroes_core::logic::Location::PDMI
+    );
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::TrainToPDMI(..)
+    );
+}
+
+#[test]
+fn click_chooses_option() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    game_ui.continue_game(Input::Click { line: 0, column: 0 });
+    assert_matches!(state.observable_state().screen(), GameScreen::Ding);
+    game_ui.continue_game(Input::Enter);
+    game_ui.continue_game(Input::Enter);
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    );
+
+    let (line, column) = position_of(game_ui, "Пойти на факультет");
+    game_ui.continue_game(Input::Click {
+        line,
+        column: column + 17,
+    });
+    assert_eq!(
+        state.game_state().location(),
+        mmheroes_core::logic::Location::PUNK
+    );
+}
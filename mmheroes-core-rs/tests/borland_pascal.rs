@@ -0,0 +1,73 @@
+mod common;
+
+use common::*;
+use mmheroes_core::logic::actions::PlayStyle;
+use mmheroes_core::logic::{create_game, GameMode, RngMode, StateHolder, Timetable};
+use mmheroes_core::ui::{Input, Locale};
+
+/// Игра с генератором из Borland Pascal. Если этот тест сломался, значит,
+/// поменялось то, как игра в этом режиме расходует случайные числа, и сверять
+/// её с оригиналом по старым зёрнам больше нельзя.
+#[test]
+fn game_with_borland_pascal_random() {
+    let seed = 1998;
+    let state_holder =
+        StateHolder::new(GameMode::Normal).with_rng_mode(RngMode::BorlandPascal);
+    let game = core::pin::pin!(create_game(seed, &state_holder));
+    let mut game_ui = TestGameUI::new(
+        &state_holder,
+        game,
+        seed,
+        None,
+        TestRendererRequestConsumer::new(),
+        None,
+        Locale::Russian,
+    );
+    let game_ui = &mut game_ui;
+    game_ui.continue_game(Input::Enter);
+    replay_until_dorm(&state_holder, game_ui, PlayStyle::RandomStudent);
+    let expected_timetable: Timetable = "
+        1 calculus 13 15 punk
+        2 algebra_and_number_theory 9 12 pdmi
+        2 calculus 11 13 punk
+        2 geometry_and_topology 11 13 punk
+        2 physical_education 14 15 punk
+        3 geometry_and_topology 9 10 pdmi
+        3 computer_science 9 11 computer_class
+        4 algebra_and_number_theory 12 14 punk
+        4 calculus 13 15 punk
+        4 english 10 12 punk
+        4 physical_education 12 13 punk
+        5 algebra_and_number_theory 9 11 punk
+        5 computer_science 13 14 computer_class
+        5 english 10 12 punk
+        6 algebra_and_number_theory 14 16 punk
+        6 calculus 15 17 punk
+    "
+    .parse()
+    .unwrap();
+    assert_eq!(*state_holder.game_state().timetable(), expected_timetable);
+    assert_ui!(
+        game_ui,
+        "
+Сегодня 22е мая; 8:00    Версия gamma3.14   Алгебра и Т.Ч.        5   Плохо
+Самочувствие: отличное (48)                 Мат. Анализ           2   Плохо
+Финансы: Надо получить деньги за май...     Геометрия и Топология 5   Плохо
+Легкость в мыслях необыкновенная (6)        Информатика           3   Плохо
+Нас ждут великие дела (6)                   English               0   Плохо
+Ты нормально относишься к окружающим (4)    Физ-ра                4   Плохо
+
+Ты в общаге. Что делать?
+
+Готовиться▁                                      АиТЧ    ----           0/12
+Посмотреть расписание                            МатАн   ПУНК  13-15    0/10
+Отдыхать                                         ГиТ     ----           0/3
+Лечь спать                                       Инф     ----           0/2
+Пойти на факультет                               ИнЯз    ----           0/3
+Поехать в ПОМИ                                   Физ-ра  ----           0/1
+Пойти в мавзолей
+С меня хватит!
+ЧТО ДЕЛАТЬ ???
+"
+    );
+}
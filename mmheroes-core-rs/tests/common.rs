@@ -7,8 +7,13 @@ use mmheroes_core::ui::*;
 
 type Canvas = [[char; TERMINAL_WIDTH]; TERMINAL_HEIGHT];
 
+/// Цвета символа и фона для каждой клетки экрана. `None` — в клетку ничего
+/// не выводилось с последней очистки экрана.
+type ColorCanvas = [[Option<(Color, Color)>; TERMINAL_WIDTH]; TERMINAL_HEIGHT];
+
 pub struct TestRendererRequestConsumer {
     canvas: Canvas,
+    colors: ColorCanvas,
     line: usize,
     column: usize,
     foreground_color: Color,
@@ -24,6 +29,7 @@ impl TestRendererRequestConsumer {
     pub fn new() -> Self {
         Self {
             canvas: Self::make_canvas(),
+            colors: [[None; TERMINAL_WIDTH]; TERMINAL_HEIGHT],
             line: 0,
             column: 0,
             foreground_color: Color::White,
@@ -49,6 +55,44 @@ impl TestRendererRequestConsumer {
         }
         output
     }
+
+    /// Цвета символов (`foreground == true`) или фона в виде строк
+    /// шестнадцатеричных цифр: одна цифра — номер цвета в клетке,
+    /// `.` — клетка, в которую ничего не выводилось.
+    fn color_layer(&self, foreground: bool) -> String {
+        let mut output = String::new();
+        for line in &self.colors {
+            let mut tmp = String::new();
+            for cell in line {
+                tmp.push(match cell {
+                    Some((fg, bg)) => {
+                        let color = if foreground { *fg } else { *bg };
+                        char::from_digit(color as u32, 16)
+                            .unwrap()
+                            .to_ascii_uppercase()
+                    }
+                    None => '.',
+                });
+            }
+            output.push_str(tmp.trim_end_matches('.'));
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Снимок экрана для сравнения с эталоном: текст с курсором,
+    /// затем цвета символов и цвета фона.
+    #[allow(dead_code)] // false positive
+    pub fn snapshot(&self) -> String {
+        format!(
+            "{}{}\n{}{}\n{}",
+            self.to_string(),
+            SNAPSHOT_FOREGROUND_SEPARATOR,
+            self.color_layer(true),
+            SNAPSHOT_BACKGROUND_SEPARATOR,
+            self.color_layer(false),
+        )
+    }
 }
 
 impl RendererRequestConsumer for TestRendererRequestConsumer {
@@ -56,6 +100,7 @@ impl RendererRequestConsumer for TestRendererRequestConsumer {
         match request {
             RendererRequest::ClearScreen => {
                 self.canvas = Self::make_canvas();
+                self.colors = [[None; TERMINAL_WIDTH]; TERMINAL_HEIGHT];
                 self.line = 0;
                 self.column = 0;
             }
@@ -71,6 +116,8 @@ impl RendererRequestConsumer for TestRendererRequestConsumer {
                             self.column = 0;
                         }
                         self.canvas[self.line][self.column] = c;
+                        self.colors[self.line][self.column] =
+                            Some((self.foreground_color, self.background_color));
                         self.column += 1;
                     }
                 }
@@ -91,6 +138,106 @@ impl RendererRequestConsumer for TestRendererRequestConsumer {
     }
 }
 
+const SNAPSHOT_FOREGROUND_SEPARATOR: &str = "--- foreground";
+const SNAPSHOT_BACKGROUND_SEPARATOR: &str = "--- background";
+
+/// Если эта переменная окружения установлена, [`check_snapshot`] не сравнивает
+/// экран с эталоном, а записывает его в качестве нового эталона.
+pub const BLESS_ENV_VAR: &str = "MMHEROES_BLESS";
+
+/// Путь к файлу эталонного снимка экрана с именем `name` для тестов из модуля
+/// `module_path`: `tests/snapshots/<модуль>/<name>.screen`.
+pub fn snapshot_path(module_path: &str, name: &str) -> std::path::PathBuf {
+    let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests");
+    path.push("snapshots");
+    for component in module_path.split("::") {
+        path.push(component);
+    }
+    path.push(format!("{name}.screen"));
+    path
+}
+
+/// Построчное сравнение снимков. Возвращает `None`, если снимки совпадают,
+/// иначе — отличающиеся строки с номерами, раскрашенные escape-последовательностями
+/// ANSI: ожидаемые красным, полученные зелёным.
+pub fn snapshot_diff(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+    const RED: &str = "\x1b[31m";
+    const GREEN: &str = "\x1b[32m";
+    const CYAN: &str = "\x1b[36m";
+    const RESET: &str = "\x1b[0m";
+    let mut layer = "text";
+    let mut layer_line = 0;
+    let mut output = String::new();
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    loop {
+        let (expected_line, actual_line) =
+            match (expected_lines.next(), actual_lines.next()) {
+                (None, None) => break,
+                lines => lines,
+            };
+        if let Some(line) = actual_line.or(expected_line) {
+            if line == SNAPSHOT_FOREGROUND_SEPARATOR
+                || line == SNAPSHOT_BACKGROUND_SEPARATOR
+            {
+                layer = &line[4..];
+                layer_line = 0;
+                continue;
+            }
+        }
+        layer_line += 1;
+        if expected_line != actual_line {
+            output.push_str(&format!("{CYAN}{layer}, line {layer_line}:{RESET}\n"));
+            if let Some(line) = expected_line {
+                output.push_str(&format!("{RED}-{line}{RESET}\n"));
+            }
+            if let Some(line) = actual_line {
+                output.push_str(&format!("{GREEN}+{line}{RESET}\n"));
+            }
+        }
+    }
+    Some(output)
+}
+
+/// Сравнивает экран с эталонным снимком из файла (см. [`snapshot_path`])
+/// и паникует с читаемым описанием различий, если они не совпадают.
+///
+/// Если установлена переменная окружения [`BLESS_ENV_VAR`], вместо сравнения
+/// записывает текущий экран в качестве эталона.
+#[allow(dead_code)] // false positive
+pub fn check_snapshot(
+    consumer: &TestRendererRequestConsumer,
+    module_path: &str,
+    name: &str,
+) {
+    let path = snapshot_path(module_path, name);
+    let actual = consumer.snapshot();
+    if std::env::var_os(BLESS_ENV_VAR).is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = match std::fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(error) => panic!(
+            "Could not read the snapshot {}: {error}\n\
+             Run the test with {BLESS_ENV_VAR}=1 to create it.",
+            path.display()
+        ),
+    };
+    if let Some(diff) = snapshot_diff(&expected, &actual) {
+        panic!(
+            "The screen does not match the snapshot {}:\n{diff}\
+             Run the test with {BLESS_ENV_VAR}=1 to update the snapshot.",
+            path.display()
+        );
+    }
+}
+
 pub type TestGameUI<'game, G> = GameUI<'game, G, TestRendererRequestConsumer>;
 
 /// Возвращает `true` как только [GameUI::continue_game] возвращает `false`,
@@ -141,21 +288,21 @@ pub fn replay_until_dorm<G: Game>(
 
 pub trait AsGameState {
     #[allow(dead_code)] // false positive
-    fn game_state(&self) -> core::cell::Ref<GameState>;
+    fn game_state(&self) -> core::cell::Ref<'_, GameState>;
 }
 
 impl AsGameState for StateHolder {
-    fn game_state(&self) -> core::cell::Ref<GameState> {
+    fn game_state(&self) -> core::cell::Ref<'_, GameState> {
         core::cell::Ref::map(self.observable_state(), |s| s.screen().state().unwrap())
     }
 }
 
 #[macro_export]
 macro_rules! initialize_game {
-    (($seed:expr, $mode:expr, $high_scores:expr) => $state:ident, $game_ui:ident) => {
-        let state_holder = mmheroes_core::logic::StateHolder::new($mode);
+    (($seed:expr, $mode:expr, $high_scores:expr, $locale:expr, $rules:expr) => $state:ident, $game_ui:ident) => {
+        let state_holder = mmheroes_core::logic::StateHolder::with_rules($mode, $rules).unwrap();
         let $state = &state_holder;
-        let mut game = mmheroes_core::logic::create_game($seed, $state);
+        let game = mmheroes_core::logic::create_game($seed, $state);
         let game = core::pin::pin!(game);
         let mut game_ui = $crate::common::TestGameUI::new(
             $state,
@@ -164,10 +311,28 @@ macro_rules! initialize_game {
             $high_scores,
             $crate::common::TestRendererRequestConsumer::new(),
             None,
+            $locale,
         );
         let $game_ui = &mut game_ui;
         $game_ui.continue_game(mmheroes_core::ui::Input::Enter)
     };
+    (($seed:expr, $mode:expr, $high_scores:expr, $locale:expr) => $state:ident, $game_ui:ident) => {
+        initialize_game!(
+            ($seed, $mode, $high_scores, $locale, &mmheroes_core::logic::Rules::DEFAULT)
+                => $state, $game_ui
+        );
+    };
+    (($seed:expr, $mode:expr, rules = $rules:expr) => $state:ident, $game_ui:ident) => {
+        initialize_game!(
+            ($seed, $mode, None, mmheroes_core::ui::Locale::Russian, $rules)
+                => $state, $game_ui
+        );
+    };
+    (($seed:expr, $mode:expr, $high_scores:expr) => $state:ident, $game_ui:ident) => {
+        initialize_game!(
+            ($seed, $mode, $high_scores, mmheroes_core::ui::Locale::Russian) => $state, $game_ui
+        );
+    };
     (($seed:expr, $mode:expr) => $state:ident, $game_ui:ident) => {
         initialize_game!(($seed, $mode, None) => $state, $game_ui);
     };
@@ -273,6 +438,16 @@ macro_rules! assert_subject_knowledge {
     }};
 }
 
+/// Сравнивает экран с эталонным снимком `tests/snapshots/<модуль>/<имя>.screen`,
+/// включая цвета. Чтобы создать или обновить снимок, нужно запустить тест
+/// с переменной окружения `MMHEROES_BLESS=1`.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($game_ui:expr, $name:literal) => {
+        $crate::common::check_snapshot($game_ui.request_consumer(), module_path!(), $name)
+    };
+}
+
 #[macro_export]
 macro_rules! assert_ui {
     ($game_ui:expr, $expected:literal) => {
@@ -680,3 +680,61 @@ fn deny_invitation_to_disco_party() {
     "
     );
 }
+
+#[test]
+fn party_past_the_end_of_the_semester() {
+    use mmheroes_core::logic::actions::InvitationFromNeighborAction::*;
+    use mmheroes_core::logic::scene_router::dorm::NeighborInvitation;
+    use mmheroes_core::logic::{
+        create_game, Action, CauseOfDeath, GameScreen, Simulation, StateHolder, Time,
+    };
+
+    /// Сидит в общаге: днём отдыхает, ночью спит. В последний день не спит,
+    /// а ждёт, пока сосед позовёт на вечеринку, которая продлится за полночь.
+    /// Возвращает причину смерти и была ли такая вечеринка.
+    fn play(seed: u64) -> (Option<CauseOfDeath>, bool) {
+        let state_holder = StateHolder::new(GameMode::Normal);
+        let game = core::pin::pin!(create_game(seed, &state_holder));
+        let mut simulation = Simulation::new(&state_holder, game);
+        let mut late_party = false;
+        loop {
+            let action = match &*simulation.screen() {
+                GameScreen::GameEnd(state) => {
+                    return (state.player().cause_of_death(), late_party)
+                }
+                GameScreen::SceneRouter(state) => {
+                    let last_day = state.current_day_index() + 1
+                        == state.timetable().length().days();
+                    let time = state.current_time();
+                    if !last_day && (time >= Time(20) || time <= Time(3)) {
+                        Action::GoToBed
+                    } else {
+                        Action::Rest
+                    }
+                }
+                GameScreen::NeighborInvites(NeighborInvitation::InvitePrompt(
+                    state,
+                    _,
+                )) => {
+                    if state.current_day_index() + 1 == state.timetable().length().days()
+                        && state.current_time() >= Time(21)
+                    {
+                        late_party = true;
+                        Action::InvitationFromNeighbor(Accept)
+                    } else {
+                        Action::InvitationFromNeighbor(Deny)
+                    }
+                }
+                _ => simulation.available_actions()[0],
+            };
+            simulation.perform_action(action).unwrap();
+        }
+    }
+
+    // Ищем игру, в которой сессия кончается посреди вечеринки.
+    let (cause_of_death, _) = (0..100)
+        .map(play)
+        .find(|&(_, late_party)| late_party)
+        .expect("The neighbor never invites to a party late on the last day");
+    assert_eq!(cause_of_death, Some(CauseOfDeath::TimeOut));
+}
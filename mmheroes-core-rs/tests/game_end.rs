@@ -0,0 +1,272 @@
+mod common;
+
+use common::*;
+use mmheroes_core::logic::actions::PlayStyle;
+use mmheroes_core::logic::{
+    create_or_restore_game, GameMode, GameScreen, Money, SavedGame, StateHolder,
+};
+use mmheroes_core::ui::{high_scores, Input, Locale};
+
+/// Смещение денег игрока в сохранении.
+const MONEY_OFFSET: usize = 24;
+
+/// Смещение битов `SubjectStatus` первого предмета в сохранении.
+const FIRST_SUBJECT_STATUS_BITS_OFFSET: usize = 36;
+
+/// Сохраняет игру в общаге в начале первого дня, а затем подделывает сохранение
+/// так, чтобы у игрока было `money` денег и было сдано `passed_exams` зачётов.
+fn forged_saved_game(passed_exams: usize, money: i16) -> SavedGame {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    let mut encoded = state.save_game().unwrap().encode();
+    encoded[MONEY_OFFSET..MONEY_OFFSET + 2].copy_from_slice(&money.to_le_bytes());
+    for subject in 0..passed_exams {
+        let offset = FIRST_SUBJECT_STATUS_BITS_OFFSET + subject * 4;
+        let mut bits = u16::from_le_bytes([encoded[offset], encoded[offset + 1]]);
+        // Зачёт сдан в первый день.
+        bits &= !(0b111 << 10);
+        encoded[offset..offset + 2].copy_from_slice(&bits.to_le_bytes());
+    }
+    SavedGame::decode(&encoded).unwrap()
+}
+
+macro_rules! restore_game {
+    ($saved_game:expr => $state:ident, $game_ui:ident) => {
+        let state_holder = StateHolder::new(GameMode::Normal);
+        let $state = &state_holder;
+        let game = create_or_restore_game(0, Some($saved_game), $state);
+        let game = core::pin::pin!(game);
+        let mut game_ui = TestGameUI::new(
+            $state,
+            game,
+            0,
+            None,
+            TestRendererRequestConsumer::new(),
+            None,
+            Locale::Russian,
+        );
+        let $game_ui = &mut game_ui;
+        $game_ui.continue_game(Input::Enter);
+        replay_game($game_ui, "r");
+    };
+}
+
+#[test]
+fn expelled_with_one_exam_left() {
+    restore_game!(forged_saved_game(5, 0) => state, game_ui);
+
+    // С меня хватит!
+    replay_game(game_ui, "2↑r↓r");
+    assert_ui!(
+        game_ui,
+        r#"
+Уффффф! Во всяком случае, ты еще живой.
+
+У тебя нет целого 1 зачета!
+ТЫ ОТЧИСЛЕН!
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+Нажми любую клавишу ...▁
+"#
+    );
+
+    // Таблицы рекордов нет, сразу предлагается начать заново.
+    replay_game(game_ui, "r");
+    assert!(matches!(
+        state.observable_state().screen(),
+        GameScreen::WannaTryAgain
+    ));
+}
+
+#[test]
+fn all_exams_passed_enter_high_score() {
+    restore_game!(forged_saved_game(6, 200) => state, game_ui);
+
+    replay_game(game_ui, "2↑r↓r");
+    assert_ui!(
+        game_ui,
+        r#"
+Поздравляю: ты можешь считать себя настоящим героем Мат-Меха!
+
+Ты успешно сдал все зачеты.
+За время сессии ты заработал 200 руб.
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+Нажми любую клавишу ...▁
+"#
+    );
+
+    replay_game(game_ui, "r");
+    assert!(matches!(
+        state.observable_state().screen(),
+        GameScreen::HighScoreEntry(Money(200))
+    ));
+    assert_ui!(
+        game_ui,
+        r#"
+Твое имя будет вписано в историю Мат-Меха!
+
+Как тебя зовут? ▁
+"#
+    );
+
+    for c in "Васиз".chars() {
+        game_ui.continue_game(Input::Character(c));
+    }
+    game_ui.continue_game(Input::Backspace);
+    game_ui.continue_game(Input::Backspace);
+    game_ui.continue_game(Input::Character('я'));
+    assert_ui!(
+        game_ui,
+        r#"
+Твое имя будет вписано в историю Мат-Меха!
+
+Как тебя зовут? Вася▁
+"#
+    );
+
+    replay_game(game_ui, "r");
+    assert_ui!(
+        game_ui,
+        r#"
+******                                           ******
+      *********                         *********
+               *************************
+Вот имена тех, кто прошел это наводящее ужас испытание:
+
+    ГЕРОЙ            ЗАРАБОТАЛ
+   Коля                 400 руб.
+   Саша                 280 руб.
+   Вася                 200 руб.
+   Эндрю                180 руб.
+   Паша                 100 руб.
+
+
+
+
+
+
+
+
+
+
+
+
+Нажми любую клавишу ...▁
+"#
+    );
+    assert_eq!(
+        game_ui.high_scores,
+        mmheroes_core::high_scores![
+            "Коля" => 400,
+            "Саша" => 280,
+            "Вася" => 200,
+            "Эндрю" => 180,
+            "Паша" => 100,
+        ]
+    );
+
+    replay_game(game_ui, "r");
+    assert!(matches!(
+        state.observable_state().screen(),
+        GameScreen::WannaTryAgain
+    ));
+}
+
+#[test]
+fn all_exams_passed_not_a_high_score() {
+    restore_game!(forged_saved_game(6, 20) => state, game_ui);
+
+    replay_game(game_ui, "2↑r↓r");
+    replay_game(game_ui, "r");
+
+    // Результат не попадает в таблицу рекордов, поэтому она показывается сразу.
+    assert!(matches!(
+        state.observable_state().screen(),
+        GameScreen::HighScoreEntry(Money(20))
+    ));
+    assert_eq!(
+        game_ui.high_scores,
+        mmheroes_core::high_scores![
+            "Коля" => 400,
+            "Саша" => 280,
+            "Эндрю" => 180,
+            "Паша" => 100,
+            "Гриша" => 20,
+        ]
+    );
+    assert_ui!(
+        game_ui,
+        r#"
+******                                           ******
+      *********                         *********
+               *************************
+Вот имена тех, кто прошел это наводящее ужас испытание:
+
+    ГЕРОЙ            ЗАРАБОТАЛ
+   Коля                 400 руб.
+   Саша                 280 руб.
+   Эндрю                180 руб.
+   Паша                 100 руб.
+   Гриша                20 руб.
+
+
+
+
+
+
+
+
+
+
+
+
+Нажми любую клавишу ...▁
+"#
+    );
+}
+
+#[test]
+fn empty_high_score_name() {
+    restore_game!(forged_saved_game(6, 1000) => state, game_ui);
+    replay_game(game_ui, "2↑r↓r2r");
+    assert!(matches!(
+        state.observable_state().screen(),
+        GameScreen::HighScoreEntry(Money(1000))
+    ));
+    assert_eq!(game_ui.high_scores[0].0, high_scores::DEFAULT_NAME);
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::*;
+use mmheroes_core::logic::actions::PlayStyle;
+use mmheroes_core::logic::{create_game, GameMode, StateHolder};
+use mmheroes_core::ui::recording::NoInputRecording;
+use mmheroes_core::ui::renderer::{RendererRequest, RendererRequestConsumer};
+use mmheroes_core::ui::*;
+
+#[test]
+fn english_welcome_screen() {
+    initialize_game!((0, GameMode::Normal, None, Locale::English) => state, game_ui);
+    assert_snapshot!(game_ui, "welcome");
+}
+
+#[test]
+fn english_initial_parameters_screen() {
+    initialize_game!((0, GameMode::God, None, Locale::English) => state, game_ui);
+    replay_game(game_ui, "r");
+    assert_snapshot!(game_ui, "initial_parameters");
+}
+
+#[test]
+fn english_dorm_screen() {
+    initialize_game!((0, GameMode::Normal, None, Locale::English) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    assert_snapshot!(game_ui, "dorm");
+}
+
+#[test]
+fn english_timetable_screen() {
+    initialize_game!((0, GameMode::Normal, None, Locale::English) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    replay_game(game_ui, "↓r");
+    assert_snapshot!(game_ui, "timetable");
+}
+
+/// Следит за тем, чтобы английский текст не содержал кириллицы и помещался
+/// в экран 80×24 без переноса строк.
+struct LayoutChecker {
+    line: usize,
+    column: usize,
+}
+
+impl RendererRequestConsumer for LayoutChecker {
+    fn consume_request(&mut self, request: RendererRequest) {
+        match request {
+            RendererRequest::ClearScreen => {
+                self.line = 0;
+                self.column = 0;
+            }
+            RendererRequest::WriteStr(s) => {
+                assert!(
+                    !s.chars().any(|c| matches!(c, 'А'..='я' | 'Ё' | 'ё')),
+                    "Untranslated text: {s:?}"
+                );
+                for c in s.chars() {
+                    if c == '\n' {
+                        self.line += 1;
+                        self.column = 0;
+                    } else {
+                        assert!(
+                            self.column < TERMINAL_WIDTH,
+                            "The text wraps at line {}: {s:?}",
+                            self.line
+                        );
+                        self.column += 1;
+                    }
+                    assert!(self.line < TERMINAL_HEIGHT, "The text is too long: {s:?}");
+                }
+            }
+            RendererRequest::MoveCursor { line, column } => {
+                self.line = line as usize;
+                self.column = column as usize;
+            }
+            RendererRequest::Flush
+            | RendererRequest::SetColor { .. }
+            | RendererRequest::Sleep(_) => (),
+        }
+    }
+}
+
+/// Играет много случайных игр на английском и проверяет каждый экран.
+#[test]
+fn english_screens_fit_into_terminal() {
+    let mut rng_state = 0x2545f4914f6cdd1du64;
+    let mut next_random = move |bound: u64| {
+        rng_state ^= rng_state << 13;
+        rng_state ^= rng_state >> 7;
+        rng_state ^= rng_state << 17;
+        rng_state % bound
+    };
+    for seed in 0..200 {
+        let mode = match seed % 3 {
+            0 => GameMode::Normal,
+            1 => GameMode::SelectInitialParameters,
+            _ => GameMode::God,
+        };
+        let state_holder = StateHolder::new(mode);
+        let game = core::pin::pin!(create_game(seed, &state_holder));
+        let mut game_ui = GameUI::new(
+            &state_holder,
+            game,
+            seed,
+            None,
+            LayoutChecker { line: 0, column: 0 },
+            None::<NoInputRecording>,
+            Locale::English,
+        );
+        for _ in 0..3000 {
+            let input = match next_random(4) {
+                0 | 1 => Input::KeyDown,
+                _ => Input::Enter,
+            };
+            if !game_ui.continue_game(input) {
+                break;
+            }
+        }
+        assert!(!game_ui.has_bug(), "The game has crashed with seed {seed}");
+    }
+}
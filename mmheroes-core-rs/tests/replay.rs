@@ -0,0 +1,191 @@
+use assert_matches::assert_matches;
+use mmheroes_core::logic::{
+    create_game, GameMode, RngMode, SemesterLength, StateHolder, Timetable,
+};
+use mmheroes_core::ui::recording::{NoInputRecording, ReplayHeader, ReplayWriter};
+use mmheroes_core::ui::renderer::{RendererRequest, RendererRequestConsumer};
+use mmheroes_core::ui::replay_verifier::{verify_replay, ReplayDivergence};
+use mmheroes_core::ui::{GameUI, Input, Locale};
+
+/// Выигранная игра, записанная вместе с хэшами состояния в каждом главном меню.
+/// Если этот тест сломался, значит, поведение игры изменилось.
+const WON_GAME: &str = include_str!("replays/won_game.replay");
+
+#[test]
+fn recorded_game_is_reproducible() {
+    let verification = verify_replay(WON_GAME).unwrap();
+    assert_eq!(verification.divergence, None);
+    assert_eq!(verification.steps, 847);
+    assert_eq!(verification.checkpoints, 173);
+}
+
+#[test]
+fn first_divergent_step_is_reported() {
+    let mut hash_lines = WON_GAME.lines().filter(|line| line.starts_with('#'));
+    let first_hash = hash_lines.next().unwrap();
+    let third_hash = hash_lines.nth(1).unwrap();
+    let expected = u64::from_str_radix(&third_hash[1..], 16).unwrap();
+    let replay = WON_GAME.replace(third_hash, "#0000000000000000");
+
+    let verification = verify_replay(&replay).unwrap();
+    assert_eq!(
+        verification.divergence,
+        Some(ReplayDivergence::StateHash {
+            step: 11,
+            checkpoint: 2,
+            expected: 0,
+            actual: Some(expected),
+        })
+    );
+    assert_eq!(verification.steps, 11);
+    assert_eq!(verification.checkpoints, 3);
+
+    // Хэш записан не в главном меню.
+    let replay = WON_GAME.replacen(&format!("4r\n{}\n", first_hash), "3r\n#0\nr\n", 1);
+    let verification = verify_replay(&replay).unwrap();
+    assert_matches!(
+        verification.divergence,
+        Some(ReplayDivergence::StateHash {
+            step: 3,
+            checkpoint: 0,
+            actual: None,
+            ..
+        })
+    );
+}
+
+#[test]
+fn inputs_after_game_end_are_reported() {
+    let replay = format!("{}2r↓r\nrr\n", WON_GAME);
+    let verification = verify_replay(&replay).unwrap();
+    let divergence = verification.divergence.unwrap();
+    assert_matches!(divergence, ReplayDivergence::GameEnded { .. });
+    assert_eq!(divergence.step(), verification.steps);
+}
+
+struct NullRenderer;
+
+impl RendererRequestConsumer for NullRenderer {
+    fn consume_request(&mut self, _request: RendererRequest) {}
+}
+
+#[test]
+fn replay_records_rng_mode() {
+    // В старых повторах режима генератора случайных чисел нет, и они
+    // воспроизводятся с одним генератором. С отдельными генераторами игра
+    // идёт по-другому.
+    let replay = WON_GAME.replacen("mode normal\n", "mode normal\nrng separate\n", 1);
+    let verification = verify_replay(&replay).unwrap();
+    assert_matches!(
+        verification.divergence,
+        Some(ReplayDivergence::StateHash { .. })
+    );
+
+    let state_holder =
+        StateHolder::new(GameMode::Normal).with_rng_mode(RngMode::SeparateStreams);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut game_ui = GameUI::new(
+        &state_holder,
+        game,
+        0,
+        None,
+        NullRenderer,
+        None::<NoInputRecording>,
+        Locale::Russian,
+    );
+    let header = ReplayHeader::new(
+        0,
+        GameMode::Normal,
+        RngMode::SeparateStreams,
+        SemesterLength::NORMAL,
+        None,
+        None,
+    );
+    let mut writer = ReplayWriter::new(String::new(), &header).unwrap();
+    let inputs = [Input::Enter; 4]
+        .into_iter()
+        .chain([Input::KeyDown, Input::KeyDown, Input::Enter].repeat(3));
+    for input in inputs {
+        game_ui.continue_game(input);
+        writer.record_input(input).unwrap();
+        writer.record_checkpoint(game_ui.state_hash()).unwrap();
+    }
+    writer.flush().unwrap();
+
+    let verification = verify_replay(writer.output()).unwrap();
+    assert_eq!(verification.divergence, None);
+    assert!(verification.checkpoints > 1);
+}
+
+#[test]
+fn replay_records_semester_length() {
+    // Короткая сессия — другое расписание, поэтому повтор обычной игры
+    // с ней расходится.
+    let replay = WON_GAME.replacen("mode normal\n", "mode normal\ndays 3\n", 1);
+    let verification = verify_replay(&replay).unwrap();
+    assert_matches!(
+        verification.divergence,
+        Some(ReplayDivergence::StateHash { .. })
+    );
+}
+
+#[test]
+fn replay_records_timetable() {
+    let timetable: Timetable = "
+        1 calculus 10 13 punk
+        1 physical_education 17 18 punk
+        2 algebra_and_number_theory 12 14 pdmi
+        3 english 9 11 punk
+        4 geometry_and_topology 10 12 pdmi
+        6 computer_science 16 18 computer_class
+    "
+    .parse()
+    .unwrap();
+    let state_holder =
+        StateHolder::new(GameMode::Normal).with_timetable(timetable.clone());
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut game_ui = GameUI::new(
+        &state_holder,
+        game,
+        0,
+        None,
+        NullRenderer,
+        None::<NoInputRecording>,
+        Locale::Russian,
+    );
+    let header = ReplayHeader::new(
+        0,
+        GameMode::Normal,
+        RngMode::SingleStream,
+        SemesterLength::NORMAL,
+        Some(timetable),
+        None,
+    );
+    let mut writer = ReplayWriter::new(String::new(), &header).unwrap();
+    let inputs = [Input::Enter; 4]
+        .into_iter()
+        .chain([Input::KeyDown, Input::KeyDown, Input::Enter].repeat(3));
+    for input in inputs {
+        game_ui.continue_game(input);
+        writer.record_input(input).unwrap();
+        writer.record_checkpoint(game_ui.state_hash()).unwrap();
+    }
+    writer.flush().unwrap();
+    let replay = writer.output();
+
+    let verification = verify_replay(replay).unwrap();
+    assert_eq!(verification.divergence, None);
+    assert!(verification.checkpoints > 1);
+
+    // Без расписания в заголовке игра идёт со случайным расписанием.
+    let replay = replay
+        .lines()
+        .filter(|line| !line.starts_with("timetable "))
+        .map(|line| format!("{}\n", line))
+        .collect::<String>();
+    let verification = verify_replay(&replay).unwrap();
+    assert_matches!(
+        verification.divergence,
+        Some(ReplayDivergence::StateHash { .. })
+    );
+}
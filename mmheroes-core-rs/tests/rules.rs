@@ -0,0 +1,90 @@
+mod common;
+use common::*;
+use mmheroes_core::logic::actions::PlayStyle;
+use mmheroes_core::logic::{
+    create_or_restore_game, GameMode, Money, Rules, RulesError, Simulation, StateHolder,
+    Subject,
+};
+
+/// Правила, в которых матан сдать труднее, зато кола почти бесплатная.
+static HOUSE_RULES: Rules = {
+    let mut rules = Rules::DEFAULT;
+    rules.subjects.calculus.required_problems = 15;
+    rules.costs.cola = Money(1);
+    rules
+};
+
+#[test]
+fn house_rules() {
+    initialize_game!((0, GameMode::Normal, rules = &HOUSE_RULES) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    assert!(core::ptr::eq(state.game_state().rules(), &HOUSE_RULES));
+
+    // Ждём 10:00, идём на факультет и получаем деньги у Паши
+    replay_game(game_ui, "2↓r2↓r4↓r7↓2r");
+
+    // Идём в мавзолей с деньгами
+    replay_game(game_ui, "4↓r3↓r");
+    assert_ui!(
+        game_ui,
+        r#"
+Сегодня 22е мая; 10:00   Версия gamma3.14   Алгебра и Т.Ч.        2   Плохо
+Самочувствие: отличное (61)                 Мат. Анализ           0   Плохо
+Финансы: 50 руб.                            Геометрия и Топология 3   Плохо
+Голова свежая (5)                           Информатика           0   Плохо
+Немного устал (4)                           English               4   Плохо
+У тебя много друзей (5)                     Физ-ра                0   Плохо
+
+Выбери себе способ "культурного отдыха".
+
+
+Стакан колы за 1 р.▁                             АиТЧ    ПУНК  13-15    0/12
+Суп, 6 р. все удовольствие                       МатАн   ----           0/15
+0,5 пива за 8 р.                                 ГиТ     ----           0/3
+Расслабляться будем своими силами.               Инф     ----           0/2
+Нет, отдыхать - это я зря сказал.                ИнЯз    ПУНК  14-16    0/3
+                                                 Физ-ра  ----           0/1
+"#
+    );
+
+    // Пьём колу
+    replay_game(game_ui, "r");
+    assert_eq!(state.game_state().player().money(), Money(49));
+}
+
+#[test]
+fn restored_game_uses_rules_of_state_holder() {
+    let saved_game = {
+        initialize_game!((0, GameMode::Normal) => state, game_ui);
+        replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+        state.save_game().unwrap()
+    };
+
+    let state_holder = StateHolder::with_rules(GameMode::Normal, &HOUSE_RULES).unwrap();
+    let game =
+        core::pin::pin!(create_or_restore_game(0, Some(saved_game), &state_holder));
+    let simulation = Simulation::new(&state_holder, game);
+    let screen = simulation.screen();
+    let state = screen.state().unwrap();
+    assert!(core::ptr::eq(state.rules(), &HOUSE_RULES));
+    assert_eq!(
+        state
+            .player()
+            .status_for_subject(Subject::Calculus)
+            .problems_remaining(state.rules()),
+        15
+    );
+}
+
+#[test]
+fn state_holder_rejects_invalid_rules() {
+    static FREE_MONEY: Rules = {
+        let mut rules = Rules::DEFAULT;
+        rules.costs.cola = Money(-1);
+        rules
+    };
+    assert_eq!(
+        StateHolder::with_rules(GameMode::Normal, &FREE_MONEY).err(),
+        Some(RulesError::NegativeCost)
+    );
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::*;
+use mmheroes_core::logic::actions::PlayStyle;
+use mmheroes_core::logic::{
+    create_or_restore_game, GameMode, GameScreen, SavedGame, StateHolder,
+};
+use mmheroes_core::ui::{Input, Locale};
+
+#[test]
+fn save_is_unavailable_outside_of_scene_router() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    assert!(state.save_game().is_none());
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    assert!(state.save_game().is_some());
+
+    // Готовимся к зачёту
+    replay_game(game_ui, "r");
+    assert!(state.save_game().is_none());
+}
+
+#[test]
+fn restored_game_continues_the_same_way() {
+    initialize_game!((0, GameMode::SelectInitialParameters) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::CleverStudent);
+
+    // Идём на факультет
+    replay_game(game_ui, "4↓r");
+    let saved_game = state.save_game().unwrap();
+    let encoded = saved_game.encode();
+
+    let restored_state = StateHolder::new(GameMode::Normal);
+    let restored_game = create_or_restore_game(
+        42,
+        Some(SavedGame::decode(&encoded).unwrap()),
+        &restored_state,
+    );
+    let restored_game = core::pin::pin!(restored_game);
+    let mut restored_game_ui = TestGameUI::new(
+        &restored_state,
+        restored_game,
+        0,
+        None,
+        TestRendererRequestConsumer::new(),
+        None,
+        Locale::Russian,
+    );
+    restored_game_ui.continue_game(Input::Enter);
+    replay_game(&mut restored_game_ui, "r");
+
+    assert_eq!(
+        restored_state.observable_state().mode(),
+        GameMode::SelectInitialParameters
+    );
+    assert!(matches!(
+        restored_state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    ));
+    assert_eq!(
+        restored_game_ui.request_consumer().to_string(),
+        game_ui.request_consumer().to_string()
+    );
+    assert_eq!(restored_state.save_game().unwrap().encode(), encoded);
+
+    for steps in ["5↓r", "r", "r", "↓r", "r", "3↓r", "r", "r"] {
+        replay_game(game_ui, steps);
+        replay_game(&mut restored_game_ui, steps);
+        assert_eq!(
+            restored_game_ui.request_consumer().to_string(),
+            game_ui.request_consumer().to_string()
+        );
+    }
+}
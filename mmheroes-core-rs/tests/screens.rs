@@ -0,0 +1,51 @@
+mod common;
+
+use common::*;
+use mmheroes_core::logic::actions::PlayStyle;
+use mmheroes_core::logic::GameMode;
+
+#[test]
+fn welcome_screen() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    assert_snapshot!(game_ui, "welcome");
+}
+
+#[test]
+fn initial_parameters_screen() {
+    initialize_game!((0, GameMode::God) => state, game_ui);
+    replay_game(game_ui, "r");
+    assert_snapshot!(game_ui, "initial_parameters");
+}
+
+#[test]
+fn dorm_screen() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    assert_snapshot!(game_ui, "dorm");
+}
+
+#[test]
+fn timetable_screen() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    replay_game(game_ui, "↓r");
+    assert_snapshot!(game_ui, "timetable");
+}
+
+#[test]
+fn snapshot_diff_shows_changed_lines() {
+    let expected =
+        "Привет\nмир\n--- foreground\n777777\n777\n--- background\n000000\n000\n";
+    let actual =
+        "Привет\nмиръ\n--- foreground\n777777\n7773\n--- background\n000000\n000\n";
+    assert_eq!(snapshot_diff(expected, expected), None);
+    assert_eq!(
+        snapshot_diff(expected, actual).unwrap(),
+        "\x1b[36mtext, line 2:\x1b[0m\n\
+         \x1b[31m-мир\x1b[0m\n\
+         \x1b[32m+миръ\x1b[0m\n\
+         \x1b[36mforeground, line 2:\x1b[0m\n\
+         \x1b[31m-777\x1b[0m\n\
+         \x1b[32m+7773\x1b[0m\n"
+    );
+}
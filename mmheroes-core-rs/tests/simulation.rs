@@ -0,0 +1,70 @@
+use mmheroes_core::logic::actions::{PlayStyle, TryAgainAction};
+use mmheroes_core::logic::{
+    create_game, Action, GameMode, GameScreen, Simulation, SimulationError, StateHolder,
+};
+
+#[test]
+fn starts_at_first_prompt() {
+    let state_holder = StateHolder::new(GameMode::Normal);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let simulation = Simulation::new(&state_holder, game);
+    assert!(matches!(*simulation.screen(), GameScreen::Ding));
+    assert_eq!(*simulation.available_actions(), [Action::AnyKey]);
+
+    let state_holder = StateHolder::new(GameMode::SelectInitialParameters);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let simulation = Simulation::new(&state_holder, game);
+    assert!(matches!(
+        *simulation.screen(),
+        GameScreen::InitialParameters
+    ));
+    assert_eq!(
+        *simulation.available_actions(),
+        [
+            Action::SelectPlayStyle(PlayStyle::RandomStudent),
+            Action::SelectPlayStyle(PlayStyle::CleverStudent),
+            Action::SelectPlayStyle(PlayStyle::ImpudentStudent),
+            Action::SelectPlayStyle(PlayStyle::SociableStudent),
+        ]
+    );
+}
+
+#[test]
+fn illegal_action_is_rejected() {
+    let state_holder = StateHolder::new(GameMode::Normal);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut simulation = Simulation::new(&state_holder, game);
+    assert_eq!(
+        simulation.perform_action(Action::GoToMausoleum),
+        Err(SimulationError::IllegalAction(Action::GoToMausoleum))
+    );
+    assert!(matches!(*simulation.screen(), GameScreen::Ding));
+
+    simulation.perform_action(Action::AnyKey).unwrap();
+    assert!(matches!(*simulation.screen(), GameScreen::Timetable(_)));
+    assert_eq!(simulation.perform_action(Action::AnyKey), Ok(()));
+    assert!(matches!(*simulation.screen(), GameScreen::SceneRouter(_)));
+}
+
+#[test]
+fn play_until_the_game_is_over() {
+    let state_holder = StateHolder::new(GameMode::Normal);
+    let game = core::pin::pin!(create_game(42, &state_holder));
+    let mut simulation = Simulation::new(&state_holder, game);
+    let mut steps = 0;
+    while !simulation.is_over() {
+        let action = if matches!(*simulation.screen(), GameScreen::WannaTryAgain) {
+            Action::TryAgain(TryAgainAction::DontWantToTryAgain)
+        } else {
+            simulation.available_actions()[0]
+        };
+        simulation.perform_action(action).unwrap();
+        steps += 1;
+        assert!(steps < 100_000, "The game never ends");
+    }
+    assert!(simulation.available_actions().is_empty());
+    assert_eq!(
+        simulation.perform_action(Action::AnyKey),
+        Err(SimulationError::GameIsOver)
+    );
+}
@@ -0,0 +1,292 @@
+mod common;
+
+use common::*;
+use mmheroes_core::logic::actions::{InvitationFromNeighborAction, PlayStyle};
+use mmheroes_core::logic::scene_router::dorm::NeighborInvitation;
+use mmheroes_core::logic::{
+    create_game, create_or_restore_game, Action, CauseOfDeath, Game, GameMode,
+    GameScreen, GameState, Location, SemesterLength, Simulation, StateHolder, Subject,
+    Time, Timetable, TimetableBuilder,
+};
+use mmheroes_core::ui::{Input, Locale};
+
+#[test]
+fn game_with_custom_timetable() {
+    let timetable: Timetable = "
+        1 calculus 10 13 punk
+        1 physical_education 17 18 punk
+        2 algebra_and_number_theory 12 14 pdmi
+        3 english 9 11 punk
+        4 geometry_and_topology 10 12 pdmi
+        6 computer_science 16 18 computer_class
+    "
+    .parse()
+    .unwrap();
+    let mut builder = TimetableBuilder::new();
+    builder
+        .add_exam(0, Subject::Calculus, Time(10), Time(13), Location::PUNK)
+        .unwrap()
+        .add_exam(
+            0,
+            Subject::PhysicalEducation,
+            Time(17),
+            Time(18),
+            Location::PUNK,
+        )
+        .unwrap()
+        .add_exam(
+            1,
+            Subject::AlgebraAndNumberTheory,
+            Time(12),
+            Time(14),
+            Location::PDMI,
+        )
+        .unwrap()
+        .add_exam(2, Subject::English, Time(9), Time(11), Location::PUNK)
+        .unwrap()
+        .add_exam(
+            3,
+            Subject::GeometryAndTopology,
+            Time(10),
+            Time(12),
+            Location::PDMI,
+        )
+        .unwrap()
+        .add_exam(
+            5,
+            Subject::ComputerScience,
+            Time(16),
+            Time(18),
+            Location::ComputerClass,
+        )
+        .unwrap();
+    assert_eq!(builder.build(), Ok(timetable.clone()));
+
+    let state_holder =
+        StateHolder::new(GameMode::Normal).with_timetable(timetable.clone());
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut game_ui = TestGameUI::new(
+        &state_holder,
+        game,
+        0,
+        None,
+        TestRendererRequestConsumer::new(),
+        None,
+        Locale::Russian,
+    );
+    let game_ui = &mut game_ui;
+    game_ui.continue_game(Input::Enter);
+    replay_until_dorm(&state_holder, game_ui, PlayStyle::RandomStudent);
+    assert_eq!(state_holder.game_state().timetable(), &timetable);
+
+    replay_game(game_ui, "↓r");
+    assert_ui!(
+        game_ui,
+        r#"
+                        22.5   23.5   24.5   25.5   26.5   27.5
+Всемирнов М.А.                 ПОМИ                                   Осталось
+Алгебра и Т.Ч.                 12-14                                  12 заданий
+
+Дубцов Е.С.             ПУНК                                          Осталось
+Мат. Анализ             10-13                                         10 заданий
+
+Подкорытов С.С.                              ПОМИ                     Осталось
+Геометрия и Топология                        10-12                    3 задания
+
+Климов А.А.                                                Компы      Осталось
+Информатика                                                16-18      2 задания
+
+Влащенко Н.П.                         ПУНК                            Осталось
+English                               9-11                            3 задания
+
+Альбинский Е.Г.         ПУНК                                          Осталось
+Физ-ра                  17-18                                         1 задание
+
+
+
+
+Осталось 6 зачетов.
+Нажми любую клавишу ...▁
+"#
+    );
+}
+
+#[test]
+fn blitz_game() {
+    let state_holder =
+        StateHolder::new(GameMode::Normal).with_semester_length(SemesterLength::BLITZ);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut game_ui = TestGameUI::new(
+        &state_holder,
+        game,
+        0,
+        None,
+        TestRendererRequestConsumer::new(),
+        None,
+        Locale::Russian,
+    );
+    let game_ui = &mut game_ui;
+    game_ui.continue_game(Input::Enter);
+    replay_until_dorm(&state_holder, game_ui, PlayStyle::RandomStudent);
+    assert_eq!(
+        state_holder.game_state().timetable().length(),
+        SemesterLength::BLITZ
+    );
+
+    replay_game(game_ui, "↓r");
+    assert_ui!(
+        game_ui,
+        r#"
+                        22.5   23.5   24.5
+Всемирнов М.А.                 ПУНК   ПОМИ                            Осталось
+Алгебра и Т.Ч.                 13-15  10-12                           12 заданий
+
+Дубцов Е.С.             ПУНК   ПУНК                                   Осталось
+Мат. Анализ             15-18  15-18                                  10 заданий
+
+Подкорытов С.С.                ПОМИ                                   Осталось
+Геометрия и Топология          10-12                                  3 задания
+
+Климов А.А.                           Компы                           Осталось
+Информатика                           9-11                            2 задания
+
+Влащенко Н.П.                  ПУНК                                   Осталось
+English                        12-14                                  3 задания
+
+Альбинский Е.Г.         ПУНК                                          Осталось
+Физ-ра                  15-16                                         1 задание
+
+
+
+
+Осталось 6 зачетов.
+Нажми любую клавишу ...▁
+"#
+    );
+
+    // Если просто сидеть в общаге, сессия кончится после третьего дня.
+    let state_holder =
+        StateHolder::new(GameMode::Normal).with_semester_length(SemesterLength::BLITZ);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut simulation = Simulation::new(&state_holder, game);
+    stay_in_dorm(&mut simulation, |_| false);
+    let screen = simulation.screen();
+    let GameScreen::GameEnd(state) = &*screen else {
+        unreachable!()
+    };
+    assert_eq!(state.player().cause_of_death(), Some(CauseOfDeath::TimeOut));
+    assert_eq!(state.current_day_index(), 3);
+}
+
+#[test]
+fn marathon_game() {
+    // Доживаем до одиннадцатого дня сессии — это уже июнь.
+    let state_holder =
+        StateHolder::new(GameMode::Normal).with_semester_length(SemesterLength::MARATHON);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut simulation = Simulation::new(&state_holder, game);
+    stay_in_dorm(&mut simulation, |state| state.current_day_index() == 10);
+    let saved_game = simulation.save_game().unwrap();
+
+    let state_holder = StateHolder::new(GameMode::Normal);
+    let game =
+        core::pin::pin!(create_or_restore_game(0, Some(saved_game), &state_holder));
+    let mut game_ui = TestGameUI::new(
+        &state_holder,
+        game,
+        0,
+        None,
+        TestRendererRequestConsumer::new(),
+        None,
+        Locale::Russian,
+    );
+    let game_ui = &mut game_ui;
+    game_ui.continue_game(Input::Enter);
+    replay_game(game_ui, "r");
+    assert_ui!(
+        game_ui,
+        r#"
+Сегодня 1е июня; 5:00    Версия gamma3.14   Алгебра и Т.Ч.        1   Плохо
+Самочувствие: отличное (50)                 Мат. Анализ           0   Плохо
+Финансы: Надо получить деньги за май...     Геометрия и Топология 1   Плохо
+Голова свежая (5)                           Информатика           0   Плохо
+Немного устал (4)                           English               3   Плохо
+У тебя очень много друзей (12)              Физ-ра                0   Плохо
+
+Ты в общаге. Что делать?
+
+Готовиться▁                                      АиТЧ    ----           0/12
+Посмотреть расписание                            МатАн   ПУНК  10-12    0/10
+Отдыхать                                         ГиТ     ----           0/3
+Лечь спать                                       Инф     Компы 12-13    0/2
+Пойти на факультет                               ИнЯз    ПУНК  12-14    0/3
+Поехать в ПОМИ                                   Физ-ра  ----           0/1
+Пойти в мавзолей
+С меня хватит!
+ЧТО ДЕЛАТЬ ???
+"#
+    );
+
+    // На экране помещаются только шесть дней, поэтому видны последние.
+    replay_game(game_ui, "↓r");
+    assert_ui!(
+        game_ui,
+        r#"
+                        30.5   31.5   1.6    2.6    3.6    4.6
+Всемирнов М.А.                 ПОМИ          ПОМИ   ПУНК   ПУНК       Осталось
+Алгебра и Т.Ч.                 10-12         9-11   12-14  9-11       12 заданий
+
+Дубцов Е.С.             ПУНК          ПУНК   ПУНК          ПУНК       Осталось
+Мат. Анализ             13-16         10-12  10-13         14-17      10 заданий
+
+Подкорытов С.С.                                                       Осталось
+Геометрия и Топология                                                 3 задания
+
+Климов А.А.             Компы         Компы  Компы                    Осталось
+Информатика             10-11         12-13  9-11                     2 задания
+
+Влащенко Н.П.           ПУНК   ПУНК   ПУНК                            Осталось
+English                 12-14  13-15  12-14                           3 задания
+
+Альбинский Е.Г.                ПУНК          ПУНК          ПУНК       Осталось
+Физ-ра                         17-18         15-16         16-17      1 задание
+
+
+
+
+Осталось 6 зачетов.
+Нажми любую клавишу ...▁
+"#
+    );
+}
+
+/// Играет за студента, который сидит в общаге: днём отдыхает, ночью спит
+/// и не отказывается от приглашений соседа, чтобы не потерять харизму.
+/// Останавливается в главном меню, как только `stop` вернёт `true`,
+/// или когда игра закончится.
+fn stay_in_dorm<G: Game + ?Sized>(
+    simulation: &mut Simulation<'_, G>,
+    stop: impl Fn(&GameState) -> bool,
+) {
+    loop {
+        let action = match &*simulation.screen() {
+            GameScreen::GameEnd(_) => return,
+            GameScreen::SceneRouter(state) => {
+                if stop(state) {
+                    return;
+                }
+                let time = state.current_time();
+                if time >= Time(20) || time <= Time(3) {
+                    Action::GoToBed
+                } else {
+                    Action::Rest
+                }
+            }
+            GameScreen::NeighborInvites(NeighborInvitation::InvitePrompt(..)) => {
+                Action::InvitationFromNeighbor(InvitationFromNeighborAction::Accept)
+            }
+            _ => simulation.available_actions()[0],
+        };
+        simulation.perform_action(action).unwrap();
+    }
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use assert_matches::assert_matches;
+use common::*;
+use mmheroes_core::logic::actions::PlayStyle;
+use mmheroes_core::logic::{create_game, GameMode, GameScreen, StateHolder};
+use mmheroes_core::ui::undo::UNDO_DEPTH;
+use mmheroes_core::ui::{GameUI, Input, Locale};
+
+#[test]
+fn nothing_to_undo_at_the_start() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    assert!(!game_ui.can_undo());
+    assert!(!game_ui.undo());
+
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+    assert!(!game_ui.can_undo());
+
+    // Выбираем, к чему готовиться
+    replay_game(game_ui, "r");
+    assert_matches!(state.observable_state().screen(), GameScreen::Study(_));
+    assert!(game_ui.can_undo());
+    assert!(game_ui.undo());
+    assert_matches!(
+        state.observable_state().screen(),
+        GameScreen::SceneRouter(_)
+    );
+    assert!(!game_ui.can_undo());
+
+    // Отмена, когда отменять нечего, ни на что не влияет
+    let state_hash = game_ui.state_hash();
+    game_ui.continue_game(Input::Undo);
+    assert_eq!(game_ui.state_hash(), state_hash);
+}
+
+#[test]
+fn death_can_be_undone() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+
+    // Учим алгебру пока уровень здоровья не упадёт до почти нуля
+    replay_game(game_ui, "10r");
+    let state_hash = game_ui.state_hash();
+    assert!(state_hash.is_some());
+
+    // Идём на факультет и умираем по дороге
+    replay_game(game_ui, "4↓r");
+    assert_matches!(state.observable_state().screen(), GameScreen::GameEnd(_));
+
+    assert!(game_ui.undo());
+    assert_eq!(game_ui.state_hash(), state_hash);
+    assert_ui!(
+        game_ui,
+        "
+Сегодня 22е мая; 13:00   Версия gamma3.14   Алгебра и Т.Ч.        26  Хорошо
+Самочувствие: пора помирать ... (2)         Мат. Анализ           0   Плохо
+Финансы: Надо получить деньги за май...     Геометрия и Топология 3   Плохо
+Голова свежая (5)                           Информатика           0   Плохо
+Немного устал (4)                           English               4   Плохо
+У тебя много друзей (5)                     Физ-ра                0   Плохо
+
+Ты в общаге. Что делать?
+
+Готовиться▁                                      АиТЧ    ПУНК  13-15    0/12
+Посмотреть расписание                            МатАн   ----           0/10
+Отдыхать                                         ГиТ     ----           0/3
+Лечь спать                                       Инф     ----           0/2
+Пойти на факультет                               ИнЯз    ПУНК  14-16    0/3
+Поехать в ПОМИ                                   Физ-ра  ----           0/1
+Пойти в мавзолей
+С меня хватит!
+ЧТО ДЕЛАТЬ ???
+"
+    );
+
+    // Генератор случайных чисел тоже вернулся назад, так что всё повторяется
+    replay_game(game_ui, "4↓r");
+    assert_matches!(state.observable_state().screen(), GameScreen::GameEnd(_));
+}
+
+#[test]
+fn turns_are_undone_one_at_a_time() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+
+    let mut state_hashes = vec![game_ui.state_hash().unwrap()];
+    for _ in 0..3 {
+        // Готовимся к алгебре
+        replay_game(game_ui, "2r");
+        state_hashes.push(game_ui.state_hash().unwrap());
+    }
+
+    state_hashes.pop();
+    while let Some(state_hash) = state_hashes.pop() {
+        assert!(game_ui.undo());
+        assert_eq!(game_ui.state_hash(), Some(state_hash));
+    }
+    assert!(!game_ui.undo());
+}
+
+#[test]
+fn undo_depth_is_limited() {
+    initialize_game!((0, GameMode::Normal) => state, game_ui);
+    replay_until_dorm(state, game_ui, PlayStyle::RandomStudent);
+
+    for _ in 0..UNDO_DEPTH + 2 {
+        // Отдыхаем. Если отвлекают (например, сосед зовёт на вечеринку), отказываемся.
+        replay_game(game_ui, "2↓r");
+        while !matches!(
+            state.observable_state().screen(),
+            GameScreen::SceneRouter(_)
+        ) {
+            replay_game(game_ui, "↓r");
+        }
+    }
+
+    let mut undone = 0;
+    while game_ui.undo() {
+        undone += 1;
+    }
+    assert_eq!(undone, UNDO_DEPTH - 1);
+}
+
+#[test]
+fn undo_is_recorded() {
+    let state_holder = StateHolder::new(GameMode::Normal);
+    let game = core::pin::pin!(create_game(0, &state_holder));
+    let mut game_ui = GameUI::new(
+        &state_holder,
+        game,
+        0,
+        None,
+        TestRendererRequestConsumer::new(),
+        Some(String::new()),
+        Locale::Russian,
+    );
+    // Доходим до общаги и готовимся к алгебре
+    for _ in 0..6 {
+        game_ui.continue_game(Input::Enter);
+    }
+    assert!(game_ui.undo());
+    game_ui.continue_game(Input::KeyDown);
+    game_ui.continue_game(Input::Enter);
+    let state_hash = game_ui.state_hash();
+    game_ui.flush_input_recorder().unwrap();
+    let recording = game_ui.recorded_input().unwrap().clone();
+    assert_eq!(recording, "6r↶↓r");
+
+    initialize_game!((0, GameMode::Normal) => state, replayed_game_ui);
+    replay_game(replayed_game_ui, &recording[1..]);
+    assert_eq!(replayed_game_ui.state_hash(), state_hash);
+}
@@ -22,13 +22,6 @@ final class Game {
 
     private(set) var requests: [RendererRequest] = [.clearScreen, .flush]
 
-    private static let rendererRequestCallback: MMHEROES_RendererRequestCallback =
-        { context, request in
-            Unmanaged<Game>.fromOpaque(context!).takeUnretainedValue()
-                .requests
-                .append(RendererRequest(request))
-        }
-
     let mode: MMHEROES_GameMode
     let seed: UInt64
     fileprivate var handle: UnsafeMutableRawPointer! = nil
@@ -39,7 +32,11 @@ final class Game {
         return inputLogPtr.pointee
     }
 
-    init(mode: MMHEROES_GameMode, seed: UInt64, highScores: [HighScore]? = nil) {
+    init(mode: MMHEROES_GameMode,
+         seed: UInt64,
+         semesterLength: UInt8 = 6,
+         locale: MMHEROES_Locale = MMHEROES_Locale_Russian,
+         highScores: [HighScore]? = nil) {
         self.mode = mode
         self.seed = seed
         inputLogPtr.initialize(to: "")
@@ -67,11 +64,12 @@ final class Game {
                 mmheroes_game_create(
                     mode,
                     seed,
+                    semesterLength,
+                    locale,
                     buffer.baseAddress,
                     nil,
                     allocator,
-                    Unmanaged.passUnretained(self).toOpaque(),
-                    Self.rendererRequestCallback,
+                    deallocator,
                     inputRecorderSink
                 )
             }
@@ -79,16 +77,21 @@ final class Game {
             handle = mmheroes_game_create(
                 mode,
                 seed,
+                semesterLength,
+                locale,
                 nil,
                 nil,
                 allocator,
-                Unmanaged.passUnretained(self).toOpaque(),
-                Self.rendererRequestCallback,
+                deallocator,
                 inputRecorderSink
             )
         }
     }
 
+    var semesterLength: Int {
+        Int(mmheroes_game_get_semester_length(handle))
+    }
+
     var dayAndTime: (Int, Time)? {
         var day: UInt8 = 255
         var time = Time(rawValue: 255)
@@ -125,16 +128,30 @@ final class Game {
     @discardableResult
     func replay(recordedInput: String) -> Bool {
         withExtendedLifetime(self) {
-            recordedInput.withCString(encodedAs: UTF8.self) { buf in
+            let result = recordedInput.withCString(encodedAs: UTF8.self) { buf in
                 mmheroes_replay(handle, buf, UInt(recordedInput.utf8.count))
             }
+            collectRendererRequests()
+            return result
         }
     }
 
     func continueGame(input: MMHEROES_Input) -> Bool {
+        withExtendedLifetime(self) {
+            let result = mmheroes_continue(handle, input)
+            collectRendererRequests()
+            return result
+        }
+    }
+
+    /// Copies the requests accumulated by the last call that advanced the game.
+    /// The strings they point to are only valid until the next such call.
+    private func collectRendererRequests() {
         requests.removeAll()
-        return withExtendedLifetime(self) {
-            mmheroes_continue(handle, input)
+        var iterator = mmheroes_renderer_request_iterator_begin(handle)
+        var request = MMHEROES_RendererRequest()
+        while mmheroes_renderer_request_iterator_next(&iterator, &request) {
+            requests.append(RendererRequest(request))
         }
     }
 
@@ -145,7 +162,7 @@ final class Game {
     }
 
     deinit {
-        mmheroes_game_destroy(handle, nil, deallocator)
+        mmheroes_game_destroy(handle)
         inputLogPtr.deinitialize(count: 1)
         inputLogPtr.deallocate()
     }
@@ -0,0 +1,188 @@
+use crate::state::GameState;
+use mmheroes_core::logic::{
+    self, create_game, GameMode, Simulation, SimulationError, StateHolder,
+};
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::pin::Pin;
+
+fn parse_mode(mode: &str) -> PyResult<GameMode> {
+    match mode {
+        "normal" => Ok(GameMode::Normal),
+        "select" => Ok(GameMode::SelectInitialParameters),
+        "god" => Ok(GameMode::God),
+        _ => Err(PyValueError::new_err(format!("Unknown mode: {mode}"))),
+    }
+}
+
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Normal => "normal",
+        GameMode::SelectInitialParameters => "select",
+        GameMode::God => "god",
+    }
+}
+
+/// Действие, которое можно совершить на текущем экране.
+///
+/// Действия сравниваются по значению и могут быть ключами словаря.
+#[pyclass(frozen, from_py_object, name = "Action", module = "mmheroes")]
+#[derive(Copy, Clone)]
+pub struct Action(logic::Action);
+
+#[pymethods]
+impl Action {
+    /// Имя варианта действия без параметров, например, `"DoStudy"`.
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.0.into()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        format!("{:?}", self.0).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// Текущий экран игры.
+#[pyclass(frozen, name = "Screen", module = "mmheroes")]
+pub struct Screen {
+    name: &'static str,
+    state: Option<logic::GameState>,
+}
+
+#[pymethods]
+impl Screen {
+    /// Имя экрана, например, `"SceneRouter"` или `"GameEnd"`.
+    #[getter]
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Состояние игры или `None`, если на этом экране оно недоступно.
+    #[getter]
+    fn state(&self) -> Option<GameState> {
+        self.state.clone().map(GameState)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Screen({})", self.name)
+    }
+}
+
+/// Игра, которой управляют действиями (см. `Simulation` в движке).
+///
+/// ```python
+/// game = mmheroes.Game(seed=42, mode="normal")
+/// while not game.is_over:
+///     game.perform_action(game.available_actions()[0])
+/// ```
+///
+/// `Simulation` хранит ссылки на `StateHolder` и игру, поэтому они лежат в куче
+/// рядом с ней. Порядок полей важен: поля удаляются в порядке объявления.
+#[pyclass(unsendable, name = "Game", module = "mmheroes")]
+pub struct Game {
+    simulation: Simulation<'static, dyn logic::Game>,
+    _game: Pin<Box<dyn logic::Game>>,
+    _state_holder: Box<StateHolder>,
+    seed: u64,
+}
+
+#[pymethods]
+impl Game {
+    /// `mode` — `"normal"`, `"select"` (выбор стиля игры) или `"god"`.
+    #[new]
+    #[pyo3(signature = (seed, mode = "normal"))]
+    fn new(seed: u64, mode: &str) -> PyResult<Self> {
+        let state_holder = Box::new(StateHolder::new(parse_mode(mode)?));
+        // SAFETY: StateHolder лежит в куче и удаляется после игры и симуляции.
+        let state_holder_ref = unsafe { &*(&*state_holder as *const StateHolder) };
+        let mut game: Pin<Box<dyn logic::Game>> =
+            Box::pin(create_game(seed, state_holder_ref));
+        // SAFETY: то же самое для игры. Она не перемещается, так как закреплена.
+        let game_ref = unsafe {
+            Pin::new_unchecked(&mut *(game.as_mut().get_unchecked_mut() as *mut _))
+        };
+        Ok(Game {
+            simulation: Simulation::new(state_holder_ref, game_ref),
+            _game: game,
+            _state_holder: state_holder,
+            seed,
+        })
+    }
+
+    #[getter]
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    #[getter]
+    fn mode(&self) -> &'static str {
+        mode_name(self.simulation.observable_state().mode())
+    }
+
+    #[getter]
+    fn screen(&self) -> Screen {
+        let screen = self.simulation.screen();
+        Screen {
+            name: (&*screen).into(),
+            state: screen.state().cloned(),
+        }
+    }
+
+    /// То же, что `screen.state`.
+    #[getter]
+    fn state(&self) -> Option<GameState> {
+        self.simulation.screen().state().cloned().map(GameState)
+    }
+
+    /// Закончилась ли игра безвозвратно.
+    #[getter]
+    fn is_over(&self) -> bool {
+        self.simulation.is_over()
+    }
+
+    fn available_actions(&self) -> Vec<Action> {
+        self.simulation
+            .available_actions()
+            .iter()
+            .copied()
+            .map(Action)
+            .collect()
+    }
+
+    /// Выполняет действие. Если оно недоступно на текущем экране, бросает
+    /// `ValueError`, а если игра закончилась — `RuntimeError`.
+    fn perform_action(&mut self, action: &Action) -> PyResult<()> {
+        self.simulation
+            .perform_action(action.0)
+            .map_err(|error| match error {
+                SimulationError::IllegalAction(action) => {
+                    PyValueError::new_err(format!("Illegal action: {action:?}"))
+                }
+                SimulationError::GameIsOver => {
+                    PyRuntimeError::new_err("The game is over")
+                }
+            })
+    }
+
+    /// Хэш состояния игры или `None`, если на экране не главное меню локации.
+    /// Одинаковые хэши означают, что игры дальше пойдут одинаково.
+    fn state_hash(&self) -> Option<u64> {
+        self.simulation.state_hash()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Game(seed={}, mode={:?})", self.seed, self.mode())
+    }
+}
@@ -0,0 +1,127 @@
+//! Модуль `mmheroes` для Python: движок игры без пользовательского интерфейса.
+//!
+//! Нужен, чтобы писать стратегии и анализировать баланс в Python, например,
+//! в Jupyter-ноутбуках. Собирается с помощью maturin (см. README).
+
+mod game;
+mod state;
+
+use pyo3::prelude::*;
+
+/// Движок «Героев Мата и Меха».
+#[pymodule]
+pub mod mmheroes {
+    #[pymodule_export]
+    use crate::game::{Action, Game, Screen};
+
+    #[pymodule_export]
+    use crate::state::{
+        ClassmateInfo, Day, Exam, GameState, Player, SubjectStatus, Timetable,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::ffi::c_str;
+    use pyo3::types::PyDict;
+
+    /// Выполняет код на Python, в котором доступен модуль `mmheroes`.
+    fn run_python(code: &core::ffi::CStr) {
+        Python::attach(|py| {
+            let globals = PyDict::new(py);
+            globals
+                .set_item("mmheroes", pyo3::wrap_pymodule!(mmheroes)(py))
+                .unwrap();
+            if let Err(error) = py.run(code, Some(&globals), None) {
+                error.display(py);
+                panic!("Python code failed: {error}");
+            }
+        })
+    }
+
+    #[test]
+    fn test_initial_state() {
+        run_python(c_str!(
+            r#"
+game = mmheroes.Game(0)
+assert game.seed == 0
+assert game.mode == "normal"
+assert not game.is_over
+assert game.screen.name == "Ding", game.screen
+assert game.state is None
+actions = game.available_actions()
+assert [action.name for action in actions] == ["AnyKey"], actions
+
+game.perform_action(actions[0])
+screen = game.screen
+assert screen.name == "Timetable", screen
+state = screen.state
+assert state.day == 0
+assert state.time == 8
+assert state.location == "Dorm"
+assert len(state.timetable.days) == 6
+assert all(exam.start < exam.end for day in state.timetable.days for exam in day.exams)
+assert len(state.player.subjects) == 6
+assert state.player.cause_of_death is None
+assert state.player.exams_left == 6
+assert {c.classmate for c in state.classmates} >= {"Kolya", "Diamond"}
+"#
+        ));
+    }
+
+    #[test]
+    fn test_actions() {
+        run_python(c_str!(
+            r#"
+game = mmheroes.Game(1, mode="god")
+assert game.screen.name == "InitialParameters"
+actions = game.available_actions()
+assert len(set(actions)) == len(actions) == 5
+assert actions[0] == game.available_actions()[0]
+try:
+    game.perform_action(mmheroes.Game(1).available_actions()[0])
+    assert False
+except ValueError:
+    pass
+
+god = next(action for action in actions if "GodMode" in repr(action))
+game.perform_action(god)
+game.perform_action(game.available_actions()[0])
+assert game.state.player.is_god_mode
+assert game.state.player.brain == 30
+
+try:
+    mmheroes.Game(1, mode="easy")
+    assert False
+except ValueError:
+    pass
+"#
+        ));
+    }
+
+    #[test]
+    fn test_play_until_the_end() {
+        run_python(c_str!(
+            r#"
+import random
+
+rng = random.Random(2)
+game = mmheroes.Game(2)
+screens = set()
+for _ in range(100000):
+    if game.is_over:
+        break
+    screens.add(game.screen.name)
+    game.perform_action(rng.choice(game.available_actions()))
+assert game.is_over
+assert "GameEnd" in screens, screens
+try:
+    game.perform_action(game.available_actions()[0])
+    assert False
+except (RuntimeError, IndexError):
+    pass
+"#
+        ));
+    }
+}
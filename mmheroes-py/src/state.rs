@@ -0,0 +1,305 @@
+//! Снимки состояния игры для Python.
+//!
+//! Объекты здесь не ссылаются на игру, а хранят копию состояния на момент, когда
+//! их получили. Перечисления движка отдаются строками с именами вариантов,
+//! например, `"AlgebraAndNumberTheory"` или `"PUNK"`.
+
+use mmheroes_core::logic::{self, ClassmateLocation, Subject};
+use pyo3::prelude::*;
+
+fn name(value: impl core::fmt::Debug) -> String {
+    format!("{value:?}")
+}
+
+#[pyclass(frozen, name = "GameState", module = "mmheroes")]
+pub struct GameState(pub(crate) logic::GameState);
+
+#[pymethods]
+impl GameState {
+    /// Индекс текущего дня, начиная с нуля.
+    #[getter]
+    fn day(&self) -> u8 {
+        self.0.current_day_index()
+    }
+
+    /// Текущее время в часах.
+    #[getter]
+    fn time(&self) -> u8 {
+        self.0.current_time().0
+    }
+
+    #[getter]
+    fn location(&self) -> String {
+        name(self.0.location())
+    }
+
+    #[getter]
+    fn player(&self) -> Player {
+        Player(self.0.player().clone(), self.0.rules())
+    }
+
+    #[getter]
+    fn timetable(&self) -> Timetable {
+        Timetable(self.0.timetable().clone())
+    }
+
+    #[getter]
+    fn classmates(&self) -> Vec<ClassmateInfo> {
+        self.0
+            .classmates()
+            .iter()
+            .map(|info| ClassmateInfo {
+                classmate: name(info.classmate()),
+                location: info.current_location(),
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        name(&self.0)
+    }
+}
+
+#[pyclass(frozen, name = "Player", module = "mmheroes")]
+pub struct Player(logic::Player, &'static logic::Rules);
+
+#[pymethods]
+impl Player {
+    #[getter]
+    fn health(&self) -> i16 {
+        self.0.health()
+    }
+
+    #[getter]
+    fn money(&self) -> i16 {
+        self.0.money().0
+    }
+
+    #[getter]
+    fn brain(&self) -> i16 {
+        self.0.brain()
+    }
+
+    #[getter]
+    fn stamina(&self) -> i16 {
+        self.0.stamina()
+    }
+
+    #[getter]
+    fn charisma(&self) -> i16 {
+        self.0.charisma()
+    }
+
+    /// Количество очков, которое попадёт в таблицу рекордов.
+    #[getter]
+    fn score(&self) -> i16 {
+        self.0.score().0
+    }
+
+    #[getter]
+    fn exams_left(&self) -> usize {
+        self.0.exams_left()
+    }
+
+    #[getter]
+    fn is_god_mode(&self) -> bool {
+        self.0.is_god_mode()
+    }
+
+    #[getter]
+    fn has_mmheroes_floppy(&self) -> bool {
+        self.0.has_mmheroes_floppy()
+    }
+
+    #[getter]
+    fn has_internet(&self) -> bool {
+        self.0.has_internet()
+    }
+
+    #[getter]
+    fn is_employed_at_terkom(&self) -> bool {
+        self.0.is_employed_at_terkom()
+    }
+
+    #[getter]
+    fn got_stipend(&self) -> bool {
+        self.0.got_stipend()
+    }
+
+    #[getter]
+    fn has_train_ticket(&self) -> bool {
+        self.0.has_train_ticket()
+    }
+
+    #[getter]
+    fn knows_djug(&self) -> bool {
+        self.0.knows_djug()
+    }
+
+    /// Причина смерти или `None`, если персонаж жив.
+    #[getter]
+    fn cause_of_death(&self) -> Option<String> {
+        self.0.cause_of_death().map(name)
+    }
+
+    /// Состояние по каждому предмету в порядке, в котором они идут в расписании.
+    #[getter]
+    fn subjects(&self) -> Vec<SubjectStatus> {
+        Subject::all_subjects()
+            .map(|subject| {
+                SubjectStatus(self.0.status_for_subject(subject).clone(), self.1)
+            })
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        name(&self.0)
+    }
+}
+
+#[pyclass(frozen, name = "SubjectStatus", module = "mmheroes")]
+pub struct SubjectStatus(logic::SubjectStatus, &'static logic::Rules);
+
+#[pymethods]
+impl SubjectStatus {
+    #[getter]
+    fn subject(&self) -> String {
+        name(self.0.subject())
+    }
+
+    #[getter]
+    fn knowledge(&self) -> i16 {
+        self.0.knowledge()
+    }
+
+    #[getter]
+    fn problems_done(&self) -> u8 {
+        self.0.problems_done()
+    }
+
+    #[getter]
+    fn problems_remaining(&self) -> u8 {
+        self.0.problems_remaining(self.1)
+    }
+
+    #[getter]
+    fn passed(&self) -> bool {
+        self.0.passed()
+    }
+
+    #[getter]
+    fn has_lecture_notes(&self) -> bool {
+        self.0.has_lecture_notes()
+    }
+
+    fn __repr__(&self) -> String {
+        name(&self.0)
+    }
+}
+
+#[pyclass(frozen, name = "Timetable", module = "mmheroes")]
+pub struct Timetable(logic::Timetable);
+
+#[pymethods]
+impl Timetable {
+    #[getter]
+    fn days(&self) -> Vec<Day> {
+        self.0.days().iter().cloned().map(Day).collect()
+    }
+
+    fn __repr__(&self) -> String {
+        name(&self.0)
+    }
+}
+
+#[pyclass(frozen, name = "Day", module = "mmheroes")]
+pub struct Day(logic::Day);
+
+#[pymethods]
+impl Day {
+    #[getter]
+    fn index(&self) -> usize {
+        self.0.index()
+    }
+
+    #[getter]
+    fn exams(&self) -> Vec<Exam> {
+        self.0.exams().copied().map(Exam).collect()
+    }
+
+    fn __repr__(&self) -> String {
+        name(&self.0)
+    }
+}
+
+#[pyclass(frozen, name = "Exam", module = "mmheroes")]
+pub struct Exam(logic::timetable::Exam);
+
+#[pymethods]
+impl Exam {
+    #[getter]
+    fn subject(&self) -> String {
+        name(self.0.subject())
+    }
+
+    /// Час начала зачёта.
+    #[getter]
+    fn start(&self) -> u8 {
+        self.0.from().0
+    }
+
+    /// Час окончания зачёта.
+    #[getter]
+    fn end(&self) -> u8 {
+        self.0.to().0
+    }
+
+    #[getter]
+    fn location(&self) -> String {
+        name(self.0.location())
+    }
+
+    fn __repr__(&self) -> String {
+        name(self.0)
+    }
+}
+
+#[pyclass(frozen, name = "ClassmateInfo", module = "mmheroes")]
+pub struct ClassmateInfo {
+    classmate: String,
+    location: ClassmateLocation,
+}
+
+#[pymethods]
+impl ClassmateInfo {
+    #[getter]
+    fn classmate(&self) -> &str {
+        &self.classmate
+    }
+
+    /// Локация, в которой находится одногруппник, или `None`, если его нигде нет
+    /// или он сидит на зачёте (см. `exam`).
+    #[getter]
+    fn location(&self) -> Option<String> {
+        match self.location {
+            ClassmateLocation::Nowhere | ClassmateLocation::Exam(_) => None,
+            ClassmateLocation::Location(location) => Some(name(location)),
+        }
+    }
+
+    /// Предмет, на зачёте по которому сидит одногруппник.
+    #[getter]
+    fn exam(&self) -> Option<String> {
+        match self.location {
+            ClassmateLocation::Exam(subject) => Some(name(subject)),
+            _ => None,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ClassmateInfo(classmate={:?}, location={:?})",
+            self.classmate, self.location
+        )
+    }
+}
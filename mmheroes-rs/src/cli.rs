@@ -0,0 +1,338 @@
+//! Разбор аргументов командной строки.
+//!
+//! Для совместимости с оригинальной игрой первый аргумент, который не является
+//! одной из длинных опций ниже, выбирает режим игры, как и раньше:
+//! `-3dec-happy-birthday-Diamond` включает GOD-режим, а любой другой, в том числе
+//! `-h` и `--что-угодно`, — выбор начальных параметров. Поэтому `-h` выводит справку,
+//! только если это не первый аргумент. Неизвестные аргументы после первого считаются
+//! ошибкой.
+
+use mmheroes_core::logic::{GameMode, RngMode, SemesterLength};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub(crate) const USAGE: &str = "\
+Usage: mmheroes [OPTIONS]
+
+Options:
+  --mode <MODE>          normal, select or god [default: normal]
+  --seed <SEED>          Seed of the game [default: current time]
+  --rng <MODE>           separate (a random number stream per game subsystem), single
+                         (one stream, as in older versions) or borland (Random of
+                         Borland Pascal 7.0, as in the original game) [default: single]
+  --replay <FILE>        Play back a replay or an input recording before playing
+  --replay-speed <N>     Playback speed, N times faster than 3 keys per second [default: 1]
+  --record <FILE>        Record a replay of the game into FILE
+  --high-scores <PATH>   Path of the high score file
+  --rules <FILE>         Read the game rules from a TOML or JSON file
+  --timetable <FILE>     Start with the exam timetable from FILE instead of a random
+                         one
+  --days <DAYS>          Length of the exam session: a number of days from 3 to 14,
+                         blitz (3 days) or marathon (14 days) [default: 6]
+  --no-delay             Don't pause between animation frames
+  --plain-text           Print plain text instead of drawing the screen and read option
+                         numbers from the standard input
+  --version              Print version
+  -h, --help             Print this help
+";
+
+/// Аргумент, включающий GOD-режим в оригинальной игре.
+const LEGACY_GOD_MODE_ARGUMENT: &str = "-3dec-happy-birthday-Diamond";
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Options {
+    /// `None`, если режим не указан. Тогда играем в обычном режиме или продолжаем
+    /// сохранённую игру.
+    pub(crate) mode: Option<GameMode>,
+    pub(crate) seed: Option<u64>,
+    pub(crate) rng_mode: Option<RngMode>,
+    pub(crate) replay: Option<PathBuf>,
+    pub(crate) replay_speed: f64,
+    pub(crate) record: Option<PathBuf>,
+    pub(crate) high_scores: Option<PathBuf>,
+    pub(crate) rules: Option<PathBuf>,
+    pub(crate) timetable: Option<PathBuf>,
+    pub(crate) semester_length: Option<SemesterLength>,
+    pub(crate) no_delay: bool,
+    pub(crate) plain_text: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            mode: None,
+            seed: None,
+            rng_mode: None,
+            replay: None,
+            replay_speed: 1.0,
+            record: None,
+            high_scores: None,
+            rules: None,
+            timetable: None,
+            semester_length: None,
+            no_delay: false,
+            plain_text: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Command {
+    Play(Options),
+    Help,
+    Version,
+}
+
+fn parse_mode(value: &str) -> Option<GameMode> {
+    match value {
+        "normal" => Some(GameMode::Normal),
+        "select" => Some(GameMode::SelectInitialParameters),
+        "god" => Some(GameMode::God),
+        _ => None,
+    }
+}
+
+fn parse_rng_mode(value: &str) -> Option<RngMode> {
+    match value {
+        "separate" => Some(RngMode::SeparateStreams),
+        "single" => Some(RngMode::SingleStream),
+        "borland" => Some(RngMode::BorlandPascal),
+        _ => None,
+    }
+}
+
+fn parse_semester_length(value: &str) -> Option<SemesterLength> {
+    match value {
+        "blitz" => Some(SemesterLength::BLITZ),
+        "marathon" => Some(SemesterLength::MARATHON),
+        _ => u8::from_str(value).ok().and_then(SemesterLength::new),
+    }
+}
+
+fn parse_speed(value: &str) -> Option<f64> {
+    f64::from_str(value)
+        .ok()
+        .filter(|&speed| speed.is_finite() && speed > 0.0)
+}
+
+pub(crate) fn parse_options(
+    args: impl IntoIterator<Item = String>,
+) -> Result<Command, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+    let mut first = true;
+    while let Some(arg) = args.next() {
+        let is_first = std::mem::replace(&mut first, false);
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "-h" if !is_first => return Ok(Command::Help),
+            "--help" => return Ok(Command::Help),
+            "--version" => return Ok(Command::Version),
+            "--mode" => {
+                let value = value()?;
+                options.mode = Some(
+                    parse_mode(&value)
+                        .ok_or_else(|| format!("Unknown mode: {}", value))?,
+                );
+            }
+            "--seed" => {
+                let value = value()?;
+                options.seed = Some(
+                    u64::from_str(&value)
+                        .map_err(|_| format!("Invalid seed: {}", value))?,
+                );
+            }
+            "--rng" => {
+                let value = value()?;
+                options.rng_mode = Some(
+                    parse_rng_mode(&value)
+                        .ok_or_else(|| format!("Unknown RNG mode: {}", value))?,
+                );
+            }
+            "--replay" => options.replay = Some(PathBuf::from(value()?)),
+            "--replay-speed" => {
+                let value = value()?;
+                options.replay_speed = parse_speed(&value)
+                    .ok_or_else(|| format!("Invalid replay speed: {}", value))?;
+            }
+            "--record" => options.record = Some(PathBuf::from(value()?)),
+            "--high-scores" => options.high_scores = Some(PathBuf::from(value()?)),
+            "--rules" => options.rules = Some(PathBuf::from(value()?)),
+            "--timetable" => options.timetable = Some(PathBuf::from(value()?)),
+            "--days" => {
+                let value = value()?;
+                options.semester_length = Some(
+                    parse_semester_length(&value)
+                        .ok_or_else(|| format!("Invalid number of days: {}", value))?,
+                );
+            }
+            "--no-delay" => options.no_delay = true,
+            "--plain-text" => options.plain_text = true,
+            LEGACY_GOD_MODE_ARGUMENT if is_first => options.mode = Some(GameMode::God),
+            _ if is_first => options.mode = Some(GameMode::SelectInitialParameters),
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    if options.plain_text && (options.replay.is_some() || options.record.is_some()) {
+        return Err("--replay and --record can't be used with --plain-text".to_owned());
+    }
+    // Правила не записываются в повтор, так что проверить или воспроизвести
+    // такой повтор было бы невозможно.
+    if options.rules.is_some() && options.record.is_some() {
+        return Err("--rules can't be used with --record".to_owned());
+    }
+    Ok(Command::Play(options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_options(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn play(options: Options) -> Result<Command, String> {
+        Ok(Command::Play(options))
+    }
+
+    #[test]
+    fn test_options() {
+        assert_eq!(parse(&[]), play(Options::default()));
+        assert_eq!(
+            parse(&[
+                "--mode",
+                "select",
+                "--seed",
+                "42",
+                "--rng",
+                "separate",
+                "--replay",
+                "game.txt",
+                "--replay-speed",
+                "2.5",
+                "--record",
+                "new.txt",
+                "--high-scores",
+                "MMHEROES.HI",
+                "--timetable",
+                "timetable.txt",
+                "--days",
+                "10",
+                "--no-delay",
+            ]),
+            play(Options {
+                mode: Some(GameMode::SelectInitialParameters),
+                seed: Some(42),
+                rng_mode: Some(RngMode::SeparateStreams),
+                replay: Some(PathBuf::from("game.txt")),
+                replay_speed: 2.5,
+                record: Some(PathBuf::from("new.txt")),
+                high_scores: Some(PathBuf::from("MMHEROES.HI")),
+                rules: None,
+                timetable: Some(PathBuf::from("timetable.txt")),
+                semester_length: SemesterLength::new(10),
+                no_delay: true,
+                plain_text: false,
+            })
+        );
+        assert_eq!(
+            parse(&["--rng", "borland"]),
+            play(Options {
+                rng_mode: Some(RngMode::BorlandPascal),
+                ..Options::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--rules", "rules.toml"]),
+            play(Options {
+                rules: Some(PathBuf::from("rules.toml")),
+                ..Options::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--days", "marathon"]),
+            play(Options {
+                semester_length: Some(SemesterLength::MARATHON),
+                ..Options::default()
+            })
+        );
+        assert_eq!(parse(&["--seed", "1", "--help"]), Ok(Command::Help));
+        assert_eq!(parse(&["--version"]), Ok(Command::Version));
+    }
+
+    #[test]
+    fn test_legacy_arguments() {
+        assert_eq!(
+            parse(&["-3dec-happy-birthday-Diamond"]),
+            play(Options {
+                mode: Some(GameMode::God),
+                ..Options::default()
+            })
+        );
+        assert_eq!(
+            parse(&["whatever", "--seed", "7"]),
+            play(Options {
+                mode: Some(GameMode::SelectInitialParameters),
+                seed: Some(7),
+                ..Options::default()
+            })
+        );
+        assert_eq!(
+            parse(&["--seed", "7", "whatever"]),
+            Err("Unknown argument: whatever".to_owned())
+        );
+        assert_eq!(
+            parse(&["--seed", "7", "--sed", "8"]),
+            Err("Unknown argument: --sed".to_owned())
+        );
+        for arg in ["-h", "--foo", "-x"] {
+            assert_eq!(
+                parse(&[arg]),
+                play(Options {
+                    mode: Some(GameMode::SelectInitialParameters),
+                    ..Options::default()
+                })
+            );
+        }
+        assert_eq!(parse(&["--help"]), Ok(Command::Help));
+        assert_eq!(parse(&["x", "-h"]), Ok(Command::Help));
+        assert_eq!(
+            parse(&["x", "--foo"]),
+            Err("Unknown argument: --foo".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert_eq!(
+            parse(&["--mode", "easy"]),
+            Err("Unknown mode: easy".to_owned())
+        );
+        assert_eq!(
+            parse(&["--seed"]),
+            Err("Missing value for --seed".to_owned())
+        );
+        assert_eq!(parse(&["--seed", "-1"]), Err("Invalid seed: -1".to_owned()));
+        assert_eq!(
+            parse(&["--rng", "double"]),
+            Err("Unknown RNG mode: double".to_owned())
+        );
+        assert_eq!(
+            parse(&["--days", "15"]),
+            Err("Invalid number of days: 15".to_owned())
+        );
+        assert_eq!(
+            parse(&["--replay-speed", "0"]),
+            Err("Invalid replay speed: 0".to_owned())
+        );
+        assert!(parse(&["--plain-text", "--record", "game.txt"]).is_err());
+        assert_eq!(
+            parse(&["--rules", "rules.toml", "--record", "game.txt"]),
+            Err("--rules can't be used with --record".to_owned())
+        );
+    }
+}
@@ -1,19 +1,31 @@
-use mmheroes_core::logic::{create_game, StateHolder};
-use mmheroes_core::ui::recording::InputRecordingParser;
-use mmheroes_core::{
-    logic::GameMode,
-    ui::{
-        self,
-        renderer::{RendererRequest, RendererRequestConsumer},
-        *,
-    },
+use mmheroes_core::logic::{
+    create_or_restore_game, Game, GameMode, RngMode, Rules, StateHolder, Timetable,
+};
+use mmheroes_core::ui::recording::{
+    is_replay, InputRecordingParser, ReplayEvent, ReplayHeader, ReplayReader,
+    ReplayWriter, ENGINE_VERSION,
+};
+use mmheroes_core::ui::{
+    self,
+    renderer::{RendererRequest, RendererRequestConsumer},
+    *,
 };
 use pancurses::*;
 use std::collections::HashMap;
+use std::fs::File;
+use std::io::{LineWriter, Write};
 use std::pin::pin;
 use std::process::ExitCode;
 use std::str::FromStr;
 
+mod cli;
+mod plain_text;
+mod rules_file;
+
+/// Сколько миллисекунд проходит между нажатиями при воспроизведении повтора
+/// с обычной скоростью.
+const REPLAY_STEP_DELAY_MS: f64 = 300.0;
+
 fn env_seed() -> Option<u64> {
     if cfg!(debug_assertions) {
         std::env::var("MMHEROES_SEED")
@@ -36,6 +48,15 @@ fn env_steps() -> Option<String> {
     }
 }
 
+/// Язык игры задаётся переменной окружения `MMHEROES_LANG`: `en` — английский,
+/// всё остальное — русский.
+fn env_locale() -> Locale {
+    match std::env::var("MMHEROES_LANG").as_deref() {
+        Ok("en") => Locale::English,
+        _ => Locale::Russian,
+    }
+}
+
 mod screen {
     use super::{endwin, initscr, Window};
 
@@ -66,24 +87,28 @@ mod screen {
     }
 }
 
-mod high_scores {
+pub(crate) mod high_scores {
     use mmheroes_core::ui::high_scores::{
         decode, encode, HighScore, BUFFER_SIZE, SCORE_COUNT,
     };
     use std::fs::*;
     use std::io::Read;
 
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
 
-    fn hi_file_path() -> PathBuf {
-        let dir = directories::ProjectDirs::from("com.broadwaylamb", "", "mmheroes")
+    pub(crate) fn data_dir() -> PathBuf {
+        directories::ProjectDirs::from("com.broadwaylamb", "", "mmheroes")
             .map(|dirs| dirs.data_local_dir().to_path_buf())
-            .unwrap_or_else(|| PathBuf::from("."));
-        dir.join("MMHEROES.HI")
+            .unwrap_or_else(|| PathBuf::from("."))
+    }
+
+    /// Путь к файлу с таблицей рекордов, если он не указан в командной строке.
+    pub(crate) fn default_path() -> PathBuf {
+        data_dir().join("MMHEROES.HI")
     }
 
-    pub(crate) fn load() -> Option<[HighScore; SCORE_COUNT]> {
-        let mut f = match OpenOptions::new().read(true).open(hi_file_path()) {
+    pub(crate) fn load(path: &Path) -> Option<[HighScore; SCORE_COUNT]> {
+        let mut f = match OpenOptions::new().read(true).open(path) {
             Ok(f) => f,
             Err(_) => return None,
         };
@@ -96,9 +121,33 @@ mod high_scores {
         decode(&buffer)
     }
 
-    pub(crate) fn save(scores: &[HighScore; SCORE_COUNT]) {
+    pub(crate) fn save(path: &Path, scores: &[HighScore; SCORE_COUNT]) {
         let encoded = encode(scores);
-        let _ = write(hi_file_path(), encoded.as_ref());
+        let _ = write(path, encoded.as_ref());
+    }
+}
+
+/// Незаконченная игра сохраняется каждый раз, когда игрок оказывается в главном меню
+/// локации, чтобы её можно было продолжить, если процесс будет завершён.
+pub(crate) mod saved_game {
+    use mmheroes_core::logic::SavedGame;
+    use std::fs::*;
+    use std::path::PathBuf;
+
+    fn sav_file_path() -> PathBuf {
+        super::high_scores::data_dir().join("MMHEROES.SAV")
+    }
+
+    pub(crate) fn load() -> Option<SavedGame> {
+        SavedGame::decode(&read(sav_file_path()).ok()?).ok()
+    }
+
+    pub(crate) fn save(saved_game: &SavedGame) {
+        let _ = write(sav_file_path(), saved_game.encode());
+    }
+
+    pub(crate) fn remove() {
+        let _ = remove_file(sav_file_path());
     }
 }
 
@@ -110,25 +159,61 @@ fn getch<G, C>(window: &ScreenRAII, game_ui: &mut GameUI<'_, G, C, String>) -> u
             None | Some(pancurses::Input::KeyResize) => continue,
             Some(pancurses::Input::KeyUp) => ui::Input::KeyUp,
             Some(pancurses::Input::KeyDown) => ui::Input::KeyDown,
-            Some(pancurses::Input::Character('\u{1b}')) => {
-                if cfg!(debug_assertions) {
-                    // В отладочной конфигурации по нажатию Esc печатаем шаги.
-                    // Удобно для тестирования.
-                    endwin();
-                    game_ui.flush_input_recorder().unwrap();
-                    println!("Шаги: {}", game_ui.recorded_input().unwrap());
-                    std::process::exit(0);
-                } else {
-                    ui::Input::Other
-                }
+            Some(pancurses::Input::KeyF12) if cfg!(debug_assertions) => {
+                // В отладочной конфигурации по нажатию F12 печатаем шаги.
+                // Удобно для тестирования.
+                endwin();
+                game_ui.flush_input_recorder().unwrap();
+                println!("Шаги: {}", game_ui.recorded_input().unwrap());
+                std::process::exit(0);
             }
+            Some(pancurses::Input::Character('\u{1b}')) => ui::Input::Escape,
+            Some(pancurses::Input::KeyHome) => ui::Input::Home,
+            Some(pancurses::Input::KeyEnd) => ui::Input::End,
+            Some(pancurses::Input::KeyPPage) => ui::Input::PageUp,
+            Some(pancurses::Input::KeyNPage) => ui::Input::PageDown,
+            Some(pancurses::Input::KeyMouse) => match mouse_input() {
+                Some(input) => input,
+                None => continue,
+            },
             Some(pancurses::Input::Character('\n')) => ui::Input::Enter,
+            Some(pancurses::Input::KeyBackspace)
+            | Some(pancurses::Input::Character('\u{7f}' | '\u{8}')) => {
+                ui::Input::Backspace
+            }
+            Some(pancurses::Input::Character(c)) if c.is_ascii_alphanumeric() => {
+                ui::Input::Hotkey(c)
+            }
+            Some(pancurses::Input::Character(c)) if !c.is_control() => {
+                ui::Input::Character(c)
+            }
             Some(_) => ui::Input::Other,
         };
         break ui_input;
     }
 }
 
+/// Преобразует последнее событие мыши во ввод игры. Нажатие левой кнопки выбирает
+/// вариант в диалоге, перемещение указателя — переводит на него курсор, а колесо
+/// работает как стрелки. Остальные события пропускаются.
+fn mouse_input() -> Option<ui::Input> {
+    let event = getmouse().ok()?;
+    let line = u8::try_from(event.y).ok()?;
+    let column = u8::try_from(event.x).ok()?;
+    let input = if event.bstate & BUTTON1_CLICKED != 0 {
+        ui::Input::Click { line, column }
+    } else if event.bstate & BUTTON4_PRESSED != 0 {
+        ui::Input::KeyUp
+    } else if event.bstate & BUTTON5_PRESSED != 0 {
+        ui::Input::KeyDown
+    } else if event.bstate & REPORT_MOUSE_POSITION != 0 {
+        ui::Input::PointerAt { line, column }
+    } else {
+        return None;
+    };
+    Some(input)
+}
+
 fn resize_terminal(height: i32, width: i32) {
     if !cfg!(windows) {
         println!("\x1B[8;{};{}t", height, width);
@@ -139,6 +224,7 @@ fn resize_terminal(height: i32, width: i32) {
 struct RendererRequestEvaluator<'a, 'b> {
     window: &'a ScreenRAII,
     color_pairs_map: &'b HashMap<(Color, Color), i16>,
+    no_delay: bool,
 }
 
 impl RendererRequestConsumer for RendererRequestEvaluator<'_, '_> {
@@ -161,12 +247,276 @@ impl RendererRequestConsumer for RendererRequestEvaluator<'_, '_> {
                         panic!("Unknown color pair: ({:?}, {:?})", foreground, background)
                     }),
             ),
+            RendererRequest::Sleep(_) if self.no_delay => 0,
             RendererRequest::Sleep(ms) => napms(ms.0),
         };
     }
 }
 
+/// Позволяет записывать повтор прямо в файл.
+struct ReplayFile(LineWriter<File>);
+
+impl std::fmt::Write for ReplayFile {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0.write_all(s.as_bytes()).map_err(|_| std::fmt::Error)
+    }
+}
+
+/// Передаёт нажатие в игру и записывает его в повтор, если он записывается.
+fn continue_game<G: Game, C: RendererRequestConsumer>(
+    game_ui: &mut GameUI<'_, G, C, String>,
+    replay_writer: &mut Option<ReplayWriter<ReplayFile>>,
+    input: ui::Input,
+) -> bool {
+    let game_continues = game_ui.continue_game(input);
+    if let Some(replay_writer) = replay_writer {
+        let _ = replay_writer.record_input(input);
+        let _ = replay_writer.record_checkpoint(game_ui.state_hash());
+    }
+    game_continues
+}
+
+/// Воспроизводит повтор или запись нажатых клавиш `replay`, делая паузу `delay`
+/// миллисекунд перед каждым нажатием. Если состояние игры разошлось с записанным
+/// в повторе, воспроизведение останавливается.
+///
+/// Возвращает `false`, если игра закончилась.
+fn play_replay<G: Game, C: RendererRequestConsumer>(
+    game_ui: &mut GameUI<'_, G, C, String>,
+    replay_writer: &mut Option<ReplayWriter<ReplayFile>>,
+    replay: &str,
+    delay: i32,
+) -> bool {
+    let mut game_continues = true;
+    let mut played = false;
+    let mut play = |game_ui: &mut GameUI<'_, G, C, String>, input| {
+        napms(delay);
+        played = true;
+        game_continues = continue_game(game_ui, replay_writer, input);
+        game_continues
+    };
+    // Повтор уже проверен при запуске, поэтому ошибка здесь может означать только
+    // то, что воспроизведение остановлено.
+    let _ = if is_replay(replay) {
+        ReplayReader::new(replay)
+            .map_err(|_| ())
+            .and_then(|mut reader| {
+                reader
+                    .parse_all(|event| match event {
+                        ReplayEvent::Input(input) => play(game_ui, input),
+                        ReplayEvent::StateHash(hash) => {
+                            game_ui.state_hash() == Some(hash)
+                        }
+                    })
+                    .map_err(|_| ())
+            })
+    } else {
+        InputRecordingParser::new(replay)
+            .parse_all(|input| play(game_ui, input))
+            .map_err(|_| ())
+    };
+    if !played {
+        return continue_game(game_ui, replay_writer, ui::Input::Enter);
+    }
+    game_continues
+}
+
+/// Проверяет, что повтор или запись нажатых клавиш можно прочитать. Возвращает
+/// заголовок повтора, если он есть.
+fn check_replay(replay: &str) -> Result<Option<ReplayHeader<'_>>, String> {
+    if is_replay(replay) {
+        let mut reader =
+            ReplayReader::new(replay).map_err(|error| format!("{:?}", error))?;
+        let header = reader.header().clone();
+        reader
+            .parse_all(|_| true)
+            .map_err(|error| format!("{:?}", error))?;
+        Ok(Some(header))
+    } else {
+        InputRecordingParser::new(replay)
+            .parse_all(|_| true)
+            .map_err(|error| format!("{:?}", error))?;
+        Ok(None)
+    }
+}
+
 fn main() -> ExitCode {
+    let options = match cli::parse_options(std::env::args().skip(1)) {
+        Ok(cli::Command::Play(options)) => options,
+        Ok(cli::Command::Help) => {
+            print!("{}", cli::USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Ok(cli::Command::Version) => {
+            println!(
+                "mmheroes {} (engine {})",
+                env!("CARGO_PKG_VERSION"),
+                ENGINE_VERSION
+            );
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let rules = match &options.rules {
+        Some(path) => match rules_file::load(path) {
+            Ok(rules) => rules,
+            Err(error) => {
+                eprintln!("Invalid rules in {}: {}", path.display(), error);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => &Rules::DEFAULT,
+    };
+
+    let timetable = match &options.timetable {
+        Some(path) => match std::fs::read_to_string(path)
+            .map_err(|error| error.to_string())
+            .and_then(|text| {
+                Timetable::parse(&text, rules).map_err(|error| error.to_string())
+            }) {
+            Ok(timetable) => Some(timetable),
+            Err(error) => {
+                eprintln!("Invalid timetable in {}: {}", path.display(), error);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let replay = match &options.replay {
+        Some(path) => match std::fs::read_to_string(path) {
+            Ok(replay) => Some(replay),
+            Err(error) => {
+                eprintln!("Can't read {}: {}", path.display(), error);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => env_steps(),
+    };
+    let replay_header = match replay.as_deref().map(check_replay).transpose() {
+        Ok(header) => header.flatten(),
+        Err(error) => {
+            eprintln!("Invalid replay: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Зерно, режим, расписание и таблица рекордов из заголовка повтора важнее
+    // командной строки: без них повтор воспроизведётся не так, как игра,
+    // которую записали.
+    let mode = replay_header
+        .as_ref()
+        .map_or(options.mode.unwrap_or(GameMode::Normal), |header| {
+            header.mode
+        });
+    // Записи нажатых клавиш без заголовка делались, когда генератор случайных
+    // чисел в игре был один.
+    let rng_mode = match (&replay_header, &replay) {
+        (Some(header), _) => header.rng_mode,
+        (None, Some(_)) => RngMode::SingleStream,
+        (None, None) => options.rng_mode.unwrap_or_default(),
+    };
+    let timetable = match &replay_header {
+        Some(header) => header.timetable.clone(),
+        None => timetable,
+    };
+    // Продолжительность сессии задаёт расписание, если оно есть.
+    let semester_length = match (&timetable, &replay_header) {
+        (Some(timetable), _) => timetable.length(),
+        (None, Some(header)) => header.semester_length,
+        (None, None) => options.semester_length.unwrap_or_default(),
+    };
+    let seed = replay_header
+        .as_ref()
+        .map(|header| header.seed)
+        .or(options.seed)
+        .or_else(env_seed)
+        .unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64
+        });
+    let high_scores_path = options
+        .high_scores
+        .clone()
+        .unwrap_or_else(high_scores::default_path);
+    let initial_high_scores = match &replay_header {
+        Some(header) => header.high_scores.clone(),
+        None => high_scores::load(&high_scores_path),
+    };
+
+    // Незаконченную игру продолжаем, только если не просили начать новую
+    // или начать игру с другими параметрами.
+    let saved_game = if options.seed.is_none()
+        && options.mode.is_none()
+        && options.rng_mode.is_none()
+        && replay.is_none()
+        && options.record.is_none()
+        && timetable.is_none()
+        && options.semester_length.is_none()
+        && options.rules.is_none()
+    {
+        saved_game::load()
+    } else {
+        None
+    };
+
+    let mut replay_writer = match &options.record {
+        Some(path) => {
+            let header = ReplayHeader::new(
+                seed,
+                mode,
+                rng_mode,
+                semester_length,
+                timetable.clone(),
+                initial_high_scores.clone(),
+            );
+            let writer = File::create(path)
+                .map_err(|error| error.to_string())
+                .and_then(|file| {
+                    ReplayWriter::new(ReplayFile(LineWriter::new(file)), &header)
+                        .map_err(|error| error.to_string())
+                });
+            match writer {
+                Ok(writer) => Some(writer),
+                Err(error) => {
+                    eprintln!("Can't record to {}: {}", path.display(), error);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        None => None,
+    };
+
+    // Мы обрабатываем панику прямо в игре, поэтому убираем дефолтный хук, чтобы
+    // не загрязнять вывод.
+    std::panic::set_hook(Box::new(|_| {}));
+
+    // Правила из файла уже проверены в `rules_file::load`.
+    let mut observable_game_state = StateHolder::with_rules(mode, rules)
+        .expect("invalid rules")
+        .with_rng_mode(rng_mode)
+        .with_semester_length(semester_length);
+    if let Some(timetable) = timetable {
+        observable_game_state = observable_game_state.with_timetable(timetable);
+    }
+
+    if options.plain_text {
+        return plain_text::run(
+            &observable_game_state,
+            seed,
+            saved_game,
+            initial_high_scores,
+            &high_scores_path,
+            env_locale(),
+        );
+    }
+
     let window = ScreenRAII::new();
     start_color();
     set_blink(true);
@@ -177,6 +527,7 @@ fn main() -> ExitCode {
 
     window.keypad(true);
     window.nodelay(false);
+    mousemask(ALL_MOUSE_EVENTS | REPORT_MOUSE_POSITION, None);
 
     resize_terminal(24, 80);
 
@@ -214,66 +565,55 @@ fn main() -> ExitCode {
         *color_pairs_map.get(&(Color::White, Color::Black)).unwrap() as chtype,
     ));
 
-    let mode = match std::env::args().nth(1).as_deref() {
-        Some("-3dec-happy-birthday-Diamond") => GameMode::God,
-        Some(_) => GameMode::SelectInitialParameters,
-        None => GameMode::Normal,
-    };
-
-    let seed = env_seed().unwrap_or_else(|| {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64
-    });
-
-    let steps = env_steps();
-
-    let observable_game_state = StateHolder::new(mode);
-    let game = create_game(seed, &observable_game_state);
+    let game = create_or_restore_game(seed, saved_game, &observable_game_state);
     let game = pin!(game);
 
     let renderer_request_evaluator = RendererRequestEvaluator {
         window: &window,
         color_pairs_map: &color_pairs_map,
+        no_delay: options.no_delay,
     };
 
     let mut game_ui = GameUI::new(
         &observable_game_state,
         game,
         seed,
-        high_scores::load(),
+        initial_high_scores,
         renderer_request_evaluator,
         Some(String::new()),
+        env_locale(),
     );
 
-    // Мы обрабатываем панику прямо в игре, поэтому убираем дефолтный хук, чтобы
-    // не загрязнять вывод.
-    std::panic::set_hook(Box::new(|_| {}));
-
-    let mut input = if let Some(steps) = steps {
-        let mut steps_parser = InputRecordingParser::new(&steps);
-        match steps_parser.parse_all(|input| {
-            napms(300);
-            game_ui.continue_game(input)
-        }) {
-            Ok(()) => {}
-            Err(error) => panic!("Parsing steps failed: {:?}", error),
+    let mut game_continues = match &replay {
+        Some(replay) => {
+            let delay = (REPLAY_STEP_DELAY_MS / options.replay_speed) as i32;
+            play_replay(&mut game_ui, &mut replay_writer, replay, delay)
         }
-        getch(&window, &mut game_ui)
-    } else {
-        ui::Input::Enter
+        None => continue_game(&mut game_ui, &mut replay_writer, ui::Input::Enter),
     };
 
-    while game_ui.continue_game(input) {
-        input = getch(&window, &mut game_ui);
+    while game_continues {
+        if let Some(saved_game) = observable_game_state.save_game() {
+            saved_game::save(&saved_game);
+        }
+        let input = getch(&window, &mut game_ui);
+        game_continues = continue_game(&mut game_ui, &mut replay_writer, input);
+    }
+
+    if let Some(replay_writer) = &mut replay_writer {
+        let _ = replay_writer.flush();
     }
 
     if game_ui.has_bug() {
         return ExitCode::FAILURE;
     }
 
-    high_scores::save(&game_ui.high_scores);
+    saved_game::remove();
+
+    // Таблицу рекордов из чужого повтора к себе не сохраняем
+    if replay_header.is_none() {
+        high_scores::save(&high_scores_path, &game_ui.high_scores);
+    }
 
     ExitCode::SUCCESS
 }
@@ -0,0 +1,386 @@
+//! Текстовый режим: без перемещения курсора и цветов. Каждый экран выводится
+//! в стандартный вывод построчно, а варианты диалога — нумерованным списком,
+//! номер выбранного варианта читается из стандартного ввода.
+//!
+//! Удобно для программ чтения с экрана и для управления игрой из скриптов.
+
+use crate::{high_scores, saved_game};
+use mmheroes_core::logic::{
+    create_or_restore_game, Game, GameScreen, SavedGame, StateHolder,
+};
+use mmheroes_core::ui::high_scores::{HighScore, SCORE_COUNT};
+use mmheroes_core::ui::renderer::{
+    Column, Line, RendererRequest, RendererRequestConsumer,
+};
+use mmheroes_core::ui::{self, GameUI, Locale};
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::pin::pin;
+use std::process::ExitCode;
+
+/// Текст, выведенный с одной клетки экрана до следующего перемещения курсора.
+struct Segment {
+    line: Line,
+    column: Column,
+    text: String,
+    printed: bool,
+}
+
+/// Собирает текст экрана по кускам, выведенным с разных клеток, и отдаёт только
+/// то, что ещё не было выведено. Если текст в клетке перерисовывается тем же
+/// самым (например, при перемещении по диалогу), он не выводится повторно.
+///
+/// Куски, выведенные на одной строке экрана, склеиваются через пробел: так
+/// колонки расписания превращаются в строки вида «АиТЧ ПУНК 13-15 0/12».
+pub(crate) struct PlainTextRenderer {
+    segments: Vec<Segment>,
+
+    /// Текст экранов, которые уже стёрты, но ещё не выведены.
+    pending: String,
+
+    cursor: (Line, Column),
+
+    /// Кусок, в который сейчас выводится текст, и его текст до перерисовки.
+    current: Option<(usize, Option<String>)>,
+}
+
+impl PlainTextRenderer {
+    pub(crate) fn new() -> Self {
+        PlainTextRenderer {
+            segments: Vec::new(),
+            pending: String::new(),
+            cursor: (0, 0),
+            current: None,
+        }
+    }
+
+    fn finish_segment(&mut self) {
+        if let Some((index, Some(old_text))) = self.current.take() {
+            let segment = &mut self.segments[index];
+            if segment.text != old_text {
+                segment.printed = false;
+            }
+        }
+    }
+
+    /// Возвращает ещё не выведенный текст и запоминает, что он выведен. Куски
+    /// текста, для которых `skip` возвращает `true`, пропускаются.
+    pub(crate) fn take_text(&mut self, skip: impl Fn(Line, Column) -> bool) -> String {
+        self.finish_segment();
+        let mut text = std::mem::take(&mut self.pending);
+        append_segments(&mut text, &mut self.segments, skip);
+        normalize(&text)
+    }
+}
+
+fn append_segments(
+    output: &mut String,
+    segments: &mut [Segment],
+    skip: impl Fn(Line, Column) -> bool,
+) {
+    let mut last_line = None;
+    for segment in segments.iter_mut().filter(|segment| !segment.printed) {
+        segment.printed = true;
+        if skip(segment.line, segment.column) || segment.text.trim().is_empty() {
+            continue;
+        }
+        if last_line == Some(segment.line) {
+            output.push(' ');
+        } else {
+            output.push('\n');
+        }
+        output.push_str(segment.text.trim());
+        last_line = if segment.text.contains('\n') {
+            None
+        } else {
+            Some(segment.line)
+        };
+    }
+    output.push('\n');
+}
+
+/// Убирает пробелы в конце строк и лишние пустые строки.
+fn normalize(text: &str) -> String {
+    let mut output = String::new();
+    let mut blank = false;
+    for line in text.lines().map(str::trim_end) {
+        if line.is_empty() {
+            blank = !output.is_empty();
+            continue;
+        }
+        if blank {
+            output.push('\n');
+            blank = false;
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+    output
+}
+
+impl RendererRequestConsumer for PlainTextRenderer {
+    fn consume_request(&mut self, request: RendererRequest) {
+        match request {
+            RendererRequest::ClearScreen => {
+                self.finish_segment();
+                append_segments(&mut self.pending, &mut self.segments, |_, _| false);
+                self.pending.push('\n');
+                self.segments.clear();
+                self.cursor = (0, 0);
+            }
+            RendererRequest::WriteStr(s) => {
+                let index = match self.current {
+                    Some((index, _)) => index,
+                    None => {
+                        let (line, column) = self.cursor;
+                        let existing = self.segments.iter().position(|segment| {
+                            segment.line == line && segment.column == column
+                        });
+                        match existing {
+                            Some(index) => {
+                                let old_text =
+                                    std::mem::take(&mut self.segments[index].text);
+                                self.current = Some((index, Some(old_text)));
+                                index
+                            }
+                            None => {
+                                self.segments.push(Segment {
+                                    line,
+                                    column,
+                                    text: String::new(),
+                                    printed: false,
+                                });
+                                self.current = Some((self.segments.len() - 1, None));
+                                self.segments.len() - 1
+                            }
+                        }
+                    }
+                };
+                self.segments[index].text.push_str(s);
+            }
+            RendererRequest::MoveCursor { line, column } => {
+                self.finish_segment();
+                self.cursor = (line, column);
+            }
+            RendererRequest::Flush
+            | RendererRequest::SetColor { .. }
+            | RendererRequest::Sleep(_) => {}
+        }
+    }
+}
+
+/// Что нужно отправить в игру после того, как игрок ввёл строку.
+enum Reply {
+    Option(usize),
+    Input(ui::Input),
+    Text(String),
+}
+
+fn read_reply<G: Game>(
+    game_ui: &GameUI<'_, G, PlainTextRenderer, String>,
+    state_holder: &StateHolder,
+    input: &mut impl BufRead,
+) -> Option<Reply> {
+    let option_count = game_ui.available_actions().len();
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let line = line.trim_end_matches(['\n', '\r']);
+        if let GameScreen::HighScoreEntry(_) = state_holder.observable_state().screen() {
+            return Some(Reply::Text(line.to_owned()));
+        }
+        if option_count == 0 {
+            return Some(Reply::Input(ui::Input::Enter));
+        }
+        match line.trim().parse::<usize>() {
+            Ok(number) if (1..=option_count).contains(&number) => {
+                return Some(Reply::Option(number - 1))
+            }
+            _ => {
+                let message = match game_ui.locale() {
+                    Locale::Russian => "Введи номер варианта от 1 до",
+                    Locale::English => "Enter an option number from 1 to",
+                };
+                println!("{} {}:", message, option_count);
+            }
+        }
+    }
+}
+
+/// Выводит новый текст экрана и варианты диалога, если он есть.
+fn print_screen<G: Game>(
+    game_ui: &mut GameUI<'_, G, PlainTextRenderer, String>,
+    output: &mut impl Write,
+) -> std::io::Result<()> {
+    let dialog = game_ui.dialog_position();
+    let option_count = game_ui.available_actions().len();
+    let text = game_ui.request_consumer_mut().take_text(|line, column| {
+        dialog.is_some_and(|(start_line, start_column)| {
+            column == start_column
+                && line >= start_line
+                && usize::from(line - start_line) < option_count
+        })
+    });
+    write!(output, "{}", text)?;
+    for (i, &action) in game_ui.available_actions().iter().enumerate() {
+        let mut label = String::new();
+        let _ = game_ui.write_action_label(action, &mut label);
+        writeln!(output, "{}. {}", i + 1, label)?;
+    }
+    writeln!(output)?;
+    output.flush()
+}
+
+/// Запускает игру в текстовом режиме.
+pub(crate) fn run(
+    state_holder: &StateHolder,
+    seed: u64,
+    saved_game: Option<SavedGame>,
+    initial_high_scores: Option<[HighScore; SCORE_COUNT]>,
+    high_scores_path: &Path,
+    locale: Locale,
+) -> ExitCode {
+    let game = create_or_restore_game(seed, saved_game, state_holder);
+    let game = pin!(game);
+    let mut game_ui = GameUI::new(
+        state_holder,
+        game,
+        seed,
+        initial_high_scores,
+        PlainTextRenderer::new(),
+        Some(String::new()),
+        locale,
+    );
+
+    let stdin = std::io::stdin();
+    let mut stdin = stdin.lock();
+    let mut stdout = std::io::stdout();
+    let mut game_continues = game_ui.continue_game(ui::Input::Enter);
+    while game_continues {
+        if let Some(saved_game) = state_holder.save_game() {
+            saved_game::save(&saved_game);
+        }
+        if print_screen(&mut game_ui, &mut stdout).is_err() {
+            return ExitCode::FAILURE;
+        }
+        game_continues = match read_reply(&game_ui, state_holder, &mut stdin) {
+            // Ввод закончился, игру можно будет продолжить в следующий раз
+            None => return ExitCode::SUCCESS,
+            Some(Reply::Option(index)) => {
+                let action = game_ui.available_actions()[index];
+                game_ui.perform_action(action)
+            }
+            Some(Reply::Input(input)) => game_ui.continue_game(input),
+            Some(Reply::Text(text)) => {
+                for c in text.chars() {
+                    game_ui.continue_game(ui::Input::Character(c));
+                }
+                game_ui.continue_game(ui::Input::Enter)
+            }
+        };
+    }
+    let _ = print_screen(&mut game_ui, &mut stdout);
+
+    if game_ui.has_bug() {
+        return ExitCode::FAILURE;
+    }
+    saved_game::remove();
+    high_scores::save(high_scores_path, &game_ui.high_scores);
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render<'a>(
+        renderer: &mut PlainTextRenderer,
+        requests: impl IntoIterator<Item = RendererRequest<'a>>,
+    ) {
+        for request in requests {
+            renderer.consume_request(request);
+        }
+    }
+
+    #[test]
+    fn test_columns_are_collapsed() {
+        let mut renderer = PlainTextRenderer::new();
+        render(
+            &mut renderer,
+            [
+                RendererRequest::ClearScreen,
+                RendererRequest::WriteStr("Сегодня 22е мая; 8:00\n\n"),
+                RendererRequest::WriteStr("Ты в общаге."),
+                RendererRequest::MoveCursor {
+                    line: 9,
+                    column: 49,
+                },
+                RendererRequest::WriteStr("АиТЧ"),
+                RendererRequest::MoveCursor {
+                    line: 9,
+                    column: 57,
+                },
+                RendererRequest::WriteStr("ПУНК"),
+                RendererRequest::MoveCursor {
+                    line: 9,
+                    column: 63,
+                },
+                RendererRequest::WriteStr("13-15"),
+                RendererRequest::MoveCursor {
+                    line: 9,
+                    column: 71,
+                },
+                RendererRequest::WriteStr(" 0/12"),
+                RendererRequest::MoveCursor {
+                    line: 10,
+                    column: 49,
+                },
+                RendererRequest::WriteStr("МатАн"),
+            ],
+        );
+        assert_eq!(
+            renderer.take_text(|_, _| false),
+            "Сегодня 22е мая; 8:00\n\nТы в общаге.\nАиТЧ ПУНК 13-15 0/12\nМатАн\n"
+        );
+        assert_eq!(renderer.take_text(|_, _| false), "");
+    }
+
+    #[test]
+    fn test_redrawn_text_is_not_repeated() {
+        let mut renderer = PlainTextRenderer::new();
+        render(
+            &mut renderer,
+            [
+                RendererRequest::MoveCursor { line: 5, column: 0 },
+                RendererRequest::WriteStr("Готовиться"),
+                RendererRequest::MoveCursor { line: 6, column: 0 },
+                RendererRequest::WriteStr("Отдыхать"),
+            ],
+        );
+        assert_eq!(renderer.take_text(|line, _| line == 6), "Готовиться\n");
+        render(
+            &mut renderer,
+            [
+                RendererRequest::MoveCursor { line: 5, column: 0 },
+                RendererRequest::WriteStr("Готовиться"),
+                RendererRequest::MoveCursor { line: 7, column: 0 },
+                RendererRequest::WriteStr("Вася"),
+            ],
+        );
+        assert_eq!(renderer.take_text(|_, _| false), "Вася\n");
+
+        // Стёртый экран всё равно выводится
+        render(
+            &mut renderer,
+            [
+                RendererRequest::MoveCursor { line: 7, column: 0 },
+                RendererRequest::WriteStr("Вас"),
+                RendererRequest::ClearScreen,
+                RendererRequest::WriteStr("Дзинь!"),
+            ],
+        );
+        assert_eq!(renderer.take_text(|_, _| false), "Вас\n\nДзинь!\n");
+    }
+}
@@ -0,0 +1,99 @@
+//! Чтение правил игры из файла в формате TOML или JSON.
+//!
+//! В файле достаточно указать только те числа, которые нужно поменять,
+//! остальные берутся из [`Rules::DEFAULT`]. Например:
+//!
+//! ```toml
+//! [subjects.calculus]
+//! required_problems = 15
+//!
+//! [costs]
+//! cola = 1
+//! ```
+
+use mmheroes_core::logic::Rules;
+use serde_json::Value;
+use std::path::Path;
+
+/// Накладывает значения из `overrides` на `base`. Таблицы сливаются
+/// рекурсивно, остальные значения заменяются целиком.
+fn merge(base: &mut Value, overrides: Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overrides) => *base = overrides,
+    }
+}
+
+/// Разбирает текст файла с правилами. Если `json` равен `false`, текст
+/// считается TOML.
+fn parse(text: &str, json: bool) -> Result<Rules, String> {
+    let overrides: Value = if json {
+        serde_json::from_str(text).map_err(|error| error.to_string())?
+    } else {
+        toml::from_str(text).map_err(|error| error.to_string())?
+    };
+    let mut rules =
+        serde_json::to_value(&Rules::DEFAULT).map_err(|error| error.to_string())?;
+    merge(&mut rules, overrides);
+    let rules: Rules =
+        serde_json::from_value(rules).map_err(|error| error.to_string())?;
+    rules.validate().map_err(|error| error.to_string())?;
+    Ok(rules)
+}
+
+/// Читает правила из файла. Файлы с расширением `.json` читаются как JSON,
+/// остальные — как TOML.
+///
+/// Правила живут до конца работы программы, поэтому возвращается `'static`
+/// ссылка.
+pub(crate) fn load(path: &Path) -> Result<&'static Rules, String> {
+    let text = std::fs::read_to_string(path).map_err(|error| error.to_string())?;
+    let json = path
+        .extension()
+        .is_some_and(|extension| extension == "json");
+    Ok(Box::leak(Box::new(parse(&text, json)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmheroes_core::logic::Money;
+
+    #[test]
+    fn test_partial_rules() {
+        let rules = parse(
+            "[subjects.calculus]\nrequired_problems = 15\n\n[costs]\ncola = 1\n",
+            false,
+        )
+        .unwrap();
+        let mut expected = Rules::DEFAULT;
+        expected.subjects.calculus.required_problems = 15;
+        expected.costs.cola = Money(1);
+        assert_eq!(rules, expected);
+
+        let rules =
+            parse(r#"{"classmates": {"kolya": {"annoyance": 0}}}"#, true).unwrap();
+        let mut expected = Rules::DEFAULT;
+        expected.classmates.kolya.annoyance = 0;
+        assert_eq!(rules, expected);
+
+        assert_eq!(parse("", false).unwrap(), Rules::DEFAULT);
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!(parse("[costs]\ncolaa = 1\n", false).is_err());
+        assert!(parse("[costs]\ncola = -1\n", false).is_err());
+        assert!(parse("[costs]\ncola = \"free\"\n", false).is_err());
+        assert!(parse("{", true).is_err());
+    }
+}
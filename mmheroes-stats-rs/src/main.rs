@@ -0,0 +1,203 @@
+//! Прогоняет много игр подряд с заданной стратегией и собирает статистику исходов:
+//! причины смерти, сданные зачёты, заработанные деньги, день и время смерти.
+//!
+//! Нужно, чтобы понимать, как изменения баланса влияют на шансы выиграть.
+//!
+//! Подкоманда `solve` вместо этого ищет для каждого зерна выигрышную
+//! последовательность действий (см. модуль `solver`). Так можно проверить, что
+//! сгенерированное расписание вообще позволяет выиграть, и получить запись
+//! демонстрационной игры.
+
+mod outcome;
+mod policy;
+mod report;
+mod solver;
+
+use mmheroes_core::logic::GameMode;
+use policy::PolicyKind;
+use report::{Format, SolverResult};
+use solver::Solver;
+use std::ops::Range;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+const USAGE: &str = "\
+Usage: mmheroes-stats [OPTIONS]
+       mmheroes-stats solve [OPTIONS]
+
+Without a command, plays the games with the given policy and reports statistics.
+The solve command searches for a winning input recording for every seed.
+
+Options:
+  --seeds <FROM>..<TO>   Range of seeds to play, end-exclusive [default: 0..1000]
+  --mode <MODE>          normal, select or god [default: normal]
+  --policy <POLICY>      random or first [default: random]
+  --format <FORMAT>      csv or json [default: csv]
+  --games                Output the outcome of every game instead of the summary
+  --beam-width <N>       Number of states the solver keeps at every step [default: 100]
+  --threads <N>          Number of worker threads [default: all cores]
+  -h, --help             Print this help
+";
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Command {
+    Play,
+    Solve,
+}
+
+struct Options {
+    command: Command,
+    seeds: Range<u64>,
+    mode: GameMode,
+    policy: PolicyKind,
+    format: Format,
+    games: bool,
+    beam_width: usize,
+    threads: usize,
+}
+
+fn parse_seeds(value: &str) -> Option<Range<u64>> {
+    let (from, to) = value.split_once("..")?;
+    let range = u64::from_str(from).ok()?..u64::from_str(to).ok()?;
+    (!range.is_empty()).then_some(range)
+}
+
+fn parse_mode(value: &str) -> Option<GameMode> {
+    match value {
+        "normal" => Some(GameMode::Normal),
+        "select" => Some(GameMode::SelectInitialParameters),
+        "god" => Some(GameMode::God),
+        _ => None,
+    }
+}
+
+fn parse_positive(value: &str) -> Option<usize> {
+    usize::from_str(value).ok().filter(|&n| n > 0)
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut args = args.by_ref().peekable();
+    let command = if args.next_if(|arg| arg == "solve").is_some() {
+        Command::Solve
+    } else {
+        Command::Play
+    };
+    let mut options = Options {
+        command,
+        seeds: 0..1000,
+        mode: GameMode::Normal,
+        policy: PolicyKind::Random,
+        format: Format::Csv,
+        games: false,
+        beam_width: 100,
+        threads: std::thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--seeds" => {
+                let value = value()?;
+                options.seeds = parse_seeds(&value)
+                    .ok_or_else(|| format!("Invalid seed range: {}", value))?;
+            }
+            "--mode" => {
+                let value = value()?;
+                options.mode = parse_mode(&value)
+                    .ok_or_else(|| format!("Unknown mode: {}", value))?;
+            }
+            "--policy" => {
+                let value = value()?;
+                options.policy = PolicyKind::parse(&value)
+                    .ok_or_else(|| format!("Unknown policy: {}", value))?;
+            }
+            "--format" => {
+                let value = value()?;
+                options.format = Format::parse(&value)
+                    .ok_or_else(|| format!("Unknown format: {}", value))?;
+            }
+            "--games" => options.games = true,
+            "--beam-width" => {
+                let value = value()?;
+                options.beam_width = parse_positive(&value)
+                    .ok_or_else(|| format!("Invalid beam width: {}", value))?;
+            }
+            "--threads" => {
+                let value = value()?;
+                options.threads = parse_positive(&value)
+                    .ok_or_else(|| format!("Invalid number of threads: {}", value))?;
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    Ok(options)
+}
+
+/// Вызывает `f` для каждого зерна, распределяя зёрна между потоками. Результаты
+/// возвращаются в порядке возрастания зёрен.
+fn for_each_seed<T: Send>(options: &Options, f: impl Fn(u64) -> T + Sync) -> Vec<T> {
+    let seed_count = options.seeds.end - options.seeds.start;
+    let chunk_size = seed_count.div_ceil(options.threads as u64);
+    std::thread::scope(|scope| {
+        let workers = (options.seeds.start..options.seeds.end)
+            .step_by(chunk_size as usize)
+            .map(|chunk_start| {
+                let chunk =
+                    chunk_start..(chunk_start + chunk_size).min(options.seeds.end);
+                let f = &f;
+                scope.spawn(move || chunk.map(f).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    })
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        print!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    let options = match parse_options(args.into_iter()) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    // Паники в движке попадают в статистику как `Ending::Crashed`,
+    // печатать каждую из них не нужно.
+    std::panic::set_hook(Box::new(|_| {}));
+    let output = match options.command {
+        Command::Play => {
+            let outcomes = for_each_seed(&options, |seed| {
+                let mut policy = options.policy.make(seed);
+                outcome::play(seed, options.mode, policy.as_mut())
+            });
+            match (options.format, options.games) {
+                (Format::Csv, true) => report::games_csv(&outcomes),
+                (Format::Csv, false) => {
+                    report::summary_csv(&report::Summary::new(&outcomes))
+                }
+                (Format::Json, games) => report::report_json(&outcomes, games),
+            }
+        }
+        Command::Solve => {
+            let results = for_each_seed(&options, |seed| SolverResult {
+                seed,
+                solution: Solver::new(seed, options.mode, options.beam_width).solve(),
+            });
+            match options.format {
+                Format::Csv => report::solutions_csv(&results),
+                Format::Json => report::solutions_json(&results),
+            }
+        }
+    };
+    print!("{}", output);
+    ExitCode::SUCCESS
+}
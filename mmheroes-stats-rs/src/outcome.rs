@@ -0,0 +1,135 @@
+use crate::policy::Policy;
+use mmheroes_core::logic::{
+    create_game, CauseOfDeath, GameMode, GameScreen, Money, Simulation, StateHolder,
+    Subject, Time,
+};
+use std::pin::pin;
+
+/// Сколько действий можно совершить в одной игре. Если стратегия за это время
+/// так и не довела игру до конца, значит, она где-то зациклилась.
+const MAX_ACTIONS_PER_GAME: usize = 100_000;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Ending {
+    /// Все зачёты сданы.
+    Won,
+
+    /// Остался жив, но сдал не все зачёты.
+    Expelled,
+
+    Died(CauseOfDeath),
+
+    /// Игра не закончилась за `MAX_ACTIONS_PER_GAME` действий.
+    Unfinished,
+
+    /// В движке произошла паника.
+    Crashed,
+}
+
+impl Ending {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Ending::Won => "won",
+            Ending::Expelled => "expelled",
+            Ending::Died(_) => "died",
+            Ending::Unfinished => "unfinished",
+            Ending::Crashed => "crashed",
+        }
+    }
+
+    pub(crate) fn cause_of_death(self) -> Option<CauseOfDeath> {
+        match self {
+            Ending::Died(cause) => Some(cause),
+            _ => None,
+        }
+    }
+}
+
+/// Итог одной игры.
+#[derive(Clone, Debug)]
+pub(crate) struct Outcome {
+    pub(crate) seed: u64,
+    pub(crate) ending: Ending,
+
+    /// Индекс дня, в который закончилась игра.
+    pub(crate) day: u8,
+
+    /// Время, в которое закончилась игра.
+    pub(crate) time: Time,
+
+    pub(crate) money: Money,
+    pub(crate) exams_passed: Vec<Subject>,
+
+    /// Сообщение паники, если `ending` — `Ending::Crashed`.
+    pub(crate) crash_message: Option<String>,
+}
+
+impl Outcome {
+    fn without_result(seed: u64, ending: Ending) -> Outcome {
+        Outcome {
+            seed,
+            ending,
+            day: 0,
+            time: Time(0),
+            money: Money(0),
+            exams_passed: Vec::new(),
+            crash_message: None,
+        }
+    }
+}
+
+/// Играет одну игру с зерном `seed` до финального экрана. Паника в движке
+/// не прерывает работу, а становится исходом `Ending::Crashed`.
+pub(crate) fn play(seed: u64, mode: GameMode, policy: &mut dyn Policy) -> Outcome {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        play_until_game_end(seed, mode, policy)
+    }));
+    result.unwrap_or_else(|cause| {
+        let message = if let Some(s) = cause.downcast_ref::<String>() {
+            s.clone()
+        } else if let Some(s) = cause.downcast_ref::<&str>() {
+            s.to_string()
+        } else {
+            "unknown panic".to_string()
+        };
+        Outcome {
+            crash_message: Some(message),
+            ..Outcome::without_result(seed, Ending::Crashed)
+        }
+    })
+}
+
+fn play_until_game_end(seed: u64, mode: GameMode, policy: &mut dyn Policy) -> Outcome {
+    let state_holder = StateHolder::new(mode);
+    let game = pin!(create_game(seed, &state_holder));
+    let mut simulation = Simulation::new(&state_holder, game);
+    for _ in 0..MAX_ACTIONS_PER_GAME {
+        let action = {
+            let screen = simulation.screen();
+            if let GameScreen::GameEnd(state) = &*screen {
+                let player = state.player();
+                let ending = match player.cause_of_death() {
+                    Some(cause) => Ending::Died(cause),
+                    None if player.exams_left() == 0 => Ending::Won,
+                    None => Ending::Expelled,
+                };
+                return Outcome {
+                    seed,
+                    ending,
+                    day: state.current_day_index(),
+                    time: state.current_time(),
+                    money: player.money(),
+                    exams_passed: Subject::all_subjects()
+                        .filter(|&subject| player.status_for_subject(subject).passed())
+                        .collect(),
+                    crash_message: None,
+                };
+            }
+            policy.choose_action(&screen, &simulation.available_actions())
+        };
+        simulation
+            .perform_action(action)
+            .expect("The policy has chosen an illegal action");
+    }
+    Outcome::without_result(seed, Ending::Unfinished)
+}
@@ -0,0 +1,94 @@
+use mmheroes_core::logic::{Action, GameScreen};
+use mmheroes_core::{Rng, SplitMix64};
+
+/// Стратегия игрока: на каждом экране выбирает одно из доступных действий.
+pub(crate) trait Policy {
+    fn choose_action(
+        &mut self,
+        screen: &GameScreen,
+        available_actions: &[Action],
+    ) -> Action;
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum PolicyKind {
+    Random,
+    First,
+}
+
+impl PolicyKind {
+    pub(crate) fn parse(name: &str) -> Option<PolicyKind> {
+        match name {
+            "random" => Some(PolicyKind::Random),
+            "first" => Some(PolicyKind::First),
+            _ => None,
+        }
+    }
+
+    /// Создаёт стратегию для игры с зерном `seed`. Одинаковые зёрна дают одинаковые
+    /// стратегии, так что результаты можно воспроизвести.
+    pub(crate) fn make(self, seed: u64) -> Box<dyn Policy> {
+        match self {
+            PolicyKind::Random => Box::new(RandomPolicy::new(seed)),
+            PolicyKind::First => Box::new(FirstActionPolicy),
+        }
+    }
+}
+
+/// Всегда выбирает первое доступное действие.
+pub(crate) struct FirstActionPolicy;
+
+impl Policy for FirstActionPolicy {
+    fn choose_action(
+        &mut self,
+        _screen: &GameScreen,
+        available_actions: &[Action],
+    ) -> Action {
+        available_actions[0]
+    }
+}
+
+/// Выбирает случайное действие, но никогда не сдаётся сам («С меня хватит!»),
+/// иначе большинство игр заканчивалось бы именно так.
+pub(crate) struct RandomPolicy {
+    rng: SplitMix64,
+}
+
+/// Смешивается с зерном игры, чтобы получить зерно стратегии.
+///
+/// Если бы стратегия использовала то же зерно, что и игра, она выбирала бы
+/// действия по той же последовательности чисел, что и случайные события в игре
+/// (в режиме `RngMode::SingleStream` игра тоже использует SplitMix64), и
+/// выборы игрока были бы связаны с исходами. Статистика от этого искажается.
+const POLICY_SEED_SALT: u64 = 0x5DEE_CE66_D1CE_5EED;
+
+impl RandomPolicy {
+    pub(crate) fn new(seed: u64) -> RandomPolicy {
+        RandomPolicy {
+            rng: SplitMix64::new(seed ^ POLICY_SEED_SALT),
+        }
+    }
+}
+
+impl Policy for RandomPolicy {
+    fn choose_action(
+        &mut self,
+        _screen: &GameScreen,
+        available_actions: &[Action],
+    ) -> Action {
+        let candidates = available_actions
+            .iter()
+            .filter(|&&action| action != Action::IAmDone)
+            .count();
+        if candidates == 0 {
+            return available_actions[0];
+        }
+        let index = self.rng.random(candidates);
+        available_actions
+            .iter()
+            .copied()
+            .filter(|&action| action != Action::IAmDone)
+            .nth(index)
+            .unwrap()
+    }
+}
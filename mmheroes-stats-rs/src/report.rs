@@ -0,0 +1,430 @@
+use crate::outcome::{Ending, Outcome};
+use crate::solver::Solution;
+use mmheroes_core::logic::Subject;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Format {
+    Csv,
+    Json,
+}
+
+impl Format {
+    pub(crate) fn parse(name: &str) -> Option<Format> {
+        match name {
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Сводная статистика по множеству игр.
+#[derive(Debug, Default)]
+pub(crate) struct Summary {
+    games: usize,
+    endings: BTreeMap<&'static str, usize>,
+    causes_of_death: BTreeMap<String, usize>,
+    exams_passed_per_subject: BTreeMap<String, usize>,
+    exams_passed_count: BTreeMap<usize, usize>,
+    min_money: Option<i16>,
+    max_money: Option<i16>,
+    total_money: i64,
+    death_day: BTreeMap<u8, usize>,
+    death_time: BTreeMap<u8, usize>,
+}
+
+impl Summary {
+    pub(crate) fn new(outcomes: &[Outcome]) -> Summary {
+        let mut summary = Summary::default();
+        for subject in Subject::all_subjects() {
+            summary
+                .exams_passed_per_subject
+                .insert(format!("{:?}", subject), 0);
+        }
+        for outcome in outcomes {
+            summary.add(outcome);
+        }
+        summary
+    }
+
+    fn add(&mut self, outcome: &Outcome) {
+        self.games += 1;
+        *self.endings.entry(outcome.ending.name()).or_default() += 1;
+        if matches!(outcome.ending, Ending::Unfinished | Ending::Crashed) {
+            return;
+        }
+        if let Some(cause) = outcome.ending.cause_of_death() {
+            *self
+                .causes_of_death
+                .entry(format!("{:?}", cause))
+                .or_default() += 1;
+            *self.death_day.entry(outcome.day).or_default() += 1;
+            *self.death_time.entry(outcome.time.0).or_default() += 1;
+        }
+        for subject in outcome.exams_passed.iter() {
+            *self
+                .exams_passed_per_subject
+                .entry(format!("{:?}", subject))
+                .or_default() += 1;
+        }
+        *self
+            .exams_passed_count
+            .entry(outcome.exams_passed.len())
+            .or_default() += 1;
+        let money = outcome.money.0;
+        self.min_money = Some(self.min_money.map_or(money, |m| m.min(money)));
+        self.max_money = Some(self.max_money.map_or(money, |m| m.max(money)));
+        self.total_money += money as i64;
+    }
+
+    fn finished_games(&self) -> usize {
+        let count = |ending: Ending| *self.endings.get(ending.name()).unwrap_or(&0);
+        self.games - count(Ending::Unfinished) - count(Ending::Crashed)
+    }
+
+    fn mean_money(&self) -> f64 {
+        match self.finished_games() {
+            0 => 0.0,
+            finished => self.total_money as f64 / finished as f64,
+        }
+    }
+
+    /// Строки вида `(раздел, ключ, значение)`.
+    fn rows(&self) -> Vec<(&'static str, String, String)> {
+        let mut rows = vec![("games", String::new(), self.games.to_string())];
+        for (ending, count) in self.endings.iter() {
+            rows.push(("ending", ending.to_string(), count.to_string()));
+        }
+        for (cause, count) in self.causes_of_death.iter() {
+            rows.push(("cause_of_death", cause.clone(), count.to_string()));
+        }
+        for (subject, count) in self.exams_passed_per_subject.iter() {
+            rows.push((
+                "exams_passed_per_subject",
+                subject.clone(),
+                count.to_string(),
+            ));
+        }
+        for (passed, count) in self.exams_passed_count.iter() {
+            rows.push(("exams_passed_count", passed.to_string(), count.to_string()));
+        }
+        if let (Some(min), Some(max)) = (self.min_money, self.max_money) {
+            rows.push(("money", "min".to_string(), min.to_string()));
+            rows.push(("money", "max".to_string(), max.to_string()));
+            rows.push((
+                "money",
+                "mean".to_string(),
+                format!("{:.2}", self.mean_money()),
+            ));
+        }
+        for (day, count) in self.death_day.iter() {
+            rows.push(("death_day", day.to_string(), count.to_string()));
+        }
+        for (time, count) in self.death_time.iter() {
+            rows.push(("death_time", time.to_string(), count.to_string()));
+        }
+        rows
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            c if c.is_control() => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_object<K: ToString, V: ToString>(
+    entries: impl IntoIterator<Item = (K, V)>,
+) -> String {
+    let entries = entries
+        .into_iter()
+        .map(|(key, value)| {
+            format!("{}: {}", json_string(&key.to_string()), value.to_string())
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", entries.join(", "))
+}
+
+pub(crate) fn games_csv(outcomes: &[Outcome]) -> String {
+    let mut output =
+        String::from("seed,ending,cause_of_death,day,time,money,exams_passed");
+    for subject in Subject::all_subjects() {
+        write!(output, ",{:?}", subject).unwrap();
+    }
+    output.push_str(",crash_message\n");
+    for outcome in outcomes {
+        let cause = outcome
+            .ending
+            .cause_of_death()
+            .map(|cause| format!("{:?}", cause))
+            .unwrap_or_default();
+        write!(
+            output,
+            "{},{},{},{},{},{},{}",
+            outcome.seed,
+            outcome.ending.name(),
+            csv_field(&cause),
+            outcome.day,
+            outcome.time.0,
+            outcome.money.0,
+            outcome.exams_passed.len(),
+        )
+        .unwrap();
+        for subject in Subject::all_subjects() {
+            let passed = outcome.exams_passed.contains(&subject);
+            write!(output, ",{}", passed as u8).unwrap();
+        }
+        writeln!(
+            output,
+            ",{}",
+            csv_field(outcome.crash_message.as_deref().unwrap_or_default())
+        )
+        .unwrap();
+    }
+    output
+}
+
+pub(crate) fn summary_csv(summary: &Summary) -> String {
+    let mut output = String::from("metric,key,value\n");
+    for (metric, key, value) in summary.rows() {
+        writeln!(output, "{},{},{}", metric, csv_field(&key), value).unwrap();
+    }
+    output
+}
+
+fn game_json(outcome: &Outcome) -> String {
+    let cause = outcome
+        .ending
+        .cause_of_death()
+        .map(|cause| json_string(&format!("{:?}", cause)))
+        .unwrap_or_else(|| "null".to_string());
+    let exams_passed = outcome
+        .exams_passed
+        .iter()
+        .map(|subject| json_string(&format!("{:?}", subject)))
+        .collect::<Vec<_>>();
+    json_object([
+        ("seed", outcome.seed.to_string()),
+        ("ending", json_string(outcome.ending.name())),
+        ("cause_of_death", cause),
+        ("day", outcome.day.to_string()),
+        ("time", outcome.time.0.to_string()),
+        ("money", outcome.money.0.to_string()),
+        ("exams_passed", format!("[{}]", exams_passed.join(", "))),
+        (
+            "crash_message",
+            outcome
+                .crash_message
+                .as_deref()
+                .map_or_else(|| "null".to_string(), json_string),
+        ),
+    ])
+}
+
+fn summary_json(summary: &Summary) -> String {
+    let mut entries = vec![
+        ("games", summary.games.to_string()),
+        ("endings", json_object(summary.endings.iter())),
+        (
+            "causes_of_death",
+            json_object(summary.causes_of_death.iter()),
+        ),
+        (
+            "exams_passed_per_subject",
+            json_object(summary.exams_passed_per_subject.iter()),
+        ),
+        (
+            "exams_passed_count",
+            json_object(summary.exams_passed_count.iter()),
+        ),
+    ];
+    if let (Some(min), Some(max)) = (summary.min_money, summary.max_money) {
+        entries.push((
+            "money",
+            json_object([
+                ("min", min.to_string()),
+                ("max", max.to_string()),
+                ("mean", format!("{:.2}", summary.mean_money())),
+            ]),
+        ));
+    }
+    entries.push(("death_day", json_object(summary.death_day.iter())));
+    entries.push(("death_time", json_object(summary.death_time.iter())));
+    json_object(entries)
+}
+
+/// Сводная статистика и, если `with_games` — `true`, итоги каждой игры.
+pub(crate) fn report_json(outcomes: &[Outcome], with_games: bool) -> String {
+    let summary = summary_json(&Summary::new(outcomes));
+    if !with_games {
+        return summary + "\n";
+    }
+    let games = outcomes.iter().map(game_json).collect::<Vec<_>>();
+    format!(
+        "{{\"summary\": {}, \"games\": [\n{}\n]}}\n",
+        summary,
+        games.join(",\n")
+    )
+}
+
+/// Результат поиска выигрышной игры для одного зерна.
+pub(crate) struct SolverResult {
+    pub(crate) seed: u64,
+
+    /// `None`, если выигрышную игру найти не удалось.
+    pub(crate) solution: Option<Solution>,
+}
+
+pub(crate) fn solutions_csv(results: &[SolverResult]) -> String {
+    let mut output = String::from("seed,solved,actions,recording\n");
+    for result in results {
+        match &result.solution {
+            Some(solution) => writeln!(
+                output,
+                "{},1,{},{}",
+                result.seed,
+                solution.actions,
+                csv_field(&solution.recording)
+            ),
+            None => writeln!(output, "{},0,,", result.seed),
+        }
+        .unwrap();
+    }
+    output
+}
+
+pub(crate) fn solutions_json(results: &[SolverResult]) -> String {
+    let results = results
+        .iter()
+        .map(|result| {
+            let (actions, recording) = match &result.solution {
+                Some(solution) => (
+                    solution.actions.to_string(),
+                    json_string(&solution.recording),
+                ),
+                None => ("null".to_string(), "null".to_string()),
+            };
+            json_object([
+                ("seed", result.seed.to_string()),
+                ("solved", result.solution.is_some().to_string()),
+                ("actions", actions),
+                ("recording", recording),
+            ])
+        })
+        .collect::<Vec<_>>();
+    format!("[\n{}\n]\n", results.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmheroes_core::logic::{CauseOfDeath, Money, Time};
+
+    fn outcomes() -> Vec<Outcome> {
+        vec![
+            Outcome {
+                seed: 1,
+                ending: Ending::Died(CauseOfDeath::TorturedByProfessor(
+                    Subject::Calculus,
+                )),
+                day: 2,
+                time: Time(14),
+                money: Money(10),
+                exams_passed: vec![Subject::English],
+                crash_message: None,
+            },
+            Outcome {
+                seed: 2,
+                ending: Ending::Won,
+                day: 5,
+                time: Time(20),
+                money: Money(150),
+                exams_passed: Subject::all_subjects().collect(),
+                crash_message: None,
+            },
+            Outcome {
+                seed: 3,
+                ending: Ending::Crashed,
+                day: 0,
+                time: Time(0),
+                money: Money(0),
+                exams_passed: Vec::new(),
+                crash_message: Some("min > max, \"oops\"".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_games_csv() {
+        assert_eq!(
+            games_csv(&outcomes()),
+            r#"seed,ending,cause_of_death,day,time,money,exams_passed,AlgebraAndNumberTheory,Calculus,GeometryAndTopology,ComputerScience,English,PhysicalEducation,crash_message
+1,died,TorturedByProfessor(Calculus),2,14,10,1,0,0,0,0,1,0,
+2,won,,5,20,150,6,1,1,1,1,1,1,
+3,crashed,,0,0,0,0,0,0,0,0,0,0,"min > max, ""oops"""
+"#
+        );
+    }
+
+    #[test]
+    fn test_summary_json() {
+        assert_eq!(
+            report_json(&outcomes(), false),
+            r#"{"games": 3, "endings": {"crashed": 1, "died": 1, "won": 1}, "causes_of_death": {"TorturedByProfessor(Calculus)": 1}, "exams_passed_per_subject": {"AlgebraAndNumberTheory": 1, "Calculus": 1, "ComputerScience": 1, "English": 2, "GeometryAndTopology": 1, "PhysicalEducation": 1}, "exams_passed_count": {"1": 1, "6": 1}, "money": {"min": 10, "max": 150, "mean": 80.00}, "death_day": {"2": 1}, "death_time": {"14": 1}}
+"#
+        );
+    }
+
+    #[test]
+    fn test_solutions() {
+        let results = [
+            SolverResult {
+                seed: 1,
+                solution: None,
+            },
+            SolverResult {
+                seed: 2,
+                solution: Some(Solution {
+                    recording: "r2↓r'a".to_string(),
+                    actions: 2,
+                }),
+            },
+        ];
+        assert_eq!(
+            solutions_csv(&results),
+            "seed,solved,actions,recording\n1,0,,\n2,1,2,r2↓r'a\n"
+        );
+        assert_eq!(
+            solutions_json(&results),
+            r#"[
+{"seed": 1, "solved": false, "actions": null, "recording": null},
+{"seed": 2, "solved": true, "actions": 2, "recording": "r2↓r'a"}
+]
+"#
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c\n"), r#""a\"b\\c\u000a""#);
+    }
+}
@@ -0,0 +1,384 @@
+//! Поиск выигрышной последовательности действий для заданного зерна.
+//!
+//! Игра полностью детерминирована, поэтому можно перебирать действия лучевым
+//! поиском (beam search): из всех продолжений оставляются `beam_width` лучших
+//! по эвристической оценке состояния игры. Сравниваются только состояния,
+//! в которых с начала игры прошло одинаковое число часов: иначе поиск
+//! предпочитал бы действия, после которых время не идёт.
+//!
+//! Сам `Game` склонировать нельзя, поэтому снимком служит сохранение
+//! (`SavedGame`), сделанное в последний раз, когда игрок был в главном меню,
+//! плюс действия, совершённые после этого.
+
+use mmheroes_core::logic::{
+    create_or_restore_game, Action, Game, GameMode, GameScreen, GameState, SavedGame,
+    Simulation, StateHolder, Subject,
+};
+use mmheroes_core::ui::recording::{inputs_for_action, InputRecorder};
+use mmheroes_core::ui::Input;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+use std::pin::{pin, Pin};
+use std::rc::Rc;
+
+/// Сколько действий подряд можно совершить, пока время стоит на месте. Некоторые
+/// действия (например, разговоры с одногруппниками) можно повторять бесконечно,
+/// и без этого ограничения поиск бы на них зацикливался.
+const MAX_IDLE_ACTIONS: usize = 16;
+
+/// Клавиши, нажатые с начала игры, в виде списка от последнего действия к первому,
+/// чтобы узлы могли делить общие префиксы.
+struct PathNode {
+    inputs: Vec<Input>,
+    parent: Option<Rc<PathNode>>,
+}
+
+struct Node {
+    /// Последнее сохранение. `None` — игра ещё ни разу не доходила до главного меню.
+    snapshot: Option<Rc<SavedGame>>,
+
+    /// Действия, совершённые после сохранения.
+    suffix: Vec<Action>,
+
+    path: Rc<PathNode>,
+
+    /// Сколько всего действий совершено с начала игры.
+    actions: usize,
+
+    /// Сколько часов прошло с начала игры.
+    hours: u32,
+
+    /// Сколько действий совершено с тех пор, как в последний раз прошёл час.
+    idle_actions: usize,
+
+    score: i64,
+}
+
+enum Expansion {
+    Continue(Node),
+    Won(Node),
+    Lost,
+}
+
+pub(crate) struct Solution {
+    /// Запись нажатых клавиш в формате `ui::recording`, начиная с самого первого
+    /// экрана игры и заканчивая экраном с поздравлением.
+    pub(crate) recording: String,
+
+    /// Сколько действий совершил игрок.
+    pub(crate) actions: usize,
+}
+
+/// Оценка состояния игры: чем больше, тем ближе игрок к победе.
+fn evaluate(state: &GameState) -> i64 {
+    let player = state.player();
+    let mut score = 0i64;
+    for subject in Subject::all_subjects() {
+        let status = player.status_for_subject(subject);
+        if status.passed() {
+            score += 10_000;
+        } else {
+            let required = state.rules().subject(subject).required_problems as i64;
+            score += (status.problems_done() as i64).min(required) * 6000 / required;
+            score += (status.knowledge() as i64).min(40) * 20;
+        }
+    }
+    let health = player.health() as i64;
+    score += health.min(60) * 40;
+    if health < 15 {
+        score -= 3000;
+    }
+    score += (player.brain() as i64).min(20) * 30;
+    score += (player.stamina() as i64).min(20) * 30;
+    score += (player.charisma() as i64).min(20) * 30;
+    score + (player.money().0 as i64).min(100)
+}
+
+fn hours_passed(state: &GameState) -> u32 {
+    state.current_day_index() as u32 * 24 + state.current_time().0 as u32
+}
+
+/// Остались ли ещё зачёты по каждому несданному предмету. Если нет, выиграть
+/// уже нельзя.
+fn can_still_win(state: &GameState) -> bool {
+    let today = state.current_day_index() as usize;
+    let now = state.current_time();
+    Subject::all_subjects()
+        .filter(|&subject| !state.player().status_for_subject(subject).passed())
+        .all(|subject| {
+            state.timetable().days().iter().skip(today).any(|day| {
+                day.exam(subject)
+                    .is_some_and(|exam| day.index() > today || exam.to() > now)
+            })
+        })
+}
+
+fn is_won(state: &GameState) -> bool {
+    state.player().cause_of_death().is_none() && state.player().exams_left() == 0
+}
+
+/// Действия, которые не приближают к победе: справка и выход из игры, пока сданы
+/// ещё не все зачёты.
+fn is_pointless(action: Action, all_exams_passed: bool) -> bool {
+    match action {
+        Action::WhatToDo | Action::ViewTimetable => true,
+        Action::IAmDone => !all_exams_passed,
+        _ => false,
+    }
+}
+
+pub(crate) struct Solver {
+    seed: u64,
+    mode: GameMode,
+    beam_width: usize,
+
+    /// Хэши состояний игры, в которых уже побывал поиск.
+    ///
+    /// Состояние генератора случайных чисел при сравнении состояний не
+    /// учитывается (см. [`SavedGame::game_state_hash`]): иначе, например, каждая
+    /// попытка поехать в ПОМИ, когда электрички уже не ходят, давала бы «новое»
+    /// состояние, и поиск бы на этом зацикливался.
+    visited: HashSet<u64>,
+}
+
+impl Solver {
+    pub(crate) fn new(seed: u64, mode: GameMode, beam_width: usize) -> Solver {
+        Solver {
+            seed,
+            mode,
+            beam_width,
+            visited: HashSet::new(),
+        }
+    }
+
+    pub(crate) fn solve(mut self) -> Option<Solution> {
+        let root = Node {
+            snapshot: None,
+            suffix: Vec::new(),
+            path: Rc::new(PathNode {
+                // Первое нажатие запускает игру, второе убирает заставку.
+                // Для логики игры заставки нет.
+                inputs: vec![Input::Enter; 2],
+                parent: None,
+            }),
+            actions: 0,
+            hours: 0,
+            idle_actions: 0,
+            score: 0,
+        };
+        // Узлы, сгруппированные по тому, сколько часов прошло с начала игры.
+        // Сравнивать между собой имеет смысл только узлы с одинаковым временем.
+        let mut layers = BTreeMap::<u32, Vec<Node>>::new();
+        layers.insert(0, vec![root]);
+        while let Some((hours, mut layer)) = layers.pop_first() {
+            // Действия, после которых время не идёт, дают узлы в том же слое.
+            // Их перебираем тем же лучевым поиском, поколение за поколением.
+            while !layer.is_empty() {
+                layer.sort_by_key(|node| Reverse(node.score));
+                layer.truncate(self.beam_width);
+                let mut next_generation = Vec::new();
+                for node in layer {
+                    for action in self.available_actions(&node) {
+                        match self.expand(&node, action) {
+                            Expansion::Continue(child) if child.hours == hours => {
+                                next_generation.push(child)
+                            }
+                            Expansion::Continue(child) => {
+                                layers.entry(child.hours).or_default().push(child)
+                            }
+                            Expansion::Won(child) => return Some(solution(&child)),
+                            Expansion::Lost => {}
+                        }
+                    }
+                }
+                layer = next_generation;
+            }
+        }
+        None
+    }
+
+    /// Восстанавливает игру в состоянии узла и передаёт её в `f`.
+    fn with_simulation<R>(
+        &self,
+        node: &Node,
+        f: impl FnOnce(&mut Simulation<'_, dyn Game + '_>) -> R,
+    ) -> R {
+        let state_holder = StateHolder::new(self.mode);
+        let saved_game = node.snapshot.as_deref().cloned();
+        let game = pin!(create_or_restore_game(self.seed, saved_game, &state_holder));
+        let game: Pin<&mut dyn Game> = game;
+        let mut simulation = Simulation::new(&state_holder, game);
+        for &action in node.suffix.iter() {
+            simulation
+                .perform_action(action)
+                .expect("Replaying a snapshot must be deterministic");
+        }
+        f(&mut simulation)
+    }
+
+    fn available_actions(&self, node: &Node) -> Vec<Action> {
+        let (actions, all_exams_passed) = self.with_simulation(node, |simulation| {
+            let all_exams_passed = simulation
+                .screen()
+                .state()
+                .is_some_and(|state| state.player().exams_left() == 0);
+            (simulation.available_actions().to_vec(), all_exams_passed)
+        });
+        let useful = actions
+            .iter()
+            .copied()
+            .filter(|&action| !is_pointless(action, all_exams_passed))
+            .collect::<Vec<_>>();
+        if useful.is_empty() {
+            actions
+        } else {
+            useful
+        }
+    }
+
+    /// Совершает действие `action`, а затем все действия на экранах, где выбора нет.
+    fn expand(&mut self, node: &Node, action: Action) -> Expansion {
+        let mut suffix = node.suffix.clone();
+        let mut inputs = Vec::new();
+        let mut actions = node.actions;
+        // Паника в движке не должна прерывать весь поиск: такую ветку просто
+        // отбрасываем.
+        let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            self.with_simulation(node, |simulation| {
+                let mut action = action;
+                loop {
+                    inputs.extend(
+                        inputs_for_action(&simulation.available_actions(), action)
+                            .unwrap(),
+                    );
+                    simulation.perform_action(action).unwrap();
+                    suffix.push(action);
+                    actions += 1;
+
+                    if let GameScreen::GameEnd(state) = &*simulation.screen() {
+                        return Err(is_won(state));
+                    }
+                    let available_actions = simulation.available_actions();
+                    if available_actions.len() != 1 {
+                        break;
+                    }
+                    action = available_actions[0];
+                }
+                let screen = simulation.screen();
+                let evaluation = match screen.state() {
+                    Some(state) if !can_still_win(state) => return Err(false),
+                    Some(state) => Some((hours_passed(state), evaluate(state))),
+                    None => None,
+                };
+                Ok((simulation.save_game(), evaluation))
+            })
+        }));
+        let path = Rc::new(PathNode {
+            inputs,
+            parent: Some(node.path.clone()),
+        });
+        let (saved_game, evaluation) = match outcome {
+            Ok(Ok(result)) => result,
+            Ok(Err(true)) => {
+                return Expansion::Won(Node {
+                    snapshot: None,
+                    suffix,
+                    path,
+                    actions,
+                    hours: node.hours,
+                    idle_actions: 0,
+                    score: i64::MAX,
+                })
+            }
+            Ok(Err(false)) | Err(_) => return Expansion::Lost,
+        };
+        let (hours, score) = evaluation.unwrap_or((node.hours, node.score));
+        let idle_actions = if hours == node.hours {
+            node.idle_actions + (actions - node.actions)
+        } else {
+            0
+        };
+        if idle_actions > MAX_IDLE_ACTIONS {
+            return Expansion::Lost;
+        }
+        let mut snapshot = node.snapshot.clone();
+        if let Some(saved_game) = saved_game {
+            if !self.visited.insert(saved_game.game_state_hash()) {
+                return Expansion::Lost;
+            }
+            snapshot = Some(Rc::new(saved_game));
+            suffix.clear();
+        }
+        Expansion::Continue(Node {
+            snapshot,
+            suffix,
+            path,
+            actions,
+            hours,
+            idle_actions,
+            score,
+        })
+    }
+}
+
+fn solution(node: &Node) -> Solution {
+    let mut chunks = Vec::new();
+    let mut path = Some(&node.path);
+    while let Some(path_node) = path {
+        chunks.push(&path_node.inputs);
+        path = path_node.parent.as_ref();
+    }
+    let mut recorder = InputRecorder::new(String::new());
+    for input in chunks.into_iter().rev().flatten() {
+        recorder.record_input(*input).unwrap();
+    }
+    recorder.flush().unwrap();
+    Solution {
+        recording: recorder.output().clone(),
+        actions: node.actions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmheroes_core::ui::recording::{
+        InputRecordingParser, InputRecordingParserError, NoInputRecording,
+    };
+    use mmheroes_core::ui::renderer::RendererRequestConsumer;
+    use mmheroes_core::ui::{GameUI, Locale, RendererRequest};
+
+    struct NullRendererRequestConsumer;
+
+    impl RendererRequestConsumer for NullRendererRequestConsumer {
+        fn consume_request(&mut self, _request: RendererRequest) {}
+    }
+
+    #[test]
+    fn test_solution_wins_the_game() {
+        let seed = 2;
+        let solution = Solver::new(seed, GameMode::Normal, 10).solve().unwrap();
+
+        let state_holder = StateHolder::new(GameMode::Normal);
+        let game = pin!(mmheroes_core::logic::create_game(seed, &state_holder));
+        let mut game_ui = GameUI::new(
+            &state_holder,
+            game,
+            seed,
+            None,
+            NullRendererRequestConsumer,
+            None::<NoInputRecording>,
+            Locale::Russian,
+        );
+        let mut parser = InputRecordingParser::new(&solution.recording);
+        match parser.parse_all(|input| game_ui.continue_game(input)) {
+            Ok(()) | Err(InputRecordingParserError::Interrupted) => {}
+            Err(error) => panic!("{:?}", error),
+        }
+        assert!(!game_ui.has_bug());
+        match state_holder.observable_state().screen() {
+            GameScreen::GameEnd(state) => assert!(is_won(state)),
+            screen => panic!("Unexpected screen: {:?}", screen),
+        };
+    }
+}
@@ -0,0 +1,147 @@
+//! Запросы рендерера, разбитые на кадры.
+//!
+//! В терминале `RendererRequest::Sleep` просто блокирует поток, но в браузере так
+//! делать нельзя. Поэтому запросы не выполняются сразу, а складываются в очередь
+//! кадров: `Sleep` начинает новый кадр, который нужно показать с задержкой.
+//! Показом кадров по таймеру занимается [`crate::WebGame`].
+
+use mmheroes_core::ui::renderer::{RendererRequest, RendererRequestConsumer};
+use mmheroes_core::ui::{Color, Milliseconds};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// Владеющая версия [`RendererRequest`] без `Flush` и `Sleep`: первый не нужен,
+/// потому что экран перерисовывается в конце каждого кадра, а второй разделяет
+/// кадры.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ScreenRequest {
+    ClearScreen,
+    WriteStr(String),
+    MoveCursor {
+        line: u8,
+        column: u8,
+    },
+    SetColor {
+        foreground: Color,
+        background: Color,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Frame {
+    /// Сколько ждать перед тем, как показать кадр.
+    pub delay: Milliseconds,
+    pub requests: Vec<ScreenRequest>,
+}
+
+#[derive(Default)]
+pub struct FrameQueue {
+    frames: VecDeque<Frame>,
+}
+
+impl FrameQueue {
+    fn push(&mut self, request: ScreenRequest) {
+        match self.frames.back_mut() {
+            Some(frame) => frame.requests.push(request),
+            None => self.frames.push_back(Frame {
+                delay: Milliseconds(0),
+                requests: vec![request],
+            }),
+        }
+    }
+
+    fn sleep(&mut self, ms: Milliseconds) {
+        self.frames.push_back(Frame {
+            delay: ms,
+            requests: Vec::new(),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut Frame> {
+        self.frames.front_mut()
+    }
+
+    pub fn pop(&mut self) -> Option<Frame> {
+        self.frames.pop_front()
+    }
+}
+
+/// Складывает запросы рендерера в общую с [`crate::WebGame`] очередь кадров.
+pub struct WebRendererRequestConsumer {
+    pub(crate) frames: Rc<RefCell<FrameQueue>>,
+}
+
+impl RendererRequestConsumer for WebRendererRequestConsumer {
+    fn consume_request(&mut self, request: RendererRequest) {
+        let mut frames = self.frames.borrow_mut();
+        match request {
+            RendererRequest::ClearScreen => frames.push(ScreenRequest::ClearScreen),
+            RendererRequest::Flush => (),
+            RendererRequest::WriteStr(s) => {
+                frames.push(ScreenRequest::WriteStr(s.to_string()))
+            }
+            RendererRequest::MoveCursor { line, column } => {
+                frames.push(ScreenRequest::MoveCursor { line, column })
+            }
+            RendererRequest::SetColor {
+                foreground,
+                background,
+            } => frames.push(ScreenRequest::SetColor {
+                foreground,
+                background,
+            }),
+            RendererRequest::Sleep(ms) => frames.sleep(ms),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sleep_starts_new_frame() {
+        let frames = Rc::new(RefCell::new(FrameQueue::default()));
+        let mut consumer = WebRendererRequestConsumer {
+            frames: frames.clone(),
+        };
+        consumer.consume_request(RendererRequest::ClearScreen);
+        consumer.consume_request(RendererRequest::WriteStr("раз"));
+        consumer.consume_request(RendererRequest::Flush);
+        consumer.consume_request(RendererRequest::Sleep(Milliseconds(500)));
+        consumer.consume_request(RendererRequest::Sleep(Milliseconds(300)));
+        consumer.consume_request(RendererRequest::WriteStr("два"));
+
+        let mut frames = frames.borrow_mut();
+        assert_eq!(
+            frames.pop(),
+            Some(Frame {
+                delay: Milliseconds(0),
+                requests: vec![
+                    ScreenRequest::ClearScreen,
+                    ScreenRequest::WriteStr("раз".to_string())
+                ],
+            })
+        );
+        assert_eq!(
+            frames.pop(),
+            Some(Frame {
+                delay: Milliseconds(500),
+                requests: vec![],
+            })
+        );
+        assert_eq!(
+            frames.pop(),
+            Some(Frame {
+                delay: Milliseconds(300),
+                requests: vec![ScreenRequest::WriteStr("два".to_string())],
+            })
+        );
+        assert!(frames.is_empty());
+    }
+}
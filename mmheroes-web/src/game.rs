@@ -0,0 +1,145 @@
+use crate::frames::{FrameQueue, WebRendererRequestConsumer};
+use crate::session::{new_session, GameSession};
+use crate::terminal::Terminal;
+use mmheroes_core::logic::GameMode;
+use mmheroes_core::ui::high_scores::{HighScore, SCORE_COUNT};
+use mmheroes_core::ui::{Input, Locale, Milliseconds};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Партия вместе с экраном, на который она выводится. Ничего не знает о DOM
+/// и таймерах, поэтому её можно тестировать без браузера.
+///
+/// После каждого ввода игра складывает вывод в очередь кадров, которые нужно
+/// показывать методом [`WebGame::show_frames`], пока он возвращает задержку
+/// до следующего кадра.
+pub struct WebGame {
+    session: Box<dyn GameSession>,
+    frames: Rc<RefCell<FrameQueue>>,
+    terminal: Terminal,
+    is_over: bool,
+}
+
+impl WebGame {
+    pub fn new(
+        mode: GameMode,
+        seed: u64,
+        high_scores: Option<[HighScore; SCORE_COUNT]>,
+        locale: Locale,
+    ) -> WebGame {
+        let frames = Rc::new(RefCell::new(FrameQueue::default()));
+        let consumer = WebRendererRequestConsumer {
+            frames: frames.clone(),
+        };
+        let mut game = WebGame {
+            session: new_session(mode, seed, high_scores, consumer, locale),
+            frames,
+            terminal: Terminal::default(),
+            is_over: false,
+        };
+        game.send_input(Input::Enter);
+        game
+    }
+
+    pub fn terminal(&self) -> &Terminal {
+        &self.terminal
+    }
+
+    /// `true`, если игра завершилась, и дальнейший ввод ни на что не влияет.
+    pub fn is_over(&self) -> bool {
+        self.is_over
+    }
+
+    pub fn high_scores(&self) -> &[HighScore; SCORE_COUNT] {
+        self.session.high_scores()
+    }
+
+    /// Передаёт ввод в игру. Кадры, которые ещё не были показаны, показываются
+    /// сразу: игрок не хочет ждать окончания анимации, если уже нажал клавишу.
+    pub fn send_input(&mut self, input: Input) {
+        while self.show_frames().is_some() {}
+        if !self.is_over {
+            self.is_over = !self.session.continue_game(input);
+        }
+    }
+
+    /// Показывает кадры, время которых пришло. Если остались кадры, которые нужно
+    /// показать позже, возвращает время, через которое нужно снова вызвать этот
+    /// метод.
+    pub fn show_frames(&mut self) -> Option<Milliseconds> {
+        let mut frames = self.frames.borrow_mut();
+        loop {
+            let frame = frames.front_mut()?;
+            if frame.delay.0 > 0 {
+                return Some(core::mem::replace(&mut frame.delay, Milliseconds(0)));
+            }
+            let frame = frames.pop().unwrap();
+            for request in &frame.requests {
+                self.terminal.apply(request)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn show_all_frames(game: &mut WebGame) -> Vec<i32> {
+        let mut delays = Vec::new();
+        while let Some(delay) = game.show_frames() {
+            delays.push(delay.0)
+        }
+        delays
+    }
+
+    #[test]
+    fn test_first_screen() {
+        let mut game = WebGame::new(GameMode::Normal, 0, None, Locale::English);
+        assert!(show_all_frames(&mut game).is_empty());
+        assert!(!game.is_over());
+        assert!(game
+            .terminal()
+            .text()
+            .contains("HEROES OF MATH AND MECHANICS"));
+    }
+
+    #[test]
+    fn test_delayed_frames() {
+        let mut game = WebGame::new(GameMode::Normal, 0, None, Locale::Russian);
+        show_all_frames(&mut game);
+        let mut delays = Vec::new();
+        for _ in 0..100 {
+            game.send_input(Input::Enter);
+            delays.extend(show_all_frames(&mut game));
+            if game.is_over() {
+                break;
+            }
+        }
+        assert!(!delays.is_empty());
+        assert!(delays.iter().all(|delay| *delay > 0));
+    }
+
+    #[test]
+    fn test_input_shows_pending_frames() {
+        let mut waiting_game = WebGame::new(GameMode::Normal, 0, None, Locale::Russian);
+        let mut impatient_game = WebGame::new(GameMode::Normal, 0, None, Locale::Russian);
+        for _ in 0..100 {
+            waiting_game.send_input(Input::Enter);
+            impatient_game.send_input(Input::Enter);
+            if impatient_game.show_frames().is_some() {
+                show_all_frames(&mut waiting_game);
+                waiting_game.send_input(Input::Other);
+                impatient_game.send_input(Input::Other);
+                show_all_frames(&mut waiting_game);
+                show_all_frames(&mut impatient_game);
+                assert_eq!(
+                    waiting_game.terminal().text(),
+                    impatient_game.terminal().text()
+                );
+                return;
+            }
+        }
+        panic!("No delayed frames found");
+    }
+}
@@ -0,0 +1,79 @@
+use mmheroes_core::ui::Input;
+
+/// Минимальное смещение пальца в пикселях, при котором касание считается
+/// свайпом, а не нажатием.
+pub const SWIPE_THRESHOLD: f64 = 30.0;
+
+/// Преобразует значение `KeyboardEvent.key` во ввод игры. Для клавиш-модификаторов
+/// возвращает `None`: их нажатие само по себе ничего не значит.
+pub fn input_for_key(key: &str) -> Option<Input> {
+    let input = match key {
+        "ArrowUp" => Input::KeyUp,
+        "ArrowDown" => Input::KeyDown,
+        "Enter" => Input::Enter,
+        "Backspace" => Input::Backspace,
+        "Escape" => Input::Escape,
+        "Home" => Input::Home,
+        "End" => Input::End,
+        "PageUp" => Input::PageUp,
+        "PageDown" => Input::PageDown,
+        "Shift" | "Control" | "Alt" | "AltGraph" | "Meta" | "CapsLock" | "NumLock"
+        | "Fn" | "Dead" | "Unidentified" => return None,
+        _ => {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphanumeric() => Input::Hotkey(c),
+                (Some(c), None) if !c.is_control() => Input::Character(c),
+                _ => Input::Other,
+            }
+        }
+    };
+    Some(input)
+}
+
+/// Преобразует касание экрана во ввод игры. `dx` и `dy` — смещение пальца между
+/// началом и концом касания.
+///
+/// Свайп вверх или вниз перемещает по меню, а нажатие выбирает пункт.
+/// Горизонтальные свайпы ничего не делают.
+pub fn input_for_touch(dx: f64, dy: f64) -> Option<Input> {
+    if dx.abs() < SWIPE_THRESHOLD && dy.abs() < SWIPE_THRESHOLD {
+        Some(Input::Enter)
+    } else if dy.abs() <= dx.abs() {
+        None
+    } else if dy < 0.0 {
+        Some(Input::KeyUp)
+    } else {
+        Some(Input::KeyDown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(input_for_key("ArrowUp"), Some(Input::KeyUp));
+        assert_eq!(input_for_key("ArrowDown"), Some(Input::KeyDown));
+        assert_eq!(input_for_key("Enter"), Some(Input::Enter));
+        assert_eq!(input_for_key("Backspace"), Some(Input::Backspace));
+        assert_eq!(input_for_key("ф"), Some(Input::Character('ф')));
+        assert_eq!(input_for_key(" "), Some(Input::Character(' ')));
+        assert_eq!(input_for_key("7"), Some(Input::Hotkey('7')));
+        assert_eq!(input_for_key("Q"), Some(Input::Hotkey('Q')));
+        assert_eq!(input_for_key("Escape"), Some(Input::Escape));
+        assert_eq!(input_for_key("PageDown"), Some(Input::PageDown));
+        assert_eq!(input_for_key("F1"), Some(Input::Other));
+        assert_eq!(input_for_key("ArrowLeft"), Some(Input::Other));
+        assert_eq!(input_for_key("Shift"), None);
+    }
+
+    #[test]
+    fn test_touches() {
+        assert_eq!(input_for_touch(3.0, -5.0), Some(Input::Enter));
+        assert_eq!(input_for_touch(10.0, -80.0), Some(Input::KeyUp));
+        assert_eq!(input_for_touch(-10.0, 80.0), Some(Input::KeyDown));
+        assert_eq!(input_for_touch(100.0, 40.0), None);
+    }
+}
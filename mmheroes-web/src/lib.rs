@@ -0,0 +1,230 @@
+//! Веб-версия игры: `mmheroes-core`, скомпилированный в WebAssembly.
+//!
+//! Экран 80×24 отрисовывается в HTML-элемент, переданный в [`start`], ввод берётся
+//! с клавиатуры и сенсорного экрана, а таблица рекордов хранится
+//! в `localStorage`.
+
+pub mod frames;
+pub mod game;
+pub mod input;
+mod session;
+pub mod storage;
+pub mod terminal;
+
+pub use game::WebGame;
+
+use mmheroes_core::logic::GameMode;
+use mmheroes_core::ui::{Input, Locale};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, TouchEvent, Window};
+
+/// Режим игры выбирается так же, как аргументом командной строки в терминальной
+/// версии.
+pub fn game_mode(mode_argument: Option<&str>) -> GameMode {
+    match mode_argument {
+        Some("-3dec-happy-birthday-Diamond") => GameMode::God,
+        Some(_) => GameMode::SelectInitialParameters,
+        None => GameMode::Normal,
+    }
+}
+
+pub fn locale(language: Option<&str>) -> Locale {
+    match language {
+        Some(language) if language.starts_with("en") => Locale::English,
+        _ => Locale::Russian,
+    }
+}
+
+fn window() -> Window {
+    web_sys::window().expect("no global `window` exists")
+}
+
+fn random_seed() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+struct App {
+    game: WebGame,
+    screen: Element,
+    mode: GameMode,
+    locale: Locale,
+    timer: Option<i32>,
+}
+
+type SharedApp = Rc<RefCell<App>>;
+
+impl App {
+    fn handle_input(app: &SharedApp, input: Input) {
+        {
+            let mut this = app.borrow_mut();
+            if let Some(timer) = this.timer.take() {
+                window().clear_timeout_with_handle(timer);
+            }
+            if this.game.is_over() {
+                // Как и в терминальной версии, после окончания игры можно
+                // начать новую.
+                this.game = WebGame::new(
+                    this.mode,
+                    random_seed(),
+                    storage::load_high_scores(),
+                    this.locale,
+                );
+            } else {
+                this.game.send_input(input);
+                if this.game.is_over() {
+                    storage::save_high_scores(this.game.high_scores());
+                }
+            }
+        }
+        App::show_frames(app)
+    }
+
+    /// Показывает кадры, время которых пришло, и заводит таймер для следующих.
+    fn show_frames(app: &SharedApp) {
+        let mut this = app.borrow_mut();
+        this.timer = None;
+        let delay = this.game.show_frames();
+        this.screen.set_inner_html(&this.game.terminal().to_html());
+        if let Some(delay) = delay {
+            let app = app.clone();
+            let callback = Closure::once_into_js(move || App::show_frames(&app));
+            this.timer = window()
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    callback.unchecked_ref(),
+                    delay.0,
+                )
+                .ok();
+        }
+    }
+}
+
+const STYLE: &str = "
+.mmheroes-screen {
+    display: inline-block;
+    font-family: monospace;
+    line-height: 1.2;
+    white-space: pre;
+    background-color: #000000;
+    user-select: none;
+}
+.mmheroes-screen .cursor {
+    animation: mmheroes-blink 1s step-end infinite;
+}
+@keyframes mmheroes-blink {
+    50% { text-decoration: underline; }
+}
+";
+
+/// Запускает игру в элементе `container`.
+///
+/// `mode_argument` — аналог аргумента командной строки терминальной версии,
+/// `language` — код языка, например, `navigator.language`.
+#[wasm_bindgen]
+pub fn start(
+    container: &HtmlElement,
+    mode_argument: Option<String>,
+    language: Option<String>,
+) -> Result<(), JsValue> {
+    let document = window()
+        .document()
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+
+    let style = document.create_element("style")?;
+    style.set_text_content(Some(&(String::from(STYLE) + &terminal::palette_css())));
+    container.append_child(&style)?;
+
+    let screen = document.create_element("div")?;
+    screen.set_class_name("mmheroes-screen");
+    container.append_child(&screen)?;
+
+    let mode = game_mode(mode_argument.as_deref());
+    let locale = locale(language.as_deref());
+    let app = Rc::new(RefCell::new(App {
+        game: WebGame::new(mode, random_seed(), storage::load_high_scores(), locale),
+        screen,
+        mode,
+        locale,
+        timer: None,
+    }));
+    App::show_frames(&app);
+
+    let keydown = {
+        let app = app.clone();
+        Closure::<dyn FnMut(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+            // Не мешаем сочетаниям клавиш браузера.
+            if event.ctrl_key() || event.meta_key() || event.alt_key() {
+                return;
+            }
+            if let Some(input) = input::input_for_key(&event.key()) {
+                event.prevent_default();
+                App::handle_input(&app, input);
+            }
+        })
+    };
+    document
+        .add_event_listener_with_callback("keydown", keydown.as_ref().unchecked_ref())?;
+    keydown.forget();
+
+    let touch_start = Rc::new(RefCell::new(None));
+    let touchstart = {
+        let touch_start = touch_start.clone();
+        Closure::<dyn FnMut(TouchEvent)>::new(move |event: TouchEvent| {
+            *touch_start.borrow_mut() = event
+                .changed_touches()
+                .get(0)
+                .map(|touch| (touch.client_x(), touch.client_y()));
+        })
+    };
+    container.add_event_listener_with_callback(
+        "touchstart",
+        touchstart.as_ref().unchecked_ref(),
+    )?;
+    touchstart.forget();
+
+    let touchend = Closure::<dyn FnMut(TouchEvent)>::new(move |event: TouchEvent| {
+        let (Some((start_x, start_y)), Some(touch)) = (
+            touch_start.borrow_mut().take(),
+            event.changed_touches().get(0),
+        ) else {
+            return;
+        };
+        let dx = (touch.client_x() - start_x) as f64;
+        let dy = (touch.client_y() - start_y) as f64;
+        if let Some(input) = input::input_for_touch(dx, dy) {
+            event.prevent_default();
+            App::handle_input(&app, input);
+        }
+    });
+    container.add_event_listener_with_callback(
+        "touchend",
+        touchend.as_ref().unchecked_ref(),
+    )?;
+    touchend.forget();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_mode() {
+        assert_eq!(game_mode(None), GameMode::Normal);
+        assert_eq!(
+            game_mode(Some("-3dec-happy-birthday-Diamond")),
+            GameMode::God
+        );
+        assert_eq!(game_mode(Some("x")), GameMode::SelectInitialParameters);
+    }
+
+    #[test]
+    fn test_locale() {
+        assert_eq!(locale(Some("en-US")), Locale::English);
+        assert_eq!(locale(Some("ru-RU")), Locale::Russian);
+        assert_eq!(locale(None), Locale::Russian);
+    }
+}
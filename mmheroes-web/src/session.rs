@@ -0,0 +1,68 @@
+use crate::frames::WebRendererRequestConsumer;
+use mmheroes_core::logic::{create_game, Game, GameMode, StateHolder};
+use mmheroes_core::ui::high_scores::{HighScore, SCORE_COUNT};
+use mmheroes_core::ui::recording::NoInputRecording;
+use mmheroes_core::ui::{GameUI, Input, Locale};
+use std::pin::Pin;
+
+/// Одна партия, у которой стёрт тип игры, чтобы её можно было хранить в структурах
+/// без параметров-типов.
+pub trait GameSession {
+    fn continue_game(&mut self, input: Input) -> bool;
+
+    fn high_scores(&self) -> &[HighScore; SCORE_COUNT];
+}
+
+/// `GameUI` хранит ссылки на `StateHolder` и игру, поэтому все три объекта живут
+/// в одной структуре, а сами `StateHolder` и игра — в куче, чтобы их адреса
+/// не менялись.
+///
+/// Порядок полей важен: поля удаляются в порядке объявления, и `GameUI` должен
+/// быть удалён раньше того, на что он ссылается.
+struct SessionImpl<G: 'static> {
+    game_ui: GameUI<'static, G, WebRendererRequestConsumer, NoInputRecording>,
+    _game: Pin<Box<G>>,
+    _state_holder: Box<StateHolder>,
+}
+
+impl<G: Game> GameSession for SessionImpl<G> {
+    fn continue_game(&mut self, input: Input) -> bool {
+        self.game_ui.continue_game(input)
+    }
+
+    fn high_scores(&self) -> &[HighScore; SCORE_COUNT] {
+        &self.game_ui.high_scores
+    }
+}
+
+pub fn new_session(
+    mode: GameMode,
+    seed: u64,
+    high_scores: Option<[HighScore; SCORE_COUNT]>,
+    renderer_request_consumer: WebRendererRequestConsumer,
+    locale: Locale,
+) -> Box<dyn GameSession> {
+    let state_holder = Box::new(StateHolder::new(mode));
+    // SAFETY: StateHolder лежит в куче и удаляется после GameUI и игры,
+    // см. SessionImpl.
+    let state_holder_ref = unsafe { &*(&*state_holder as *const StateHolder) };
+    let mut game = Box::pin(create_game(seed, state_holder_ref));
+    // SAFETY: то же самое для игры. Она не перемещается, так как закреплена.
+    let game_ref = unsafe {
+        Pin::new_unchecked(&mut *(game.as_mut().get_unchecked_mut() as *mut _))
+    };
+    let game_ui = GameUI::new(
+        state_holder_ref,
+        game_ref,
+        seed,
+        high_scores,
+        renderer_request_consumer,
+        None,
+        locale,
+    );
+    Box::new(SessionImpl {
+        game_ui,
+        _game: game,
+        _state_holder: state_holder,
+    })
+}
@@ -0,0 +1,59 @@
+//! Таблица рекордов хранится в `localStorage` браузера в том же формате, что и файл
+//! `MMHEROES.HI`, записанном в шестнадцатеричном виде.
+
+use mmheroes_core::ui::high_scores::{decode, encode, HighScore, SCORE_COUNT};
+
+const HIGH_SCORES_KEY: &str = "mmheroes.high_scores";
+
+pub fn encode_high_scores(scores: &[HighScore; SCORE_COUNT]) -> String {
+    encode(scores)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub fn decode_high_scores(s: &str) -> Option<[HighScore; SCORE_COUNT]> {
+    if !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return None;
+    }
+    let bytes = (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    decode(&bytes)
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+pub fn load_high_scores() -> Option<[HighScore; SCORE_COUNT]> {
+    let value = local_storage()?.get_item(HIGH_SCORES_KEY).ok().flatten()?;
+    decode_high_scores(&value)
+}
+
+pub fn save_high_scores(scores: &[HighScore; SCORE_COUNT]) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(HIGH_SCORES_KEY, &encode_high_scores(scores));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebGame;
+    use mmheroes_core::logic::GameMode;
+    use mmheroes_core::ui::Locale;
+
+    #[test]
+    fn test_high_scores_round_trip() {
+        let game = WebGame::new(GameMode::Normal, 0, None, Locale::Russian);
+        let scores = game.high_scores().clone();
+        let encoded = encode_high_scores(&scores);
+        assert!(encoded.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(decode_high_scores(&encoded), Some(scores));
+        assert_eq!(decode_high_scores(&encoded[1..]), None);
+        assert_eq!(decode_high_scores("zz"), None);
+        assert_eq!(decode_high_scores("ёё"), None);
+    }
+}
@@ -0,0 +1,273 @@
+//! Модель экрана 80×24, в которую применяются запросы рендерера, и её
+//! отображение в HTML.
+
+use crate::frames::ScreenRequest;
+use core::fmt::Write;
+use mmheroes_core::ui::{Color, TERMINAL_HEIGHT, TERMINAL_WIDTH};
+
+/// Цвета терминала в CSS. Совпадают с палитрой текстового режима VGA.
+pub const PALETTE: [(Color, &str); 15] = [
+    (Color::Black, "#000000"),
+    (Color::Red, "#aa0000"),
+    (Color::Yellow, "#aa5500"),
+    (Color::Blue, "#0000aa"),
+    (Color::Magenta, "#aa00aa"),
+    (Color::Cyan, "#00aaaa"),
+    (Color::White, "#aaaaaa"),
+    (Color::Gray, "#555555"),
+    (Color::RedBright, "#ff5555"),
+    (Color::Green, "#55ff55"),
+    (Color::YellowBright, "#ffff55"),
+    (Color::BlueBright, "#5555ff"),
+    (Color::MagentaBright, "#ff55ff"),
+    (Color::CyanBright, "#55ffff"),
+    (Color::WhiteBright, "#ffffff"),
+];
+
+/// CSS-классы для цветов из [`PALETTE`]: `f<номер>` для цвета символа
+/// и `b<номер>` для цвета фона, где номер — значение [`Color`].
+pub fn palette_css() -> String {
+    let mut css = String::new();
+    for (color, value) in PALETTE {
+        let index = color as u8;
+        writeln!(css, ".f{index} {{ color: {value}; }}").unwrap();
+        writeln!(css, ".b{index} {{ background-color: {value}; }}").unwrap();
+    }
+    css
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Cell {
+    pub character: char,
+    pub foreground: Color,
+    pub background: Color,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell {
+            character: ' ',
+            foreground: Color::White,
+            background: Color::Black,
+        }
+    }
+}
+
+pub struct Terminal {
+    cells: [[Cell; TERMINAL_WIDTH]; TERMINAL_HEIGHT],
+    line: usize,
+    column: usize,
+    foreground: Color,
+    background: Color,
+}
+
+impl Default for Terminal {
+    fn default() -> Self {
+        Terminal {
+            cells: [[Cell::default(); TERMINAL_WIDTH]; TERMINAL_HEIGHT],
+            line: 0,
+            column: 0,
+            foreground: Color::White,
+            background: Color::Black,
+        }
+    }
+}
+
+impl Terminal {
+    pub fn cell(&self, line: usize, column: usize) -> Cell {
+        self.cells[line][column]
+    }
+
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    pub fn apply(&mut self, request: &ScreenRequest) {
+        match request {
+            ScreenRequest::ClearScreen => {
+                self.cells = [[Cell::default(); TERMINAL_WIDTH]; TERMINAL_HEIGHT];
+                self.line = 0;
+                self.column = 0;
+            }
+            ScreenRequest::WriteStr(s) => {
+                for c in s.chars() {
+                    self.put(c)
+                }
+            }
+            ScreenRequest::MoveCursor { line, column } => {
+                self.line = *line as usize;
+                self.column = *column as usize;
+            }
+            ScreenRequest::SetColor {
+                foreground,
+                background,
+            } => {
+                self.foreground = *foreground;
+                self.background = *background;
+            }
+        }
+    }
+
+    fn put(&mut self, c: char) {
+        if c == '\n' {
+            self.line += 1;
+            self.column = 0;
+            return;
+        }
+        if self.column >= TERMINAL_WIDTH {
+            self.line += 1;
+            self.column = 0;
+        }
+        // Как и curses, не прокручиваем экран, а просто ничего не выводим
+        // за его пределами.
+        if self.line < TERMINAL_HEIGHT {
+            self.cells[self.line][self.column] = Cell {
+                character: c,
+                foreground: self.foreground,
+                background: self.background,
+            };
+        }
+        self.column += 1;
+    }
+
+    /// Текст строки `line` без завершающих пробелов.
+    pub fn line_text(&self, line: usize) -> String {
+        let text: String = self.cells[line].iter().map(|cell| cell.character).collect();
+        text.trim_end().to_string()
+    }
+
+    /// Текст всего экрана, по строке на каждую строку терминала.
+    pub fn text(&self) -> String {
+        (0..TERMINAL_HEIGHT)
+            .map(|line| self.line_text(line) + "\n")
+            .collect()
+    }
+
+    /// HTML-разметка экрана: по элементу `div` на строку, соседние символы
+    /// одного цвета объединяются в один `span` с классами из [`palette_css`].
+    /// Клетка под курсором помечается классом `cursor`.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        for (line_index, line) in self.cells.iter().enumerate() {
+            html.push_str("<div>");
+            let mut current_span = None;
+            for (column_index, cell) in line.iter().enumerate() {
+                let is_cursor = (line_index, column_index) == (self.line, self.column);
+                let span = (cell.foreground, cell.background, is_cursor);
+                if current_span != Some(span) {
+                    if current_span.is_some() {
+                        html.push_str("</span>");
+                    }
+                    write!(
+                        html,
+                        "<span class=\"f{} b{}{}\">",
+                        cell.foreground as u8,
+                        cell.background as u8,
+                        if is_cursor { " cursor" } else { "" }
+                    )
+                    .unwrap();
+                    current_span = Some(span);
+                }
+                match cell.character {
+                    '<' => html.push_str("&lt;"),
+                    '>' => html.push_str("&gt;"),
+                    '&' => html.push_str("&amp;"),
+                    c => html.push(c),
+                }
+            }
+            html.push_str("</span></div>");
+        }
+        html
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(terminal: &mut Terminal, s: &str) {
+        terminal.apply(&ScreenRequest::WriteStr(s.to_string()))
+    }
+
+    #[test]
+    fn test_write_and_wrap() {
+        let mut terminal = Terminal::default();
+        write(&mut terminal, "Привет\nмир");
+        assert_eq!(terminal.line_text(0), "Привет");
+        assert_eq!(terminal.line_text(1), "мир");
+        assert_eq!(terminal.cursor(), (1, 3));
+
+        terminal.apply(&ScreenRequest::MoveCursor {
+            line: 2,
+            column: 78,
+        });
+        write(&mut terminal, "abc");
+        assert_eq!(terminal.line_text(2).trim(), "ab");
+        assert_eq!(terminal.line_text(3), "c");
+
+        // За пределами экрана ничего не выводится, но и не падает.
+        terminal.apply(&ScreenRequest::MoveCursor {
+            line: 23,
+            column: 0,
+        });
+        write(&mut terminal, "last\nbeyond the screen");
+        assert_eq!(terminal.line_text(23), "last");
+
+        terminal.apply(&ScreenRequest::ClearScreen);
+        assert_eq!(terminal.text(), "\n".repeat(TERMINAL_HEIGHT));
+        assert_eq!(terminal.cursor(), (0, 0));
+    }
+
+    #[test]
+    fn test_colors() {
+        let mut terminal = Terminal::default();
+        terminal.apply(&ScreenRequest::SetColor {
+            foreground: Color::YellowBright,
+            background: Color::Blue,
+        });
+        write(&mut terminal, "x");
+        assert_eq!(
+            terminal.cell(0, 0),
+            Cell {
+                character: 'x',
+                foreground: Color::YellowBright,
+                background: Color::Blue,
+            }
+        );
+        assert_eq!(terminal.cell(0, 1), Cell::default());
+    }
+
+    #[test]
+    fn test_html() {
+        let mut terminal = Terminal::default();
+        terminal.apply(&ScreenRequest::SetColor {
+            foreground: Color::RedBright,
+            background: Color::Black,
+        });
+        write(&mut terminal, "<a&b>");
+        let html = terminal.to_html();
+        let first_line = html.split("</div>").next().unwrap();
+        assert_eq!(
+            first_line,
+            format!(
+                "<div><span class=\"f9 b0\">&lt;a&amp;b&gt;</span>\
+                 <span class=\"f7 b0 cursor\"> </span>\
+                 <span class=\"f7 b0\">{}</span>",
+                " ".repeat(TERMINAL_WIDTH - 6)
+            )
+        );
+        assert_eq!(html.matches("<div>").count(), TERMINAL_HEIGHT);
+    }
+
+    #[test]
+    fn test_palette_covers_all_colors() {
+        for value in 0..16 {
+            let is_color = Color::try_from(value).is_ok();
+            assert_eq!(
+                PALETTE.iter().any(|(color, _)| *color as u8 == value),
+                is_color
+            );
+        }
+        assert!(palette_css().contains(".b15 { background-color: #ffffff; }"));
+    }
+}
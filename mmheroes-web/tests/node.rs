@@ -0,0 +1,31 @@
+//! Тесты, которые запускаются в Node.js без браузера:
+//!
+//! ```sh
+//! cargo test --target wasm32-unknown-unknown
+//! ```
+#![cfg(target_arch = "wasm32")]
+
+use mmheroes_core::logic::GameMode;
+use mmheroes_core::ui::{Input, Locale};
+use mmheroes_web::storage::load_high_scores;
+use mmheroes_web::WebGame;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn game_renders_into_terminal() {
+    let mut game = WebGame::new(GameMode::Normal, 0, None, Locale::Russian);
+    while game.show_frames().is_some() {}
+    assert!(game.terminal().text().contains("ГЕРОИ МАТА И МЕХА"));
+    assert!(game.terminal().to_html().contains("<span class=\"f"));
+
+    for _ in 0..50 {
+        game.send_input(Input::Enter);
+        while game.show_frames().is_some() {}
+    }
+    assert!(!game.is_over());
+}
+
+#[wasm_bindgen_test]
+fn no_local_storage_in_node() {
+    assert_eq!(load_high_scores(), None);
+}